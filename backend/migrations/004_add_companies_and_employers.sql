@@ -0,0 +1,42 @@
+-- Migration: Add companies and employer accounts
+-- Ties job postings to an organisation instead of the free-text jobs.company field
+
+DO $$
+BEGIN
+    IF NOT EXISTS (SELECT 1 FROM pg_type WHERE typname = 'user_role') THEN
+        CREATE TYPE user_role AS ENUM ('job_seeker', 'employer', 'admin');
+    END IF;
+    IF NOT EXISTS (SELECT 1 FROM pg_type WHERE typname = 'verification_status') THEN
+        CREATE TYPE verification_status AS ENUM ('pending', 'verified', 'rejected');
+    END IF;
+END $$;
+
+CREATE TABLE IF NOT EXISTS companies (
+    id SERIAL PRIMARY KEY,
+    name VARCHAR(255) NOT NULL,
+    logo_url TEXT,
+    website TEXT,
+    industry VARCHAR(255),
+    company_size VARCHAR(50),
+    locations TEXT[] NOT NULL DEFAULT '{}',
+    description TEXT,
+    verification_status verification_status NOT NULL DEFAULT 'pending',
+    created_at TIMESTAMP WITH TIME ZONE DEFAULT CURRENT_TIMESTAMP,
+    updated_at TIMESTAMP WITH TIME ZONE DEFAULT CURRENT_TIMESTAMP
+);
+
+-- Employer users belong to a company
+ALTER TABLE users ADD COLUMN IF NOT EXISTS role user_role NOT NULL DEFAULT 'job_seeker';
+ALTER TABLE users ADD COLUMN IF NOT EXISTS company_id INTEGER REFERENCES companies(id) ON DELETE SET NULL;
+
+-- Jobs posted by employers are linked to their company
+ALTER TABLE jobs ADD COLUMN IF NOT EXISTS company_id INTEGER REFERENCES companies(id) ON DELETE SET NULL;
+ALTER TABLE jobs ADD COLUMN IF NOT EXISTS posted_by UUID REFERENCES users(id) ON DELETE SET NULL;
+
+CREATE INDEX IF NOT EXISTS idx_jobs_company_id ON jobs(company_id);
+CREATE INDEX IF NOT EXISTS idx_users_company_id ON users(company_id);
+
+COMMENT ON COLUMN users.role IS 'Account role: job_seeker, employer or admin';
+COMMENT ON COLUMN users.company_id IS 'Company the employer user belongs to';
+COMMENT ON COLUMN jobs.company_id IS 'Company that owns the posting (NULL for legacy/seeded jobs)';
+COMMENT ON COLUMN jobs.posted_by IS 'Employer user who created the posting';
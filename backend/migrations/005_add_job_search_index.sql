@@ -0,0 +1,31 @@
+-- Migration: Full-text search over jobs
+-- Maintains a weighted tsvector over title, skills, description and requirements
+
+ALTER TABLE jobs ADD COLUMN IF NOT EXISTS search_vector TSVECTOR;
+
+-- Function to rebuild the search vector for a job row
+CREATE OR REPLACE FUNCTION update_job_search_vector()
+RETURNS TRIGGER AS $$
+BEGIN
+    NEW.search_vector =
+        setweight(to_tsvector('english', COALESCE(NEW.job_title, '')), 'A') ||
+        setweight(to_tsvector('english', array_to_string(NEW.required_skills, ' ')), 'B') ||
+        setweight(to_tsvector('english', COALESCE(NEW.job_description, '')), 'C') ||
+        setweight(to_tsvector('english', array_to_string(NEW.requirements, ' ')), 'D');
+    RETURN NEW;
+END;
+$$ LANGUAGE plpgsql;
+
+DROP TRIGGER IF EXISTS update_job_search_vector ON jobs;
+CREATE TRIGGER update_job_search_vector
+    BEFORE INSERT OR UPDATE ON jobs
+    FOR EACH ROW
+    EXECUTE FUNCTION update_job_search_vector();
+
+-- Backfill existing rows (fires the trigger)
+UPDATE jobs SET job_title = job_title;
+
+CREATE INDEX IF NOT EXISTS idx_jobs_search_vector ON jobs USING GIN(search_vector);
+CREATE INDEX IF NOT EXISTS idx_jobs_created_at ON jobs(created_at DESC);
+
+COMMENT ON COLUMN jobs.search_vector IS 'Weighted full-text search vector maintained by trigger';
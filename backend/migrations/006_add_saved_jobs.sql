@@ -0,0 +1,27 @@
+-- Migration: Add saved jobs (bookmarks)
+-- Lets users keep jobs for later without creating an application_tracking row
+
+CREATE TABLE IF NOT EXISTS saved_jobs (
+    id SERIAL PRIMARY KEY,
+    user_id UUID NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    job_id INTEGER REFERENCES jobs(id) ON DELETE CASCADE,
+    external_job_id TEXT,
+    external_job JSONB,
+    tags TEXT[] NOT NULL DEFAULT '{}',
+    notes TEXT,
+    created_at TIMESTAMP WITH TIME ZONE DEFAULT CURRENT_TIMESTAMP,
+    updated_at TIMESTAMP WITH TIME ZONE DEFAULT CURRENT_TIMESTAMP,
+    UNIQUE(user_id, job_id),
+    UNIQUE(user_id, external_job_id),
+    -- A bookmark points at exactly one internal or external job
+    CHECK ((job_id IS NULL) <> (external_job_id IS NULL)),
+    CHECK (external_job_id IS NULL OR external_job IS NOT NULL)
+);
+
+CREATE INDEX IF NOT EXISTS idx_saved_jobs_user_id ON saved_jobs(user_id);
+CREATE INDEX IF NOT EXISTS idx_saved_jobs_tags ON saved_jobs USING GIN(tags);
+
+COMMENT ON COLUMN saved_jobs.job_id IS 'Saved internal job (NULL for external jobs)';
+COMMENT ON COLUMN saved_jobs.external_job_id IS 'Source-prefixed ID of a saved external job, e.g. reliefweb_123';
+COMMENT ON COLUMN saved_jobs.external_job IS 'Snapshot of the external job at the time it was saved';
+COMMENT ON COLUMN saved_jobs.tags IS 'User-defined labels, stored lowercase';
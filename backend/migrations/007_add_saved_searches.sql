@@ -0,0 +1,38 @@
+-- Migration: Add saved searches and job alerts
+-- A background task matches new postings against saved searches and writes
+-- job_alert rows into notifications
+
+CREATE TABLE IF NOT EXISTS saved_searches (
+    id SERIAL PRIMARY KEY,
+    user_id UUID NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    name VARCHAR(255) NOT NULL,
+    query TEXT,
+    job_type job_type,
+    experience_level experience_level,
+    location TEXT,
+    salary_min INTEGER,
+    salary_max INTEGER,
+    min_match_score DOUBLE PRECISION CHECK (min_match_score >= 0 AND min_match_score <= 100),
+    email_digest BOOLEAN NOT NULL DEFAULT FALSE,
+    is_active BOOLEAN NOT NULL DEFAULT TRUE,
+    last_checked_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    created_at TIMESTAMP WITH TIME ZONE DEFAULT CURRENT_TIMESTAMP,
+    updated_at TIMESTAMP WITH TIME ZONE DEFAULT CURRENT_TIMESTAMP
+);
+
+CREATE INDEX IF NOT EXISTS idx_saved_searches_user_id ON saved_searches(user_id);
+CREATE INDEX IF NOT EXISTS idx_saved_searches_active ON saved_searches(is_active) WHERE is_active;
+
+-- Link alert notifications to the job and search that produced them
+ALTER TABLE notifications ADD COLUMN IF NOT EXISTS job_id INTEGER REFERENCES jobs(id) ON DELETE CASCADE;
+ALTER TABLE notifications ADD COLUMN IF NOT EXISTS saved_search_id INTEGER REFERENCES saved_searches(id) ON DELETE CASCADE;
+ALTER TABLE notifications ADD COLUMN IF NOT EXISTS emailed_at TIMESTAMP WITH TIME ZONE;
+
+-- A job is announced at most once per saved search
+CREATE UNIQUE INDEX IF NOT EXISTS idx_notifications_search_job ON notifications(saved_search_id, job_id);
+
+COMMENT ON COLUMN saved_searches.query IS 'Free-text query, same syntax as GET /api/jobs/search';
+COMMENT ON COLUMN saved_searches.min_match_score IS 'Only alert on jobs matching the user profile at or above this percentage';
+COMMENT ON COLUMN saved_searches.email_digest IS 'Also send new alerts by email';
+COMMENT ON COLUMN saved_searches.last_checked_at IS 'Postings created after this time are considered new';
+COMMENT ON COLUMN notifications.emailed_at IS 'When the notification was included in an email digest';
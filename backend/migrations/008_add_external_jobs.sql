@@ -0,0 +1,51 @@
+-- Migration: Persist external job postings
+-- External sources are ingested on a schedule instead of being fetched on every request
+
+DO $$
+BEGIN
+    IF NOT EXISTS (SELECT 1 FROM pg_type WHERE typname = 'external_job_category') THEN
+        CREATE TYPE external_job_category AS ENUM ('ngo', 'govt', 'local');
+    END IF;
+END $$;
+
+CREATE TABLE IF NOT EXISTS external_jobs (
+    id SERIAL PRIMARY KEY,
+    source VARCHAR(50) NOT NULL,
+    external_id TEXT NOT NULL,
+    category external_job_category NOT NULL,
+    title TEXT NOT NULL,
+    company TEXT NOT NULL,
+    location TEXT NOT NULL,
+    description TEXT NOT NULL DEFAULT '',
+    url TEXT NOT NULL,
+    posted_date TEXT NOT NULL DEFAULT '',
+    source_label VARCHAR(255) NOT NULL,
+    job_type VARCHAR(100),
+    experience_level VARCHAR(100),
+    skills TEXT[] NOT NULL DEFAULT '{}',
+    salary VARCHAR(255),
+    first_seen_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    last_seen_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    expired_at TIMESTAMP WITH TIME ZONE,
+    UNIQUE(source, external_id)
+);
+
+CREATE INDEX IF NOT EXISTS idx_external_jobs_category ON external_jobs(category) WHERE expired_at IS NULL;
+CREATE INDEX IF NOT EXISTS idx_external_jobs_source_last_seen ON external_jobs(source, last_seen_at);
+
+-- Health of each ingestion source
+CREATE TABLE IF NOT EXISTS external_job_sources (
+    source VARCHAR(50) PRIMARY KEY,
+    last_attempt_at TIMESTAMP WITH TIME ZONE NOT NULL,
+    last_success_at TIMESTAMP WITH TIME ZONE,
+    last_error TEXT,
+    consecutive_failures INTEGER NOT NULL DEFAULT 0,
+    jobs_fetched INTEGER NOT NULL DEFAULT 0
+);
+
+COMMENT ON COLUMN external_jobs.source IS 'Ingestion source key, e.g. reliefweb';
+COMMENT ON COLUMN external_jobs.external_id IS 'Source-prefixed posting ID, e.g. reliefweb_123';
+COMMENT ON COLUMN external_jobs.source_label IS 'Publisher shown to users, e.g. ReliefWeb or BPSC';
+COMMENT ON COLUMN external_jobs.last_seen_at IS 'Last successful fetch that still listed the posting';
+COMMENT ON COLUMN external_jobs.expired_at IS 'Set when the posting has not been seen for a while';
+COMMENT ON COLUMN external_job_sources.jobs_fetched IS 'Postings returned by the last successful fetch';
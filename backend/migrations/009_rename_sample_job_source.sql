@@ -0,0 +1,10 @@
+-- Migration: Rename the sample external job source to "fixture"
+-- Sample listings are now served by an explicit fixture source that can be disabled
+
+UPDATE external_jobs SET source = 'fixture' WHERE source = 'sample';
+
+UPDATE external_job_sources SET source = 'fixture'
+WHERE source = 'sample'
+  AND NOT EXISTS (SELECT 1 FROM external_job_sources WHERE source = 'fixture');
+
+DELETE FROM external_job_sources WHERE source = 'sample';
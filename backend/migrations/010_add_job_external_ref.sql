@@ -0,0 +1,11 @@
+-- Migration: External references for imported jobs
+-- Bulk imports upsert jobs by the partner's own reference, scoped to the company
+
+ALTER TABLE jobs ADD COLUMN IF NOT EXISTS external_ref VARCHAR(255);
+
+COMMENT ON COLUMN jobs.external_ref IS 'Reference from the bulk import source; unique per company';
+
+-- Legacy listings without a company share one namespace (company_id 0)
+CREATE UNIQUE INDEX IF NOT EXISTS idx_jobs_company_external_ref
+    ON jobs ((COALESCE(company_id, 0)), external_ref)
+    WHERE external_ref IS NOT NULL;
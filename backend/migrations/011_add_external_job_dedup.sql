@@ -0,0 +1,24 @@
+-- Migration: Cross-source deduplication of external job postings
+-- The same vacancy listed by several sources is clustered under one canonical posting
+
+ALTER TABLE external_jobs ADD COLUMN IF NOT EXISTS fingerprint TEXT;
+ALTER TABLE external_jobs ADD COLUMN IF NOT EXISTS canonical_id INTEGER REFERENCES external_jobs(id) ON DELETE SET NULL;
+
+COMMENT ON COLUMN external_jobs.fingerprint IS 'Normalized title, company and location used to find duplicates';
+COMMENT ON COLUMN external_jobs.canonical_id IS 'Canonical posting this one duplicates; NULL for canonical postings';
+
+CREATE INDEX IF NOT EXISTS idx_external_jobs_canonical ON external_jobs(canonical_id) WHERE canonical_id IS NOT NULL;
+
+-- Audit log of deduplication decisions
+CREATE TABLE IF NOT EXISTS external_job_merges (
+    id SERIAL PRIMARY KEY,
+    canonical_id INTEGER NOT NULL REFERENCES external_jobs(id) ON DELETE CASCADE,
+    duplicate_id INTEGER NOT NULL REFERENCES external_jobs(id) ON DELETE CASCADE,
+    fingerprint TEXT NOT NULL,
+    similarity REAL,
+    merged BOOLEAN NOT NULL,
+    decided_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    UNIQUE(canonical_id, duplicate_id)
+);
+
+CREATE INDEX IF NOT EXISTS idx_external_job_merges_decided_at ON external_job_merges(decided_at DESC);
@@ -0,0 +1,20 @@
+-- Migration: Job lifecycle
+-- Jobs carry a status, an application deadline and an expiry date; open jobs past
+-- either date are expired by a background task
+
+DO $$
+BEGIN
+    IF NOT EXISTS (SELECT 1 FROM pg_type WHERE typname = 'job_status') THEN
+        CREATE TYPE job_status AS ENUM ('draft', 'open', 'closed', 'expired');
+    END IF;
+END $$;
+
+ALTER TABLE jobs ADD COLUMN IF NOT EXISTS status job_status NOT NULL DEFAULT 'open';
+ALTER TABLE jobs ADD COLUMN IF NOT EXISTS application_deadline TIMESTAMP WITH TIME ZONE;
+ALTER TABLE jobs ADD COLUMN IF NOT EXISTS expires_at TIMESTAMP WITH TIME ZONE;
+
+COMMENT ON COLUMN jobs.application_deadline IS 'Last moment applications are accepted';
+COMMENT ON COLUMN jobs.expires_at IS 'When the listing is taken down';
+
+CREATE INDEX IF NOT EXISTS idx_jobs_status ON jobs(status);
+CREATE INDEX IF NOT EXISTS idx_jobs_application_deadline ON jobs(application_deadline) WHERE status = 'open';
@@ -0,0 +1,64 @@
+-- Migration: Structured salaries
+-- Salaries carry a currency, pay period and negotiable flag, and are normalized to
+-- a monthly BDT figure so internal and external postings can be compared
+
+DO $$
+BEGIN
+    IF NOT EXISTS (SELECT 1 FROM pg_type WHERE typname = 'salary_currency') THEN
+        CREATE TYPE salary_currency AS ENUM ('BDT', 'USD', 'EUR', 'GBP', 'INR');
+    END IF;
+    IF NOT EXISTS (SELECT 1 FROM pg_type WHERE typname = 'salary_period') THEN
+        CREATE TYPE salary_period AS ENUM ('hour', 'day', 'week', 'month', 'year');
+    END IF;
+END $$;
+
+-- Approximate exchange rates and a 40-hour, 5-day week. After changing the rates,
+-- re-normalize stored salaries with e.g. UPDATE jobs SET salary_min = salary_min.
+CREATE OR REPLACE FUNCTION salary_to_monthly_bdt(
+    amount INTEGER,
+    currency salary_currency,
+    period salary_period
+) RETURNS INTEGER
+LANGUAGE sql IMMUTABLE STRICT
+AS $$
+    SELECT LEAST(
+        ROUND(
+            amount::NUMERIC
+            * CASE currency
+                WHEN 'BDT' THEN 1
+                WHEN 'USD' THEN 120
+                WHEN 'EUR' THEN 130
+                WHEN 'GBP' THEN 152
+                WHEN 'INR' THEN 1.42
+            END
+            * CASE period
+                WHEN 'hour' THEN 173.33
+                WHEN 'day' THEN 21.67
+                WHEN 'week' THEN 4.33
+                WHEN 'month' THEN 1
+                WHEN 'year' THEN 1 / 12.0
+            END
+        ),
+        2147483647
+    )::INTEGER
+$$;
+
+ALTER TABLE jobs ADD COLUMN IF NOT EXISTS salary_currency salary_currency NOT NULL DEFAULT 'BDT';
+ALTER TABLE jobs ADD COLUMN IF NOT EXISTS salary_period salary_period NOT NULL DEFAULT 'month';
+ALTER TABLE jobs ADD COLUMN IF NOT EXISTS salary_negotiable BOOLEAN NOT NULL DEFAULT FALSE;
+ALTER TABLE jobs ADD COLUMN IF NOT EXISTS salary_min_monthly_bdt INTEGER
+    GENERATED ALWAYS AS (salary_to_monthly_bdt(salary_min, salary_currency, salary_period)) STORED;
+ALTER TABLE jobs ADD COLUMN IF NOT EXISTS salary_max_monthly_bdt INTEGER
+    GENERATED ALWAYS AS (salary_to_monthly_bdt(salary_max, salary_currency, salary_period)) STORED;
+
+-- Parsed from the source's free-text salary; currency and period are NULL when
+-- the text could not be parsed
+ALTER TABLE external_jobs ADD COLUMN IF NOT EXISTS salary_min INTEGER;
+ALTER TABLE external_jobs ADD COLUMN IF NOT EXISTS salary_max INTEGER;
+ALTER TABLE external_jobs ADD COLUMN IF NOT EXISTS salary_currency salary_currency;
+ALTER TABLE external_jobs ADD COLUMN IF NOT EXISTS salary_period salary_period;
+ALTER TABLE external_jobs ADD COLUMN IF NOT EXISTS salary_negotiable BOOLEAN NOT NULL DEFAULT FALSE;
+ALTER TABLE external_jobs ADD COLUMN IF NOT EXISTS salary_min_monthly_bdt INTEGER
+    GENERATED ALWAYS AS (salary_to_monthly_bdt(salary_min, salary_currency, salary_period)) STORED;
+ALTER TABLE external_jobs ADD COLUMN IF NOT EXISTS salary_max_monthly_bdt INTEGER
+    GENERATED ALWAYS AS (salary_to_monthly_bdt(salary_max, salary_currency, salary_period)) STORED;
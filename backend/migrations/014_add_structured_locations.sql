@@ -0,0 +1,47 @@
+-- Migration: Structured locations
+-- Jobs and external postings carry a country, division, district, city and
+-- coordinates resolved from their location text against the bundled Bangladesh
+-- gazetteer, plus a work arrangement. Users can list preferred locations.
+-- Existing rows are resolved by the API server at startup.
+
+DO $$
+BEGIN
+    IF NOT EXISTS (SELECT 1 FROM pg_type WHERE typname = 'work_arrangement') THEN
+        CREATE TYPE work_arrangement AS ENUM ('on_site', 'hybrid', 'remote');
+    END IF;
+END $$;
+
+-- Great-circle distance in kilometres (haversine)
+CREATE OR REPLACE FUNCTION distance_km(
+    lat1 DOUBLE PRECISION,
+    lon1 DOUBLE PRECISION,
+    lat2 DOUBLE PRECISION,
+    lon2 DOUBLE PRECISION
+) RETURNS DOUBLE PRECISION
+LANGUAGE sql IMMUTABLE STRICT
+AS $$
+    SELECT 2 * 6371 * ASIN(SQRT(
+        POWER(SIN(RADIANS(lat2 - lat1) / 2), 2)
+        + COS(RADIANS(lat1)) * COS(RADIANS(lat2)) * POWER(SIN(RADIANS(lon2 - lon1) / 2), 2)
+    ))
+$$;
+
+ALTER TABLE jobs ADD COLUMN IF NOT EXISTS work_arrangement work_arrangement NOT NULL DEFAULT 'on_site';
+ALTER TABLE jobs ADD COLUMN IF NOT EXISTS country VARCHAR(100);
+ALTER TABLE jobs ADD COLUMN IF NOT EXISTS division VARCHAR(100);
+ALTER TABLE jobs ADD COLUMN IF NOT EXISTS district VARCHAR(100);
+ALTER TABLE jobs ADD COLUMN IF NOT EXISTS city VARCHAR(100);
+ALTER TABLE jobs ADD COLUMN IF NOT EXISTS latitude DOUBLE PRECISION;
+ALTER TABLE jobs ADD COLUMN IF NOT EXISTS longitude DOUBLE PRECISION;
+
+ALTER TABLE external_jobs ADD COLUMN IF NOT EXISTS work_arrangement work_arrangement NOT NULL DEFAULT 'on_site';
+ALTER TABLE external_jobs ADD COLUMN IF NOT EXISTS country VARCHAR(100);
+ALTER TABLE external_jobs ADD COLUMN IF NOT EXISTS division VARCHAR(100);
+ALTER TABLE external_jobs ADD COLUMN IF NOT EXISTS district VARCHAR(100);
+ALTER TABLE external_jobs ADD COLUMN IF NOT EXISTS city VARCHAR(100);
+ALTER TABLE external_jobs ADD COLUMN IF NOT EXISTS latitude DOUBLE PRECISION;
+ALTER TABLE external_jobs ADD COLUMN IF NOT EXISTS longitude DOUBLE PRECISION;
+
+ALTER TABLE users ADD COLUMN IF NOT EXISTS preferred_locations TEXT[] NOT NULL DEFAULT '{}';
+
+CREATE INDEX IF NOT EXISTS idx_jobs_division ON jobs(division);
@@ -0,0 +1,33 @@
+-- Migration: Skill demand trend snapshots
+-- Periodic counts of open jobs requiring each skill, by career track, experience
+-- level and location, so demand can be compared over time
+
+CREATE TABLE IF NOT EXISTS skill_demand_snapshots (
+    id SERIAL PRIMARY KEY,
+    snapshot_date DATE NOT NULL,
+    skill TEXT NOT NULL,
+    skill_key TEXT NOT NULL,
+    track career_track,
+    experience_level experience_level NOT NULL,
+    location VARCHAR(100),
+    job_count INTEGER NOT NULL
+);
+
+COMMENT ON COLUMN skill_demand_snapshots.skill IS 'Most common spelling of the skill on that date';
+COMMENT ON COLUMN skill_demand_snapshots.skill_key IS 'Lowercased skill used to compare snapshots';
+COMMENT ON COLUMN skill_demand_snapshots.track IS 'Career track inferred from the job title; NULL when none matches';
+COMMENT ON COLUMN skill_demand_snapshots.location IS 'Division, or Remote; NULL when unresolved';
+
+-- Open jobs per slice on each snapshot date, to turn skill counts into shares
+CREATE TABLE IF NOT EXISTS job_demand_snapshots (
+    id SERIAL PRIMARY KEY,
+    snapshot_date DATE NOT NULL,
+    track career_track,
+    experience_level experience_level NOT NULL,
+    location VARCHAR(100),
+    job_count INTEGER NOT NULL
+);
+
+CREATE INDEX IF NOT EXISTS idx_skill_demand_snapshots_date ON skill_demand_snapshots(snapshot_date);
+CREATE INDEX IF NOT EXISTS idx_skill_demand_snapshots_skill ON skill_demand_snapshots(skill_key, snapshot_date);
+CREATE INDEX IF NOT EXISTS idx_job_demand_snapshots_date ON job_demand_snapshots(snapshot_date);
@@ -0,0 +1,35 @@
+-- Migration: Cached AI match explanations
+-- One explanation per user and job, valid while the user's profile is unchanged
+-- (users.updated_at) and cleared when the job's matching fields change
+
+CREATE TABLE IF NOT EXISTS match_explanations (
+    user_id UUID NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    job_id INTEGER NOT NULL REFERENCES jobs(id) ON DELETE CASCADE,
+    profile_version TIMESTAMP WITH TIME ZONE NOT NULL,
+    explanation TEXT NOT NULL,
+    created_at TIMESTAMP WITH TIME ZONE DEFAULT CURRENT_TIMESTAMP,
+    PRIMARY KEY (user_id, job_id)
+);
+
+CREATE INDEX IF NOT EXISTS idx_match_explanations_job_id ON match_explanations(job_id);
+
+COMMENT ON COLUMN match_explanations.profile_version IS 'users.updated_at when the explanation was generated';
+
+-- Function to drop cached explanations of an edited job
+CREATE OR REPLACE FUNCTION clear_match_explanations()
+RETURNS TRIGGER AS $$
+BEGIN
+    DELETE FROM match_explanations WHERE job_id = NEW.id;
+    RETURN NEW;
+END;
+$$ LANGUAGE plpgsql;
+
+DROP TRIGGER IF EXISTS clear_match_explanations ON jobs;
+CREATE TRIGGER clear_match_explanations
+    AFTER UPDATE ON jobs
+    FOR EACH ROW
+    WHEN (OLD.job_title IS DISTINCT FROM NEW.job_title
+          OR OLD.required_skills IS DISTINCT FROM NEW.required_skills
+          OR OLD.experience_level IS DISTINCT FROM NEW.experience_level
+          OR OLD.job_description IS DISTINCT FROM NEW.job_description)
+    EXECUTE FUNCTION clear_match_explanations();
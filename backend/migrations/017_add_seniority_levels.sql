@@ -0,0 +1,35 @@
+-- Migration: Senior and lead experience levels, plus years of experience
+-- Levels cover whole years: fresher 0, junior 1-2, mid 3-4, senior 5-7, lead 8+.
+-- Explicit years take precedence over the level when matching users and jobs
+
+ALTER TYPE experience_level ADD VALUE IF NOT EXISTS 'senior' AFTER 'mid';
+ALTER TYPE experience_level ADD VALUE IF NOT EXISTS 'lead' AFTER 'senior';
+
+ALTER TABLE users ADD COLUMN IF NOT EXISTS experience_years INTEGER
+    CHECK (experience_years BETWEEN 0 AND 60);
+
+ALTER TABLE jobs ADD COLUMN IF NOT EXISTS experience_years_min INTEGER
+    CHECK (experience_years_min BETWEEN 0 AND 60);
+ALTER TABLE jobs ADD COLUMN IF NOT EXISTS experience_years_max INTEGER
+    CHECK (experience_years_max BETWEEN 0 AND 60);
+
+ALTER TABLE jobs DROP CONSTRAINT IF EXISTS jobs_experience_years_range;
+ALTER TABLE jobs ADD CONSTRAINT jobs_experience_years_range
+    CHECK (experience_years_max >= experience_years_min);
+
+COMMENT ON COLUMN users.experience_years IS 'Years of professional experience; refines experience_level when set';
+COMMENT ON COLUMN jobs.experience_years_min IS 'Minimum years of experience; NULL uses the experience_level range';
+COMMENT ON COLUMN jobs.experience_years_max IS 'Maximum years of experience; NULL means no upper bound (or the level range when both are NULL)';
+
+-- Year range changes invalidate cached match explanations, which quote them
+DROP TRIGGER IF EXISTS clear_match_explanations ON jobs;
+CREATE TRIGGER clear_match_explanations
+    AFTER UPDATE ON jobs
+    FOR EACH ROW
+    WHEN (OLD.job_title IS DISTINCT FROM NEW.job_title
+          OR OLD.required_skills IS DISTINCT FROM NEW.required_skills
+          OR OLD.experience_level IS DISTINCT FROM NEW.experience_level
+          OR OLD.experience_years_min IS DISTINCT FROM NEW.experience_years_min
+          OR OLD.experience_years_max IS DISTINCT FROM NEW.experience_years_max
+          OR OLD.job_description IS DISTINCT FROM NEW.job_description)
+    EXECUTE FUNCTION clear_match_explanations();
@@ -0,0 +1,70 @@
+-- Migration: Data-driven career tracks
+-- Replaces the career_track enum with an admin-editable table of tracks and
+-- sub-tracks, each with title keywords (used to infer a job's track) and
+-- related skills (used in track alignment). Columns keep the old enum values
+-- as track slugs
+
+CREATE TABLE IF NOT EXISTS career_tracks (
+    slug VARCHAR(50) PRIMARY KEY CHECK (slug ~ '^[a-z][a-z0-9_]*$'),
+    display_name VARCHAR(100) NOT NULL,
+    cv_label VARCHAR(100),
+    parent_slug VARCHAR(50) REFERENCES career_tracks(slug),
+    keywords TEXT[] NOT NULL DEFAULT '{}',
+    related_skills TEXT[] NOT NULL DEFAULT '{}',
+    active BOOLEAN NOT NULL DEFAULT TRUE,
+    created_at TIMESTAMP WITH TIME ZONE DEFAULT CURRENT_TIMESTAMP,
+    updated_at TIMESTAMP WITH TIME ZONE DEFAULT CURRENT_TIMESTAMP,
+    CHECK (parent_slug <> slug)
+);
+
+COMMENT ON COLUMN career_tracks.cv_label IS 'Label on generated CVs; NULL uses display_name';
+COMMENT ON COLUMN career_tracks.parent_slug IS 'Parent track of a sub-track; NULL for top-level tracks';
+COMMENT ON COLUMN career_tracks.keywords IS 'Lowercase job title keywords that identify the track';
+COMMENT ON COLUMN career_tracks.active IS 'Inactive tracks cannot be chosen for profiles or inferred for jobs';
+
+INSERT INTO career_tracks (slug, display_name, cv_label, parent_slug, keywords, related_skills) VALUES
+('web_development', 'Web Development', 'Web Development', NULL,
+    ARRAY['frontend', 'backend', 'full stack', 'web', 'react', 'node'],
+    ARRAY['JavaScript', 'TypeScript', 'HTML', 'CSS', 'React', 'Node.js', 'REST APIs', 'SQL', 'Git']),
+('data', 'Data', 'Data Science & Analytics', NULL,
+    ARRAY['data', 'analyst', 'scientist', 'ml', 'machine learning'],
+    ARRAY['Python', 'SQL', 'Excel', 'Pandas', 'Statistics', 'Machine Learning', 'Data Visualization', 'Power BI', 'Tableau']),
+('design', 'Design', 'UI/UX Design', NULL,
+    ARRAY['designer', 'ui', 'ux', 'graphic'],
+    ARRAY['Figma', 'Adobe XD', 'Photoshop', 'Illustrator', 'Prototyping', 'User Research', 'Wireframing', 'Design Systems']),
+('marketing', 'Marketing', 'Digital Marketing', NULL,
+    ARRAY['marketing', 'seo', 'content', 'social'],
+    ARRAY['SEO', 'Content Strategy', 'Copywriting', 'Google Analytics', 'Social Media Marketing', 'Email Marketing']),
+('product', 'Product & Business Analysis', 'Product Management', NULL,
+    ARRAY['product manager', 'product owner', 'product analyst', 'business analyst'],
+    ARRAY['Product Management', 'Agile', 'Scrum', 'Jira', 'Requirements Analysis', 'User Stories', 'Roadmapping']),
+('finance', 'Finance & Accounting', 'Finance & Accounting', NULL,
+    ARRAY['finance', 'financial', 'accountant', 'accounting', 'audit', 'banking'],
+    ARRAY['Excel', 'Accounting', 'Financial Analysis', 'Financial Modeling', 'Auditing', 'Tally', 'QuickBooks']),
+('ngo_development', 'NGO & Development Sector', 'International Development', NULL,
+    ARRAY['ngo', 'humanitarian', 'programme', 'program officer', 'project officer', 'field officer', 'm&e', 'monitoring and evaluation', 'livelihood'],
+    ARRAY['Monitoring and Evaluation', 'Project Management', 'Report Writing', 'Proposal Writing', 'Community Engagement', 'Data Collection']),
+('devops', 'DevOps & Cloud', 'DevOps & Cloud Engineering', 'web_development',
+    ARRAY['devops', 'sre', 'site reliability', 'cloud', 'infrastructure', 'platform engineer'],
+    ARRAY['Docker', 'Kubernetes', 'AWS', 'Linux', 'CI/CD', 'Terraform', 'Bash']),
+('mobile', 'Mobile Development', 'Mobile App Development', 'web_development',
+    ARRAY['mobile', 'android', 'ios', 'flutter', 'react native'],
+    ARRAY['Kotlin', 'Swift', 'Flutter', 'Dart', 'React Native', 'Java'])
+ON CONFLICT (slug) DO NOTHING;
+
+-- Track columns become slugs referencing career_tracks
+ALTER TABLE users ALTER COLUMN preferred_track TYPE VARCHAR(50) USING preferred_track::text;
+ALTER TABLE skill_demand_snapshots ALTER COLUMN track TYPE VARCHAR(50) USING track::text;
+ALTER TABLE job_demand_snapshots ALTER COLUMN track TYPE VARCHAR(50) USING track::text;
+
+ALTER TABLE users DROP CONSTRAINT IF EXISTS users_preferred_track_fkey;
+ALTER TABLE users ADD CONSTRAINT users_preferred_track_fkey
+    FOREIGN KEY (preferred_track) REFERENCES career_tracks(slug);
+ALTER TABLE skill_demand_snapshots DROP CONSTRAINT IF EXISTS skill_demand_snapshots_track_fkey;
+ALTER TABLE skill_demand_snapshots ADD CONSTRAINT skill_demand_snapshots_track_fkey
+    FOREIGN KEY (track) REFERENCES career_tracks(slug);
+ALTER TABLE job_demand_snapshots DROP CONSTRAINT IF EXISTS job_demand_snapshots_track_fkey;
+ALTER TABLE job_demand_snapshots ADD CONSTRAINT job_demand_snapshots_track_fkey
+    FOREIGN KEY (track) REFERENCES career_tracks(slug);
+
+DROP TYPE IF EXISTS career_track;
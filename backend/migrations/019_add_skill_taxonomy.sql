@@ -0,0 +1,202 @@
+-- Migration: Skill taxonomy
+-- Canonical skills with aliases ("JS", "React.js"), a category and an optional
+-- parent skill. Skills on profiles, jobs, external postings, learning
+-- resources and career tracks are rewritten to their canonical names; unknown
+-- skills are kept as entered
+
+CREATE TABLE IF NOT EXISTS skills (
+    slug VARCHAR(60) PRIMARY KEY CHECK (slug ~ '^[a-z0-9][a-z0-9_]*$'),
+    name VARCHAR(100) NOT NULL UNIQUE,
+    category VARCHAR(50) NOT NULL,
+    parent_slug VARCHAR(60) REFERENCES skills(slug),
+    aliases TEXT[] NOT NULL DEFAULT '{}',
+    created_at TIMESTAMP WITH TIME ZONE DEFAULT CURRENT_TIMESTAMP,
+    updated_at TIMESTAMP WITH TIME ZONE DEFAULT CURRENT_TIMESTAMP,
+    CHECK (parent_slug <> slug)
+);
+
+COMMENT ON COLUMN skills.name IS 'Canonical spelling stored on profiles, jobs and resources';
+COMMENT ON COLUMN skills.category IS 'language, frontend, backend, database, devops, mobile, data, design, marketing, business, finance, development, testing or soft_skill';
+COMMENT ON COLUMN skills.parent_slug IS 'Broader skill implied by this one (React implies JavaScript)';
+COMMENT ON COLUMN skills.aliases IS 'Other spellings; compared ignoring case, spaces and punctuation';
+
+INSERT INTO skills (slug, name, category, parent_slug, aliases) VALUES
+('javascript', 'JavaScript', 'language', NULL, ARRAY['JS', 'JavaScript ES6', 'ES6', 'ECMAScript', 'Vanilla JS']),
+('typescript', 'TypeScript', 'language', 'javascript', ARRAY['TS']),
+('python', 'Python', 'language', NULL, ARRAY['Python3', 'Python 3']),
+('java', 'Java', 'language', NULL, ARRAY['Core Java']),
+('kotlin', 'Kotlin', 'language', NULL, ARRAY[]::TEXT[]),
+('swift', 'Swift', 'language', NULL, ARRAY[]::TEXT[]),
+('dart', 'Dart', 'language', NULL, ARRAY[]::TEXT[]),
+('rust', 'Rust', 'language', NULL, ARRAY['Rust Lang']),
+('go', 'Go', 'language', NULL, ARRAY['Golang']),
+('php', 'PHP', 'language', NULL, ARRAY[]::TEXT[]),
+('csharp', 'C#', 'language', NULL, ARRAY['C Sharp']),
+('cpp', 'C++', 'language', NULL, ARRAY['CPP']),
+('sql', 'SQL', 'language', NULL, ARRAY['Structured Query Language']),
+('bash', 'Bash', 'language', NULL, ARRAY['Shell Scripting', 'Shell']),
+('html', 'HTML', 'frontend', NULL, ARRAY['HTML5']),
+('css', 'CSS', 'frontend', NULL, ARRAY['CSS3']),
+('tailwind_css', 'Tailwind CSS', 'frontend', 'css', ARRAY['Tailwind']),
+('responsive_design', 'Responsive Design', 'frontend', 'css', ARRAY['Responsive Web Design']),
+('dom', 'DOM', 'frontend', 'javascript', ARRAY['DOM Manipulation']),
+('react', 'React', 'frontend', 'javascript', ARRAY['React.js', 'ReactJS']),
+('redux', 'Redux', 'frontend', 'react', ARRAY['Redux Toolkit']),
+('nextjs', 'Next.js', 'frontend', 'react', ARRAY[]::TEXT[]),
+('vuejs', 'Vue.js', 'frontend', 'javascript', ARRAY['Vue']),
+('angular', 'Angular', 'frontend', 'typescript', ARRAY[]::TEXT[]),
+('wordpress', 'WordPress', 'frontend', NULL, ARRAY['WP']),
+('nodejs', 'Node.js', 'backend', 'javascript', ARRAY['Node', 'NodeJS']),
+('express', 'Express', 'backend', 'nodejs', ARRAY['Express.js', 'ExpressJS']),
+('django', 'Django', 'backend', 'python', ARRAY[]::TEXT[]),
+('flask', 'Flask', 'backend', 'python', ARRAY[]::TEXT[]),
+('laravel', 'Laravel', 'backend', 'php', ARRAY[]::TEXT[]),
+('spring_boot', 'Spring Boot', 'backend', 'java', ARRAY['Spring']),
+('rest_apis', 'REST APIs', 'backend', NULL, ARRAY['REST', 'REST API', 'RESTful APIs', 'RESTful API', 'APIs']),
+('api_design', 'API Design', 'backend', NULL, ARRAY[]::TEXT[]),
+('graphql', 'GraphQL', 'backend', NULL, ARRAY[]::TEXT[]),
+('postgresql', 'PostgreSQL', 'database', 'sql', ARRAY['Postgres']),
+('mysql', 'MySQL', 'database', 'sql', ARRAY[]::TEXT[]),
+('mongodb', 'MongoDB', 'database', NULL, ARRAY['Mongo']),
+('redis', 'Redis', 'database', NULL, ARRAY[]::TEXT[]),
+('database_design', 'Database Design', 'database', NULL, ARRAY['Data Modeling', 'Data Modelling']),
+('git', 'Git', 'devops', NULL, ARRAY['Version Control']),
+('github', 'GitHub', 'devops', 'git', ARRAY[]::TEXT[]),
+('linux', 'Linux', 'devops', NULL, ARRAY[]::TEXT[]),
+('docker', 'Docker', 'devops', NULL, ARRAY['Containers', 'Containerization']),
+('kubernetes', 'Kubernetes', 'devops', NULL, ARRAY['K8s']),
+('ci_cd', 'CI/CD', 'devops', NULL, ARRAY['Continuous Integration', 'Continuous Deployment']),
+('terraform', 'Terraform', 'devops', NULL, ARRAY[]::TEXT[]),
+('cloud_computing', 'Cloud Computing', 'devops', NULL, ARRAY['Cloud']),
+('aws', 'AWS', 'devops', 'cloud_computing', ARRAY['Amazon Web Services']),
+('azure', 'Azure', 'devops', 'cloud_computing', ARRAY['Microsoft Azure']),
+('gcp', 'Google Cloud', 'devops', 'cloud_computing', ARRAY['GCP', 'Google Cloud Platform']),
+('devops', 'DevOps', 'devops', NULL, ARRAY[]::TEXT[]),
+('android', 'Android', 'mobile', NULL, ARRAY['Android Development']),
+('ios', 'iOS', 'mobile', NULL, ARRAY['iOS Development']),
+('flutter', 'Flutter', 'mobile', 'dart', ARRAY[]::TEXT[]),
+('react_native', 'React Native', 'mobile', 'react', ARRAY[]::TEXT[]),
+('excel', 'Excel', 'data', NULL, ARRAY['Microsoft Excel', 'MS Excel', 'Advanced Excel']),
+('pivot_tables', 'Pivot Tables', 'data', 'excel', ARRAY[]::TEXT[]),
+('data_analysis', 'Data Analysis', 'data', NULL, ARRAY['Data Analytics']),
+('data_visualization', 'Data Visualization', 'data', NULL, ARRAY['Data Visualisation', 'Data Viz', 'Charts']),
+('statistics', 'Statistics', 'data', NULL, ARRAY['Stats']),
+('pandas', 'Pandas', 'data', 'python', ARRAY[]::TEXT[]),
+('numpy', 'NumPy', 'data', 'python', ARRAY[]::TEXT[]),
+('matplotlib', 'Matplotlib', 'data', 'python', ARRAY[]::TEXT[]),
+('machine_learning', 'Machine Learning', 'data', NULL, ARRAY['ML']),
+('deep_learning', 'Deep Learning', 'data', 'machine_learning', ARRAY[]::TEXT[]),
+('tensorflow', 'TensorFlow', 'data', 'deep_learning', ARRAY[]::TEXT[]),
+('pytorch', 'PyTorch', 'data', 'deep_learning', ARRAY[]::TEXT[]),
+('apache_spark', 'Apache Spark', 'data', NULL, ARRAY['Spark', 'PySpark']),
+('hadoop', 'Hadoop', 'data', NULL, ARRAY['Apache Hadoop']),
+('etl', 'ETL', 'data', NULL, ARRAY['Extract Transform Load']),
+('power_bi', 'Power BI', 'data', NULL, ARRAY['Microsoft Power BI']),
+('tableau', 'Tableau', 'data', NULL, ARRAY[]::TEXT[]),
+('figma', 'Figma', 'design', NULL, ARRAY[]::TEXT[]),
+('adobe_xd', 'Adobe XD', 'design', NULL, ARRAY['XD']),
+('photoshop', 'Photoshop', 'design', NULL, ARRAY['Adobe Photoshop']),
+('illustrator', 'Illustrator', 'design', NULL, ARRAY['Adobe Illustrator']),
+('indesign', 'InDesign', 'design', NULL, ARRAY['Adobe InDesign']),
+('sketch', 'Sketch', 'design', NULL, ARRAY[]::TEXT[]),
+('ui_design', 'UI Design', 'design', NULL, ARRAY['User Interface Design']),
+('ux_design', 'UX Design', 'design', NULL, ARRAY['User Experience Design']),
+('prototyping', 'Prototyping', 'design', NULL, ARRAY[]::TEXT[]),
+('wireframing', 'Wireframing', 'design', NULL, ARRAY['Wireframes']),
+('user_research', 'User Research', 'design', NULL, ARRAY['UX Research']),
+('user_testing', 'User Testing', 'design', 'user_research', ARRAY['Usability Testing']),
+('design_systems', 'Design Systems', 'design', NULL, ARRAY[]::TEXT[]),
+('graphic_design', 'Graphic Design', 'design', NULL, ARRAY[]::TEXT[]),
+('color_theory', 'Color Theory', 'design', NULL, ARRAY['Colour Theory']),
+('design_thinking', 'Design Thinking', 'design', NULL, ARRAY[]::TEXT[]),
+('seo', 'SEO', 'marketing', NULL, ARRAY['Search Engine Optimization', 'Search Engine Optimisation']),
+('keyword_research', 'Keyword Research', 'marketing', 'seo', ARRAY[]::TEXT[]),
+('link_building', 'Link Building', 'marketing', 'seo', ARRAY[]::TEXT[]),
+('content_strategy', 'Content Strategy', 'marketing', NULL, ARRAY[]::TEXT[]),
+('content_marketing', 'Content Marketing', 'marketing', NULL, ARRAY[]::TEXT[]),
+('copywriting', 'Copywriting', 'marketing', NULL, ARRAY['Copy Writing']),
+('google_analytics', 'Google Analytics', 'marketing', NULL, ARRAY['GA4']),
+('social_media_marketing', 'Social Media Marketing', 'marketing', NULL, ARRAY['SMM', 'Social Media']),
+('email_marketing', 'Email Marketing', 'marketing', NULL, ARRAY[]::TEXT[]),
+('ppc', 'PPC', 'marketing', NULL, ARRAY['Pay Per Click']),
+('google_ads', 'Google Ads', 'marketing', 'ppc', ARRAY['Google AdWords', 'AdWords']),
+('facebook_ads', 'Facebook Ads', 'marketing', NULL, ARRAY['Meta Ads']),
+('digital_marketing', 'Digital Marketing', 'marketing', NULL, ARRAY[]::TEXT[]),
+('product_management', 'Product Management', 'business', NULL, ARRAY[]::TEXT[]),
+('agile', 'Agile', 'business', NULL, ARRAY['Agile Methodology']),
+('scrum', 'Scrum', 'business', 'agile', ARRAY[]::TEXT[]),
+('jira', 'Jira', 'business', NULL, ARRAY[]::TEXT[]),
+('requirements_analysis', 'Requirements Analysis', 'business', NULL, ARRAY['Requirements Gathering']),
+('user_stories', 'User Stories', 'business', NULL, ARRAY[]::TEXT[]),
+('roadmapping', 'Roadmapping', 'business', NULL, ARRAY['Product Roadmapping']),
+('ab_testing', 'A/B Testing', 'business', NULL, ARRAY['Split Testing']),
+('project_management', 'Project Management', 'business', NULL, ARRAY[]::TEXT[]),
+('accounting', 'Accounting', 'finance', NULL, ARRAY[]::TEXT[]),
+('auditing', 'Auditing', 'finance', NULL, ARRAY['Audit']),
+('financial_analysis', 'Financial Analysis', 'finance', NULL, ARRAY[]::TEXT[]),
+('financial_modeling', 'Financial Modeling', 'finance', NULL, ARRAY['Financial Modelling']),
+('tally', 'Tally', 'finance', NULL, ARRAY['Tally ERP', 'Tally ERP 9']),
+('quickbooks', 'QuickBooks', 'finance', NULL, ARRAY[]::TEXT[]),
+('monitoring_and_evaluation', 'Monitoring and Evaluation', 'development', NULL, ARRAY['M&E', 'Monitoring & Evaluation', 'MEAL']),
+('proposal_writing', 'Proposal Writing', 'development', NULL, ARRAY['Grant Writing']),
+('report_writing', 'Report Writing', 'development', NULL, ARRAY[]::TEXT[]),
+('community_engagement', 'Community Engagement', 'development', NULL, ARRAY['Community Mobilization', 'Community Mobilisation']),
+('data_collection', 'Data Collection', 'development', NULL, ARRAY[]::TEXT[]),
+('test_automation', 'Test Automation', 'testing', NULL, ARRAY['Automation Testing']),
+('selenium', 'Selenium', 'testing', 'test_automation', ARRAY[]::TEXT[]),
+('cypress', 'Cypress', 'testing', 'test_automation', ARRAY[]::TEXT[]),
+('manual_testing', 'Manual Testing', 'testing', NULL, ARRAY[]::TEXT[]),
+('communication', 'Communication', 'soft_skill', NULL, ARRAY['Communication Skills']),
+('problem_solving', 'Problem Solving', 'soft_skill', NULL, ARRAY[]::TEXT[]),
+('teamwork', 'Teamwork', 'soft_skill', NULL, ARRAY['Team Work']),
+('leadership', 'Leadership', 'soft_skill', NULL, ARRAY[]::TEXT[])
+ON CONFLICT (slug) DO NOTHING;
+
+-- Comparison key of a skill name, as in skills::skill_key
+CREATE OR REPLACE FUNCTION pg_temp.skill_key(name TEXT) RETURNS TEXT
+    LANGUAGE SQL IMMUTABLE
+    AS $$ SELECT regexp_replace(lower(name), '[^[:alnum:]+#&]', '', 'g') $$;
+
+-- Canonical names of a skill list, without blanks or duplicates
+CREATE OR REPLACE FUNCTION pg_temp.canonical_skills(list TEXT[]) RETURNS TEXT[]
+    LANGUAGE SQL STABLE
+    AS $$
+    SELECT COALESCE(array_agg(name ORDER BY position), '{}')
+    FROM (
+        SELECT DISTINCT ON (pg_temp.skill_key(name)) name, position
+        FROM (
+            SELECT
+                COALESCE(
+                    (SELECT s.name
+                     FROM skills s
+                     WHERE pg_temp.skill_key(s.name) = pg_temp.skill_key(item)
+                        OR EXISTS (
+                            SELECT 1 FROM unnest(s.aliases) AS alias
+                            WHERE pg_temp.skill_key(alias) = pg_temp.skill_key(item)
+                        )
+                     ORDER BY pg_temp.skill_key(s.name) = pg_temp.skill_key(item) DESC, s.slug
+                     LIMIT 1),
+                    regexp_replace(btrim(item), '\s+', ' ', 'g')
+                ) AS name,
+                position
+            FROM unnest(list) WITH ORDINALITY AS t(item, position)
+            WHERE btrim(item) <> ''
+        ) named
+        ORDER BY pg_temp.skill_key(name), position
+    ) deduped
+    $$;
+
+UPDATE users SET skills = pg_temp.canonical_skills(skills)
+WHERE skills IS DISTINCT FROM pg_temp.canonical_skills(skills);
+
+UPDATE jobs SET required_skills = pg_temp.canonical_skills(required_skills)
+WHERE required_skills IS DISTINCT FROM pg_temp.canonical_skills(required_skills);
+
+UPDATE external_jobs SET skills = pg_temp.canonical_skills(skills)
+WHERE skills IS DISTINCT FROM pg_temp.canonical_skills(skills);
+
+UPDATE learning_resources SET related_skills = pg_temp.canonical_skills(related_skills)
+WHERE related_skills IS DISTINCT FROM pg_temp.canonical_skills(related_skills);
+
+UPDATE career_tracks SET related_skills = pg_temp.canonical_skills(related_skills)
+WHERE related_skills IS DISTINCT FROM pg_temp.canonical_skills(related_skills);
@@ -0,0 +1,48 @@
+-- Migration: Weighted required vs preferred skills, and skill proficiency
+-- Jobs list nice-to-have skills apart from must-haves and may weight each
+-- skill (1-5; required skills default to 3, preferred to 1). Users record a
+-- proficiency per skill, which earns partial credit in matching
+
+ALTER TABLE jobs ADD COLUMN IF NOT EXISTS preferred_skills TEXT[] NOT NULL DEFAULT '{}';
+ALTER TABLE jobs ADD COLUMN IF NOT EXISTS skill_weights JSONB NOT NULL DEFAULT '{}';
+ALTER TABLE jobs DROP CONSTRAINT IF EXISTS jobs_skill_weights_object;
+ALTER TABLE jobs ADD CONSTRAINT jobs_skill_weights_object
+    CHECK (jsonb_typeof(skill_weights) = 'object');
+
+ALTER TABLE users ADD COLUMN IF NOT EXISTS skill_proficiency JSONB NOT NULL DEFAULT '{}';
+ALTER TABLE users DROP CONSTRAINT IF EXISTS users_skill_proficiency_object;
+ALTER TABLE users ADD CONSTRAINT users_skill_proficiency_object
+    CHECK (jsonb_typeof(skill_proficiency) = 'object');
+
+COMMENT ON COLUMN jobs.required_skills IS 'Must-have skills';
+COMMENT ON COLUMN jobs.preferred_skills IS 'Nice-to-have skills';
+COMMENT ON COLUMN jobs.skill_weights IS 'Weight (1-5) per skill name; unlisted required skills weigh 3, preferred skills 1';
+COMMENT ON COLUMN users.skill_proficiency IS 'Proficiency per skill name: beginner, intermediate, advanced or expert';
+
+-- Search preferred skills alongside required ones
+CREATE OR REPLACE FUNCTION update_job_search_vector()
+RETURNS TRIGGER AS $$
+BEGIN
+    NEW.search_vector =
+        setweight(to_tsvector('english', COALESCE(NEW.job_title, '')), 'A') ||
+        setweight(to_tsvector('english', array_to_string(NEW.required_skills || NEW.preferred_skills, ' ')), 'B') ||
+        setweight(to_tsvector('english', COALESCE(NEW.job_description, '')), 'C') ||
+        setweight(to_tsvector('english', array_to_string(NEW.requirements, ' ')), 'D');
+    RETURN NEW;
+END;
+$$ LANGUAGE plpgsql;
+
+-- Skill changes invalidate cached match explanations
+DROP TRIGGER IF EXISTS clear_match_explanations ON jobs;
+CREATE TRIGGER clear_match_explanations
+    AFTER UPDATE ON jobs
+    FOR EACH ROW
+    WHEN (OLD.job_title IS DISTINCT FROM NEW.job_title
+          OR OLD.required_skills IS DISTINCT FROM NEW.required_skills
+          OR OLD.preferred_skills IS DISTINCT FROM NEW.preferred_skills
+          OR OLD.skill_weights IS DISTINCT FROM NEW.skill_weights
+          OR OLD.experience_level IS DISTINCT FROM NEW.experience_level
+          OR OLD.experience_years_min IS DISTINCT FROM NEW.experience_years_min
+          OR OLD.experience_years_max IS DISTINCT FROM NEW.experience_years_max
+          OR OLD.job_description IS DISTINCT FROM NEW.job_description)
+    EXECUTE FUNCTION clear_match_explanations();
@@ -0,0 +1,24 @@
+-- Migration: Match strategy experiments
+-- Records which match strategy each user is assigned in an A/B experiment,
+-- and the experiment arm behind each application, so strategies can be
+-- compared by application outcomes
+
+CREATE TABLE IF NOT EXISTS match_assignments (
+    user_id UUID NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    experiment VARCHAR(100) NOT NULL,
+    strategy VARCHAR(100) NOT NULL,
+    assigned_at TIMESTAMP WITH TIME ZONE DEFAULT CURRENT_TIMESTAMP,
+    PRIMARY KEY (user_id, experiment)
+);
+
+CREATE INDEX IF NOT EXISTS idx_match_assignments_experiment ON match_assignments(experiment, strategy);
+
+ALTER TABLE application_tracking ADD COLUMN IF NOT EXISTS match_experiment VARCHAR(100);
+ALTER TABLE application_tracking ADD COLUMN IF NOT EXISTS match_strategy VARCHAR(100);
+
+CREATE INDEX IF NOT EXISTS idx_application_tracking_experiment
+    ON application_tracking(match_experiment, match_strategy) WHERE match_experiment IS NOT NULL;
+
+COMMENT ON TABLE match_assignments IS 'Match strategy each user is scored with in an experiment';
+COMMENT ON COLUMN application_tracking.match_experiment IS 'Match experiment running when the user applied';
+COMMENT ON COLUMN application_tracking.match_strategy IS 'Match strategy the user was assigned in match_experiment';
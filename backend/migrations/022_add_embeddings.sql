@@ -0,0 +1,27 @@
+-- Migration: Semantic matching
+-- Stores embedding vectors of jobs, learning resources and user profiles,
+-- with the model and document hash they were computed from, and compares
+-- them in SQL for semantic job search
+
+CREATE OR REPLACE FUNCTION cosine_similarity(a REAL[], b REAL[]) RETURNS REAL
+LANGUAGE sql IMMUTABLE STRICT
+AS $$
+    SELECT CASE
+        WHEN cardinality(a) <> cardinality(b) OR SUM(x * x) = 0 OR SUM(y * y) = 0 THEN 0
+        ELSE (SUM(x * y) / (SQRT(SUM(x * x)) * SQRT(SUM(y * y))))::REAL
+    END
+    FROM unnest(a, b) AS v(x, y)
+$$;
+
+CREATE TABLE IF NOT EXISTS embeddings (
+    entity VARCHAR(20) NOT NULL CHECK (entity IN ('job', 'resource', 'user')),
+    entity_id TEXT NOT NULL,
+    model VARCHAR(200) NOT NULL,
+    content_hash VARCHAR(16) NOT NULL,
+    vector REAL[] NOT NULL,
+    updated_at TIMESTAMP WITH TIME ZONE DEFAULT CURRENT_TIMESTAMP,
+    PRIMARY KEY (entity, entity_id)
+);
+
+COMMENT ON TABLE embeddings IS 'Embedding vectors of jobs, learning resources and user profiles';
+COMMENT ON COLUMN embeddings.content_hash IS 'Hash of the document the vector was computed from';
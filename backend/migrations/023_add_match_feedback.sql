@@ -0,0 +1,54 @@
+-- Migration: Match feedback
+-- Records the latest showing of each recommended job with its score
+-- components, and feedback on recommended jobs (views, saves, applications,
+-- application status changes and "not interested" with a reason), so match
+-- strategies can be evaluated offline and their weights tuned
+
+DO $$
+BEGIN
+    IF NOT EXISTS (SELECT 1 FROM pg_type WHERE typname = 'match_feedback_event') THEN
+        CREATE TYPE match_feedback_event AS ENUM ('view', 'save', 'apply', 'status', 'not_interested');
+    END IF;
+    IF NOT EXISTS (SELECT 1 FROM pg_type WHERE typname = 'not_interested_reason') THEN
+        CREATE TYPE not_interested_reason AS ENUM (
+            'irrelevant', 'skills', 'seniority', 'location', 'salary', 'company', 'other'
+        );
+    END IF;
+END $$;
+
+CREATE TABLE IF NOT EXISTS match_impressions (
+    user_id UUID NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    job_id INTEGER NOT NULL REFERENCES jobs(id) ON DELETE CASCADE,
+    match_strategy VARCHAR(100) NOT NULL,
+    match_score DOUBLE PRECISION NOT NULL,
+    skill_overlap DOUBLE PRECISION NOT NULL,
+    experience_alignment DOUBLE PRECISION NOT NULL,
+    track_alignment DOUBLE PRECISION NOT NULL,
+    semantic_similarity DOUBLE PRECISION,
+    shown_at TIMESTAMP WITH TIME ZONE DEFAULT CURRENT_TIMESTAMP,
+    PRIMARY KEY (user_id, job_id)
+);
+
+CREATE TABLE IF NOT EXISTS match_feedback (
+    id BIGSERIAL PRIMARY KEY,
+    user_id UUID NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    job_id INTEGER NOT NULL REFERENCES jobs(id) ON DELETE CASCADE,
+    event match_feedback_event NOT NULL,
+    reason not_interested_reason,
+    application_status VARCHAR(50),
+    match_strategy VARCHAR(100),
+    match_score DOUBLE PRECISION,
+    skill_overlap DOUBLE PRECISION,
+    experience_alignment DOUBLE PRECISION,
+    track_alignment DOUBLE PRECISION,
+    semantic_similarity DOUBLE PRECISION,
+    created_at TIMESTAMP WITH TIME ZONE DEFAULT CURRENT_TIMESTAMP,
+    CHECK ((event = 'not_interested') = (reason IS NOT NULL))
+);
+
+CREATE INDEX IF NOT EXISTS idx_match_impressions_shown_at ON match_impressions(shown_at);
+CREATE INDEX IF NOT EXISTS idx_match_feedback_user_job ON match_feedback(user_id, job_id);
+CREATE INDEX IF NOT EXISTS idx_match_feedback_created_at ON match_feedback(created_at);
+
+COMMENT ON TABLE match_impressions IS 'Latest showing of each recommended job to a user, with its score components';
+COMMENT ON TABLE match_feedback IS 'Feedback on recommended jobs, with the score components they were shown with';
@@ -0,0 +1,32 @@
+-- Migration: Candidate discovery
+-- Job seekers can opt in to being discoverable, so employers see them ranked
+-- against their jobs with anonymized profiles. Employers ask to contact a
+-- candidate about a job; the candidate's name and email are only shown once
+-- they accept.
+
+DO $$
+BEGIN
+    IF NOT EXISTS (SELECT 1 FROM pg_type WHERE typname = 'contact_request_status') THEN
+        CREATE TYPE contact_request_status AS ENUM ('pending', 'accepted', 'declined');
+    END IF;
+END $$;
+
+ALTER TABLE users ADD COLUMN IF NOT EXISTS discoverable BOOLEAN NOT NULL DEFAULT FALSE;
+
+CREATE TABLE IF NOT EXISTS contact_requests (
+    id SERIAL PRIMARY KEY,
+    job_id INTEGER NOT NULL REFERENCES jobs(id) ON DELETE CASCADE,
+    candidate_id UUID NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    requested_by UUID REFERENCES users(id) ON DELETE SET NULL,
+    message TEXT,
+    status contact_request_status NOT NULL DEFAULT 'pending',
+    created_at TIMESTAMP WITH TIME ZONE DEFAULT CURRENT_TIMESTAMP,
+    responded_at TIMESTAMP WITH TIME ZONE,
+    UNIQUE (job_id, candidate_id)
+);
+
+CREATE INDEX IF NOT EXISTS idx_users_discoverable ON users(id) WHERE discoverable;
+CREATE INDEX IF NOT EXISTS idx_contact_requests_candidate ON contact_requests(candidate_id, created_at DESC);
+
+COMMENT ON COLUMN users.discoverable IS 'Whether employers can find the user (anonymized) as a candidate for their jobs';
+COMMENT ON TABLE contact_requests IS 'Employer requests to contact a discoverable candidate about a job';
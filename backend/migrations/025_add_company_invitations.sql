@@ -0,0 +1,25 @@
+-- Migration: Company invitations
+-- Employers invite users to their company instead of adding them directly;
+-- the user's role and company only change once they accept.
+
+DO $$
+BEGIN
+    IF NOT EXISTS (SELECT 1 FROM pg_type WHERE typname = 'company_invitation_status') THEN
+        CREATE TYPE company_invitation_status AS ENUM ('pending', 'accepted', 'declined');
+    END IF;
+END $$;
+
+CREATE TABLE IF NOT EXISTS company_invitations (
+    id SERIAL PRIMARY KEY,
+    company_id INTEGER NOT NULL REFERENCES companies(id) ON DELETE CASCADE,
+    user_id UUID NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    invited_by UUID REFERENCES users(id) ON DELETE SET NULL,
+    status company_invitation_status NOT NULL DEFAULT 'pending',
+    created_at TIMESTAMP WITH TIME ZONE DEFAULT CURRENT_TIMESTAMP,
+    responded_at TIMESTAMP WITH TIME ZONE,
+    UNIQUE (company_id, user_id)
+);
+
+CREATE INDEX IF NOT EXISTS idx_company_invitations_user ON company_invitations(user_id, created_at DESC);
+
+COMMENT ON TABLE company_invitations IS 'Invitations for users to join a company as employers';
@@ -0,0 +1,10 @@
+-- Migration: Keep every external job deduplication decision
+-- A pair's decisions were overwritten in place; new decisions are now appended
+-- so the history of merges can be audited.
+
+ALTER TABLE external_job_merges DROP CONSTRAINT IF EXISTS external_job_merges_canonical_id_duplicate_id_key;
+
+CREATE INDEX IF NOT EXISTS idx_external_job_merges_pair
+    ON external_job_merges(canonical_id, duplicate_id, decided_at DESC);
+
+COMMENT ON TABLE external_job_merges IS 'Append-only log of external job deduplication decisions';
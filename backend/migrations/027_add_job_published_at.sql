@@ -0,0 +1,30 @@
+-- Migration: Job publication time
+-- Job alerts announce jobs by the time they were opened, so a job saved as a
+-- draft and opened later is still announced
+
+ALTER TABLE jobs ADD COLUMN IF NOT EXISTS published_at TIMESTAMP WITH TIME ZONE;
+
+COMMENT ON COLUMN jobs.published_at IS 'When the job last became open; NULL while it has never been open';
+
+-- Jobs opened before this migration were published when they were created
+UPDATE jobs SET published_at = created_at
+WHERE published_at IS NULL AND status <> 'draft';
+
+CREATE INDEX IF NOT EXISTS idx_jobs_published_at ON jobs(published_at, id) WHERE published_at IS NOT NULL;
+
+-- Function to stamp a job when it becomes open
+CREATE OR REPLACE FUNCTION set_job_published_at()
+RETURNS TRIGGER AS $$
+BEGIN
+    IF NEW.status = 'open' AND (TG_OP = 'INSERT' OR OLD.status <> 'open') THEN
+        NEW.published_at = CURRENT_TIMESTAMP;
+    END IF;
+    RETURN NEW;
+END;
+$$ LANGUAGE plpgsql;
+
+DROP TRIGGER IF EXISTS set_job_published_at ON jobs;
+CREATE TRIGGER set_job_published_at
+    BEFORE INSERT OR UPDATE OF status ON jobs
+    FOR EACH ROW
+    EXECUTE FUNCTION set_job_published_at();
@@ -0,0 +1,9 @@
+-- Migration: In-process job vector index
+-- Semantic search looks up the jobs nearest to a query in an in-memory index
+-- of the stored job vectors instead of computing a cosine per row in SQL.
+-- The index loads vectors changed since its last sync
+
+DROP FUNCTION IF EXISTS cosine_similarity(REAL[], REAL[]);
+
+CREATE INDEX IF NOT EXISTS idx_embeddings_job_updated_at
+    ON embeddings(model, updated_at) WHERE entity = 'job';
@@ -0,0 +1,98 @@
+-- Migration: Exchange rates in a table
+-- Salaries are normalized to monthly BDT with rates from salary_exchange_rates
+-- instead of rates hard-coded in salary_to_monthly_bdt. The *_monthly_bdt
+-- columns become regular columns kept up to date by triggers, and changing a
+-- rate re-normalizes every salary in that currency
+
+CREATE TABLE IF NOT EXISTS salary_exchange_rates (
+    currency salary_currency PRIMARY KEY,
+    bdt_per_unit NUMERIC(12, 4) NOT NULL CHECK (bdt_per_unit > 0),
+    updated_at TIMESTAMP WITH TIME ZONE DEFAULT CURRENT_TIMESTAMP
+);
+
+COMMENT ON TABLE salary_exchange_rates IS 'BDT per unit of each salary currency, used to normalize salaries to monthly BDT';
+
+INSERT INTO salary_exchange_rates (currency, bdt_per_unit) VALUES
+    ('BDT', 1),
+    ('USD', 120),
+    ('EUR', 130),
+    ('GBP', 152),
+    ('INR', 1.42)
+ON CONFLICT (currency) DO NOTHING;
+
+-- Generated columns need an immutable expression; the rates are data now
+ALTER TABLE jobs ALTER COLUMN salary_min_monthly_bdt DROP EXPRESSION IF EXISTS;
+ALTER TABLE jobs ALTER COLUMN salary_max_monthly_bdt DROP EXPRESSION IF EXISTS;
+ALTER TABLE external_jobs ALTER COLUMN salary_min_monthly_bdt DROP EXPRESSION IF EXISTS;
+ALTER TABLE external_jobs ALTER COLUMN salary_max_monthly_bdt DROP EXPRESSION IF EXISTS;
+
+-- Normalizes a salary amount to monthly BDT, assuming a 40-hour, 5-day week.
+-- NULL when the currency has no exchange rate
+CREATE OR REPLACE FUNCTION salary_to_monthly_bdt(
+    amount INTEGER,
+    currency salary_currency,
+    period salary_period
+) RETURNS INTEGER
+LANGUAGE sql STABLE STRICT
+AS $$
+    SELECT LEAST(
+        ROUND(
+            amount::NUMERIC
+            * r.bdt_per_unit
+            * CASE period
+                WHEN 'hour' THEN 173.33
+                WHEN 'day' THEN 21.67
+                WHEN 'week' THEN 4.33
+                WHEN 'month' THEN 1
+                WHEN 'year' THEN 1 / 12.0
+            END
+        ),
+        2147483647
+    )::INTEGER
+    FROM salary_exchange_rates r
+    WHERE r.currency = salary_to_monthly_bdt.currency
+$$;
+
+-- Function to normalize a job's salary to monthly BDT
+CREATE OR REPLACE FUNCTION normalize_salary()
+RETURNS TRIGGER AS $$
+BEGIN
+    NEW.salary_min_monthly_bdt = salary_to_monthly_bdt(NEW.salary_min, NEW.salary_currency, NEW.salary_period);
+    NEW.salary_max_monthly_bdt = salary_to_monthly_bdt(NEW.salary_max, NEW.salary_currency, NEW.salary_period);
+    RETURN NEW;
+END;
+$$ LANGUAGE plpgsql;
+
+DROP TRIGGER IF EXISTS normalize_salary ON jobs;
+CREATE TRIGGER normalize_salary
+    BEFORE INSERT OR UPDATE OF salary_min, salary_max, salary_currency, salary_period ON jobs
+    FOR EACH ROW
+    EXECUTE FUNCTION normalize_salary();
+
+DROP TRIGGER IF EXISTS normalize_salary ON external_jobs;
+CREATE TRIGGER normalize_salary
+    BEFORE INSERT OR UPDATE OF salary_min, salary_max, salary_currency, salary_period ON external_jobs
+    FOR EACH ROW
+    EXECUTE FUNCTION normalize_salary();
+
+-- Function to re-normalize the salaries in a currency whose rate changed
+CREATE OR REPLACE FUNCTION renormalize_salaries()
+RETURNS TRIGGER AS $$
+BEGIN
+    UPDATE jobs SET
+        salary_min_monthly_bdt = salary_to_monthly_bdt(salary_min, salary_currency, salary_period),
+        salary_max_monthly_bdt = salary_to_monthly_bdt(salary_max, salary_currency, salary_period)
+    WHERE salary_currency = NEW.currency;
+    UPDATE external_jobs SET
+        salary_min_monthly_bdt = salary_to_monthly_bdt(salary_min, salary_currency, salary_period),
+        salary_max_monthly_bdt = salary_to_monthly_bdt(salary_max, salary_currency, salary_period)
+    WHERE salary_currency = NEW.currency;
+    RETURN NULL;
+END;
+$$ LANGUAGE plpgsql;
+
+DROP TRIGGER IF EXISTS renormalize_salaries ON salary_exchange_rates;
+CREATE TRIGGER renormalize_salaries
+    AFTER INSERT OR UPDATE OF bdt_per_unit ON salary_exchange_rates
+    FOR EACH ROW
+    EXECUTE FUNCTION renormalize_salaries();
@@ -0,0 +1,13 @@
+-- Migration: Cache match explanations per match strategy
+-- Explanations quote the match score, which depends on the strategy and
+-- weights a request is scored with, so each strategy gets its own entry.
+-- Existing entries don't record their strategy and are dropped
+
+ALTER TABLE match_explanations ADD COLUMN IF NOT EXISTS strategy_hash TEXT NOT NULL DEFAULT '';
+ALTER TABLE match_explanations ALTER COLUMN strategy_hash DROP DEFAULT;
+DELETE FROM match_explanations WHERE strategy_hash = '';
+
+ALTER TABLE match_explanations DROP CONSTRAINT IF EXISTS match_explanations_pkey;
+ALTER TABLE match_explanations ADD PRIMARY KEY (user_id, job_id, strategy_hash);
+
+COMMENT ON COLUMN match_explanations.strategy_hash IS 'Hash of the match strategy (name, weights and settings) the explained score was computed with';
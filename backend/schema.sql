@@ -1,8 +1,291 @@
 -- Create custom enum types
-CREATE TYPE experience_level AS ENUM ('fresher', 'junior', 'mid');
-CREATE TYPE career_track AS ENUM ('web_development', 'data', 'design', 'marketing');
+CREATE TYPE experience_level AS ENUM ('fresher', 'junior', 'mid', 'senior', 'lead');
 CREATE TYPE job_type AS ENUM ('internship', 'part_time', 'full_time', 'freelance');
 CREATE TYPE cost_indicator AS ENUM ('free', 'paid');
+CREATE TYPE user_role AS ENUM ('job_seeker', 'employer', 'admin');
+CREATE TYPE verification_status AS ENUM ('pending', 'verified', 'rejected');
+CREATE TYPE external_job_category AS ENUM ('ngo', 'govt', 'local');
+CREATE TYPE job_status AS ENUM ('draft', 'open', 'closed', 'expired');
+CREATE TYPE salary_currency AS ENUM ('BDT', 'USD', 'EUR', 'GBP', 'INR');
+CREATE TYPE salary_period AS ENUM ('hour', 'day', 'week', 'month', 'year');
+CREATE TYPE work_arrangement AS ENUM ('on_site', 'hybrid', 'remote');
+CREATE TYPE match_feedback_event AS ENUM ('view', 'save', 'apply', 'status', 'not_interested');
+CREATE TYPE not_interested_reason AS ENUM (
+    'irrelevant', 'skills', 'seniority', 'location', 'salary', 'company', 'other'
+);
+CREATE TYPE contact_request_status AS ENUM ('pending', 'accepted', 'declined');
+CREATE TYPE company_invitation_status AS ENUM ('pending', 'accepted', 'declined');
+
+-- Create salary_exchange_rates table (used to normalize salaries to monthly BDT)
+CREATE TABLE salary_exchange_rates (
+    currency salary_currency PRIMARY KEY,
+    bdt_per_unit NUMERIC(12, 4) NOT NULL CHECK (bdt_per_unit > 0),
+    updated_at TIMESTAMP WITH TIME ZONE DEFAULT CURRENT_TIMESTAMP
+);
+
+COMMENT ON TABLE salary_exchange_rates IS 'BDT per unit of each salary currency, used to normalize salaries to monthly BDT';
+
+INSERT INTO salary_exchange_rates (currency, bdt_per_unit) VALUES
+    ('BDT', 1),
+    ('USD', 120),
+    ('EUR', 130),
+    ('GBP', 152),
+    ('INR', 1.42);
+
+-- Normalizes a salary amount to monthly BDT, assuming a 40-hour, 5-day week.
+-- NULL when the currency has no exchange rate
+CREATE OR REPLACE FUNCTION salary_to_monthly_bdt(
+    amount INTEGER,
+    currency salary_currency,
+    period salary_period
+) RETURNS INTEGER
+LANGUAGE sql STABLE STRICT
+AS $$
+    SELECT LEAST(
+        ROUND(
+            amount::NUMERIC
+            * r.bdt_per_unit
+            * CASE period
+                WHEN 'hour' THEN 173.33
+                WHEN 'day' THEN 21.67
+                WHEN 'week' THEN 4.33
+                WHEN 'month' THEN 1
+                WHEN 'year' THEN 1 / 12.0
+            END
+        ),
+        2147483647
+    )::INTEGER
+    FROM salary_exchange_rates r
+    WHERE r.currency = salary_to_monthly_bdt.currency
+$$;
+
+-- Great-circle distance in kilometres (haversine), used by radius filters
+CREATE OR REPLACE FUNCTION distance_km(
+    lat1 DOUBLE PRECISION,
+    lon1 DOUBLE PRECISION,
+    lat2 DOUBLE PRECISION,
+    lon2 DOUBLE PRECISION
+) RETURNS DOUBLE PRECISION
+LANGUAGE sql IMMUTABLE STRICT
+AS $$
+    SELECT 2 * 6371 * ASIN(SQRT(
+        POWER(SIN(RADIANS(lat2 - lat1) / 2), 2)
+        + COS(RADIANS(lat1)) * COS(RADIANS(lat2)) * POWER(SIN(RADIANS(lon2 - lon1) / 2), 2)
+    ))
+$$;
+
+-- Create companies table (employer organisations)
+CREATE TABLE companies (
+    id SERIAL PRIMARY KEY,
+    name VARCHAR(255) NOT NULL,
+    logo_url TEXT,
+    website TEXT,
+    industry VARCHAR(255),
+    company_size VARCHAR(50),
+    locations TEXT[] NOT NULL DEFAULT '{}',
+    description TEXT,
+    verification_status verification_status NOT NULL DEFAULT 'pending',
+    created_at TIMESTAMP WITH TIME ZONE DEFAULT CURRENT_TIMESTAMP,
+    updated_at TIMESTAMP WITH TIME ZONE DEFAULT CURRENT_TIMESTAMP
+);
+
+-- Create career_tracks table (career tracks and sub-tracks, editable by admins)
+CREATE TABLE career_tracks (
+    slug VARCHAR(50) PRIMARY KEY CHECK (slug ~ '^[a-z][a-z0-9_]*$'),
+    display_name VARCHAR(100) NOT NULL,
+    cv_label VARCHAR(100),
+    parent_slug VARCHAR(50) REFERENCES career_tracks(slug),
+    keywords TEXT[] NOT NULL DEFAULT '{}',
+    related_skills TEXT[] NOT NULL DEFAULT '{}',
+    active BOOLEAN NOT NULL DEFAULT TRUE,
+    created_at TIMESTAMP WITH TIME ZONE DEFAULT CURRENT_TIMESTAMP,
+    updated_at TIMESTAMP WITH TIME ZONE DEFAULT CURRENT_TIMESTAMP,
+    CHECK (parent_slug <> slug)
+);
+
+COMMENT ON COLUMN career_tracks.cv_label IS 'Label on generated CVs; NULL uses display_name';
+COMMENT ON COLUMN career_tracks.parent_slug IS 'Parent track of a sub-track; NULL for top-level tracks';
+COMMENT ON COLUMN career_tracks.keywords IS 'Lowercase job title keywords that identify the track';
+COMMENT ON COLUMN career_tracks.active IS 'Inactive tracks cannot be chosen for profiles or inferred for jobs';
+
+INSERT INTO career_tracks (slug, display_name, cv_label, parent_slug, keywords, related_skills) VALUES
+('web_development', 'Web Development', 'Web Development', NULL,
+    ARRAY['frontend', 'backend', 'full stack', 'web', 'react', 'node'],
+    ARRAY['JavaScript', 'TypeScript', 'HTML', 'CSS', 'React', 'Node.js', 'REST APIs', 'SQL', 'Git']),
+('data', 'Data', 'Data Science & Analytics', NULL,
+    ARRAY['data', 'analyst', 'scientist', 'ml', 'machine learning'],
+    ARRAY['Python', 'SQL', 'Excel', 'Pandas', 'Statistics', 'Machine Learning', 'Data Visualization', 'Power BI', 'Tableau']),
+('design', 'Design', 'UI/UX Design', NULL,
+    ARRAY['designer', 'ui', 'ux', 'graphic'],
+    ARRAY['Figma', 'Adobe XD', 'Photoshop', 'Illustrator', 'Prototyping', 'User Research', 'Wireframing', 'Design Systems']),
+('marketing', 'Marketing', 'Digital Marketing', NULL,
+    ARRAY['marketing', 'seo', 'content', 'social'],
+    ARRAY['SEO', 'Content Strategy', 'Copywriting', 'Google Analytics', 'Social Media Marketing', 'Email Marketing']),
+('product', 'Product & Business Analysis', 'Product Management', NULL,
+    ARRAY['product manager', 'product owner', 'product analyst', 'business analyst'],
+    ARRAY['Product Management', 'Agile', 'Scrum', 'Jira', 'Requirements Analysis', 'User Stories', 'Roadmapping']),
+('finance', 'Finance & Accounting', 'Finance & Accounting', NULL,
+    ARRAY['finance', 'financial', 'accountant', 'accounting', 'audit', 'banking'],
+    ARRAY['Excel', 'Accounting', 'Financial Analysis', 'Financial Modeling', 'Auditing', 'Tally', 'QuickBooks']),
+('ngo_development', 'NGO & Development Sector', 'International Development', NULL,
+    ARRAY['ngo', 'humanitarian', 'programme', 'program officer', 'project officer', 'field officer', 'm&e', 'monitoring and evaluation', 'livelihood'],
+    ARRAY['Monitoring and Evaluation', 'Project Management', 'Report Writing', 'Proposal Writing', 'Community Engagement', 'Data Collection']),
+('devops', 'DevOps & Cloud', 'DevOps & Cloud Engineering', 'web_development',
+    ARRAY['devops', 'sre', 'site reliability', 'cloud', 'infrastructure', 'platform engineer'],
+    ARRAY['Docker', 'Kubernetes', 'AWS', 'Linux', 'CI/CD', 'Terraform', 'Bash']),
+('mobile', 'Mobile Development', 'Mobile App Development', 'web_development',
+    ARRAY['mobile', 'android', 'ios', 'flutter', 'react native'],
+    ARRAY['Kotlin', 'Swift', 'Flutter', 'Dart', 'React Native', 'Java'])
+ON CONFLICT (slug) DO NOTHING;
+
+-- Create skills table (skill taxonomy with aliases and parent skills)
+CREATE TABLE skills (
+    slug VARCHAR(60) PRIMARY KEY CHECK (slug ~ '^[a-z0-9][a-z0-9_]*$'),
+    name VARCHAR(100) NOT NULL UNIQUE,
+    category VARCHAR(50) NOT NULL,
+    parent_slug VARCHAR(60) REFERENCES skills(slug),
+    aliases TEXT[] NOT NULL DEFAULT '{}',
+    created_at TIMESTAMP WITH TIME ZONE DEFAULT CURRENT_TIMESTAMP,
+    updated_at TIMESTAMP WITH TIME ZONE DEFAULT CURRENT_TIMESTAMP,
+    CHECK (parent_slug <> slug)
+);
+
+COMMENT ON COLUMN skills.name IS 'Canonical spelling stored on profiles, jobs and resources';
+COMMENT ON COLUMN skills.category IS 'language, frontend, backend, database, devops, mobile, data, design, marketing, business, finance, development, testing or soft_skill';
+COMMENT ON COLUMN skills.parent_slug IS 'Broader skill implied by this one (React implies JavaScript)';
+COMMENT ON COLUMN skills.aliases IS 'Other spellings; compared ignoring case, spaces and punctuation';
+
+INSERT INTO skills (slug, name, category, parent_slug, aliases) VALUES
+('javascript', 'JavaScript', 'language', NULL, ARRAY['JS', 'JavaScript ES6', 'ES6', 'ECMAScript', 'Vanilla JS']),
+('typescript', 'TypeScript', 'language', 'javascript', ARRAY['TS']),
+('python', 'Python', 'language', NULL, ARRAY['Python3', 'Python 3']),
+('java', 'Java', 'language', NULL, ARRAY['Core Java']),
+('kotlin', 'Kotlin', 'language', NULL, ARRAY[]::TEXT[]),
+('swift', 'Swift', 'language', NULL, ARRAY[]::TEXT[]),
+('dart', 'Dart', 'language', NULL, ARRAY[]::TEXT[]),
+('rust', 'Rust', 'language', NULL, ARRAY['Rust Lang']),
+('go', 'Go', 'language', NULL, ARRAY['Golang']),
+('php', 'PHP', 'language', NULL, ARRAY[]::TEXT[]),
+('csharp', 'C#', 'language', NULL, ARRAY['C Sharp']),
+('cpp', 'C++', 'language', NULL, ARRAY['CPP']),
+('sql', 'SQL', 'language', NULL, ARRAY['Structured Query Language']),
+('bash', 'Bash', 'language', NULL, ARRAY['Shell Scripting', 'Shell']),
+('html', 'HTML', 'frontend', NULL, ARRAY['HTML5']),
+('css', 'CSS', 'frontend', NULL, ARRAY['CSS3']),
+('tailwind_css', 'Tailwind CSS', 'frontend', 'css', ARRAY['Tailwind']),
+('responsive_design', 'Responsive Design', 'frontend', 'css', ARRAY['Responsive Web Design']),
+('dom', 'DOM', 'frontend', 'javascript', ARRAY['DOM Manipulation']),
+('react', 'React', 'frontend', 'javascript', ARRAY['React.js', 'ReactJS']),
+('redux', 'Redux', 'frontend', 'react', ARRAY['Redux Toolkit']),
+('nextjs', 'Next.js', 'frontend', 'react', ARRAY[]::TEXT[]),
+('vuejs', 'Vue.js', 'frontend', 'javascript', ARRAY['Vue']),
+('angular', 'Angular', 'frontend', 'typescript', ARRAY[]::TEXT[]),
+('wordpress', 'WordPress', 'frontend', NULL, ARRAY['WP']),
+('nodejs', 'Node.js', 'backend', 'javascript', ARRAY['Node', 'NodeJS']),
+('express', 'Express', 'backend', 'nodejs', ARRAY['Express.js', 'ExpressJS']),
+('django', 'Django', 'backend', 'python', ARRAY[]::TEXT[]),
+('flask', 'Flask', 'backend', 'python', ARRAY[]::TEXT[]),
+('laravel', 'Laravel', 'backend', 'php', ARRAY[]::TEXT[]),
+('spring_boot', 'Spring Boot', 'backend', 'java', ARRAY['Spring']),
+('rest_apis', 'REST APIs', 'backend', NULL, ARRAY['REST', 'REST API', 'RESTful APIs', 'RESTful API', 'APIs']),
+('api_design', 'API Design', 'backend', NULL, ARRAY[]::TEXT[]),
+('graphql', 'GraphQL', 'backend', NULL, ARRAY[]::TEXT[]),
+('postgresql', 'PostgreSQL', 'database', 'sql', ARRAY['Postgres']),
+('mysql', 'MySQL', 'database', 'sql', ARRAY[]::TEXT[]),
+('mongodb', 'MongoDB', 'database', NULL, ARRAY['Mongo']),
+('redis', 'Redis', 'database', NULL, ARRAY[]::TEXT[]),
+('database_design', 'Database Design', 'database', NULL, ARRAY['Data Modeling', 'Data Modelling']),
+('git', 'Git', 'devops', NULL, ARRAY['Version Control']),
+('github', 'GitHub', 'devops', 'git', ARRAY[]::TEXT[]),
+('linux', 'Linux', 'devops', NULL, ARRAY[]::TEXT[]),
+('docker', 'Docker', 'devops', NULL, ARRAY['Containers', 'Containerization']),
+('kubernetes', 'Kubernetes', 'devops', NULL, ARRAY['K8s']),
+('ci_cd', 'CI/CD', 'devops', NULL, ARRAY['Continuous Integration', 'Continuous Deployment']),
+('terraform', 'Terraform', 'devops', NULL, ARRAY[]::TEXT[]),
+('cloud_computing', 'Cloud Computing', 'devops', NULL, ARRAY['Cloud']),
+('aws', 'AWS', 'devops', 'cloud_computing', ARRAY['Amazon Web Services']),
+('azure', 'Azure', 'devops', 'cloud_computing', ARRAY['Microsoft Azure']),
+('gcp', 'Google Cloud', 'devops', 'cloud_computing', ARRAY['GCP', 'Google Cloud Platform']),
+('devops', 'DevOps', 'devops', NULL, ARRAY[]::TEXT[]),
+('android', 'Android', 'mobile', NULL, ARRAY['Android Development']),
+('ios', 'iOS', 'mobile', NULL, ARRAY['iOS Development']),
+('flutter', 'Flutter', 'mobile', 'dart', ARRAY[]::TEXT[]),
+('react_native', 'React Native', 'mobile', 'react', ARRAY[]::TEXT[]),
+('excel', 'Excel', 'data', NULL, ARRAY['Microsoft Excel', 'MS Excel', 'Advanced Excel']),
+('pivot_tables', 'Pivot Tables', 'data', 'excel', ARRAY[]::TEXT[]),
+('data_analysis', 'Data Analysis', 'data', NULL, ARRAY['Data Analytics']),
+('data_visualization', 'Data Visualization', 'data', NULL, ARRAY['Data Visualisation', 'Data Viz', 'Charts']),
+('statistics', 'Statistics', 'data', NULL, ARRAY['Stats']),
+('pandas', 'Pandas', 'data', 'python', ARRAY[]::TEXT[]),
+('numpy', 'NumPy', 'data', 'python', ARRAY[]::TEXT[]),
+('matplotlib', 'Matplotlib', 'data', 'python', ARRAY[]::TEXT[]),
+('machine_learning', 'Machine Learning', 'data', NULL, ARRAY['ML']),
+('deep_learning', 'Deep Learning', 'data', 'machine_learning', ARRAY[]::TEXT[]),
+('tensorflow', 'TensorFlow', 'data', 'deep_learning', ARRAY[]::TEXT[]),
+('pytorch', 'PyTorch', 'data', 'deep_learning', ARRAY[]::TEXT[]),
+('apache_spark', 'Apache Spark', 'data', NULL, ARRAY['Spark', 'PySpark']),
+('hadoop', 'Hadoop', 'data', NULL, ARRAY['Apache Hadoop']),
+('etl', 'ETL', 'data', NULL, ARRAY['Extract Transform Load']),
+('power_bi', 'Power BI', 'data', NULL, ARRAY['Microsoft Power BI']),
+('tableau', 'Tableau', 'data', NULL, ARRAY[]::TEXT[]),
+('figma', 'Figma', 'design', NULL, ARRAY[]::TEXT[]),
+('adobe_xd', 'Adobe XD', 'design', NULL, ARRAY['XD']),
+('photoshop', 'Photoshop', 'design', NULL, ARRAY['Adobe Photoshop']),
+('illustrator', 'Illustrator', 'design', NULL, ARRAY['Adobe Illustrator']),
+('indesign', 'InDesign', 'design', NULL, ARRAY['Adobe InDesign']),
+('sketch', 'Sketch', 'design', NULL, ARRAY[]::TEXT[]),
+('ui_design', 'UI Design', 'design', NULL, ARRAY['User Interface Design']),
+('ux_design', 'UX Design', 'design', NULL, ARRAY['User Experience Design']),
+('prototyping', 'Prototyping', 'design', NULL, ARRAY[]::TEXT[]),
+('wireframing', 'Wireframing', 'design', NULL, ARRAY['Wireframes']),
+('user_research', 'User Research', 'design', NULL, ARRAY['UX Research']),
+('user_testing', 'User Testing', 'design', 'user_research', ARRAY['Usability Testing']),
+('design_systems', 'Design Systems', 'design', NULL, ARRAY[]::TEXT[]),
+('graphic_design', 'Graphic Design', 'design', NULL, ARRAY[]::TEXT[]),
+('color_theory', 'Color Theory', 'design', NULL, ARRAY['Colour Theory']),
+('design_thinking', 'Design Thinking', 'design', NULL, ARRAY[]::TEXT[]),
+('seo', 'SEO', 'marketing', NULL, ARRAY['Search Engine Optimization', 'Search Engine Optimisation']),
+('keyword_research', 'Keyword Research', 'marketing', 'seo', ARRAY[]::TEXT[]),
+('link_building', 'Link Building', 'marketing', 'seo', ARRAY[]::TEXT[]),
+('content_strategy', 'Content Strategy', 'marketing', NULL, ARRAY[]::TEXT[]),
+('content_marketing', 'Content Marketing', 'marketing', NULL, ARRAY[]::TEXT[]),
+('copywriting', 'Copywriting', 'marketing', NULL, ARRAY['Copy Writing']),
+('google_analytics', 'Google Analytics', 'marketing', NULL, ARRAY['GA4']),
+('social_media_marketing', 'Social Media Marketing', 'marketing', NULL, ARRAY['SMM', 'Social Media']),
+('email_marketing', 'Email Marketing', 'marketing', NULL, ARRAY[]::TEXT[]),
+('ppc', 'PPC', 'marketing', NULL, ARRAY['Pay Per Click']),
+('google_ads', 'Google Ads', 'marketing', 'ppc', ARRAY['Google AdWords', 'AdWords']),
+('facebook_ads', 'Facebook Ads', 'marketing', NULL, ARRAY['Meta Ads']),
+('digital_marketing', 'Digital Marketing', 'marketing', NULL, ARRAY[]::TEXT[]),
+('product_management', 'Product Management', 'business', NULL, ARRAY[]::TEXT[]),
+('agile', 'Agile', 'business', NULL, ARRAY['Agile Methodology']),
+('scrum', 'Scrum', 'business', 'agile', ARRAY[]::TEXT[]),
+('jira', 'Jira', 'business', NULL, ARRAY[]::TEXT[]),
+('requirements_analysis', 'Requirements Analysis', 'business', NULL, ARRAY['Requirements Gathering']),
+('user_stories', 'User Stories', 'business', NULL, ARRAY[]::TEXT[]),
+('roadmapping', 'Roadmapping', 'business', NULL, ARRAY['Product Roadmapping']),
+('ab_testing', 'A/B Testing', 'business', NULL, ARRAY['Split Testing']),
+('project_management', 'Project Management', 'business', NULL, ARRAY[]::TEXT[]),
+('accounting', 'Accounting', 'finance', NULL, ARRAY[]::TEXT[]),
+('auditing', 'Auditing', 'finance', NULL, ARRAY['Audit']),
+('financial_analysis', 'Financial Analysis', 'finance', NULL, ARRAY[]::TEXT[]),
+('financial_modeling', 'Financial Modeling', 'finance', NULL, ARRAY['Financial Modelling']),
+('tally', 'Tally', 'finance', NULL, ARRAY['Tally ERP', 'Tally ERP 9']),
+('quickbooks', 'QuickBooks', 'finance', NULL, ARRAY[]::TEXT[]),
+('monitoring_and_evaluation', 'Monitoring and Evaluation', 'development', NULL, ARRAY['M&E', 'Monitoring & Evaluation', 'MEAL']),
+('proposal_writing', 'Proposal Writing', 'development', NULL, ARRAY['Grant Writing']),
+('report_writing', 'Report Writing', 'development', NULL, ARRAY[]::TEXT[]),
+('community_engagement', 'Community Engagement', 'development', NULL, ARRAY['Community Mobilization', 'Community Mobilisation']),
+('data_collection', 'Data Collection', 'development', NULL, ARRAY[]::TEXT[]),
+('test_automation', 'Test Automation', 'testing', NULL, ARRAY['Automation Testing']),
+('selenium', 'Selenium', 'testing', 'test_automation', ARRAY[]::TEXT[]),
+('cypress', 'Cypress', 'testing', 'test_automation', ARRAY[]::TEXT[]),
+('manual_testing', 'Manual Testing', 'testing', NULL, ARRAY[]::TEXT[]),
+('communication', 'Communication', 'soft_skill', NULL, ARRAY['Communication Skills']),
+('problem_solving', 'Problem Solving', 'soft_skill', NULL, ARRAY[]::TEXT[]),
+('teamwork', 'Teamwork', 'soft_skill', NULL, ARRAY['Team Work']),
+('leadership', 'Leadership', 'soft_skill', NULL, ARRAY[]::TEXT[])
+ON CONFLICT (slug) DO NOTHING;
 
 -- Create users table
 CREATE TABLE users (
@@ -15,34 +298,75 @@ CREATE TABLE users (
     avatar_url TEXT,
     education_level VARCHAR(255),
     experience_level experience_level,
-    preferred_track career_track,
+    experience_years INTEGER CHECK (experience_years BETWEEN 0 AND 60),
+    preferred_track VARCHAR(50) REFERENCES career_tracks(slug),
     skills TEXT[] NOT NULL DEFAULT '{}',
+    skill_proficiency JSONB NOT NULL DEFAULT '{}'
+        CONSTRAINT users_skill_proficiency_object CHECK (jsonb_typeof(skill_proficiency) = 'object'),
     projects TEXT[] NOT NULL DEFAULT '{}',
     target_roles TEXT[] NOT NULL DEFAULT '{}',
     profile_completed BOOLEAN DEFAULT FALSE,
     raw_cv_text TEXT,
+    role user_role NOT NULL DEFAULT 'job_seeker',
+    company_id INTEGER REFERENCES companies(id) ON DELETE SET NULL,
+    preferred_locations TEXT[] NOT NULL DEFAULT '{}',
+    discoverable BOOLEAN NOT NULL DEFAULT FALSE,
     created_at TIMESTAMP WITH TIME ZONE DEFAULT CURRENT_TIMESTAMP,
     updated_at TIMESTAMP WITH TIME ZONE DEFAULT CURRENT_TIMESTAMP
 );
 
+COMMENT ON COLUMN users.skill_proficiency IS 'Proficiency per skill name: beginner, intermediate, advanced or expert';
+COMMENT ON COLUMN users.discoverable IS 'Whether employers can find the user (anonymized) as a candidate for their jobs';
+
 -- Create jobs table
 CREATE TABLE jobs (
     id SERIAL PRIMARY KEY,
     job_title VARCHAR(255) NOT NULL,
     company VARCHAR(255) NOT NULL,
     location VARCHAR(255) NOT NULL,
+    work_arrangement work_arrangement NOT NULL DEFAULT 'on_site',
+    country VARCHAR(100),
+    division VARCHAR(100),
+    district VARCHAR(100),
+    city VARCHAR(100),
+    latitude DOUBLE PRECISION,
+    longitude DOUBLE PRECISION,
     job_description TEXT NOT NULL,
     required_skills TEXT[] NOT NULL DEFAULT '{}',
+    preferred_skills TEXT[] NOT NULL DEFAULT '{}',
+    skill_weights JSONB NOT NULL DEFAULT '{}'
+        CONSTRAINT jobs_skill_weights_object CHECK (jsonb_typeof(skill_weights) = 'object'),
     experience_level experience_level NOT NULL,
+    experience_years_min INTEGER CHECK (experience_years_min BETWEEN 0 AND 60),
+    experience_years_max INTEGER CHECK (experience_years_max BETWEEN 0 AND 60),
     job_type job_type NOT NULL,
     salary_min INTEGER,
     salary_max INTEGER,
+    salary_currency salary_currency NOT NULL DEFAULT 'BDT',
+    salary_period salary_period NOT NULL DEFAULT 'month',
+    salary_negotiable BOOLEAN NOT NULL DEFAULT FALSE,
+    salary_min_monthly_bdt INTEGER,
+    salary_max_monthly_bdt INTEGER,
     responsibilities TEXT[] NOT NULL DEFAULT '{}',
     requirements TEXT[] NOT NULL DEFAULT '{}',
     benefits TEXT[] NOT NULL DEFAULT '{}',
-    created_at TIMESTAMP WITH TIME ZONE DEFAULT CURRENT_TIMESTAMP
+    company_id INTEGER REFERENCES companies(id) ON DELETE SET NULL,
+    posted_by UUID REFERENCES users(id) ON DELETE SET NULL,
+    external_ref VARCHAR(255),
+    status job_status NOT NULL DEFAULT 'open',
+    application_deadline TIMESTAMP WITH TIME ZONE,
+    expires_at TIMESTAMP WITH TIME ZONE,
+    published_at TIMESTAMP WITH TIME ZONE,
+    search_vector TSVECTOR,
+    created_at TIMESTAMP WITH TIME ZONE DEFAULT CURRENT_TIMESTAMP,
+    CONSTRAINT jobs_experience_years_range CHECK (experience_years_max >= experience_years_min)
 );
 
+COMMENT ON COLUMN jobs.required_skills IS 'Must-have skills';
+COMMENT ON COLUMN jobs.preferred_skills IS 'Nice-to-have skills';
+COMMENT ON COLUMN jobs.published_at IS 'When the job last became open; NULL while it has never been open';
+COMMENT ON COLUMN jobs.skill_weights IS 'Weight (1-5) per skill name; unlisted required skills weigh 3, preferred skills 1';
+
 -- Create learning_resources table
 CREATE TABLE learning_resources (
     id SERIAL PRIMARY KEY,
@@ -62,9 +386,94 @@ CREATE TABLE application_tracking (
     status VARCHAR(50) NOT NULL DEFAULT 'applied',
     applied_at TIMESTAMP WITH TIME ZONE DEFAULT CURRENT_TIMESTAMP,
     notes TEXT,
+    match_experiment VARCHAR(100),
+    match_strategy VARCHAR(100),
     UNIQUE(user_id, job_id)
 );
 
+COMMENT ON COLUMN application_tracking.match_experiment IS 'Match experiment running when the user applied';
+COMMENT ON COLUMN application_tracking.match_strategy IS 'Match strategy the user was assigned in match_experiment';
+
+-- Create external_jobs table (postings ingested from external sources)
+CREATE TABLE external_jobs (
+    id SERIAL PRIMARY KEY,
+    source VARCHAR(50) NOT NULL,
+    external_id TEXT NOT NULL,
+    category external_job_category NOT NULL,
+    title TEXT NOT NULL,
+    company TEXT NOT NULL,
+    location TEXT NOT NULL,
+    work_arrangement work_arrangement NOT NULL DEFAULT 'on_site',
+    country VARCHAR(100),
+    division VARCHAR(100),
+    district VARCHAR(100),
+    city VARCHAR(100),
+    latitude DOUBLE PRECISION,
+    longitude DOUBLE PRECISION,
+    description TEXT NOT NULL DEFAULT '',
+    url TEXT NOT NULL,
+    posted_date TEXT NOT NULL DEFAULT '',
+    source_label VARCHAR(255) NOT NULL,
+    job_type VARCHAR(100),
+    experience_level VARCHAR(100),
+    skills TEXT[] NOT NULL DEFAULT '{}',
+    salary VARCHAR(255),
+    salary_min INTEGER,
+    salary_max INTEGER,
+    salary_currency salary_currency,
+    salary_period salary_period,
+    salary_negotiable BOOLEAN NOT NULL DEFAULT FALSE,
+    salary_min_monthly_bdt INTEGER,
+    salary_max_monthly_bdt INTEGER,
+    first_seen_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    last_seen_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    expired_at TIMESTAMP WITH TIME ZONE,
+    fingerprint TEXT,
+    canonical_id INTEGER REFERENCES external_jobs(id) ON DELETE SET NULL,
+    UNIQUE(source, external_id)
+);
+
+-- Create external_job_sources table (ingestion health per source)
+CREATE TABLE external_job_sources (
+    source VARCHAR(50) PRIMARY KEY,
+    last_attempt_at TIMESTAMP WITH TIME ZONE NOT NULL,
+    last_success_at TIMESTAMP WITH TIME ZONE,
+    last_error TEXT,
+    consecutive_failures INTEGER NOT NULL DEFAULT 0,
+    jobs_fetched INTEGER NOT NULL DEFAULT 0
+);
+
+-- Create external_job_merges table (audit log of deduplication decisions)
+CREATE TABLE external_job_merges (
+    id SERIAL PRIMARY KEY,
+    canonical_id INTEGER NOT NULL REFERENCES external_jobs(id) ON DELETE CASCADE,
+    duplicate_id INTEGER NOT NULL REFERENCES external_jobs(id) ON DELETE CASCADE,
+    fingerprint TEXT NOT NULL,
+    similarity REAL,
+    merged BOOLEAN NOT NULL,
+    decided_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT CURRENT_TIMESTAMP
+);
+
+COMMENT ON TABLE external_job_merges IS 'Append-only log of external job deduplication decisions';
+
+-- Create saved_jobs table (bookmarks for internal and external jobs)
+CREATE TABLE saved_jobs (
+    id SERIAL PRIMARY KEY,
+    user_id UUID NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    job_id INTEGER REFERENCES jobs(id) ON DELETE CASCADE,
+    external_job_id TEXT,
+    external_job JSONB,
+    tags TEXT[] NOT NULL DEFAULT '{}',
+    notes TEXT,
+    created_at TIMESTAMP WITH TIME ZONE DEFAULT CURRENT_TIMESTAMP,
+    updated_at TIMESTAMP WITH TIME ZONE DEFAULT CURRENT_TIMESTAMP,
+    UNIQUE(user_id, job_id),
+    UNIQUE(user_id, external_job_id),
+    -- A bookmark points at exactly one internal or external job
+    CHECK ((job_id IS NULL) <> (external_job_id IS NULL)),
+    CHECK (external_job_id IS NULL OR external_job IS NOT NULL)
+);
+
 -- Create user_progress table
 CREATE TABLE user_progress (
     id SERIAL PRIMARY KEY,
@@ -76,6 +485,25 @@ CREATE TABLE user_progress (
     UNIQUE(user_id, resource_id)
 );
 
+-- Create saved_searches table (job alerts)
+CREATE TABLE saved_searches (
+    id SERIAL PRIMARY KEY,
+    user_id UUID NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    name VARCHAR(255) NOT NULL,
+    query TEXT,
+    job_type job_type,
+    experience_level experience_level,
+    location TEXT,
+    salary_min INTEGER,
+    salary_max INTEGER,
+    min_match_score DOUBLE PRECISION CHECK (min_match_score >= 0 AND min_match_score <= 100),
+    email_digest BOOLEAN NOT NULL DEFAULT FALSE,
+    is_active BOOLEAN NOT NULL DEFAULT TRUE,
+    last_checked_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    created_at TIMESTAMP WITH TIME ZONE DEFAULT CURRENT_TIMESTAMP,
+    updated_at TIMESTAMP WITH TIME ZONE DEFAULT CURRENT_TIMESTAMP
+);
+
 -- Create notifications table
 CREATE TABLE notifications (
     id SERIAL PRIMARY KEY,
@@ -84,7 +512,10 @@ CREATE TABLE notifications (
     message TEXT NOT NULL,
     type VARCHAR(50) NOT NULL,
     is_read BOOLEAN DEFAULT FALSE,
-    created_at TIMESTAMP WITH TIME ZONE DEFAULT CURRENT_TIMESTAMP
+    created_at TIMESTAMP WITH TIME ZONE DEFAULT CURRENT_TIMESTAMP,
+    job_id INTEGER REFERENCES jobs(id) ON DELETE CASCADE,
+    saved_search_id INTEGER REFERENCES saved_searches(id) ON DELETE CASCADE,
+    emailed_at TIMESTAMP WITH TIME ZONE
 );
 
 -- Create skill_assessments table
@@ -96,17 +527,180 @@ CREATE TABLE skill_assessments (
     assessed_at TIMESTAMP WITH TIME ZONE DEFAULT CURRENT_TIMESTAMP
 );
 
+-- Create skill_demand_snapshots table (skill demand trends)
+CREATE TABLE skill_demand_snapshots (
+    id SERIAL PRIMARY KEY,
+    snapshot_date DATE NOT NULL,
+    skill TEXT NOT NULL,
+    skill_key TEXT NOT NULL,
+    track VARCHAR(50) REFERENCES career_tracks(slug),
+    experience_level experience_level NOT NULL,
+    location VARCHAR(100),
+    job_count INTEGER NOT NULL
+);
+
+COMMENT ON COLUMN skill_demand_snapshots.skill IS 'Most common spelling of the skill on that date';
+COMMENT ON COLUMN skill_demand_snapshots.skill_key IS 'Lowercased skill used to compare snapshots';
+COMMENT ON COLUMN skill_demand_snapshots.track IS 'Career track inferred from the job title; NULL when none matches';
+COMMENT ON COLUMN skill_demand_snapshots.location IS 'Division, or Remote; NULL when unresolved';
+
+-- Create job_demand_snapshots table (open jobs per slice, for demand shares)
+CREATE TABLE job_demand_snapshots (
+    id SERIAL PRIMARY KEY,
+    snapshot_date DATE NOT NULL,
+    track VARCHAR(50) REFERENCES career_tracks(slug),
+    experience_level experience_level NOT NULL,
+    location VARCHAR(100),
+    job_count INTEGER NOT NULL
+);
+
+-- Create match_explanations table (cached AI match explanations)
+CREATE TABLE match_explanations (
+    user_id UUID NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    job_id INTEGER NOT NULL REFERENCES jobs(id) ON DELETE CASCADE,
+    profile_version TIMESTAMP WITH TIME ZONE NOT NULL,
+    strategy_hash TEXT NOT NULL,
+    explanation TEXT NOT NULL,
+    created_at TIMESTAMP WITH TIME ZONE DEFAULT CURRENT_TIMESTAMP,
+    PRIMARY KEY (user_id, job_id, strategy_hash)
+);
+
+-- Create match_assignments table (match strategy per user per experiment)
+CREATE TABLE match_assignments (
+    user_id UUID NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    experiment VARCHAR(100) NOT NULL,
+    strategy VARCHAR(100) NOT NULL,
+    assigned_at TIMESTAMP WITH TIME ZONE DEFAULT CURRENT_TIMESTAMP,
+    PRIMARY KEY (user_id, experiment)
+);
+
+COMMENT ON TABLE match_assignments IS 'Match strategy each user is scored with in an experiment';
+
+-- Create embeddings table (vectors for semantic matching)
+CREATE TABLE embeddings (
+    entity VARCHAR(20) NOT NULL CHECK (entity IN ('job', 'resource', 'user')),
+    entity_id TEXT NOT NULL,
+    model VARCHAR(200) NOT NULL,
+    content_hash VARCHAR(16) NOT NULL,
+    vector REAL[] NOT NULL,
+    updated_at TIMESTAMP WITH TIME ZONE DEFAULT CURRENT_TIMESTAMP,
+    PRIMARY KEY (entity, entity_id)
+);
+
+COMMENT ON TABLE embeddings IS 'Embedding vectors of jobs, learning resources and user profiles';
+
+-- Create match_impressions table (latest showing of each recommended job)
+CREATE TABLE match_impressions (
+    user_id UUID NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    job_id INTEGER NOT NULL REFERENCES jobs(id) ON DELETE CASCADE,
+    match_strategy VARCHAR(100) NOT NULL,
+    match_score DOUBLE PRECISION NOT NULL,
+    skill_overlap DOUBLE PRECISION NOT NULL,
+    experience_alignment DOUBLE PRECISION NOT NULL,
+    track_alignment DOUBLE PRECISION NOT NULL,
+    semantic_similarity DOUBLE PRECISION,
+    shown_at TIMESTAMP WITH TIME ZONE DEFAULT CURRENT_TIMESTAMP,
+    PRIMARY KEY (user_id, job_id)
+);
+
+COMMENT ON TABLE match_impressions IS 'Latest showing of each recommended job to a user, with its score components';
+
+-- Create match_feedback table (feedback on recommended jobs)
+CREATE TABLE match_feedback (
+    id BIGSERIAL PRIMARY KEY,
+    user_id UUID NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    job_id INTEGER NOT NULL REFERENCES jobs(id) ON DELETE CASCADE,
+    event match_feedback_event NOT NULL,
+    reason not_interested_reason,
+    application_status VARCHAR(50),
+    match_strategy VARCHAR(100),
+    match_score DOUBLE PRECISION,
+    skill_overlap DOUBLE PRECISION,
+    experience_alignment DOUBLE PRECISION,
+    track_alignment DOUBLE PRECISION,
+    semantic_similarity DOUBLE PRECISION,
+    created_at TIMESTAMP WITH TIME ZONE DEFAULT CURRENT_TIMESTAMP,
+    CHECK ((event = 'not_interested') = (reason IS NOT NULL))
+);
+
+COMMENT ON TABLE match_feedback IS 'Feedback on recommended jobs, with the score components they were shown with';
+
+-- Create contact_requests table (employer requests to contact discoverable candidates)
+CREATE TABLE contact_requests (
+    id SERIAL PRIMARY KEY,
+    job_id INTEGER NOT NULL REFERENCES jobs(id) ON DELETE CASCADE,
+    candidate_id UUID NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    requested_by UUID REFERENCES users(id) ON DELETE SET NULL,
+    message TEXT,
+    status contact_request_status NOT NULL DEFAULT 'pending',
+    created_at TIMESTAMP WITH TIME ZONE DEFAULT CURRENT_TIMESTAMP,
+    responded_at TIMESTAMP WITH TIME ZONE,
+    UNIQUE (job_id, candidate_id)
+);
+
+COMMENT ON TABLE contact_requests IS 'Employer requests to contact a discoverable candidate about a job';
+
+-- Create company_invitations table (users invited to join a company as employers)
+CREATE TABLE company_invitations (
+    id SERIAL PRIMARY KEY,
+    company_id INTEGER NOT NULL REFERENCES companies(id) ON DELETE CASCADE,
+    user_id UUID NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    invited_by UUID REFERENCES users(id) ON DELETE SET NULL,
+    status company_invitation_status NOT NULL DEFAULT 'pending',
+    created_at TIMESTAMP WITH TIME ZONE DEFAULT CURRENT_TIMESTAMP,
+    responded_at TIMESTAMP WITH TIME ZONE,
+    UNIQUE (company_id, user_id)
+);
+
+COMMENT ON TABLE company_invitations IS 'Invitations for users to join a company as employers';
+
 -- Create indexes
 CREATE INDEX idx_users_email ON users(email);
 CREATE UNIQUE INDEX idx_users_oauth ON users(oauth_provider, oauth_id) WHERE oauth_provider IS NOT NULL;
 CREATE INDEX idx_jobs_experience_level ON jobs(experience_level);
 CREATE INDEX idx_jobs_job_type ON jobs(job_type);
+CREATE INDEX idx_jobs_company_id ON jobs(company_id);
+CREATE INDEX idx_jobs_search_vector ON jobs USING GIN(search_vector);
+CREATE INDEX idx_jobs_created_at ON jobs(created_at DESC);
+CREATE INDEX idx_jobs_published_at ON jobs(published_at, id) WHERE published_at IS NOT NULL;
+CREATE INDEX idx_jobs_status ON jobs(status);
+CREATE INDEX idx_jobs_application_deadline ON jobs(application_deadline) WHERE status = 'open';
+CREATE INDEX idx_jobs_division ON jobs(division);
+CREATE UNIQUE INDEX idx_jobs_company_external_ref
+    ON jobs ((COALESCE(company_id, 0)), external_ref)
+    WHERE external_ref IS NOT NULL;
+CREATE INDEX idx_users_company_id ON users(company_id);
 CREATE INDEX idx_application_tracking_user_id ON application_tracking(user_id);
 CREATE INDEX idx_application_tracking_status ON application_tracking(status);
 CREATE INDEX idx_user_progress_user_id ON user_progress(user_id);
 CREATE INDEX idx_notifications_user_id ON notifications(user_id);
 CREATE INDEX idx_notifications_is_read ON notifications(is_read);
+CREATE UNIQUE INDEX idx_notifications_search_job ON notifications(saved_search_id, job_id);
+CREATE INDEX idx_saved_searches_user_id ON saved_searches(user_id);
+CREATE INDEX idx_saved_searches_active ON saved_searches(is_active) WHERE is_active;
 CREATE INDEX idx_skill_assessments_user_id ON skill_assessments(user_id);
+CREATE INDEX idx_saved_jobs_user_id ON saved_jobs(user_id);
+CREATE INDEX idx_external_jobs_category ON external_jobs(category) WHERE expired_at IS NULL;
+CREATE INDEX idx_external_jobs_source_last_seen ON external_jobs(source, last_seen_at);
+CREATE INDEX idx_external_jobs_canonical ON external_jobs(canonical_id) WHERE canonical_id IS NOT NULL;
+CREATE INDEX idx_external_job_merges_decided_at ON external_job_merges(decided_at DESC);
+CREATE INDEX idx_external_job_merges_pair
+    ON external_job_merges(canonical_id, duplicate_id, decided_at DESC);
+CREATE INDEX idx_saved_jobs_tags ON saved_jobs USING GIN(tags);
+CREATE INDEX idx_skill_demand_snapshots_date ON skill_demand_snapshots(snapshot_date);
+CREATE INDEX idx_skill_demand_snapshots_skill ON skill_demand_snapshots(skill_key, snapshot_date);
+CREATE INDEX idx_job_demand_snapshots_date ON job_demand_snapshots(snapshot_date);
+CREATE INDEX idx_match_explanations_job_id ON match_explanations(job_id);
+CREATE INDEX idx_embeddings_job_updated_at ON embeddings(model, updated_at) WHERE entity = 'job';
+CREATE INDEX idx_match_assignments_experiment ON match_assignments(experiment, strategy);
+CREATE INDEX idx_application_tracking_experiment
+    ON application_tracking(match_experiment, match_strategy) WHERE match_experiment IS NOT NULL;
+CREATE INDEX idx_match_impressions_shown_at ON match_impressions(shown_at);
+CREATE INDEX idx_match_feedback_user_job ON match_feedback(user_id, job_id);
+CREATE INDEX idx_match_feedback_created_at ON match_feedback(created_at);
+CREATE INDEX idx_users_discoverable ON users(id) WHERE discoverable;
+CREATE INDEX idx_contact_requests_candidate ON contact_requests(candidate_id, created_at DESC);
+CREATE INDEX idx_company_invitations_user ON company_invitations(user_id, created_at DESC);
 
 -- Career roadmaps table for AI-generated learning paths (Part 2, Point 4)
 CREATE TABLE career_roadmaps (
@@ -148,3 +742,105 @@ CREATE TRIGGER update_roadmap_timestamp
     BEFORE UPDATE ON career_roadmaps
     FOR EACH ROW
     EXECUTE FUNCTION update_roadmap_timestamp();
+
+-- Function to rebuild the full-text search vector for a job
+CREATE OR REPLACE FUNCTION update_job_search_vector()
+RETURNS TRIGGER AS $$
+BEGIN
+    NEW.search_vector =
+        setweight(to_tsvector('english', COALESCE(NEW.job_title, '')), 'A') ||
+        setweight(to_tsvector('english', array_to_string(NEW.required_skills || NEW.preferred_skills, ' ')), 'B') ||
+        setweight(to_tsvector('english', COALESCE(NEW.job_description, '')), 'C') ||
+        setweight(to_tsvector('english', array_to_string(NEW.requirements, ' ')), 'D');
+    RETURN NEW;
+END;
+$$ LANGUAGE plpgsql;
+
+-- Trigger to keep jobs.search_vector in sync
+CREATE TRIGGER update_job_search_vector
+    BEFORE INSERT OR UPDATE ON jobs
+    FOR EACH ROW
+    EXECUTE FUNCTION update_job_search_vector();
+
+-- Function to normalize a job's salary to monthly BDT
+CREATE OR REPLACE FUNCTION normalize_salary()
+RETURNS TRIGGER AS $$
+BEGIN
+    NEW.salary_min_monthly_bdt = salary_to_monthly_bdt(NEW.salary_min, NEW.salary_currency, NEW.salary_period);
+    NEW.salary_max_monthly_bdt = salary_to_monthly_bdt(NEW.salary_max, NEW.salary_currency, NEW.salary_period);
+    RETURN NEW;
+END;
+$$ LANGUAGE plpgsql;
+
+-- Triggers to keep the *_monthly_bdt salary columns in sync
+CREATE TRIGGER normalize_salary
+    BEFORE INSERT OR UPDATE OF salary_min, salary_max, salary_currency, salary_period ON jobs
+    FOR EACH ROW
+    EXECUTE FUNCTION normalize_salary();
+
+CREATE TRIGGER normalize_salary
+    BEFORE INSERT OR UPDATE OF salary_min, salary_max, salary_currency, salary_period ON external_jobs
+    FOR EACH ROW
+    EXECUTE FUNCTION normalize_salary();
+
+-- Function to re-normalize the salaries in a currency whose rate changed
+CREATE OR REPLACE FUNCTION renormalize_salaries()
+RETURNS TRIGGER AS $$
+BEGIN
+    UPDATE jobs SET
+        salary_min_monthly_bdt = salary_to_monthly_bdt(salary_min, salary_currency, salary_period),
+        salary_max_monthly_bdt = salary_to_monthly_bdt(salary_max, salary_currency, salary_period)
+    WHERE salary_currency = NEW.currency;
+    UPDATE external_jobs SET
+        salary_min_monthly_bdt = salary_to_monthly_bdt(salary_min, salary_currency, salary_period),
+        salary_max_monthly_bdt = salary_to_monthly_bdt(salary_max, salary_currency, salary_period)
+    WHERE salary_currency = NEW.currency;
+    RETURN NULL;
+END;
+$$ LANGUAGE plpgsql;
+
+-- Trigger to re-normalize salaries when an exchange rate changes
+CREATE TRIGGER renormalize_salaries
+    AFTER INSERT OR UPDATE OF bdt_per_unit ON salary_exchange_rates
+    FOR EACH ROW
+    EXECUTE FUNCTION renormalize_salaries();
+
+-- Function to stamp a job when it becomes open
+CREATE OR REPLACE FUNCTION set_job_published_at()
+RETURNS TRIGGER AS $$
+BEGIN
+    IF NEW.status = 'open' AND (TG_OP = 'INSERT' OR OLD.status <> 'open') THEN
+        NEW.published_at = CURRENT_TIMESTAMP;
+    END IF;
+    RETURN NEW;
+END;
+$$ LANGUAGE plpgsql;
+
+-- Trigger to keep jobs.published_at in sync
+CREATE TRIGGER set_job_published_at
+    BEFORE INSERT OR UPDATE OF status ON jobs
+    FOR EACH ROW
+    EXECUTE FUNCTION set_job_published_at();
+
+-- Function to drop cached match explanations of an edited job
+CREATE OR REPLACE FUNCTION clear_match_explanations()
+RETURNS TRIGGER AS $$
+BEGIN
+    DELETE FROM match_explanations WHERE job_id = NEW.id;
+    RETURN NEW;
+END;
+$$ LANGUAGE plpgsql;
+
+-- Trigger to invalidate explanations when a job's matching fields change
+CREATE TRIGGER clear_match_explanations
+    AFTER UPDATE ON jobs
+    FOR EACH ROW
+    WHEN (OLD.job_title IS DISTINCT FROM NEW.job_title
+          OR OLD.required_skills IS DISTINCT FROM NEW.required_skills
+          OR OLD.preferred_skills IS DISTINCT FROM NEW.preferred_skills
+          OR OLD.skill_weights IS DISTINCT FROM NEW.skill_weights
+          OR OLD.experience_level IS DISTINCT FROM NEW.experience_level
+          OR OLD.experience_years_min IS DISTINCT FROM NEW.experience_years_min
+          OR OLD.experience_years_max IS DISTINCT FROM NEW.experience_years_max
+          OR OLD.job_description IS DISTINCT FROM NEW.job_description)
+    EXECUTE FUNCTION clear_match_explanations();
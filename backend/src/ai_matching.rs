@@ -1,6 +1,23 @@
 use serde::{Deserialize, Serialize};
-use tracing::{info, warn};
+use tracing::info;
+use std::collections::{HashMap, HashSet};
 use std::env;
+use crate::career_tracks::TrackTaxonomy;
+use crate::errors::{AppError, AppResult};
+use crate::models::{ExperienceLevel, Job, SkillProficiency, WorkArrangement};
+use crate::skills::{SkillComparison, SkillTaxonomy};
+
+/// Weight of a required skill the job gives no weight of its own
+pub const DEFAULT_REQUIRED_SKILL_WEIGHT: i32 = 3;
+
+/// Weight of a preferred skill the job gives no weight of its own
+pub const DEFAULT_PREFERRED_SKILL_WEIGHT: i32 = 1;
+
+/// Lowest weight a job can give a skill
+pub const MIN_SKILL_WEIGHT: i32 = 1;
+
+/// Highest weight a job can give a skill
+pub const MAX_SKILL_WEIGHT: i32 = 5;
 
 /// Enhanced match analysis with AI-generated explanations
 #[derive(Debug, Serialize, Deserialize)]
@@ -19,37 +36,459 @@ pub struct EnhancedMatchAnalysis {
     pub track_alignment: f64,
     /// Skill overlap score (0-100)
     pub skill_overlap: f64,
+    /// Semantic similarity of profile and job (0-100), when it was scored
+    pub semantic_similarity: Option<f64>,
+    /// Weights the factors were combined with, after making up for a
+    /// missing semantic similarity
+    pub weights: MatchWeights,
+    /// How each of the job's skills counted towards the skill overlap
+    pub skill_credits: Vec<SkillCredit>,
+}
+
+impl EnhancedMatchAnalysis {
+    /// Job skills the candidate has, required ones first
+    pub fn matched_skills(&self) -> Vec<String> {
+        self.skill_credits
+            .iter()
+            .filter(|credit| credit.proficiency.is_some())
+            .map(|credit| credit.skill.clone())
+            .collect()
+    }
+
+    /// Required (or preferred) job skills the candidate lacks
+    pub fn missing_skills(&self, required: bool) -> Vec<String> {
+        self.skill_credits
+            .iter()
+            .filter(|credit| credit.required == required && credit.proficiency.is_none())
+            .map(|credit| credit.skill.clone())
+            .collect()
+    }
+
+    /// Points of the match score a skill earned, and the most it could
+    /// earn, leaving out the extra skill bonus
+    pub fn skill_contribution(&self, credit: &SkillCredit) -> (f64, f64) {
+        let total: i32 = self.skill_credits.iter().map(|credit| credit.weight).sum();
+        if total == 0 {
+            return (0.0, 0.0);
+        }
+        let max = credit.weight as f64 / total as f64 * 100.0 * self.weights.skills;
+        (max * credit.credit, max)
+    }
+}
+
+/// The skills a job asks for
+#[derive(Debug, Clone, Copy)]
+pub struct JobSkills<'a> {
+    /// Must-have skills
+    pub required: &'a [String],
+    /// Nice-to-have skills
+    pub preferred: &'a [String],
+    /// Weight (1-5) per skill, by canonical name
+    pub weights: &'a HashMap<String, i32>,
+}
+
+impl<'a> JobSkills<'a> {
+    /// The skills of a job posting
+    pub fn of(job: &'a Job) -> Self {
+        JobSkills {
+            required: &job.required_skills,
+            preferred: &job.preferred_skills,
+            weights: &job.skill_weights,
+        }
+    }
+}
+
+/// The skills a candidate has
+#[derive(Debug, Clone, Copy)]
+pub struct CandidateSkills<'a> {
+    /// Skills on the profile
+    pub skills: &'a [String],
+    /// Proficiency per skill, by canonical name
+    pub proficiency: &'a HashMap<String, SkillProficiency>,
+}
+
+/// A candidate and a job, as compared by [`calculate_enhanced_match`]
+#[derive(Debug, Clone, Copy)]
+pub struct MatchInput<'a> {
+    /// The candidate's skills and proficiency
+    pub candidate: CandidateSkills<'a>,
+    /// The job's required and preferred skills
+    pub job: JobSkills<'a>,
+    /// The candidate's experience, if known
+    pub user_experience: Option<ExperienceRange>,
+    /// Experience the job asks for
+    pub job_experience: ExperienceRange,
+    /// The candidate's preferred career track
+    pub user_track: Option<&'a str>,
+    /// Job title, used to infer the job's track
+    pub job_title: &'a str,
+    /// Semantic similarity of profile and job (0-100), if embeddings were
+    /// computed (see [`crate::embeddings`])
+    pub semantic_similarity: Option<f64>,
+}
+
+impl<'a> MatchInput<'a> {
+    /// Compares a candidate with a job posting
+    pub fn new(
+        candidate: CandidateSkills<'a>,
+        user_experience: Option<ExperienceRange>,
+        user_track: Option<&'a str>,
+        job: &'a Job,
+    ) -> Self {
+        MatchInput {
+            candidate,
+            job: JobSkills::of(job),
+            user_experience,
+            job_experience: ExperienceRange::job(
+                job.experience_level,
+                job.experience_years_min,
+                job.experience_years_max,
+            ),
+            user_track,
+            job_title: &job.job_title,
+            semantic_similarity: None,
+        }
+    }
+
+    /// Adds the semantic similarity of profile and job (0-100)
+    pub fn with_semantic_similarity(mut self, similarity: Option<f64>) -> Self {
+        self.semantic_similarity = similarity;
+        self
+    }
+}
+
+/// How much skill overlap, experience alignment, track alignment and
+/// semantic similarity each count towards the match score
+///
+/// Weights are relative: they are scaled to sum to 1 when parsed or loaded.
+/// Semantic similarity weighs nothing unless configured.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct MatchWeights {
+    /// Weight of skill overlap
+    pub skills: f64,
+    /// Weight of experience alignment
+    pub experience: f64,
+    /// Weight of track alignment
+    pub track: f64,
+    /// Weight of semantic similarity
+    #[serde(default)]
+    pub semantic: f64,
+}
+
+impl Default for MatchWeights {
+    fn default() -> Self {
+        MatchWeights {
+            skills: 0.6,
+            experience: 0.2,
+            track: 0.2,
+            semantic: 0.0,
+        }
+    }
+}
+
+impl MatchWeights {
+    /// Scales the weights to sum to 1
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a weight is negative or not a number, or all are zero.
+    pub fn normalized(self) -> AppResult<Self> {
+        let weights = [self.skills, self.experience, self.track, self.semantic];
+        if weights.iter().any(|w| !w.is_finite() || *w < 0.0) {
+            return Err(AppError::BadRequest(
+                "Match weights must be non-negative numbers".to_string(),
+            ));
+        }
+        let total: f64 = weights.iter().sum();
+        if total <= 0.0 {
+            return Err(AppError::BadRequest(
+                "At least one match weight must be positive".to_string(),
+            ));
+        }
+
+        Ok(MatchWeights {
+            skills: self.skills / total,
+            experience: self.experience / total,
+            track: self.track / total,
+            semantic: self.semantic / total,
+        })
+    }
+
+    /// Parses weights such as `skills:0.6,experience:0.2,track:0.1,semantic:0.1`
+    ///
+    /// Factors left out weigh nothing.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a factor is unknown or repeated, or a weight is
+    /// invalid (see [`MatchWeights::normalized`]).
+    pub fn parse(text: &str) -> AppResult<Self> {
+        let mut weights = MatchWeights {
+            skills: 0.0,
+            experience: 0.0,
+            track: 0.0,
+            semantic: 0.0,
+        };
+        let mut seen = HashSet::new();
+        for part in text.split(',').map(str::trim).filter(|p| !p.is_empty()) {
+            let invalid = || {
+                AppError::BadRequest(format!(
+                    "Invalid match weight '{}', expected e.g. skills:0.6",
+                    part
+                ))
+            };
+            let (factor, value) = part.split_once(':').ok_or_else(invalid)?;
+            let factor = factor.trim().to_lowercase();
+            let value: f64 = value.trim().parse().map_err(|_| invalid())?;
+            let slot = match factor.as_str() {
+                "skills" => &mut weights.skills,
+                "experience" => &mut weights.experience,
+                "track" => &mut weights.track,
+                "semantic" => &mut weights.semantic,
+                _ => {
+                    return Err(AppError::BadRequest(format!(
+                        "Unknown match factor '{}', expected skills, experience, track or semantic",
+                        factor
+                    )));
+                }
+            };
+            if !seen.insert(factor.clone()) {
+                return Err(AppError::BadRequest(format!(
+                    "Match factor '{}' is given more than once",
+                    factor
+                )));
+            }
+            *slot = value;
+        }
+
+        weights.normalized()
+    }
+
+    /// The match score of a job's factor scores (0-100)
+    ///
+    /// Semantic similarity only counts when weighted; without it, its weight
+    /// is shared out among the other factors.
+    pub fn score(
+        self,
+        skill_overlap: f64,
+        experience_alignment: f64,
+        track_alignment: f64,
+        semantic_similarity: Option<f64>,
+    ) -> f64 {
+        let semantic_similarity = semantic_similarity.filter(|_| self.semantic > 0.0);
+        let weights = effective_weights(self, semantic_similarity.is_some());
+        (skill_overlap * weights.skills)
+            + (experience_alignment * weights.experience)
+            + (track_alignment * weights.track)
+            + semantic_similarity.map_or(0.0, |similarity| similarity * weights.semantic)
+    }
+}
+
+/// How a candidate matches one of a job's skills
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SkillMatchStatus {
+    /// Has the skill, or an alias of it, at the expected proficiency
+    Matched,
+    /// Has it below the expected proficiency, or only through a more
+    /// specific skill
+    Partial,
+    /// Lacks the skill
+    Missing,
+}
+
+/// Hours of study to reach each proficiency from scratch (a rough guide)
+fn hours_to_reach(proficiency: SkillProficiency) -> i32 {
+    match proficiency {
+        SkillProficiency::Beginner => 20,
+        SkillProficiency::Intermediate => 60,
+        SkillProficiency::Advanced => 160,
+        SkillProficiency::Expert => 360,
+    }
+}
+
+/// How one of a job's skills counted towards the skill overlap
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SkillCredit {
+    /// Canonical skill name
+    pub skill: String,
+    /// Whether the skill is required rather than preferred
+    pub required: bool,
+    /// Whether the candidate has the skill, partly or not at all
+    pub status: SkillMatchStatus,
+    /// The alias or more specific skill through which the candidate has it
+    pub matched_via: Option<String>,
+    /// Weight of the skill (1-5)
+    pub weight: i32,
+    /// Proficiency the job's experience level expects
+    pub expected_proficiency: SkillProficiency,
+    /// The candidate's (possibly assumed) proficiency; `None` when they
+    /// lack the skill
+    pub proficiency: Option<SkillProficiency>,
+    /// Share of the weight earned (0-1)
+    pub credit: f64,
+}
+
+impl SkillCredit {
+    /// Rough hours of study to reach the expected proficiency, from the
+    /// candidate's proficiency or from scratch; halved with a `head_start`
+    /// such as knowing the parent skill. 0 once the full credit is earned.
+    pub fn learning_hours(&self, head_start: bool) -> i32 {
+        if self.credit >= 1.0 {
+            return 0;
+        }
+        let hours = hours_to_reach(self.expected_proficiency)
+            - self.proficiency.map_or(0, hours_to_reach);
+        if head_start { hours / 2 } else { hours }
+    }
+}
+
+/// Years of experience a candidate has or a job asks for
+///
+/// Whole years, inclusive at both ends; `max` is `None` for "or more".
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ExperienceRange {
+    pub min: i32,
+    pub max: Option<i32>,
+}
+
+impl ExperienceRange {
+    /// The years an experience level covers
+    pub fn of_level(level: ExperienceLevel) -> Self {
+        let (min, max) = level.years();
+        ExperienceRange { min, max }
+    }
+
+    /// A candidate's experience: their exact years when known, otherwise
+    /// the range of their level
+    pub fn candidate(level: Option<ExperienceLevel>, years: Option<i32>) -> Option<Self> {
+        match years {
+            Some(years) => Some(ExperienceRange { min: years, max: Some(years) }),
+            None => level.map(Self::of_level),
+        }
+    }
+
+    /// A job's experience requirement: its explicit bounds when set,
+    /// otherwise the range of its level
+    pub fn job(level: ExperienceLevel, min_years: Option<i32>, max_years: Option<i32>) -> Self {
+        match (min_years, max_years) {
+            (None, None) => Self::of_level(level),
+            (min, max) => ExperienceRange { min: min.unwrap_or(0), max },
+        }
+    }
+
+    /// Parse a source-reported requirement such as "3-5 years", "5+ years",
+    /// "Minimum 2 years", "Entry Level" or "Mid-Senior level"
+    ///
+    /// A single number of years is read as a minimum.
+    pub fn parse(text: &str) -> Option<Self> {
+        let text = text.to_lowercase();
+        if text.contains("year") || text.contains("yr") {
+            let numbers: Vec<i32> = text
+                .split(|c: char| !c.is_ascii_digit())
+                .filter_map(|n| n.parse().ok())
+                .filter(|n| *n <= 60)
+                .take(2)
+                .collect();
+            match numbers[..] {
+                [min, max] if max >= min => return Some(ExperienceRange { min, max: Some(max) }),
+                [min, ..] => return Some(ExperienceRange { min, max: None }),
+                [] => {}
+            }
+        }
+
+        // Every level mentioned, from the most junior to the most senior
+        let levels: Vec<ExperienceLevel> = text
+            .split(|c: char| !c.is_alphanumeric())
+            .filter_map(|word| match word {
+                "fresher" | "entry" | "graduate" | "intern" | "internship" | "trainee" => {
+                    Some(ExperienceLevel::Fresher)
+                }
+                "junior" | "jr" => Some(ExperienceLevel::Junior),
+                "mid" | "intermediate" => Some(ExperienceLevel::Mid),
+                "senior" | "sr" => Some(ExperienceLevel::Senior),
+                "lead" | "principal" | "head" => Some(ExperienceLevel::Lead),
+                _ => None,
+            })
+            .collect();
+        let lowest = levels.iter().min_by_key(|level| level.years().0)?;
+        let highest = levels.iter().max_by_key(|level| level.years().0)?;
+        Some(ExperienceRange {
+            min: lowest.years().0,
+            max: highest.years().1,
+        })
+    }
+
+    /// The experience level of the range's lower bound
+    pub fn level(&self) -> ExperienceLevel {
+        ExperienceLevel::from_years(self.min)
+    }
+}
+
+impl std::fmt::Display for ExperienceRange {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.max {
+            Some(1) if self.min == 1 => write!(f, "1 year"),
+            Some(max) if max == self.min => write!(f, "{} years", max),
+            Some(max) => write!(f, "{}-{} years", self.min, max),
+            None => write!(f, "{}+ years", self.min),
+        }
+    }
 }
 
 /// Calculate enhanced match score using heuristic algorithm
+///
+/// Skills are compared through the skill taxonomy, so aliases and more
+/// specific skills count (see [`SkillTaxonomy::compare`]), and weighted by
+/// importance and the candidate's proficiency (see [`score_job_skills`]).
+/// The match score is the weighted sum of skill overlap, experience
+/// alignment, track alignment and semantic similarity. Without a semantic
+/// similarity, the other weights are scaled up to make up for it. With
+/// `extra_skill_bonus`, skills beyond the job's count add up to the skill
+/// overlap, whether related or not.
 pub fn calculate_enhanced_match(
-    user_skills: &[String],
-    job_skills: &[String],
-    user_experience: Option<&str>,
-    job_experience: &str,
-    user_track: Option<&str>,
-    job_title: &str,
+    input: &MatchInput,
+    skills: &SkillTaxonomy,
+    tracks: &TrackTaxonomy,
+    weights: MatchWeights,
+    extra_skill_bonus: bool,
 ) -> EnhancedMatchAnalysis {
-    // 1. Skill Overlap (60% weight) - Most important factor
-    let skill_overlap = calculate_skill_overlap(user_skills, job_skills);
+    // 1. Skill Overlap - Most important factor by default
+    let skill_credits = score_job_skills(
+        skills,
+        input.candidate,
+        input.user_experience.map(|exp| exp.level()),
+        input.job,
+        input.job_experience.level(),
+    );
+    let extra_skills = if extra_skill_bonus { input.candidate.skills.len() } else { 0 };
+    let skill_overlap = calculate_weighted_skill_overlap(&skill_credits, extra_skills);
     
-    // 2. Experience Alignment (20% weight)
-    let experience_alignment = calculate_experience_alignment(user_experience, job_experience);
+    // 2. Experience Alignment
+    let experience_alignment = calculate_experience_alignment(input.user_experience, input.job_experience);
     
-    // 3. Track Alignment (20% weight)
-    let track_alignment = calculate_track_alignment(user_track, job_title);
+    // 3. Track Alignment
+    let track_alignment = tracks.alignment(input.user_track, input.job_title, input.job.required);
     
-    // Weighted overall score (skill overlap has highest importance)
-    let match_score = (skill_overlap * 0.6) + (experience_alignment * 0.2) + (track_alignment * 0.2);
+    // 4. Semantic Similarity, when weighted and computed
+    let semantic_similarity = input.semantic_similarity.filter(|_| weights.semantic > 0.0);
+
+    // Weighted overall score
+    let match_score = weights.score(
+        skill_overlap,
+        experience_alignment,
+        track_alignment,
+        semantic_similarity,
+    );
+    let weights = effective_weights(weights, semantic_similarity.is_some());
     
     // Generate explanation
     let (explanation, strengths, improvements) = generate_match_explanation(
-        user_skills,
-        job_skills,
-        user_experience,
-        job_experience,
-        user_track,
-        job_title,
+        &skill_credits,
+        input.user_experience,
+        input.job_experience,
+        input.user_track,
+        input.job_title,
         skill_overlap,
         experience_alignment,
         track_alignment,
@@ -64,112 +503,310 @@ pub fn calculate_enhanced_match(
         experience_alignment,
         track_alignment,
         skill_overlap,
+        semantic_similarity,
+        weights,
+        skill_credits,
     }
 }
 
+/// The weights to combine factors with: without a semantic similarity, its
+/// weight is shared out among the other factors in proportion
+fn effective_weights(weights: MatchWeights, has_semantic: bool) -> MatchWeights {
+    if has_semantic || weights.semantic <= 0.0 || weights.semantic >= 1.0 {
+        return weights;
+    }
+    let scale = 1.0 / (1.0 - weights.semantic);
+    MatchWeights {
+        skills: weights.skills * scale,
+        experience: weights.experience * scale,
+        track: weights.track * scale,
+        semantic: 0.0,
+    }
+}
+
+/// Score each of a job's skills for a candidate
+///
+/// Required skills come first, then preferred ones; a skill listed as both
+/// counts as required. Skills weigh what the job says, or 3 (required) and
+/// 1 (preferred) by default.
+///
+/// A skill the candidate has earns its full weight at the proficiency the
+/// job's level expects ([`SkillProficiency::expected_for`]) or above, and a
+/// proportional share below it: a beginner earns a third of a skill an
+/// advanced user is expected to have. Skills without a recorded proficiency
+/// are assumed to be at the candidate's own level, or intermediate.
+pub fn score_job_skills(
+    skills: &SkillTaxonomy,
+    candidate: CandidateSkills,
+    candidate_level: Option<ExperienceLevel>,
+    job: JobSkills,
+    job_level: ExperienceLevel,
+) -> Vec<SkillCredit> {
+    let expected = SkillProficiency::expected_for(job_level);
+    let assumed = candidate_level.map_or(SkillProficiency::Intermediate, SkillProficiency::expected_for);
+    let proficiency: HashMap<String, SkillProficiency> = candidate
+        .proficiency
+        .iter()
+        .map(|(skill, level)| (skills.key(skill), *level))
+        .collect();
+    let weights: HashMap<String, i32> = job
+        .weights
+        .iter()
+        .map(|(skill, weight)| (skills.key(skill), *weight))
+        .collect();
+
+    let required = skills.compare(candidate.skills, job.required);
+    let required_keys: HashSet<String> = required
+        .matched
+        .iter()
+        .chain(&required.missing)
+        .map(|skill| skills.key(skill))
+        .collect();
+    let mut preferred = skills.compare(candidate.skills, job.preferred);
+    preferred.matched.retain(|skill| !required_keys.contains(&skills.key(skill)));
+    preferred.missing.retain(|skill| !required_keys.contains(&skills.key(skill)));
+
+    let groups = [
+        (required.matched, true, true),
+        (required.missing, true, false),
+        (preferred.matched, false, true),
+        (preferred.missing, false, false),
+    ];
+    groups
+        .into_iter()
+        .flat_map(|(names, is_required, has_skill)| {
+            names.into_iter().map(move |skill| (skill, is_required, has_skill))
+        })
+        .map(|(skill, is_required, has_skill)| {
+            let key = skills.key(&skill);
+            let default_weight = if is_required {
+                DEFAULT_REQUIRED_SKILL_WEIGHT
+            } else {
+                DEFAULT_PREFERRED_SKILL_WEIGHT
+            };
+            let weight = weights
+                .get(&key)
+                .map_or(default_weight, |w| (*w).clamp(MIN_SKILL_WEIGHT, MAX_SKILL_WEIGHT));
+            let level = has_skill.then(|| proficiency.get(&key).copied().unwrap_or(assumed));
+            let credit = level.map_or(0.0, |level| {
+                (level.rank() as f64 / expected.rank() as f64).min(1.0)
+            });
+            let via = if has_skill { skills.matched_via(candidate.skills, &skill) } else { None };
+            let status = match (&via, level) {
+                (_, None) => SkillMatchStatus::Missing,
+                (Some((_, true)), _) => SkillMatchStatus::Partial,
+                _ if credit < 1.0 => SkillMatchStatus::Partial,
+                _ => SkillMatchStatus::Matched,
+            };
+            SkillCredit {
+                skill,
+                required: is_required,
+                status,
+                matched_via: via.map(|(entry, _)| entry),
+                weight,
+                expected_proficiency: expected,
+                proficiency: level,
+                credit,
+            }
+        })
+        .collect()
+}
+
+/// Calculate weighted skill overlap score (0-100): the share of the job's
+/// total skill weight the candidate earns, plus the extra skill bonus for
+/// `user_skill_count` (0 for none)
+fn calculate_weighted_skill_overlap(credits: &[SkillCredit], user_skill_count: usize) -> f64 {
+    let total: i32 = credits.iter().map(|credit| credit.weight).sum();
+    if total == 0 {
+        return 100.0; // No skills required = perfect match
+    }
+
+    let earned: f64 = credits
+        .iter()
+        .map(|credit| credit.weight as f64 * credit.credit)
+        .sum();
+    let overlap = (earned / total as f64) * 100.0;
+
+    (overlap + extra_skill_bonus(user_skill_count, credits.len())).min(100.0)
+}
+
 /// Calculate skill overlap score (0-100)
-fn calculate_skill_overlap(user_skills: &[String], job_skills: &[String]) -> f64 {
-    if job_skills.is_empty() {
+fn calculate_skill_overlap(comparison: &SkillComparison, user_skill_count: usize) -> f64 {
+    let required = comparison.matched.len() + comparison.missing.len();
+    if required == 0 {
         return 100.0; // No skills required = perfect match
     }
     
-    let user_skills_set: std::collections::HashSet<_> = 
-        user_skills.iter().map(|s| s.to_lowercase()).collect();
-    let job_skills_set: std::collections::HashSet<_> = 
-        job_skills.iter().map(|s| s.to_lowercase()).collect();
+    let overlap = (comparison.matched.len() as f64 / required as f64) * 100.0;
     
-    let matched = user_skills_set.intersection(&job_skills_set).count();
-    let overlap = (matched as f64 / job_skills.len() as f64) * 100.0;
-    
-    // Bonus for having more skills than required
-    let bonus = if user_skills.len() > job_skills.len() {
-        ((user_skills.len() - job_skills.len()) as f64 / job_skills.len() as f64) * 10.0
+    (overlap + extra_skill_bonus(user_skill_count, required)).min(100.0)
+}
+
+/// Bonus for having more skills than required
+fn extra_skill_bonus(user_skill_count: usize, required: usize) -> f64 {
+    if required > 0 && user_skill_count > required {
+        ((user_skill_count - required) as f64 / required as f64) * 10.0
     } else {
         0.0
-    };
-    
-    (overlap + bonus).min(100.0)
+    }
 }
 
-/// Calculate experience level alignment (0-100)
-fn calculate_experience_alignment(user_exp: Option<&str>, job_exp: &str) -> f64 {
+/// Calculate experience alignment (0-100)
+///
+/// Overlapping ranges align fully. Otherwise each year short of the job's
+/// minimum costs 20 points, and being overqualified costs 20 points plus 10
+/// per year beyond the job's maximum.
+fn calculate_experience_alignment(user_exp: Option<ExperienceRange>, job_exp: ExperienceRange) -> f64 {
     let user_exp = match user_exp {
-        Some(exp) => exp.to_lowercase(),
+        Some(exp) => exp,
         None => return 50.0, // Unknown = neutral
     };
-    
-    let job_exp = job_exp.to_lowercase();
-    
-    // Exact match
-    if user_exp == job_exp {
-        return 100.0;
+
+    let shortfall = user_exp.max.map_or(0, |max| job_exp.min - max);
+    let excess = job_exp.max.map_or(0, |max| user_exp.min - max);
+
+    if shortfall > 0 {
+        (100.0 - 20.0 * shortfall as f64).max(10.0) // Underqualified
+    } else if excess > 0 {
+        (80.0 - 10.0 * excess as f64).max(20.0) // Overqualified
+    } else {
+        100.0
     }
-    
-    // Experience hierarchy: fresher < junior < mid
-    match (user_exp.as_str(), job_exp.as_str()) {
-        ("fresher", "junior") | ("junior", "mid") => 80.0, // Close match
-        ("fresher", "mid") => 60.0, // Somewhat aligned
-        ("junior", "fresher") | ("mid", "junior") => 70.0, // Overqualified but okay
-        ("mid", "fresher") => 40.0, // Overqualified
-        _ => 50.0, // Unknown
+}
+
+/// A job or external posting, as compared by [`calculate_job_similarity`].
+pub struct JobSimilarityInput<'a> {
+    /// Job title
+    pub title: &'a str,
+    /// Required skills (or the source's skills for external postings)
+    pub skills: &'a [String],
+    /// Experience asked for, if known
+    pub experience: Option<ExperienceRange>,
+    /// On-site, hybrid or remote
+    pub work_arrangement: WorkArrangement,
+    /// Resolved division, if known
+    pub division: Option<&'a str>,
+    /// Resolved district, if known
+    pub district: Option<&'a str>,
+}
+
+/// Similarity between two postings
+#[derive(Debug)]
+pub struct JobSimilarity {
+    /// Overall similarity (0-100)
+    pub similarity: f64,
+    /// Required skill overlap (0-100)
+    pub skill_overlap: f64,
+    /// Title word overlap (0-100)
+    pub title_similarity: f64,
+    /// Experience level alignment (0-100)
+    pub experience_alignment: f64,
+    /// Location alignment (0-100)
+    pub location_alignment: f64,
+    /// Skills both postings require, as canonical names
+    pub shared_skills: Vec<String>,
+}
+
+/// Words ignored when comparing titles, including seniority words so that
+/// "Junior Backend Developer" and "Senior Backend Developer" read as similar
+const TITLE_STOP_WORDS: [&str; 16] = [
+    "a", "an", "and", "at", "for", "in", "of", "the", "to", "with",
+    "junior", "jr", "senior", "sr", "lead", "trainee",
+];
+
+/// Calculate how similar a candidate posting is to the one being viewed
+///
+/// Skill overlap uses the same overlap score as [`calculate_enhanced_match`],
+/// taken in both directions so that a posting with many skills is not a
+/// perfect match for every posting sharing one of them. Postings without
+/// skills share none.
+pub fn calculate_job_similarity(
+    skills: &SkillTaxonomy,
+    source: &JobSimilarityInput,
+    candidate: &JobSimilarityInput,
+) -> JobSimilarity {
+    // 1. Skill Overlap (50% weight)
+    let shared = skills.compare(source.skills, candidate.skills);
+    let skill_overlap = if source.skills.is_empty() || candidate.skills.is_empty() {
+        0.0
+    } else {
+        let reverse = skills.compare(candidate.skills, source.skills);
+        calculate_skill_overlap(&shared, source.skills.len())
+            .min(calculate_skill_overlap(&reverse, candidate.skills.len()))
+    };
+
+    // 2. Title Similarity (25% weight)
+    let title_similarity = calculate_title_similarity(source.title, candidate.title);
+
+    // 3. Experience Alignment (10% weight)
+    let experience_alignment = match candidate.experience {
+        Some(candidate_exp) => calculate_experience_alignment(source.experience, candidate_exp),
+        None => 50.0, // Unknown = neutral
+    };
+
+    // 4. Location Alignment (15% weight)
+    let location_alignment = calculate_location_alignment(source, candidate);
+
+    let similarity = (skill_overlap * 0.5)
+        + (title_similarity * 0.25)
+        + (experience_alignment * 0.1)
+        + (location_alignment * 0.15);
+
+    JobSimilarity {
+        similarity,
+        skill_overlap,
+        title_similarity,
+        experience_alignment,
+        location_alignment,
+        shared_skills: shared.matched,
     }
 }
 
-/// Calculate track alignment (0-100)
-fn calculate_track_alignment(user_track: Option<&str>, job_title: &str) -> f64 {
-    let user_track = match user_track {
-        Some(track) => track.to_lowercase(),
-        None => return 50.0, // Unknown = neutral
+/// Calculate title similarity as the overlap of title words (0-100)
+fn calculate_title_similarity(a: &str, b: &str) -> f64 {
+    let words = |title: &str| -> std::collections::HashSet<String> {
+        title
+            .to_lowercase()
+            .split(|c: char| !c.is_alphanumeric())
+            .filter(|w| !w.is_empty() && !TITLE_STOP_WORDS.contains(w))
+            .map(str::to_string)
+            .collect()
     };
-    
-    let title_lower = job_title.to_lowercase();
-    
-    // Check if job title matches user's track
-    match user_track.as_str() {
-        "web_development" => {
-            if title_lower.contains("frontend") || title_lower.contains("backend") 
-                || title_lower.contains("full stack") || title_lower.contains("web") 
-                || title_lower.contains("react") || title_lower.contains("node") {
-                100.0
-            } else {
-                50.0
-            }
-        }
-        "data" => {
-            if title_lower.contains("data") || title_lower.contains("analyst") 
-                || title_lower.contains("scientist") || title_lower.contains("ml") 
-                || title_lower.contains("machine learning") {
-                100.0
-            } else {
-                50.0
-            }
-        }
-        "design" => {
-            if title_lower.contains("designer") || title_lower.contains("ui") 
-                || title_lower.contains("ux") || title_lower.contains("graphic") {
-                100.0
-            } else {
-                50.0
-            }
-        }
-        "marketing" => {
-            if title_lower.contains("marketing") || title_lower.contains("seo") 
-                || title_lower.contains("content") || title_lower.contains("social") {
-                100.0
-            } else {
-                50.0
-            }
-        }
-        _ => 50.0,
+    let (a, b) = (words(a), words(b));
+    let union = a.union(&b).count();
+    if union == 0 {
+        return 0.0;
+    }
+
+    (a.intersection(&b).count() as f64 / union as f64) * 100.0
+}
+
+/// Calculate location alignment (0-100)
+fn calculate_location_alignment(source: &JobSimilarityInput, candidate: &JobSimilarityInput) -> f64 {
+    match (source.work_arrangement, candidate.work_arrangement) {
+        (WorkArrangement::Remote, WorkArrangement::Remote) => return 100.0,
+        (WorkArrangement::Remote, _) | (_, WorkArrangement::Remote) => return 50.0,
+        _ => {}
+    }
+
+    let same = |a: Option<&str>, b: Option<&str>| a.zip(b).map(|(a, b)| a == b);
+    match (
+        same(source.district, candidate.district),
+        same(source.division, candidate.division),
+    ) {
+        (Some(true), _) => 100.0,
+        (_, Some(true)) => 70.0,
+        (_, Some(false)) => 0.0,
+        _ => 50.0, // Unknown = neutral
     }
 }
 
 /// Generate human-readable match explanation
+#[allow(clippy::too_many_arguments)]
 fn generate_match_explanation(
-    user_skills: &[String],
-    job_skills: &[String],
-    user_experience: Option<&str>,
-    job_experience: &str,
+    skill_credits: &[SkillCredit],
+    user_experience: Option<ExperienceRange>,
+    job_experience: ExperienceRange,
     _user_track: Option<&str>,
     _job_title: &str,
     _skill_overlap: f64,
@@ -177,19 +814,18 @@ fn generate_match_explanation(
     track_alignment: f64,
     match_score: f64,
 ) -> (String, Vec<String>, Vec<String>) {
-    let user_skills_set: std::collections::HashSet<_> = 
-        user_skills.iter().map(|s| s.to_lowercase()).collect();
-    let job_skills_set: std::collections::HashSet<_> = 
-        job_skills.iter().map(|s| s.to_lowercase()).collect();
-    
-    let matched_skills: Vec<String> = user_skills_set
-        .intersection(&job_skills_set)
-        .map(|s| s.to_string())
-        .collect();
-    
-    let missing_skills: Vec<String> = job_skills_set
-        .difference(&user_skills_set)
-        .map(|s| s.to_string())
+    let list = |credits: &[&SkillCredit], n: usize| {
+        credits.iter().take(n).map(|c| c.skill.as_str()).collect::<Vec<_>>().join(", ")
+    };
+    let (required, preferred): (Vec<&SkillCredit>, Vec<&SkillCredit>) =
+        skill_credits.iter().partition(|credit| credit.required);
+    let (matched, missing): (Vec<&SkillCredit>, Vec<&SkillCredit>) =
+        required.iter().partition(|credit| credit.proficiency.is_some());
+    let (matched_preferred, missing_preferred): (Vec<&SkillCredit>, Vec<&SkillCredit>) =
+        preferred.iter().partition(|credit| credit.proficiency.is_some());
+    let below_expected: Vec<&SkillCredit> = skill_credits
+        .iter()
+        .filter(|credit| credit.proficiency.is_some() && credit.credit < 1.0)
         .collect();
     
     let mut explanation_parts = Vec::new();
@@ -197,31 +833,52 @@ fn generate_match_explanation(
     let mut improvements = Vec::new();
     
     // Skill analysis
-    if !matched_skills.is_empty() {
-        let skill_list = matched_skills.iter().take(5).map(|s| s.as_str()).collect::<Vec<_>>().join(", ");
+    if !matched.is_empty() {
+        let skill_list = list(&matched, 5);
         strengths.push(format!("Strong skills match: {}", skill_list));
         explanation_parts.push(format!(
             "You have {} of {} required skills ({})",
-            matched_skills.len(),
-            job_skills.len(),
+            matched.len(),
+            required.len(),
             skill_list
         ));
     }
+
+    if !matched_preferred.is_empty() {
+        strengths.push(format!("Nice-to-have skills: {}", list(&matched_preferred, 3)));
+        explanation_parts.push(format!(
+            "You also have {} of {} preferred skills",
+            matched_preferred.len(),
+            preferred.len()
+        ));
+    }
     
-    if !missing_skills.is_empty() {
-        let missing_list = missing_skills.iter().take(3).map(|s| s.as_str()).collect::<Vec<_>>().join(", ");
+    if !missing.is_empty() {
+        let missing_list = list(&missing, 3);
         improvements.push(format!("Learn: {}", missing_list));
         explanation_parts.push(format!("Consider learning: {}", missing_list));
+    } else if !missing_preferred.is_empty() {
+        improvements.push(format!("Nice to have: {}", list(&missing_preferred, 3)));
+    }
+
+    if !below_expected.is_empty() {
+        improvements.push(format!(
+            "Deepen: {} (this role expects {} level)",
+            list(&below_expected, 3),
+            below_expected[0].expected_proficiency.as_str()
+        ));
     }
     
     // Experience alignment
     if experience_alignment >= 80.0 {
         strengths.push(format!("Experience level ({}) aligns well with this position", 
-            user_experience.unwrap_or("your level")));
+            user_experience.map_or("your level".to_string(), |exp| exp.to_string())));
         explanation_parts.push("Your experience level is a good fit for this role".to_string());
     } else if experience_alignment < 60.0 {
-        improvements.push(format!("This role requires {} experience, but you have {}", 
-            job_experience, user_experience.unwrap_or("different")));
+        improvements.push(match user_experience {
+            Some(exp) => format!("This role requires {} of experience, but you have {}", job_experience, exp),
+            None => format!("This role requires {} of experience", job_experience),
+        });
     }
     
     // Track alignment
@@ -246,6 +903,7 @@ fn generate_match_explanation(
     (full_explanation, strengths, improvements)
 }
 
+#[allow(clippy::too_many_arguments)]
 pub async fn generate_ai_explanation_hf(
     user_skills: &[String],
     job_skills: &[String],
@@ -296,7 +954,7 @@ Format: Start with overall assessment, then mention key strengths, then areas fo
     
     info!("Calling Hugging Face API for job match explanation");
     let response = client
-        .post(&format!("https://router.huggingface.co/hf-inference/models/{}", model))
+        .post(format!("https://router.huggingface.co/hf-inference/models/{}", model))
         .header("Authorization", format!("Bearer {}", api_key))
         .header("Content-Type", "application/json")
         .json(&serde_json::json!({
@@ -353,54 +1011,3 @@ Format: Start with overall assessment, then mention key strengths, then areas fo
     
     Ok(cleaned)
 }
-
-/// Generate AI explanation with fallback to heuristic
-/// Tries Hugging Face API first, falls back to heuristic if it fails
-pub async fn generate_ai_explanation(
-    user_skills: &[String],
-    job_skills: &[String],
-    user_experience: Option<&str>,
-    job_experience: &str,
-    user_track: Option<&str>,
-    job_title: &str,
-    job_description: &str,
-    match_score: f64,
-    skill_overlap: f64,
-    experience_alignment: f64,
-    track_alignment: f64,
-) -> String {
-    // Try Hugging Face API first
-    match generate_ai_explanation_hf(
-        user_skills,
-        job_skills,
-        user_experience,
-        job_experience,
-        user_track,
-        job_title,
-        job_description,
-        match_score,
-    ).await {
-        Ok(explanation) => {
-            info!("Successfully generated AI explanation using Hugging Face");
-            explanation
-        }
-        Err(e) => {
-            warn!("Failed to generate AI explanation: {}. Falling back to heuristic.", e);
-            // Fallback to heuristic explanation
-            let (explanation, _, _) = generate_match_explanation(
-                user_skills,
-                job_skills,
-                user_experience,
-                job_experience,
-                user_track,
-                job_title,
-                skill_overlap,
-                experience_alignment,
-                track_alignment,
-                match_score,
-            );
-            explanation
-        }
-    }
-}
-    
\ No newline at end of file
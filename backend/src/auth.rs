@@ -112,33 +112,28 @@ where
 {
     type Rejection = AppError;
 
-    fn from_request_parts(
-        parts: &mut Parts,
-        _state: &S,
-    ) -> impl std::future::Future<Output = Result<Self, Self::Rejection>> + Send {
-        async move {
-            // Extract token from Authorization header
-            let auth_header = parts
-                .headers
-                .get(header::AUTHORIZATION)
-                .and_then(|value| value.to_str().ok())
-                .ok_or(AppError::Unauthorized)?;
+    async fn from_request_parts(parts: &mut Parts, _state: &S) -> Result<Self, Self::Rejection> {
+        // Extract token from Authorization header
+        let auth_header = parts
+            .headers
+            .get(header::AUTHORIZATION)
+            .and_then(|value| value.to_str().ok())
+            .ok_or(AppError::Unauthorized)?;
 
-            // Expect format: "Bearer <token>"
-            let token = auth_header
-                .strip_prefix("Bearer ")
-                .ok_or(AppError::Unauthorized)?;
+        // Expect format: "Bearer <token>"
+        let token = auth_header
+            .strip_prefix("Bearer ")
+            .ok_or(AppError::Unauthorized)?;
 
-            // Verify the token
-            let claims = verify_jwt(token)?;
-            
-            let user_id = Uuid::parse_str(&claims.sub)
-                .map_err(|_| AppError::Unauthorized)?;
+        // Verify the token
+        let claims = verify_jwt(token)?;
+        
+        let user_id = Uuid::parse_str(&claims.sub)
+            .map_err(|_| AppError::Unauthorized)?;
 
-            Ok(AuthUser {
-                user_id,
-                email: claims.email,
-            })
-        }
+        Ok(AuthUser {
+            user_id,
+            email: claims.email,
+        })
     }
 }
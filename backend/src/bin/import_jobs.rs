@@ -0,0 +1,135 @@
+//! Bulk job import command-line tool.
+//!
+//! Imports jobs from a CSV or JSON file, the same way as
+//! `POST /api/employer/jobs/import`, and prints the import report as JSON.
+//!
+//! ```text
+//! cargo run --bin import_jobs -- <file> [--format csv|json] [--mapping mapping.json]
+//!     [--company-id <id>] [--dry-run]
+//! ```
+//!
+//! Without `--company-id` the jobs are unaffiliated listings and every row
+//! must have a `company` column. Reads `DATABASE_URL` from the environment.
+
+use backend::errors::{AppError, AppResult};
+use backend::job_import::{self, ColumnMapping, ImportFormat, ImportOptions};
+use dotenvy::dotenv;
+use sqlx::PgPool;
+use std::env;
+use std::process::ExitCode;
+
+const USAGE: &str = "Usage: import_jobs <file> [--format csv|json] [--mapping mapping.json] \
+[--company-id <id>] [--dry-run]";
+
+/// Parsed command-line arguments.
+struct Args {
+    file: String,
+    format: Option<ImportFormat>,
+    mapping: Option<String>,
+    company_id: Option<i32>,
+    dry_run: bool,
+}
+
+fn parse_args() -> Result<Args, String> {
+    let mut args = env::args().skip(1);
+    let mut file = None;
+    let mut format = None;
+    let mut mapping = None;
+    let mut company_id = None;
+    let mut dry_run = false;
+
+    while let Some(arg) = args.next() {
+        let mut value = |name: &str| args.next().ok_or(format!("{} requires a value", name));
+        match arg.as_str() {
+            "--format" => format = Some(value("--format")?.parse()?),
+            "--mapping" => mapping = Some(value("--mapping")?),
+            "--company-id" => {
+                company_id = Some(
+                    value("--company-id")?
+                        .parse()
+                        .map_err(|_| "--company-id must be a number".to_string())?,
+                )
+            }
+            "--dry-run" => dry_run = true,
+            "-h" | "--help" => return Err(USAGE.to_string()),
+            other if other.starts_with("--") => return Err(format!("Unknown option {}", other)),
+            other => file = Some(other.to_string()),
+        }
+    }
+
+    Ok(Args {
+        file: file.ok_or("No import file given")?,
+        format,
+        mapping,
+        company_id,
+        dry_run,
+    })
+}
+
+async fn run(args: Args) -> AppResult<bool> {
+    let format = args
+        .format
+        .or_else(|| ImportFormat::from_file_name(&args.file))
+        .ok_or_else(|| {
+            AppError::BadRequest("Cannot tell the file format; pass --format csv|json".to_string())
+        })?;
+    let data = std::fs::read(&args.file)
+        .map_err(|e| AppError::BadRequest(format!("Failed to read {}: {}", args.file, e)))?;
+    let mapping = match &args.mapping {
+        Some(path) => ColumnMapping::from_json(&std::fs::read_to_string(path).map_err(|e| {
+            AppError::BadRequest(format!("Failed to read {}: {}", path, e))
+        })?)?,
+        None => ColumnMapping::default(),
+    };
+
+    let database_url = env::var("DATABASE_URL")
+        .map_err(|_| AppError::ConfigurationError("DATABASE_URL must be set".to_string()))?;
+    let pool = PgPool::connect(&database_url).await?;
+
+    if let Some(company_id) = args.company_id {
+        sqlx::query_scalar!("SELECT id FROM companies WHERE id = $1", company_id)
+            .fetch_optional(&pool)
+            .await?
+            .ok_or_else(|| AppError::BadRequest(format!("Company {} not found", company_id)))?;
+    }
+
+    let rows = job_import::parse_rows(format, &data)?;
+    let report = job_import::import_jobs(
+        &pool,
+        rows,
+        &mapping,
+        &ImportOptions {
+            company_id: args.company_id,
+            posted_by: None,
+            dry_run: args.dry_run,
+        },
+    )
+    .await?;
+
+    let output = serde_json::to_string_pretty(&report).map_err(|_| AppError::InternalServerError)?;
+    println!("{}", output);
+
+    Ok(report.failed == 0)
+}
+
+#[tokio::main]
+async fn main() -> ExitCode {
+    dotenv().ok();
+
+    let args = match parse_args() {
+        Ok(args) => args,
+        Err(message) => {
+            eprintln!("{}\n{}", message, USAGE);
+            return ExitCode::from(2);
+        }
+    };
+
+    match run(args).await {
+        Ok(true) => ExitCode::SUCCESS,
+        Ok(false) => ExitCode::from(1),
+        Err(e) => {
+            eprintln!("Import failed: {}", e);
+            ExitCode::from(2)
+        }
+    }
+}
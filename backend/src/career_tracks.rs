@@ -0,0 +1,233 @@
+//! Career track taxonomy.
+//!
+//! Tracks and their sub-tracks (Web Development > DevOps & Cloud, Mobile
+//! Development) are stored in the `career_tracks` table and edited by admins.
+//! Each track has:
+//! - job title keywords, used to infer the track of a job
+//!   ([`TrackTaxonomy::infer`])
+//! - related skills, used with the keywords to score how well a job fits a
+//!   candidate's preferred track ([`TrackTaxonomy::alignment`])
+//! - a display name, and an optional label for generated CVs
+//!
+//! Profiles, filters and skill demand snapshots refer to tracks by slug.
+//! Inactive tracks stay valid on existing profiles, but cannot be chosen again
+//! and are never inferred for jobs.
+
+use crate::errors::{AppError, AppResult};
+use crate::models::CareerTrack;
+use sqlx::PgPool;
+use std::collections::HashSet;
+
+/// Alignment when the job is in the candidate's track or one of its sub-tracks.
+const SAME_TRACK: f64 = 100.0;
+
+/// Alignment when the job is in a related track (the parent or a sibling
+/// sub-track), or mostly requires the track's related skills.
+const RELATED_TRACK: f64 = 75.0;
+
+/// Alignment when the track is unknown or the job is in an unrelated track.
+const NEUTRAL: f64 = 50.0;
+
+/// Share of a job's required skills that must be related skills of the
+/// candidate's track for the job to count as related.
+const RELATED_SKILL_SHARE: f64 = 0.5;
+
+/// All career tracks, as loaded from the database.
+#[derive(Debug, Clone, Default)]
+pub struct TrackTaxonomy {
+    tracks: Vec<CareerTrack>,
+}
+
+/// Loads every career track, including inactive ones.
+pub async fn load_taxonomy(pool: &PgPool) -> AppResult<TrackTaxonomy> {
+    let tracks = sqlx::query_as!(
+        CareerTrack,
+        r#"
+        SELECT
+            slug, display_name, cv_label, parent_slug, keywords, related_skills, active,
+            created_at, updated_at
+        FROM career_tracks
+        ORDER BY parent_slug NULLS FIRST, slug
+        "#
+    )
+    .fetch_all(pool)
+    .await?;
+
+    Ok(TrackTaxonomy { tracks })
+}
+
+impl TrackTaxonomy {
+    /// Every track, top-level tracks first.
+    pub fn tracks(&self) -> &[CareerTrack] {
+        &self.tracks
+    }
+
+    /// Returns the track with the given slug.
+    pub fn get(&self, slug: &str) -> Option<&CareerTrack> {
+        self.tracks.iter().find(|track| track.slug == slug)
+    }
+
+    /// Finds a track by slug or display name, ignoring case, spaces, hyphens
+    /// and underscores ("Web Development", "web-development", "webdevelopment").
+    pub fn find(&self, name: &str) -> Option<&CareerTrack> {
+        let key = name_key(name);
+        self.tracks
+            .iter()
+            .find(|track| name_key(&track.slug) == key || name_key(&track.display_name) == key)
+    }
+
+    /// Resolves a track chosen by a user or filter to an active track.
+    ///
+    /// # Errors
+    ///
+    /// Returns a validation error listing the active tracks if `name` is not
+    /// one of them.
+    pub fn resolve(&self, name: &str) -> AppResult<&CareerTrack> {
+        self.find(name)
+            .filter(|track| track.active)
+            .ok_or_else(|| {
+                let valid: Vec<&str> = self
+                    .tracks
+                    .iter()
+                    .filter(|track| track.active)
+                    .map(|track| track.slug.as_str())
+                    .collect();
+                AppError::ValidationError(format!(
+                    "Unknown career track: {}. Valid tracks: {}",
+                    name,
+                    valid.join(", ")
+                ))
+            })
+    }
+
+    /// Slugs of a track and its sub-tracks, for filtering by a top-level track.
+    pub fn with_sub_tracks(&self, slug: &str) -> Vec<String> {
+        self.tracks
+            .iter()
+            .filter(|track| track.slug == slug || track.parent_slug.as_deref() == Some(slug))
+            .map(|track| track.slug.clone())
+            .collect()
+    }
+
+    /// Resolves a `track` query filter to the slugs of the track and its
+    /// sub-tracks.
+    ///
+    /// # Errors
+    ///
+    /// Returns a validation error if `track` is not an active track.
+    pub fn resolve_filter(&self, track: Option<&str>) -> AppResult<Option<Vec<String>>> {
+        track
+            .map(|track| Ok(self.with_sub_tracks(&self.resolve(track)?.slug)))
+            .transpose()
+    }
+
+    /// Display name of a track, falling back to the slug for unknown tracks.
+    pub fn display_name<'a>(&'a self, slug: &'a str) -> &'a str {
+        self.get(slug).map_or(slug, |track| track.display_name.as_str())
+    }
+
+    /// Label of a track on generated CVs.
+    pub fn cv_label<'a>(&'a self, slug: &'a str) -> &'a str {
+        self.get(slug)
+            .and_then(|track| track.cv_label.as_deref())
+            .unwrap_or_else(|| self.display_name(slug))
+    }
+
+    /// Infers the track of a job from its title.
+    ///
+    /// The active track with the longest keyword found in the title wins,
+    /// preferring sub-tracks on ties, so "Android Developer" is Mobile
+    /// Development and "Web Designer" is Design.
+    pub fn infer(&self, job_title: &str) -> Option<&CareerTrack> {
+        let title = job_title.to_lowercase();
+        self.tracks
+            .iter()
+            .filter(|track| track.active)
+            .filter_map(|track| {
+                longest_keyword(track, &title).map(|len| ((len, track.parent_slug.is_some()), track))
+            })
+            .max_by_key(|(rank, _)| *rank)
+            .map(|(_, track)| track)
+    }
+
+    /// Scores how well a job fits a candidate's preferred track (0-100).
+    ///
+    /// - 100: the title has a keyword of the track or one of its sub-tracks
+    /// - 75: the job's inferred track is the parent or a sibling sub-track, or
+    ///   at least half of its required skills are related skills of the track
+    /// - 50: otherwise, or when the candidate has no (known) track
+    pub fn alignment(&self, user_track: Option<&str>, job_title: &str, job_skills: &[String]) -> f64 {
+        let Some(track) = user_track.and_then(|slug| self.get(slug)) else {
+            return NEUTRAL;
+        };
+
+        let title = job_title.to_lowercase();
+        let in_track = self
+            .tracks
+            .iter()
+            .filter(|t| t.slug == track.slug || t.parent_slug.as_deref() == Some(track.slug.as_str()))
+            .any(|t| longest_keyword(t, &title).is_some());
+        if in_track {
+            return SAME_TRACK;
+        }
+
+        let related_track = self
+            .infer(job_title)
+            .is_some_and(|job_track| family(job_track) == family(track));
+        if related_track || related_skill_share(track, job_skills) >= RELATED_SKILL_SHARE {
+            RELATED_TRACK
+        } else {
+            NEUTRAL
+        }
+    }
+}
+
+/// Lowercases, trims and deduplicates title keywords.
+pub fn normalize_keywords(keywords: Vec<String>) -> Vec<String> {
+    let mut seen = HashSet::new();
+    keywords
+        .into_iter()
+        .map(|keyword| keyword.trim().to_lowercase())
+        .filter(|keyword| !keyword.is_empty() && seen.insert(keyword.clone()))
+        .collect()
+}
+
+/// Comparison key of a track slug or name.
+fn name_key(name: &str) -> String {
+    name.chars()
+        .filter(|c| !matches!(c, ' ' | '-' | '_'))
+        .flat_map(char::to_lowercase)
+        .collect()
+}
+
+/// Length of the longest of the track's keywords found in a lowercased title.
+fn longest_keyword(track: &CareerTrack, title: &str) -> Option<usize> {
+    track
+        .keywords
+        .iter()
+        .filter(|keyword| !keyword.is_empty() && title.contains(keyword.as_str()))
+        .map(String::len)
+        .max()
+}
+
+/// The top-level track a track belongs to.
+fn family(track: &CareerTrack) -> &str {
+    track.parent_slug.as_deref().unwrap_or(&track.slug)
+}
+
+/// Share of a job's required skills that are related skills of a track.
+fn related_skill_share(track: &CareerTrack, job_skills: &[String]) -> f64 {
+    if job_skills.is_empty() {
+        return 0.0;
+    }
+    let related: HashSet<String> = track
+        .related_skills
+        .iter()
+        .map(|skill| skill.to_lowercase())
+        .collect();
+    let matching = job_skills
+        .iter()
+        .filter(|skill| related.contains(&skill.to_lowercase()))
+        .count();
+    matching as f64 / job_skills.len() as f64
+}
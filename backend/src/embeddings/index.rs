@@ -0,0 +1,162 @@
+//! In-memory index of job vectors for semantic search.
+//!
+//! Keeps the stored vectors of jobs for the provider's model as unit vectors
+//! in one contiguous buffer, so finding the jobs closest to a query is a
+//! single pass of dot products instead of a cosine per row in SQL. The index
+//! picks up vectors stored since it was last synced before each search, and
+//! is rebuilt after every embedding refresh to drop vectors of jobs that
+//! closed.
+
+use crate::errors::AppResult;
+use chrono::{DateTime, Duration, Utc};
+use sqlx::PgPool;
+use std::collections::HashMap;
+use std::sync::RwLock;
+use tracing::debug;
+
+/// How far before the newest vector seen each sync looks back.
+///
+/// Covers vectors whose transaction committed after a previous sync ran.
+const SYNC_OVERLAP_SECONDS: i64 = 60;
+
+/// Job vectors of one model, normalized and stored contiguously.
+#[derive(Debug, Default)]
+struct IndexState {
+    /// Length of every vector; 0 until the first vector is added
+    dimensions: usize,
+    /// Job ID of each vector, in buffer order
+    ids: Vec<i32>,
+    /// Position of each job's vector in `ids`
+    positions: HashMap<i32, usize>,
+    /// All vectors back to back
+    vectors: Vec<f32>,
+    /// Newest `updated_at` among the vectors loaded
+    synced_until: Option<DateTime<Utc>>,
+}
+
+impl IndexState {
+    /// Adds or replaces a job's vector, skipping vectors of another length.
+    fn upsert(&mut self, job_id: i32, vector: &[f32]) {
+        if self.dimensions == 0 {
+            self.dimensions = vector.len();
+        }
+        let norm = vector.iter().map(|x| x * x).sum::<f32>().sqrt();
+        if vector.len() != self.dimensions || norm == 0.0 {
+            return;
+        }
+
+        let normalized = vector.iter().map(|x| x / norm);
+        match self.positions.get(&job_id) {
+            Some(&position) => {
+                let start = position * self.dimensions;
+                self.vectors.splice(start..start + self.dimensions, normalized);
+            }
+            None => {
+                self.positions.insert(job_id, self.ids.len());
+                self.ids.push(job_id);
+                self.vectors.extend(normalized);
+            }
+        }
+    }
+}
+
+/// Searchable job vectors of a single embedding model.
+#[derive(Debug)]
+pub struct JobVectorIndex {
+    model: String,
+    state: RwLock<IndexState>,
+}
+
+impl JobVectorIndex {
+    /// Creates an empty index for vectors of the given model.
+    pub fn new(model: impl Into<String>) -> Self {
+        Self {
+            model: model.into(),
+            state: RwLock::new(IndexState::default()),
+        }
+    }
+
+    /// Loads job vectors stored since the last sync (all of them the first
+    /// time).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the vectors cannot be loaded.
+    pub async fn sync(&self, pool: &PgPool) -> AppResult<()> {
+        let since = self
+            .state
+            .read()
+            .expect("job vector index lock poisoned")
+            .synced_until
+            .map(|at| at - Duration::seconds(SYNC_OVERLAP_SECONDS));
+
+        let rows = sqlx::query!(
+            r#"
+            SELECT entity_id, vector, updated_at
+            FROM embeddings
+            WHERE entity = 'job' AND model = $1
+              AND ($2::TIMESTAMPTZ IS NULL OR updated_at > $2)
+            "#,
+            self.model,
+            since
+        )
+        .fetch_all(pool)
+        .await?;
+
+        if rows.is_empty() {
+            return Ok(());
+        }
+
+        let mut state = self.state.write().expect("job vector index lock poisoned");
+        for row in &rows {
+            if let Ok(job_id) = row.entity_id.parse() {
+                state.upsert(job_id, &row.vector);
+            }
+            state.synced_until = state.synced_until.max(row.updated_at);
+        }
+        debug!("Job vector index synced {} vectors", rows.len());
+        Ok(())
+    }
+
+    /// Rebuilds the index from the stored vectors, dropping removed ones.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the vectors cannot be loaded.
+    pub async fn reload(&self, pool: &PgPool) -> AppResult<()> {
+        let fresh = Self::new(self.model.clone());
+        fresh.sync(pool).await?;
+        let state = fresh.state.into_inner().expect("job vector index lock poisoned");
+        debug!("Job vector index rebuilt with {} vectors", state.ids.len());
+        *self.state.write().expect("job vector index lock poisoned") = state;
+        Ok(())
+    }
+
+    /// The `k` jobs most similar to the query with a positive cosine, most
+    /// similar first.
+    pub fn nearest(&self, query: &[f32], k: usize) -> Vec<(i32, f32)> {
+        let state = self.state.read().expect("job vector index lock poisoned");
+        let norm = query.iter().map(|x| x * x).sum::<f32>().sqrt();
+        if query.len() != state.dimensions || norm == 0.0 || k == 0 {
+            return Vec::new();
+        }
+
+        let mut scored: Vec<(i32, f32)> = state
+            .vectors
+            .chunks_exact(state.dimensions)
+            .zip(&state.ids)
+            .map(|(vector, &id)| {
+                let dot: f32 = vector.iter().zip(query).map(|(x, y)| x * y).sum();
+                (id, dot / norm)
+            })
+            .filter(|(_, similarity)| *similarity > 0.0)
+            .collect();
+
+        if scored.len() > k {
+            scored.select_nth_unstable_by(k - 1, |a, b| b.1.total_cmp(&a.1));
+            scored.truncate(k);
+        }
+        scored.sort_by(|a, b| b.1.total_cmp(&a.1));
+        scored
+    }
+}
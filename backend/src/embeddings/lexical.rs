@@ -0,0 +1,120 @@
+//! Lexical fallback for embeddings.
+//!
+//! Not a language model: it hashes words, word pairs and character trigrams
+//! into a fixed number of dimensions (the "hashing trick"), so texts sharing
+//! vocabulary, or spellings of it ("Postgres", "PostgreSQL"), point the same
+//! way. It has no knowledge of meaning; texts with no words in common are
+//! unrelated to it. It runs on the CPU in microseconds and needs no model
+//! files, so semantic features work out of the box, and relates skills only
+//! through the parent skills and categories the documents spell out. Use a
+//! real model through [`super::LocalEmbedding`] or
+//! [`super::RemoteEmbedding`] for semantic similarity.
+
+use super::EmbeddingProvider;
+use crate::errors::AppResult;
+use crate::hashing::fnv1a;
+use std::collections::HashMap;
+
+/// Default number of dimensions.
+const DEFAULT_DIMENSIONS: usize = 384;
+
+/// Weight of a word.
+const WORD_WEIGHT: f32 = 1.0;
+
+/// Weight of a pair of adjacent words.
+const BIGRAM_WEIGHT: f32 = 0.5;
+
+/// Total weight of a word's character trigrams.
+const TRIGRAM_WEIGHT: f32 = 0.5;
+
+/// Words that carry no meaning for matching.
+const STOP_WORDS: [&str; 40] = [
+    "a", "an", "and", "are", "as", "at", "be", "by", "can", "for", "from", "has", "have", "in",
+    "is", "it", "its", "of", "on", "or", "our", "should", "that", "the", "their", "this", "to",
+    "we", "will", "with", "you", "your", "who", "which", "looking", "join", "team", "work",
+    "role", "skills",
+];
+
+/// Hashed bag-of-words vectors, computed in-process.
+#[derive(Debug, Clone)]
+pub struct LexicalEmbedding {
+    dimensions: usize,
+    model: String,
+}
+
+impl Default for LexicalEmbedding {
+    fn default() -> Self {
+        Self::new(DEFAULT_DIMENSIONS)
+    }
+}
+
+impl LexicalEmbedding {
+    /// Creates a model with the given number of dimensions.
+    pub fn new(dimensions: usize) -> Self {
+        let dimensions = dimensions.max(1);
+        Self {
+            dimensions,
+            model: format!("lexical-hashing-{}", dimensions),
+        }
+    }
+
+    /// Embeds a single text as a unit vector (all zeros for empty text).
+    pub fn embed_text(&self, text: &str) -> Vec<f32> {
+        let words = tokenize(text);
+
+        let mut features: HashMap<String, f32> = HashMap::new();
+        for word in &words {
+            *features.entry(word.clone()).or_default() += WORD_WEIGHT;
+
+            let padded: Vec<char> = format!("<{}>", word).chars().collect();
+            if padded.len() > 4 {
+                let trigrams = padded.len() - 2;
+                for window in padded.windows(3) {
+                    let trigram: String = window.iter().collect();
+                    *features.entry(format!("#{}", trigram)).or_default() +=
+                        TRIGRAM_WEIGHT / trigrams as f32;
+                }
+            }
+        }
+        for pair in words.windows(2) {
+            *features.entry(format!("{} {}", pair[0], pair[1])).or_default() += BIGRAM_WEIGHT;
+        }
+
+        let mut vector = vec![0.0f32; self.dimensions];
+        for (feature, weight) in features {
+            let hash = fnv1a(feature.as_bytes());
+            let index = (hash % self.dimensions as u64) as usize;
+            let sign = if hash >> 63 == 0 { 1.0 } else { -1.0 };
+            // Dampen repeated words so long texts are not dominated by them
+            vector[index] += sign * weight.ln_1p();
+        }
+
+        let norm = vector.iter().map(|x| x * x).sum::<f32>().sqrt();
+        if norm > 0.0 {
+            vector.iter_mut().for_each(|x| *x /= norm);
+        }
+        vector
+    }
+}
+
+#[async_trait::async_trait]
+impl EmbeddingProvider for LexicalEmbedding {
+    fn model(&self) -> &str {
+        &self.model
+    }
+
+    async fn embed(&self, texts: &[String]) -> AppResult<Vec<Vec<f32>>> {
+        Ok(texts.iter().map(|text| self.embed_text(text)).collect())
+    }
+}
+
+/// Lowercase words, keeping `+`, `#` and inner dots ("c++", "c#", "node.js").
+fn tokenize(text: &str) -> Vec<String> {
+    text.to_lowercase()
+        .split(|c: char| !(c.is_alphanumeric() || matches!(c, '+' | '#' | '.')))
+        .map(|word| word.trim_matches('.'))
+        .filter(|word| !word.is_empty() && !STOP_WORDS.contains(word))
+        .filter(|word| word.chars().count() > 1 || matches!(*word, "c" | "r"))
+        .map(str::to_string)
+        .collect()
+}
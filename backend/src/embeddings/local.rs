@@ -0,0 +1,322 @@
+//! Sentence embeddings from a local transformer model.
+//!
+//! Runs a BERT-family sentence-transformers model, such as
+//! `sentence-transformers/all-MiniLM-L6-v2`, on the CPU with Candle. The
+//! model is read from a directory holding its `config.json`,
+//! `tokenizer.json` and `model.safetensors`, as downloaded from the Hugging
+//! Face Hub. Token vectors are pooled the way the model's
+//! `1_Pooling/config.json` says (mean pooling when it is missing) and
+//! normalized, so texts close in meaning get close vectors even when they
+//! share no words.
+
+use super::EmbeddingProvider;
+use crate::errors::{AppError, AppResult};
+use candle_core::{DType, Device, IndexOp, Tensor};
+use candle_nn::VarBuilder;
+use candle_transformers::models::bert::{BertModel, Config};
+use serde::Deserialize;
+use std::fmt::Display;
+use std::path::Path;
+use std::sync::Arc;
+use tokenizers::{PaddingParams, PaddingStrategy, Tokenizer, TruncationParams};
+use tokio::task::spawn_blocking;
+use tracing::error;
+
+/// Directory the model is read from when `EMBEDDING_MODEL_DIR` is not set.
+pub const DEFAULT_MODEL_DIR: &str = "models/all-MiniLM-L6-v2";
+
+/// Texts embedded per forward pass.
+const BATCH_SIZE: usize = 32;
+
+/// Tokens of a text that are embedded; longer texts are cut off.
+const MAX_TOKENS: usize = 256;
+
+/// How token vectors are combined into a text vector.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Pooling {
+    /// Mean of the token vectors
+    Mean,
+    /// Vector of the `[CLS]` token
+    Cls,
+}
+
+/// Pooling settings of a sentence-transformers model.
+#[derive(Debug, Default, Deserialize)]
+struct PoolingConfig {
+    #[serde(default)]
+    pooling_mode_cls_token: bool,
+}
+
+/// A loaded model and its tokenizer.
+struct Model {
+    bert: BertModel,
+    tokenizer: Tokenizer,
+    pooling: Pooling,
+}
+
+impl Model {
+    /// Embeds a batch of texts as unit vectors.
+    fn embed_batch(&self, texts: &[String]) -> candle_core::Result<Vec<Vec<f32>>> {
+        let encodings = self
+            .tokenizer
+            .encode_batch(texts.to_vec(), true)
+            .map_err(candle_core::Error::msg)?;
+        // Padding makes every encoding as long as the longest one
+        let tokens = encodings.first().map_or(0, |encoding| encoding.len());
+        let ids: Vec<u32> = encodings.iter().flat_map(|e| e.get_ids().to_vec()).collect();
+        let mask: Vec<u32> = encodings
+            .iter()
+            .flat_map(|e| e.get_attention_mask().to_vec())
+            .collect();
+
+        let input_ids = Tensor::from_vec(ids, (texts.len(), tokens), &Device::Cpu)?;
+        let attention_mask = Tensor::from_vec(mask, (texts.len(), tokens), &Device::Cpu)?;
+        let token_type_ids = input_ids.zeros_like()?;
+        let hidden = self
+            .bert
+            .forward(&input_ids, &token_type_ids, Some(&attention_mask))?;
+
+        let pooled = match self.pooling {
+            Pooling::Cls => hidden.i((.., 0))?,
+            Pooling::Mean => {
+                let mask = attention_mask.to_dtype(DType::F32)?.unsqueeze(2)?;
+                let counts = mask.sum(1)?.clamp(1e-9, f32::MAX)?;
+                hidden.broadcast_mul(&mask)?.sum(1)?.broadcast_div(&counts)?
+            }
+        };
+        let norms = pooled.sqr()?.sum_keepdim(1)?.sqrt()?.clamp(1e-12, f32::MAX)?;
+        pooled.broadcast_div(&norms)?.to_vec2()
+    }
+}
+
+/// Sentence embeddings computed in-process on the CPU.
+pub struct LocalEmbedding {
+    model: String,
+    inner: Arc<Model>,
+}
+
+impl LocalEmbedding {
+    /// Loads the model in `dir`; its vectors are stored as `local:{name}`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a model file is missing or invalid.
+    pub fn load(dir: &Path, name: &str) -> AppResult<Self> {
+        let load_error = |file: &str, e: &dyn Display| {
+            AppError::ConfigurationError(format!(
+                "Failed to load embedding model file {}: {}",
+                dir.join(file).display(),
+                e
+            ))
+        };
+
+        let config = std::fs::read_to_string(dir.join("config.json"))
+            .map_err(|e| load_error("config.json", &e))?;
+        let config: Config =
+            serde_json::from_str(&config).map_err(|e| load_error("config.json", &e))?;
+
+        let mut tokenizer = Tokenizer::from_file(dir.join("tokenizer.json"))
+            .map_err(|e| load_error("tokenizer.json", &e))?;
+        let padding = PaddingParams {
+            strategy: PaddingStrategy::BatchLongest,
+            ..tokenizer.get_padding().cloned().unwrap_or_default()
+        };
+        tokenizer.with_padding(Some(padding));
+        tokenizer
+            .with_truncation(Some(TruncationParams {
+                max_length: MAX_TOKENS.min(config.max_position_embeddings),
+                ..Default::default()
+            }))
+            .map_err(|e| load_error("tokenizer.json", &e))?;
+
+        let weights = std::fs::read(dir.join("model.safetensors"))
+            .map_err(|e| load_error("model.safetensors", &e))?;
+        let bert = VarBuilder::from_buffered_safetensors(weights, DType::F32, &Device::Cpu)
+            .and_then(|weights| BertModel::load(weights, &config))
+            .map_err(|e| load_error("model.safetensors", &e))?;
+
+        let pooling = match std::fs::read_to_string(dir.join("1_Pooling/config.json")) {
+            Ok(pooling) => serde_json::from_str::<PoolingConfig>(&pooling)
+                .map_err(|e| load_error("1_Pooling/config.json", &e))?,
+            Err(_) => PoolingConfig::default(),
+        };
+        let pooling = if pooling.pooling_mode_cls_token {
+            Pooling::Cls
+        } else {
+            Pooling::Mean
+        };
+
+        Ok(Self {
+            model: format!("local:{}", name),
+            inner: Arc::new(Model {
+                bert,
+                tokenizer,
+                pooling,
+            }),
+        })
+    }
+}
+
+#[async_trait::async_trait]
+impl EmbeddingProvider for LocalEmbedding {
+    fn model(&self) -> &str {
+        &self.model
+    }
+
+    async fn embed(&self, texts: &[String]) -> AppResult<Vec<Vec<f32>>> {
+        let model = self.inner.clone();
+        let texts = texts.to_vec();
+        spawn_blocking(move || {
+            let mut vectors = Vec::with_capacity(texts.len());
+            for batch in texts.chunks(BATCH_SIZE) {
+                vectors.extend(model.embed_batch(batch).map_err(|e| {
+                    error!("Failed to embed texts with the local model: {}", e);
+                    AppError::InternalServerError
+                })?);
+            }
+            Ok(vectors)
+        })
+        .await
+        .map_err(|e| {
+            error!("Task join error during embedding: {}", e);
+            AppError::InternalServerError
+        })?
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use candle_nn::VarMap;
+    use tokenizers::models::wordpiece::WordPiece;
+    use tokenizers::normalizers::BertNormalizer;
+    use tokenizers::pre_tokenizers::bert::BertPreTokenizer;
+    use tokenizers::processors::bert::BertProcessing;
+
+    const VOCAB: [&str; 12] = [
+        "[PAD]", "[UNK]", "[CLS]", "[SEP]", "python", "django", "developer", "backend", "account",
+        "##ant", "senior", ".",
+    ];
+
+    const HIDDEN_SIZE: usize = 8;
+
+    /// Writes a tiny randomly initialized BERT model and its tokenizer.
+    fn write_model(dir: &Path) {
+        let config = serde_json::json!({
+            "vocab_size": VOCAB.len(),
+            "hidden_size": HIDDEN_SIZE,
+            "num_hidden_layers": 1,
+            "num_attention_heads": 2,
+            "intermediate_size": 16,
+            "hidden_act": "gelu",
+            "hidden_dropout_prob": 0.0,
+            "max_position_embeddings": 16,
+            "type_vocab_size": 2,
+            "initializer_range": 0.02,
+            "layer_norm_eps": 1e-12,
+            "pad_token_id": 0,
+            "classifier_dropout": null,
+            "model_type": "bert"
+        });
+        std::fs::write(dir.join("config.json"), config.to_string()).unwrap();
+
+        let vocab_file = dir.join("vocab.txt");
+        std::fs::write(&vocab_file, VOCAB.join("\n")).unwrap();
+        let wordpiece = WordPiece::from_file(vocab_file.to_str().unwrap())
+            .unk_token("[UNK]".to_string())
+            .build()
+            .unwrap();
+        let mut tokenizer = Tokenizer::new(wordpiece);
+        tokenizer
+            .with_normalizer(Some(BertNormalizer::default()))
+            .with_pre_tokenizer(Some(BertPreTokenizer))
+            .with_post_processor(Some(BertProcessing::new(
+                ("[SEP]".to_string(), 3),
+                ("[CLS]".to_string(), 2),
+            )));
+        tokenizer.save(dir.join("tokenizer.json"), false).unwrap();
+
+        let config: Config = serde_json::from_value(config).unwrap();
+        let weights = VarMap::new();
+        BertModel::load(
+            VarBuilder::from_varmap(&weights, DType::F32, &Device::Cpu),
+            &config,
+        )
+        .unwrap();
+        weights.save(dir.join("model.safetensors")).unwrap();
+    }
+
+    fn texts(texts: &[&str]) -> Vec<String> {
+        texts.iter().map(|text| text.to_string()).collect()
+    }
+
+    #[tokio::test]
+    async fn embeds_texts_as_unit_vectors() {
+        let dir = tempfile::tempdir().unwrap();
+        write_model(dir.path());
+        let provider = LocalEmbedding::load(dir.path(), "tiny").unwrap();
+        assert_eq!(provider.model(), "local:tiny");
+
+        let vectors = provider
+            .embed(&texts(&["Python developer", "Accountant", "python DEVELOPER", ""]))
+            .await
+            .unwrap();
+
+        assert_eq!(vectors.len(), 4);
+        for vector in &vectors {
+            assert_eq!(vector.len(), HIDDEN_SIZE);
+            let norm = vector.iter().map(|x| x * x).sum::<f32>().sqrt();
+            assert!((norm - 1.0).abs() < 1e-4, "norm {norm}");
+        }
+        assert_eq!(vectors[0], vectors[2]);
+        assert_ne!(vectors[0], vectors[1]);
+        assert!(provider.embed(&[]).await.unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn padding_and_batching_leave_vectors_unchanged() {
+        let dir = tempfile::tempdir().unwrap();
+        write_model(dir.path());
+        let provider = LocalEmbedding::load(dir.path(), "tiny").unwrap();
+
+        let alone = provider.embed(&texts(&["django"])).await.unwrap();
+        // More texts than a batch, the first padded to the longest
+        let mut many = texts(&["django", "senior python backend developer ."]);
+        many.extend(std::iter::repeat_n("python".to_string(), BATCH_SIZE));
+        many.push("django".to_string());
+        let batched = provider.embed(&many).await.unwrap();
+
+        assert_eq!(batched.len(), many.len());
+        for vector in [&batched[0], &batched[many.len() - 1]] {
+            let distance: f32 = vector.iter().zip(&alone[0]).map(|(a, b)| (a - b).abs()).sum();
+            assert!(distance < 1e-4, "distance {distance}");
+        }
+    }
+
+    #[tokio::test]
+    async fn pools_the_cls_token_when_configured() {
+        let dir = tempfile::tempdir().unwrap();
+        write_model(dir.path());
+        let text = texts(&["python backend developer"]);
+        let mean = LocalEmbedding::load(dir.path(), "tiny").unwrap().embed(&text).await.unwrap();
+
+        std::fs::create_dir(dir.path().join("1_Pooling")).unwrap();
+        std::fs::write(
+            dir.path().join("1_Pooling/config.json"),
+            r#"{"word_embedding_dimension": 8, "pooling_mode_cls_token": true}"#,
+        )
+        .unwrap();
+        let cls = LocalEmbedding::load(dir.path(), "tiny").unwrap().embed(&text).await.unwrap();
+
+        assert_ne!(mean, cls);
+    }
+
+    #[test]
+    fn load_fails_without_model_files() {
+        let dir = tempfile::tempdir().unwrap();
+
+        let error = LocalEmbedding::load(dir.path(), "missing").err().unwrap();
+
+        assert!(matches!(error, AppError::ConfigurationError(ref m) if m.contains("config.json")));
+    }
+}
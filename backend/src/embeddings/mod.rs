@@ -0,0 +1,249 @@
+//! Text embeddings for semantic matching.
+//!
+//! An [`EmbeddingProvider`] turns text into vectors whose cosine similarity
+//! reflects how related the texts are. Providers, chosen with
+//! `EMBEDDING_PROVIDER`:
+//! - `local` (default) - [`LocalEmbedding`], a sentence-transformers model
+//!   run on the CPU, which places a profile listing Django close to a
+//!   "Python backend" role from meaning alone
+//! - `remote` - [`RemoteEmbedding`], any OpenAI-compatible `/embeddings`
+//!   API (OpenAI, Hugging Face Text Embeddings Inference, Ollama)
+//! - `lexical` - [`LexicalEmbedding`], a hashed bag-of-words fallback that
+//!   needs no model files, but only relates texts through shared words
+//! - `none` - disables semantic matching
+//!
+//! Jobs, learning resources and user profiles are described by documents
+//! ([`job_document`], [`resource_document`], [`profile_document`]) that
+//! spell out skills with their parent skills and categories, so related
+//! skills share words. Vectors are stored in the `embeddings` table and kept
+//! fresh by [`store`]; semantic search looks up jobs in a [`JobVectorIndex`].
+
+mod index;
+mod lexical;
+mod local;
+mod remote;
+pub mod store;
+
+pub use index::JobVectorIndex;
+pub use lexical::LexicalEmbedding;
+pub use local::LocalEmbedding;
+pub use remote::RemoteEmbedding;
+
+use crate::career_tracks::TrackTaxonomy;
+use crate::errors::{AppError, AppResult};
+use crate::match_explanations::CandidateProfile;
+use crate::models::{ExperienceLevel, Job, LearningResource, User};
+use crate::skills::SkillTaxonomy;
+use std::env;
+use std::path::Path;
+use std::sync::Arc;
+use tracing::{info, warn};
+
+/// Characters of a job description included in its document.
+const MAX_DESCRIPTION_CHARS: usize = 1000;
+
+/// Turns text into embedding vectors.
+#[async_trait::async_trait]
+pub trait EmbeddingProvider: Send + Sync {
+    /// Identifies the model; vectors from different models are never compared.
+    fn model(&self) -> &str;
+
+    /// Embeds each text, returning one vector per text in the same order.
+    async fn embed(&self, texts: &[String]) -> AppResult<Vec<Vec<f32>>>;
+}
+
+/// Creates the embedding provider from environment variables.
+///
+/// - `EMBEDDING_PROVIDER` - `local` (default), `remote`, `lexical` or `none`
+/// - `EMBEDDING_MODEL_DIR` - Model directory for `local` (default:
+///   `models/all-MiniLM-L6-v2`)
+/// - `EMBEDDING_API_URL` - Embeddings endpoint for `remote`, e.g.
+///   `https://api.openai.com/v1/embeddings`
+/// - `EMBEDDING_API_KEY` - Bearer token for `remote`, if the API needs one
+/// - `EMBEDDING_MODEL` - Model name for `remote` (default:
+///   `text-embedding-3-small`)
+///
+/// Returns None when semantic matching is disabled, or no provider is set
+/// and the default model directory is missing.
+///
+/// # Errors
+///
+/// Returns an error if the provider is unknown, the model for `local` cannot
+/// be loaded, `EMBEDDING_API_URL` is missing for `remote` or the HTTP client
+/// cannot be created.
+pub fn provider_from_env() -> AppResult<Option<Arc<dyn EmbeddingProvider>>> {
+    let configured = env::var("EMBEDDING_PROVIDER").ok();
+    let provider: Arc<dyn EmbeddingProvider> =
+        match configured.as_deref().unwrap_or("local") {
+            "none" => {
+                info!("⚠ Semantic matching disabled (EMBEDDING_PROVIDER=none)");
+                return Ok(None);
+            }
+            "local" => {
+                let dir = env::var("EMBEDDING_MODEL_DIR")
+                    .unwrap_or_else(|_| local::DEFAULT_MODEL_DIR.to_string());
+                let dir = Path::new(&dir);
+                if configured.is_none() && !dir.exists() {
+                    warn!(
+                        "⚠ Semantic matching disabled: no embedding model in {}",
+                        dir.display()
+                    );
+                    return Ok(None);
+                }
+                let name = dir.file_name().unwrap_or(dir.as_os_str()).to_string_lossy();
+                Arc::new(LocalEmbedding::load(dir, &name)?)
+            }
+            "lexical" => {
+                warn!("⚠ Lexical embeddings only relate texts through shared words");
+                Arc::new(LexicalEmbedding::default())
+            }
+            "remote" => {
+                let url = env::var("EMBEDDING_API_URL").map_err(|_| {
+                    AppError::ConfigurationError(
+                        "EMBEDDING_API_URL must be set for EMBEDDING_PROVIDER=remote".to_string(),
+                    )
+                })?;
+                let model = env::var("EMBEDDING_MODEL")
+                    .unwrap_or_else(|_| remote::DEFAULT_REMOTE_MODEL.to_string());
+                Arc::new(RemoteEmbedding::new(
+                    url,
+                    env::var("EMBEDDING_API_KEY").ok(),
+                    model,
+                )?)
+            }
+            other => {
+                return Err(AppError::ConfigurationError(format!(
+                    "Unknown EMBEDDING_PROVIDER '{}', expected local, remote, lexical or none",
+                    other
+                )));
+            }
+        };
+
+    info!("✓ Semantic matching with embedding model {}", provider.model());
+    Ok(Some(provider))
+}
+
+/// Cosine similarity of two vectors (-1 to 1); 0 when either is empty or
+/// their lengths differ.
+pub fn cosine_similarity(a: &[f32], b: &[f32]) -> f32 {
+    if a.len() != b.len() {
+        return 0.0;
+    }
+    let (dot, norm_a, norm_b) = a.iter().zip(b).fold((0.0, 0.0, 0.0), |(dot, na, nb), (x, y)| {
+        (dot + x * y, na + x * x, nb + y * y)
+    });
+    if norm_a == 0.0 || norm_b == 0.0 {
+        return 0.0;
+    }
+    dot / (norm_a.sqrt() * norm_b.sqrt())
+}
+
+/// Semantic similarity as a score (0-100); dissimilar texts score 0.
+pub fn similarity_score(a: &[f32], b: &[f32]) -> f64 {
+    (cosine_similarity(a, b).max(0.0) as f64) * 100.0
+}
+
+/// Skills with their parent skills and categories, as words.
+fn skill_words(skills: &SkillTaxonomy, names: &[String]) -> String {
+    let mut words = Vec::new();
+    for name in names {
+        words.push(skills.canonical_name(name));
+        if let Some(skill) = skills.find(name) {
+            words.push(skill.category.replace('_', " "));
+        }
+    }
+    for key in skills.keys_with_parents(names) {
+        if let Some(parent) = skills.get(&key)
+            && !words.contains(&parent.name)
+        {
+            words.push(parent.name.clone());
+        }
+    }
+    words.join(", ")
+}
+
+/// Document describing a job: title, skills, level and the start of the
+/// description.
+pub fn job_document(job: &Job, skills: &SkillTaxonomy, tracks: &TrackTaxonomy) -> String {
+    let description: String = job.job_description.chars().take(MAX_DESCRIPTION_CHARS).collect();
+    let mut parts = vec![
+        job.job_title.clone(),
+        format!("Skills: {}", skill_words(skills, &job.required_skills)),
+    ];
+    if !job.preferred_skills.is_empty() {
+        parts.push(format!("Nice to have: {}", skill_words(skills, &job.preferred_skills)));
+    }
+    if let Some(track) = tracks.infer(&job.job_title) {
+        parts.push(format!("Track: {}", track.display_name));
+    }
+    parts.push(format!("Level: {}", job.experience_level.as_str()));
+    parts.push(description);
+    parts.join(". ")
+}
+
+/// Document describing a learning resource: title and skills taught.
+pub fn resource_document(resource: &LearningResource, skills: &SkillTaxonomy) -> String {
+    format!(
+        "{}. Skills: {}",
+        resource.title,
+        skill_words(skills, &resource.related_skills)
+    )
+}
+
+/// Profile fields a user's document is built from.
+pub struct ProfileText<'a> {
+    /// Target job roles
+    pub target_roles: &'a [String],
+    /// Slug of the preferred career track
+    pub preferred_track: Option<&'a str>,
+    /// Skills
+    pub skills: &'a [String],
+    /// Experience level
+    pub experience_level: Option<ExperienceLevel>,
+    /// Completed projects
+    pub projects: &'a [String],
+}
+
+impl<'a> From<&'a User> for ProfileText<'a> {
+    fn from(user: &'a User) -> Self {
+        Self {
+            target_roles: &user.target_roles,
+            preferred_track: user.preferred_track.as_deref(),
+            skills: &user.skills,
+            experience_level: user.experience_level,
+            projects: &user.projects,
+        }
+    }
+}
+
+impl<'a> From<&'a CandidateProfile> for ProfileText<'a> {
+    fn from(candidate: &'a CandidateProfile) -> Self {
+        Self {
+            target_roles: &candidate.target_roles,
+            preferred_track: candidate.preferred_track.as_deref(),
+            skills: &candidate.skills,
+            experience_level: candidate.experience_level,
+            projects: &candidate.projects,
+        }
+    }
+}
+
+/// Document describing a user's profile: target roles, track, skills and
+/// projects.
+pub fn profile_document(user: ProfileText<'_>, skills: &SkillTaxonomy, tracks: &TrackTaxonomy) -> String {
+    let mut parts = Vec::new();
+    if !user.target_roles.is_empty() {
+        parts.push(user.target_roles.join(", "));
+    }
+    if let Some(track) = user.preferred_track {
+        parts.push(format!("Track: {}", tracks.display_name(track)));
+    }
+    parts.push(format!("Skills: {}", skill_words(skills, user.skills)));
+    if let Some(level) = user.experience_level {
+        parts.push(format!("Level: {}", level.as_str()));
+    }
+    if !user.projects.is_empty() {
+        parts.push(format!("Projects: {}", user.projects.join(". ")));
+    }
+    parts.join(". ")
+}
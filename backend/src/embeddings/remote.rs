@@ -0,0 +1,115 @@
+//! Embeddings from an OpenAI-compatible API.
+//!
+//! Works with any service implementing `POST /embeddings` with
+//! `{"model", "input": [...]}` and answering `{"data": [{"index",
+//! "embedding"}]}`: OpenAI, Hugging Face Text Embeddings Inference, Ollama
+//! and others.
+
+use super::EmbeddingProvider;
+use crate::errors::{AppError, AppResult};
+use serde::Deserialize;
+use std::time::Duration;
+
+/// Model used when `EMBEDDING_MODEL` is not set.
+pub const DEFAULT_REMOTE_MODEL: &str = "text-embedding-3-small";
+
+/// Texts sent per request.
+const BATCH_SIZE: usize = 64;
+
+/// Timeout for a single request.
+const REQUEST_TIMEOUT_SECS: u64 = 30;
+
+/// Embeddings API response.
+#[derive(Debug, Deserialize)]
+struct EmbeddingResponse {
+    data: Vec<EmbeddingData>,
+}
+
+#[derive(Debug, Deserialize)]
+struct EmbeddingData {
+    index: usize,
+    embedding: Vec<f32>,
+}
+
+/// Embeddings from a remote API.
+pub struct RemoteEmbedding {
+    client: reqwest::Client,
+    api_url: String,
+    api_key: Option<String>,
+    model_name: String,
+    model: String,
+}
+
+impl RemoteEmbedding {
+    /// Creates a provider for the given endpoint and model.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the HTTP client cannot be created.
+    pub fn new(api_url: String, api_key: Option<String>, model_name: String) -> AppResult<Self> {
+        let client = reqwest::Client::builder()
+            .timeout(Duration::from_secs(REQUEST_TIMEOUT_SECS))
+            .user_agent("CareerBridge/1.0")
+            .build()
+            .map_err(|e| {
+                AppError::ConfigurationError(format!("Failed to create HTTP client: {}", e))
+            })?;
+
+        Ok(Self {
+            client,
+            api_url,
+            api_key,
+            model: format!("remote:{}", model_name),
+            model_name,
+        })
+    }
+
+    /// Embeds up to [`BATCH_SIZE`] texts in one request.
+    async fn embed_batch(&self, texts: &[String]) -> AppResult<Vec<Vec<f32>>> {
+        let mut request = self.client.post(&self.api_url).json(&serde_json::json!({
+            "model": self.model_name,
+            "input": texts,
+        }));
+        if let Some(key) = &self.api_key {
+            request = request.bearer_auth(key);
+        }
+
+        let response = request.send().await.map_err(|e| {
+            AppError::ExternalServiceError(format!("Embedding request failed: {}", e))
+        })?;
+        if !response.status().is_success() {
+            return Err(AppError::ExternalServiceError(format!(
+                "Embedding API returned status: {}",
+                response.status()
+            )));
+        }
+        let mut body: EmbeddingResponse = response.json().await.map_err(|e| {
+            AppError::ExternalServiceError(format!("Failed to parse embedding response: {}", e))
+        })?;
+
+        if body.data.len() != texts.len() {
+            return Err(AppError::ExternalServiceError(format!(
+                "Embedding API returned {} vectors for {} texts",
+                body.data.len(),
+                texts.len()
+            )));
+        }
+        body.data.sort_by_key(|data| data.index);
+        Ok(body.data.into_iter().map(|data| data.embedding).collect())
+    }
+}
+
+#[async_trait::async_trait]
+impl EmbeddingProvider for RemoteEmbedding {
+    fn model(&self) -> &str {
+        &self.model
+    }
+
+    async fn embed(&self, texts: &[String]) -> AppResult<Vec<Vec<f32>>> {
+        let mut vectors = Vec::with_capacity(texts.len());
+        for batch in texts.chunks(BATCH_SIZE) {
+            vectors.extend(self.embed_batch(batch).await?);
+        }
+        Ok(vectors)
+    }
+}
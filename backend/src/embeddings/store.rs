@@ -0,0 +1,416 @@
+//! Stored embedding vectors.
+//!
+//! Vectors are kept in the `embeddings` table per entity (`job`, `resource`
+//! or `user`) together with the model that computed them and a hash of the
+//! document they were computed from. [`embeddings_for`] returns stored
+//! vectors that are still current and embeds the rest, so a document is only
+//! sent to the provider again after it changed. A periodic task embeds open
+//! jobs, learning resources and completed profiles ahead of requests and
+//! removes vectors of deleted or closed entities.
+
+use super::{
+    EmbeddingProvider, JobVectorIndex, ProfileText, job_document, profile_document,
+    resource_document, similarity_score,
+};
+use crate::career_tracks::{TrackTaxonomy, load_taxonomy};
+use crate::errors::AppResult;
+use crate::hashing::fnv1a;
+use crate::job_search::JOB_COLUMNS;
+use crate::models::{CostIndicator, Job, LearningResource, User};
+use crate::skills::{SkillTaxonomy, load_skill_taxonomy};
+use sqlx::PgPool;
+use std::collections::HashMap;
+use std::sync::Arc;
+use tokio::task::JoinHandle;
+use tokio::time::MissedTickBehavior;
+use tracing::{debug, error, info};
+use uuid::Uuid;
+
+/// Documents embedded per provider call during a refresh.
+const REFRESH_BATCH_SIZE: usize = 100;
+
+/// Kind of entity a vector describes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EmbeddingEntity {
+    Job,
+    Resource,
+    User,
+}
+
+impl EmbeddingEntity {
+    /// Value stored in the `entity` column.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            EmbeddingEntity::Job => "job",
+            EmbeddingEntity::Resource => "resource",
+            EmbeddingEntity::User => "user",
+        }
+    }
+}
+
+/// Vectors returned by [`embeddings_for`].
+#[derive(Debug, Default)]
+pub struct EmbeddingLookup {
+    /// Vector per entity ID
+    pub vectors: HashMap<String, Vec<f32>>,
+    /// Number of documents that had to be embedded
+    pub computed: usize,
+}
+
+/// Outcome of a single embedding refresh.
+#[derive(Debug, Default)]
+pub struct EmbeddingRefreshRun {
+    /// Number of job vectors computed
+    pub jobs: usize,
+    /// Number of learning resource vectors computed
+    pub resources: usize,
+    /// Number of profile vectors computed
+    pub users: usize,
+    /// Number of stale vectors removed
+    pub removed: u64,
+}
+
+/// Hash identifying the document a vector was computed from.
+fn content_hash(document: &str) -> String {
+    format!("{:016x}", fnv1a(document.as_bytes()))
+}
+
+/// Returns a vector for each `(entity ID, document)` pair.
+///
+/// Stored vectors are reused when they were computed by the provider's model
+/// from the same document; the others are embedded in one provider call and
+/// stored.
+///
+/// # Errors
+///
+/// Returns an error if the provider fails or a database operation fails.
+pub async fn embeddings_for(
+    pool: &PgPool,
+    provider: &dyn EmbeddingProvider,
+    entity: EmbeddingEntity,
+    documents: &[(String, String)],
+) -> AppResult<EmbeddingLookup> {
+    if documents.is_empty() {
+        return Ok(EmbeddingLookup::default());
+    }
+
+    let ids: Vec<String> = documents.iter().map(|(id, _)| id.clone()).collect();
+    let stored = sqlx::query!(
+        r#"
+        SELECT entity_id, model, content_hash, vector
+        FROM embeddings
+        WHERE entity = $1 AND entity_id = ANY($2)
+        "#,
+        entity.as_str(),
+        &ids
+    )
+    .fetch_all(pool)
+    .await?;
+
+    let mut vectors = HashMap::new();
+    let mut stale = Vec::new();
+    for (id, document) in documents {
+        let hash = content_hash(document);
+        match stored.iter().find(|row| &row.entity_id == id) {
+            Some(row) if row.model == provider.model() && row.content_hash == hash => {
+                vectors.insert(id.clone(), row.vector.clone());
+            }
+            _ => stale.push((id, document, hash)),
+        }
+    }
+
+    if stale.is_empty() {
+        return Ok(EmbeddingLookup {
+            vectors,
+            computed: 0,
+        });
+    }
+
+    let texts: Vec<String> = stale.iter().map(|(_, document, _)| (*document).clone()).collect();
+    let embedded = provider.embed(&texts).await?;
+
+    let mut tx = pool.begin().await?;
+    for ((id, _, hash), vector) in stale.iter().zip(embedded) {
+        sqlx::query!(
+            r#"
+            INSERT INTO embeddings (entity, entity_id, model, content_hash, vector)
+            VALUES ($1, $2, $3, $4, $5)
+            ON CONFLICT (entity, entity_id) DO UPDATE SET
+                model = EXCLUDED.model,
+                content_hash = EXCLUDED.content_hash,
+                vector = EXCLUDED.vector,
+                updated_at = NOW()
+            "#,
+            entity.as_str(),
+            id.as_str(),
+            provider.model(),
+            hash,
+            &vector
+        )
+        .execute(&mut *tx)
+        .await?;
+        vectors.insert((*id).clone(), vector);
+    }
+    tx.commit().await?;
+
+    debug!("Embedded {} {} documents", stale.len(), entity.as_str());
+    Ok(EmbeddingLookup {
+        computed: stale.len(),
+        vectors,
+    })
+}
+
+/// Semantic similarity (0-100) of a user's profile to each job, by job ID.
+///
+/// # Errors
+///
+/// Returns an error if the provider fails or a database operation fails.
+pub async fn job_similarities(
+    pool: &PgPool,
+    provider: &dyn EmbeddingProvider,
+    user_id: Uuid,
+    profile: ProfileText<'_>,
+    jobs: &[Job],
+    skills: &SkillTaxonomy,
+    tracks: &TrackTaxonomy,
+) -> AppResult<HashMap<i32, f64>> {
+    if jobs.is_empty() {
+        return Ok(HashMap::new());
+    }
+
+    let profile = profile_vector(pool, provider, user_id, profile, skills, tracks).await?;
+    let documents: Vec<(String, String)> = jobs
+        .iter()
+        .map(|job| (job.id.to_string(), job_document(job, skills, tracks)))
+        .collect();
+    let lookup = embeddings_for(pool, provider, EmbeddingEntity::Job, &documents).await?;
+
+    Ok(jobs
+        .iter()
+        .filter_map(|job| {
+            lookup
+                .vectors
+                .get(&job.id.to_string())
+                .map(|vector| (job.id, similarity_score(&profile, vector)))
+        })
+        .collect())
+}
+
+/// Semantic similarity (0-100) of a user's profile to each learning
+/// resource, by resource ID.
+///
+/// # Errors
+///
+/// Returns an error if the provider fails or a database operation fails.
+pub async fn resource_similarities(
+    pool: &PgPool,
+    provider: &dyn EmbeddingProvider,
+    user: &User,
+    resources: &[LearningResource],
+    skills: &SkillTaxonomy,
+    tracks: &TrackTaxonomy,
+) -> AppResult<HashMap<i32, f64>> {
+    if resources.is_empty() {
+        return Ok(HashMap::new());
+    }
+
+    let profile = profile_vector(pool, provider, user.id, user.into(), skills, tracks).await?;
+    let documents: Vec<(String, String)> = resources
+        .iter()
+        .map(|resource| (resource.id.to_string(), resource_document(resource, skills)))
+        .collect();
+    let lookup = embeddings_for(pool, provider, EmbeddingEntity::Resource, &documents).await?;
+
+    Ok(resources
+        .iter()
+        .filter_map(|resource| {
+            lookup
+                .vectors
+                .get(&resource.id.to_string())
+                .map(|vector| (resource.id, similarity_score(&profile, vector)))
+        })
+        .collect())
+}
+
+/// Semantic similarity (0-100) of each candidate's profile to a job, by
+/// user ID.
+///
+/// # Errors
+///
+/// Returns an error if the provider fails or a database operation fails.
+pub async fn candidate_similarities(
+    pool: &PgPool,
+    provider: &dyn EmbeddingProvider,
+    job: &Job,
+    candidates: Vec<(Uuid, ProfileText<'_>)>,
+    skills: &SkillTaxonomy,
+    tracks: &TrackTaxonomy,
+) -> AppResult<HashMap<Uuid, f64>> {
+    if candidates.is_empty() {
+        return Ok(HashMap::new());
+    }
+
+    let id = job.id.to_string();
+    let mut lookup = embeddings_for(
+        pool,
+        provider,
+        EmbeddingEntity::Job,
+        &[(id.clone(), job_document(job, skills, tracks))],
+    )
+    .await?;
+    let job_vector = lookup.vectors.remove(&id).unwrap_or_default();
+
+    let ids: Vec<Uuid> = candidates.iter().map(|(id, _)| *id).collect();
+    let documents: Vec<(String, String)> = candidates
+        .into_iter()
+        .map(|(id, profile)| (id.to_string(), profile_document(profile, skills, tracks)))
+        .collect();
+    let lookup = embeddings_for(pool, provider, EmbeddingEntity::User, &documents).await?;
+
+    Ok(ids
+        .into_iter()
+        .filter_map(|id| {
+            lookup
+                .vectors
+                .get(&id.to_string())
+                .map(|vector| (id, similarity_score(vector, &job_vector)))
+        })
+        .collect())
+}
+
+/// The vector of a user's profile, embedding it if it changed.
+async fn profile_vector(
+    pool: &PgPool,
+    provider: &dyn EmbeddingProvider,
+    user_id: Uuid,
+    profile: ProfileText<'_>,
+    skills: &SkillTaxonomy,
+    tracks: &TrackTaxonomy,
+) -> AppResult<Vec<f32>> {
+    let id = user_id.to_string();
+    let document = profile_document(profile, skills, tracks);
+    let mut lookup = embeddings_for(
+        pool,
+        provider,
+        EmbeddingEntity::User,
+        &[(id.clone(), document)],
+    )
+    .await?;
+    Ok(lookup.vectors.remove(&id).unwrap_or_default())
+}
+
+/// Spawns the periodic embedding refresh task.
+///
+/// The job vector index is rebuilt after each run.
+pub fn spawn_embedding_refresh(
+    pool: PgPool,
+    provider: Arc<dyn EmbeddingProvider>,
+    job_vectors: Arc<JobVectorIndex>,
+    interval: std::time::Duration,
+) -> JoinHandle<()> {
+    tokio::spawn(async move {
+        let mut ticker = tokio::time::interval(interval);
+        ticker.set_missed_tick_behavior(MissedTickBehavior::Delay);
+
+        loop {
+            ticker.tick().await;
+            match refresh_embeddings(&pool, provider.as_ref()).await {
+                Ok(run) if run.jobs + run.resources + run.users > 0 || run.removed > 0 => info!(
+                    "Embeddings: computed {} jobs, {} resources, {} profiles, removed {}",
+                    run.jobs, run.resources, run.users, run.removed
+                ),
+                Ok(_) => debug!("Embeddings: all vectors current"),
+                Err(e) => error!("Embedding refresh failed: {}", e),
+            }
+            if let Err(e) = job_vectors.reload(&pool).await {
+                error!("Failed to rebuild job vector index: {}", e);
+            }
+        }
+    })
+}
+
+/// Embeds open jobs, learning resources and completed profiles whose
+/// documents changed, and removes vectors of deleted users and resources and
+/// of jobs that are no longer open.
+///
+/// # Errors
+///
+/// Returns an error if the provider fails or a database operation fails.
+pub async fn refresh_embeddings(
+    pool: &PgPool,
+    provider: &dyn EmbeddingProvider,
+) -> AppResult<EmbeddingRefreshRun> {
+    let skills = load_skill_taxonomy(pool).await?;
+    let tracks = load_taxonomy(pool).await?;
+    let mut run = EmbeddingRefreshRun::default();
+
+    let jobs: Vec<Job> = sqlx::query_as(&format!(
+        "SELECT {} FROM jobs WHERE status = 'open' ORDER BY id",
+        JOB_COLUMNS
+    ))
+    .fetch_all(pool)
+    .await?;
+    let documents: Vec<(String, String)> = jobs
+        .iter()
+        .map(|job| (job.id.to_string(), job_document(job, &skills, &tracks)))
+        .collect();
+    run.jobs = refresh_batches(pool, provider, EmbeddingEntity::Job, &documents).await?;
+
+    let resources = sqlx::query_as!(
+        LearningResource,
+        r#"
+        SELECT id, title, platform, url, related_skills, cost as "cost: CostIndicator"
+        FROM learning_resources
+        ORDER BY id
+        "#
+    )
+    .fetch_all(pool)
+    .await?;
+    let documents: Vec<(String, String)> = resources
+        .iter()
+        .map(|resource| (resource.id.to_string(), resource_document(resource, &skills)))
+        .collect();
+    run.resources = refresh_batches(pool, provider, EmbeddingEntity::Resource, &documents).await?;
+
+    let users: Vec<User> =
+        sqlx::query_as("SELECT * FROM users WHERE profile_completed = TRUE ORDER BY id")
+            .fetch_all(pool)
+            .await?;
+    let documents: Vec<(String, String)> = users
+        .iter()
+        .map(|user| (user.id.to_string(), profile_document(user.into(), &skills, &tracks)))
+        .collect();
+    run.users = refresh_batches(pool, provider, EmbeddingEntity::User, &documents).await?;
+
+    run.removed = sqlx::query!(
+        r#"
+        DELETE FROM embeddings e
+        WHERE (e.entity = 'job' AND NOT EXISTS (
+                SELECT 1 FROM jobs j WHERE j.id::TEXT = e.entity_id AND j.status = 'open'))
+           OR (e.entity = 'resource' AND NOT EXISTS (
+                SELECT 1 FROM learning_resources r WHERE r.id::TEXT = e.entity_id))
+           OR (e.entity = 'user' AND NOT EXISTS (
+                SELECT 1 FROM users u WHERE u.id::TEXT = e.entity_id))
+        "#
+    )
+    .execute(pool)
+    .await?
+    .rows_affected();
+
+    Ok(run)
+}
+
+/// Brings the vectors of `documents` up to date in batches, returning how
+/// many were computed.
+async fn refresh_batches(
+    pool: &PgPool,
+    provider: &dyn EmbeddingProvider,
+    entity: EmbeddingEntity,
+    documents: &[(String, String)],
+) -> AppResult<usize> {
+    let mut computed = 0;
+    for batch in documents.chunks(REFRESH_BATCH_SIZE) {
+        computed += embeddings_for(pool, provider, entity, batch).await?.computed;
+    }
+    Ok(computed)
+}
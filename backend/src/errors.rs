@@ -35,6 +35,10 @@ pub enum AppError {
     #[error("Unauthorized")]
     Unauthorized,
     
+    /// Authenticated user lacks permission for the action
+    #[error("Forbidden")]
+    Forbidden,
+    
     /// Requested resource not found
     #[error("Not found")]
     NotFound,
@@ -61,6 +65,7 @@ impl IntoResponse for AppError {
             AppError::ValidationError(msg) => debug!("Validation error: {}", msg),
             AppError::ValidatorErrors(_) => debug!("Validation errors: {:?}", self),
             AppError::Unauthorized => debug!("Unauthorized access attempt"),
+            AppError::Forbidden => warn!("Forbidden access attempt"),
             AppError::NotFound => debug!("Resource not found"),
             AppError::BadRequest(msg) => warn!("Bad request: {}", msg),
             AppError::ConfigurationError(msg) => error!("Configuration error: {}", msg),
@@ -97,21 +102,21 @@ impl IntoResponse for AppError {
             ),
             
             AppError::DatabaseError(err) => {
-                if let Some(db_err) = err.as_database_error() {
-                    if db_err.is_unique_violation() {
-                        // Check which constraint was violated for better error messages
-                        let constraint = db_err.constraint().unwrap_or("");
-                        let message = if constraint.contains("email") {
-                            "An account with this email already exists. Please login or use a different email."
-                        } else {
-                            "A record with this information already exists."
-                        };
-                        
-                        return (
-                            StatusCode::CONFLICT,
-                            Json(json!({"error": message}))
-                        ).into_response();
-                    }
+                if let Some(db_err) = err.as_database_error()
+                    && db_err.is_unique_violation()
+                {
+                    // Check which constraint was violated for better error messages
+                    let constraint = db_err.constraint().unwrap_or("");
+                    let message = if constraint.contains("email") {
+                        "An account with this email already exists. Please login or use a different email."
+                    } else {
+                        "A record with this information already exists."
+                    };
+                    
+                    return (
+                        StatusCode::CONFLICT,
+                        Json(json!({"error": message}))
+                    ).into_response();
                 }
                 
                 (
@@ -125,6 +130,11 @@ impl IntoResponse for AppError {
                 json!({"error": "Unauthorized"})
             ),
             
+            AppError::Forbidden => (
+                StatusCode::FORBIDDEN,
+                json!({"error": "Forbidden"})
+            ),
+            
             AppError::NotFound => (
                 StatusCode::NOT_FOUND,
                 json!({"error": "Not found"})
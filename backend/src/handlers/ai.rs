@@ -5,12 +5,18 @@
 
 use axum::{Json, extract::State};
 use serde_json::json;
+use sqlx::types::Json as SqlJson;
+use std::collections::HashMap;
 
 use crate::{
     AppState,
     ai::types::{AIActionRequest, AIActionResponse},
     auth::AuthUser,
+    career_tracks::load_taxonomy,
     errors::AppError,
+    models::SkillProficiency,
+    skill_trends::{RECENT_TREND_DAYS, TrendFilters, skill_movers},
+    skills::load_skill_taxonomy,
 };
 
 /// Process an AI action
@@ -144,10 +150,8 @@ pub async fn extract_and_save_skills(
                             Some(name.to_string())
                         }
                         // Fallback: try as plain string
-                        else if let Some(name) = skill.as_str() {
-                            Some(name.to_string())
-                        } else {
-                            None
+                        else {
+                            skill.as_str().map(|name| name.to_string())
                         }
                     })
                     .collect()
@@ -156,6 +160,21 @@ pub async fn extract_and_save_skills(
 
         tracing::info!("Extracted {} technical skills: {:?}", technical_skills.len(), technical_skills);
 
+        // Proficiencies the AI assessed, for skills in object format
+        let extracted_proficiency: HashMap<String, SkillProficiency> = extracted_data
+            .get("technical_skills")
+            .and_then(|v| v.as_array())
+            .map(|arr| {
+                arr.iter()
+                    .filter_map(|skill| {
+                        let name = skill.get("name")?.as_str()?;
+                        let proficiency = skill.get("proficiency")?.as_str()?.parse().ok()?;
+                        Some((name.to_string(), proficiency))
+                    })
+                    .collect()
+            })
+            .unwrap_or_default();
+
         // Extract roles
         let roles: Vec<String> = extracted_data
             .get("roles")
@@ -174,19 +193,22 @@ pub async fn extract_and_save_skills(
         let user_id = auth_user.user_id;
         let existing_user =
             sqlx::query_as::<_, crate::models::User>("SELECT * FROM users WHERE id = $1")
-                .bind(&user_id)
+                .bind(user_id)
                 .fetch_one(&state.db_pool)
                 .await?;
 
         tracing::info!("Existing user skills before update: {:?}", existing_user.skills);
         tracing::info!("Existing user roles before update: {:?}", existing_user.target_roles);
 
+        // Canonical names, so "JS" isn't added next to an existing "JavaScript"
         let mut combined_skills = existing_user.skills.clone();
-        for skill in technical_skills {
-            if !combined_skills.contains(&skill) {
-                combined_skills.push(skill);
-            }
-        }
+        combined_skills.extend(technical_skills);
+        let skill_taxonomy = load_skill_taxonomy(&state.db_pool).await?;
+        let combined_skills = skill_taxonomy.normalize(&combined_skills);
+
+        // Proficiencies the user set themselves take precedence
+        let mut combined_proficiency = skill_taxonomy.restrict(&extracted_proficiency, &combined_skills);
+        combined_proficiency.extend(existing_user.skill_proficiency.0.clone());
 
         let mut combined_roles = existing_user.target_roles.clone();
         for role in roles {
@@ -204,13 +226,15 @@ pub async fn extract_and_save_skills(
              SET skills = $1, 
                  target_roles = $2, 
                  raw_cv_text = $3,
+                 skill_proficiency = $5,
                  updated_at = CURRENT_TIMESTAMP
              WHERE id = $4",
         )
         .bind(&combined_skills)
         .bind(&combined_roles)
         .bind(cv_text)
-        .bind(&user_id)
+        .bind(user_id)
+        .bind(SqlJson(&combined_proficiency))
         .execute(&state.db_pool)
         .await?;
 
@@ -660,6 +684,9 @@ pub async fn ask_career_mentor(
 
 /// Enhanced career mentor with intelligent context awareness
 ///
+/// Market analysis includes the skills rising and falling in demand over the
+/// last 30 days, from the skill demand snapshots (see [`crate::skill_trends`]).
+///
 /// # Endpoint
 /// `POST /api/ai/enhanced-mentor`
 ///
@@ -767,7 +794,7 @@ pub async fn enhanced_career_mentor(
                 let role_lower = role.to_lowercase();
                 role_keywords.iter()
                     .find(|&&keyword| role_lower.contains(keyword))
-                    .map(|&s| s)
+                    .copied()
                     .or_else(|| user.target_roles.first().map(|s| s.as_str()))
                     .unwrap_or("Software Developer")
             } else {
@@ -785,7 +812,7 @@ pub async fn enhanced_career_mentor(
             let question_lower = question.to_lowercase();
             role_keywords.iter()
                 .find(|&&keyword| question_lower.contains(keyword))
-                .map(|&s| s)
+                .copied()
                 .or_else(|| user.target_roles.first().map(|s| s.as_str()))
                 .unwrap_or("Software Developer")
         };
@@ -805,7 +832,7 @@ pub async fn enhanced_career_mentor(
             r#"
             SELECT required_skills
             FROM jobs
-            WHERE LOWER(job_title) LIKE LOWER($1)
+            WHERE LOWER(job_title) LIKE LOWER($1) AND status = 'open'
             LIMIT 10
             "#,
             format!("%{}%", search_pattern)
@@ -876,7 +903,7 @@ pub async fn enhanced_career_mentor(
             
             // Suggest available job titles
             let sample_titles = sqlx::query_scalar::<_, String>(
-                "SELECT DISTINCT job_title FROM jobs LIMIT 10"
+                "SELECT DISTINCT job_title FROM jobs WHERE status = 'open' LIMIT 10"
             )
             .fetch_all(&state.db_pool)
             .await?;
@@ -895,7 +922,7 @@ pub async fn enhanced_career_mentor(
         tracing::info!("Performing market analysis");
 
         // Get total job count
-        let total_jobs = sqlx::query_scalar::<_, i64>("SELECT COUNT(*) FROM jobs")
+        let total_jobs = sqlx::query_scalar::<_, i64>("SELECT COUNT(*) FROM jobs WHERE status = 'open'")
             .fetch_one(&state.db_pool)
             .await?;
 
@@ -910,7 +937,7 @@ pub async fn enhanced_career_mentor(
             .await?;
 
         context_parts.push(format!(
-            "\nDatabase Statistics:\n- Total Jobs: {}\n- Total Learning Resources: {}\n- Total Users: {}",
+            "\nDatabase Statistics:\n- Open Jobs: {}\n- Total Learning Resources: {}\n- Total Users: {}",
             total_jobs, total_resources, total_users
         ));
 
@@ -942,7 +969,7 @@ pub async fn enhanced_career_mentor(
         }
 
         // Get unique companies count
-        let total_companies = sqlx::query_scalar::<_, i64>("SELECT COUNT(DISTINCT company) FROM jobs")
+        let total_companies = sqlx::query_scalar::<_, i64>("SELECT COUNT(DISTINCT company) FROM jobs WHERE status = 'open'")
             .fetch_one(&state.db_pool)
             .await?;
 
@@ -953,6 +980,7 @@ pub async fn enhanced_career_mentor(
             r#"
             SELECT experience_level as "experience_level: crate::models::ExperienceLevel", COUNT(*) as count
             FROM jobs
+            WHERE status = 'open'
             GROUP BY experience_level
             ORDER BY count DESC
             "#
@@ -974,6 +1002,7 @@ pub async fn enhanced_career_mentor(
             r#"
             SELECT unnest(required_skills) as skill, COUNT(*) as demand_count
             FROM jobs
+            WHERE status = 'open'
             GROUP BY skill
             ORDER BY demand_count DESC
             LIMIT 15
@@ -994,16 +1023,14 @@ pub async fn enhanced_career_mentor(
             ));
 
             // Identify trending skills user doesn't have
-            let user_skills_lower: std::collections::HashSet<String> = user.skills
-                .iter()
-                .map(|s| s.to_lowercase())
-                .collect();
+            let skills = load_skill_taxonomy(&state.db_pool).await?;
+            let user_skill_keys = skills.keys_with_parents(&user.skills);
 
             let missing_trending_skills: Vec<String> = skill_demand
                 .iter()
                 .filter_map(|row| {
                     let skill = row.skill.as_deref()?;
-                    if !user_skills_lower.contains(&skill.to_lowercase()) {
+                    if !user_skill_keys.contains(&skills.key(skill)) {
                         Some(format!("{} ({} jobs)", skill, row.demand_count.unwrap_or(0)))
                     } else {
                         None
@@ -1020,11 +1047,54 @@ pub async fn enhanced_career_mentor(
             }
         }
 
+        // Get skills whose demand is changing, in the user's track if known
+        let user_tracks = match &user.preferred_track {
+            Some(track) => Some(load_taxonomy(&state.db_pool).await?.with_sub_tracks(track)),
+            None => None,
+        };
+        let movers = skill_movers(
+            &state.db_pool,
+            &TrendFilters {
+                tracks: user_tracks,
+                ..Default::default()
+            },
+            RECENT_TREND_DAYS,
+            5,
+        )
+        .await?;
+
+        if let Some(since) = movers.from {
+            let describe = |changes: &[crate::models::SkillDemandChange]| -> String {
+                changes
+                    .iter()
+                    .map(|c| {
+                        format!("{} ({:+.1} points, now {} jobs)", c.skill, c.share_change, c.current_count)
+                    })
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            };
+            if !movers.risers.is_empty() {
+                context_parts.push(format!(
+                    "- Skills Rising in Demand Since {}: {}",
+                    since,
+                    describe(&movers.risers)
+                ));
+            }
+            if !movers.fallers.is_empty() {
+                context_parts.push(format!(
+                    "- Skills Falling in Demand Since {}: {}",
+                    since,
+                    describe(&movers.fallers)
+                ));
+            }
+        }
+
         // Get job type distribution
         let job_types = sqlx::query!(
             r#"
             SELECT job_type as "job_type: crate::models::JobType", COUNT(*) as count
             FROM jobs
+            WHERE status = 'open'
             GROUP BY job_type
             ORDER BY count DESC
             "#
@@ -1317,7 +1387,7 @@ pub async fn update_roadmap_progress(
     let mut query = String::from("UPDATE career_roadmaps SET updated_at = CURRENT_TIMESTAMP");
     
     if let Some(progress) = progress_percentage {
-        if progress < 0 || progress > 100 {
+        if !(0..=100).contains(&progress) {
             return Err(AppError::ValidationError("Progress percentage must be between 0 and 100".to_string()));
         }
         update_fields.push(format!(" progress_percentage = {}", progress));
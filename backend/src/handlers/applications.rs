@@ -2,21 +2,27 @@
 
 use axum::{extract::{State, Path}, Json};
 use tracing::{info, debug};
-use crate::models::ApplicationTracking;
-use crate::errors::AppResult;
+use crate::match_feedback::record_outcome;
+use crate::models::{ApplicationTracking, JobStatus, MatchFeedbackEvent};
+use crate::errors::{AppError, AppResult};
 use crate::auth::AuthUser;
 use crate::AppState;
 use super::types::{CreateApplicationPayload, UpdateApplicationPayload};
 
 /// Creates a new job application record.
 /// 
-/// Tracks when a user applies to a job with optional notes.
+/// Tracks when a user applies to a job with optional notes. Only open jobs
+/// accept applications. While a match strategy experiment is running, the
+/// application records the user's arm so strategies can be compared by
+/// application outcomes. The application also counts as feedback on the
+/// job's recommendation (see [`crate::match_feedback`]).
 /// 
 /// # Errors
 /// 
 /// Returns an error if:
 /// - User is not authenticated
 /// - Job ID doesn't exist
+/// - Job is closed or expired
 /// - Database operation fails
 pub async fn create_application(
     auth_user: AuthUser,
@@ -25,21 +31,61 @@ pub async fn create_application(
 ) -> AppResult<Json<ApplicationTracking>> {
     info!("Creating application for user: {}, job_id: {}", 
           auth_user.user_id, payload.job_id);
+
+    let status = sqlx::query_scalar!(
+        r#"SELECT status as "status: JobStatus" FROM jobs WHERE id = $1"#,
+        payload.job_id
+    )
+    .fetch_optional(&app_state.db_pool)
+    .await?;
+    match status {
+        None | Some(JobStatus::Draft) => return Err(AppError::NotFound),
+        Some(JobStatus::Closed | JobStatus::Expired) => {
+            return Err(AppError::BadRequest(
+                "This job is no longer accepting applications".to_string(),
+            ));
+        }
+        Some(JobStatus::Open) => {}
+    }
+
+    let strategies = &app_state.match_strategies;
+    let match_experiment = strategies.experiment().map(|experiment| experiment.name.clone());
+    let match_strategy = match match_experiment {
+        Some(_) => Some(
+            strategies
+                .for_user(&app_state.db_pool, auth_user.user_id)
+                .await?
+                .name()
+                .to_string(),
+        ),
+        None => None,
+    };
     
     let application = sqlx::query_as!(
         ApplicationTracking,
         r#"
-        INSERT INTO application_tracking (user_id, job_id, notes)
-        VALUES ($1, $2, $3)
+        INSERT INTO application_tracking (user_id, job_id, notes, match_experiment, match_strategy)
+        VALUES ($1, $2, $3, $4, $5)
         RETURNING id, user_id, job_id, status, applied_at, notes
         "#,
         auth_user.user_id,
         payload.job_id,
-        payload.notes
+        payload.notes,
+        match_experiment,
+        match_strategy
     )
     .fetch_one(&app_state.db_pool)
     .await?;
 
+    record_outcome(
+        &app_state.db_pool,
+        auth_user.user_id,
+        payload.job_id,
+        MatchFeedbackEvent::Apply,
+        None,
+    )
+    .await;
+
     info!("Application created successfully: application_id={}, user_id={}, job_id={}",
           application.id.unwrap_or(0), auth_user.user_id, payload.job_id);
     
@@ -82,7 +128,8 @@ pub async fn get_my_applications(
 /// Updates an existing application.
 /// 
 /// Updates application status and/or notes. Only the user who created
-/// the application can update it.
+/// the application can update it. A change of status counts as feedback on
+/// the job's recommendation (see [`crate::match_feedback`]).
 /// 
 /// # Path Parameters
 /// 
@@ -103,20 +150,40 @@ pub async fn update_application(
     info!("Updating application: application_id={}, user_id={}, new_status={}",
           application_id, auth_user.user_id, payload.status);
     
-    sqlx::query!(
+    let updated = sqlx::query!(
         r#"
-        UPDATE application_tracking
-        SET status = $1, notes = COALESCE($2, notes)
-        WHERE id = $3 AND user_id = $4
+        WITH previous AS (
+            SELECT id, status FROM application_tracking
+            WHERE id = $3 AND user_id = $4
+            FOR UPDATE
+        )
+        UPDATE application_tracking a
+        SET status = $1, notes = COALESCE($2, a.notes)
+        FROM previous
+        WHERE a.id = previous.id
+        RETURNING a.job_id, previous.status as previous_status
         "#,
         payload.status,
         payload.notes,
         application_id,
         auth_user.user_id
     )
-    .execute(&app_state.db_pool)
+    .fetch_optional(&app_state.db_pool)
     .await?;
 
+    if let Some(updated) = updated
+        && updated.previous_status != payload.status
+    {
+        record_outcome(
+            &app_state.db_pool,
+            auth_user.user_id,
+            updated.job_id,
+            MatchFeedbackEvent::Status,
+            Some(&payload.status),
+        )
+        .await;
+    }
+
     info!("Application updated successfully: application_id={}", application_id);
     
     Ok(Json(serde_json::json!({
@@ -3,7 +3,7 @@
 use axum::{extract::State, Json};
 use validator::Validate;
 use tracing::{info, warn, error, debug};
-use crate::models::{User, ExperienceLevel, CareerTrack};
+use crate::models::{User, ExperienceLevel, UserRole};
 use crate::errors::{AppResult, AppError};
 use crate::security::{hash_password, verify_password};
 use crate::auth::create_jwt;
@@ -51,11 +51,11 @@ pub async fn register(
     .await
     .map_err(|e| {
         // Check for unique constraint violation (duplicate email)
-        if let Some(db_err) = e.as_database_error() {
-            if db_err.is_unique_violation() {
-                warn!("Registration failed: Email already exists - {}", payload.email);
-                return AppError::DatabaseError(e);
-            }
+        if let Some(db_err) = e.as_database_error()
+            && db_err.is_unique_violation()
+        {
+            warn!("Registration failed: Email already exists - {}", payload.email);
+            return AppError::DatabaseError(e);
         }
         error!("Database error during registration for {}: {}", payload.email, e);
         AppError::DatabaseError(e)
@@ -107,10 +107,11 @@ pub async fn login(
         r#"
         SELECT 
             id, full_name, email, education_level,
-            experience_level as "experience_level: ExperienceLevel",
-            preferred_track as "preferred_track: CareerTrack",
+            experience_level as "experience_level: ExperienceLevel", experience_years,
+            preferred_track,
             profile_completed as "profile_completed!",
-            skills, projects, target_roles, raw_cv_text, password_hash
+            skills, skill_proficiency as "skill_proficiency: _", projects, target_roles, raw_cv_text, password_hash,
+            role as "role: UserRole", company_id, preferred_locations, discoverable
         FROM users 
         WHERE email = $1
         "#,
@@ -150,10 +151,16 @@ pub async fn login(
             profile_completed: user.profile_completed,
             education_level: user.education_level,
             experience_level: user.experience_level,
+            experience_years: user.experience_years,
             preferred_track: user.preferred_track,
             skills: user.skills,
+            skill_proficiency: user.skill_proficiency.0,
             projects: user.projects,
             target_roles: user.target_roles,
+            role: user.role,
+            company_id: user.company_id,
+            preferred_locations: user.preferred_locations,
+            discoverable: user.discoverable,
         },
     }))
 }
@@ -0,0 +1,524 @@
+//! Candidate discovery handlers.
+//!
+//! Job seekers who opted in to being discoverable (`discoverable` on their
+//! profile) are ranked against an employer's job with the same scoring as
+//! job recommendations, using the default match strategy. Employers see
+//! anonymized profiles; a candidate's name and email are only shown once
+//! they accept the company's contact request for the job.
+
+use super::companies::require_employer;
+use super::types::{
+    AnonymizedProfile, CandidateContact, CandidateMatch, CandidateQueryParams,
+    ContactRequestDetails, CreateContactRequestPayload, JobCandidatesResponse,
+    RespondContactRequestPayload,
+};
+use crate::AppState;
+use crate::ai_matching::{CandidateSkills, ExperienceRange, MatchInput};
+use crate::auth::AuthUser;
+use crate::career_tracks::load_taxonomy;
+use crate::embeddings::ProfileText;
+use crate::embeddings::store::candidate_similarities;
+use crate::errors::{AppError, AppResult};
+use crate::job_search::JOB_COLUMNS;
+use crate::models::{ContactRequest, ContactRequestStatus, ExperienceLevel, Job, SkillProficiency};
+use crate::skills::load_skill_taxonomy;
+use axum::{
+    Json,
+    extract::{Path, Query, State},
+};
+use sqlx::types::Json as SqlJson;
+use std::collections::HashMap;
+use tracing::{debug, info, warn};
+use uuid::Uuid;
+use validator::Validate;
+
+/// Default number of candidates returned.
+const DEFAULT_LIMIT: usize = 20;
+
+/// Maximum number of candidates returned.
+const MAX_LIMIT: usize = 100;
+
+/// Number of candidates scored per request.
+///
+/// Candidates are preselected in SQL by how many of the job's required, then
+/// preferred, skills they list; only this many are loaded and scored.
+const CANDIDATE_POOL_SIZE: i64 = 500;
+
+/// A discoverable candidate as loaded for ranking.
+struct CandidateRow {
+    id: Uuid,
+    education_level: Option<String>,
+    experience_level: Option<ExperienceLevel>,
+    experience_years: Option<i32>,
+    preferred_track: Option<String>,
+    skills: Vec<String>,
+    skill_proficiency: SqlJson<HashMap<String, SkillProficiency>>,
+    preferred_locations: Vec<String>,
+    target_roles: Vec<String>,
+    projects: Vec<String>,
+    contact_status: Option<ContactRequestStatus>,
+    /// Set only once the candidate accepted the company's contact request
+    full_name: Option<String>,
+    /// Set only once the candidate accepted the company's contact request
+    email: Option<String>,
+    /// Discoverable candidates in total
+    total: i64,
+}
+
+/// Ranks discoverable candidates for one of the company's jobs.
+///
+/// Candidates are job seekers with a completed profile who opted in to
+/// being discoverable. The [`CANDIDATE_POOL_SIZE`] listing the most of the
+/// job's skills are scored.
+///
+/// # Path Parameters
+///
+/// - `job_id` - ID of the job
+///
+/// # Query Parameters
+///
+/// - `limit` - Maximum candidates to return (default: 20, max: 100)
+/// - `min_score` - Only candidates matching at or above this percentage
+///
+/// # Returns
+///
+/// Candidates, best match first, with their match score and components,
+/// matched and missing skills, an anonymized profile, the status of the
+/// company's contact request and, once accepted, their contact details.
+///
+/// # Errors
+///
+/// Returns an error if:
+/// - User is not authenticated
+/// - User is not an employer of a company
+/// - Job doesn't exist or belongs to another company
+/// - `min_score` is not between 0 and 100
+/// - Database operation fails
+pub async fn get_job_candidates(
+    auth_user: AuthUser,
+    State(app_state): State<AppState>,
+    Path(job_id): Path<i32>,
+    Query(params): Query<CandidateQueryParams>,
+) -> AppResult<Json<JobCandidatesResponse>> {
+    info!(
+        "Ranking candidates: job_id={}, user_id={}",
+        job_id, auth_user.user_id
+    );
+
+    if let Some(min_score) = params.min_score
+        && !(0.0..=100.0).contains(&min_score)
+    {
+        return Err(AppError::BadRequest(
+            "min_score must be between 0 and 100".to_string(),
+        ));
+    }
+    let limit = params.limit.unwrap_or(DEFAULT_LIMIT).clamp(1, MAX_LIMIT);
+
+    let company_id = require_employer(&app_state.db_pool, auth_user.user_id).await?;
+    let job: Job = sqlx::query_as(&format!(
+        "SELECT {} FROM jobs WHERE id = $1 AND company_id = $2",
+        JOB_COLUMNS
+    ))
+    .bind(job_id)
+    .bind(company_id)
+    .fetch_optional(&app_state.db_pool)
+    .await?
+    .ok_or(AppError::NotFound)?;
+
+    let required: Vec<String> = job.required_skills.iter().map(|s| s.to_lowercase()).collect();
+    let preferred: Vec<String> = job.preferred_skills.iter().map(|s| s.to_lowercase()).collect();
+    let rows = sqlx::query_as!(
+        CandidateRow,
+        r#"
+        SELECT
+            u.id, u.education_level,
+            u.experience_level as "experience_level: ExperienceLevel", u.experience_years,
+            u.preferred_track, u.skills,
+            u.skill_proficiency as "skill_proficiency: SqlJson<HashMap<String, SkillProficiency>>",
+            u.preferred_locations, u.target_roles, u.projects,
+            c.status as "contact_status?: ContactRequestStatus",
+            CASE WHEN c.status = 'accepted' THEN u.full_name END as full_name,
+            CASE WHEN c.status = 'accepted' THEN u.email END as email,
+            COUNT(*) OVER () as "total!"
+        FROM users u
+        LEFT JOIN contact_requests c ON c.candidate_id = u.id AND c.job_id = $1
+        WHERE u.discoverable AND u.profile_completed = TRUE AND u.role = 'job_seeker'
+        ORDER BY
+            (SELECT COUNT(*) FROM unnest(u.skills) s WHERE lower(s) = ANY($2)) DESC,
+            (SELECT COUNT(*) FROM unnest(u.skills) s WHERE lower(s) = ANY($3)) DESC,
+            u.updated_at DESC NULLS LAST, u.id
+        LIMIT $4
+        "#,
+        job_id,
+        &required,
+        &preferred,
+        CANDIDATE_POOL_SIZE
+    )
+    .fetch_all(&app_state.db_pool)
+    .await?;
+
+    let skills = load_skill_taxonomy(&app_state.db_pool).await?;
+    let tracks = load_taxonomy(&app_state.db_pool).await?;
+    let strategy = app_state.match_strategies.default_strategy().clone();
+
+    // Embedding failures leave candidates to be scored without semantic similarity
+    let similarities = match app_state
+        .embeddings
+        .as_deref()
+        .filter(|_| strategy.weights().semantic > 0.0)
+    {
+        Some(provider) => {
+            let profiles = rows
+                .iter()
+                .map(|row| {
+                    let profile = ProfileText {
+                        target_roles: &row.target_roles,
+                        preferred_track: row.preferred_track.as_deref(),
+                        skills: &row.skills,
+                        experience_level: row.experience_level,
+                        projects: &row.projects,
+                    };
+                    (row.id, profile)
+                })
+                .collect();
+            candidate_similarities(&app_state.db_pool, provider, &job, profiles, &skills, &tracks)
+                .await
+                .unwrap_or_else(|e| {
+                    warn!("Semantic similarity unavailable for job {}: {}", job.id, e);
+                    HashMap::new()
+                })
+        }
+        None => HashMap::new(),
+    };
+
+    let total_candidates = rows.first().map_or(0, |row| row.total as usize);
+    let scored = rows.len();
+    let mut candidates: Vec<CandidateMatch> = rows
+        .into_iter()
+        .map(|row| {
+            let input = MatchInput::new(
+                CandidateSkills {
+                    skills: &row.skills,
+                    proficiency: &row.skill_proficiency,
+                },
+                ExperienceRange::candidate(row.experience_level, row.experience_years),
+                row.preferred_track.as_deref(),
+                &job,
+            )
+            .with_semantic_similarity(similarities.get(&row.id).copied());
+            let analysis = strategy.score(&input, &skills, &tracks);
+            let contact = match (row.full_name, row.email) {
+                (Some(full_name), Some(email)) => Some(CandidateContact {
+                    full_name,
+                    email,
+                    target_roles: row.target_roles,
+                    projects: row.projects,
+                }),
+                _ => None,
+            };
+
+            CandidateMatch {
+                candidate_id: row.id,
+                match_score: analysis.match_score,
+                skill_overlap: analysis.skill_overlap,
+                experience_alignment: analysis.experience_alignment,
+                track_alignment: analysis.track_alignment,
+                semantic_similarity: analysis.semantic_similarity,
+                matched_skills: analysis.matched_skills(),
+                missing_required_skills: analysis.missing_skills(true),
+                missing_preferred_skills: analysis.missing_skills(false),
+                profile: AnonymizedProfile {
+                    education_level: row.education_level,
+                    experience_level: row.experience_level,
+                    experience_years: row.experience_years,
+                    preferred_track: row.preferred_track,
+                    skills: row.skills,
+                    skill_proficiency: row.skill_proficiency.0,
+                    preferred_locations: row.preferred_locations,
+                },
+                contact_status: row.contact_status,
+                contact,
+            }
+        })
+        .filter(|candidate| {
+            params
+                .min_score
+                .is_none_or(|min_score| candidate.match_score >= min_score)
+        })
+        .collect();
+
+    candidates.sort_by(|a, b| b.match_score.total_cmp(&a.match_score));
+    candidates.truncate(limit);
+
+    debug!(
+        "Ranked {} of {} scored ({} discoverable) candidates for job {}",
+        candidates.len(),
+        scored,
+        total_candidates,
+        job_id
+    );
+
+    Ok(Json(JobCandidatesResponse {
+        job_id,
+        match_strategy: strategy.name().to_string(),
+        total_candidates,
+        candidates,
+    }))
+}
+
+/// Asks a discoverable candidate whether the company may contact them about
+/// one of its open jobs.
+///
+/// The candidate is notified and sees the request in
+/// `GET /api/contact-requests`.
+///
+/// # Path Parameters
+///
+/// - `job_id` - ID of the job
+/// - `candidate_id` - ID of the candidate
+///
+/// # Errors
+///
+/// Returns an error if:
+/// - User is not authenticated
+/// - User is not an employer of a company
+/// - Message is longer than 1000 characters
+/// - Job doesn't exist, isn't open or belongs to another company
+/// - Candidate doesn't exist or isn't discoverable
+/// - The company already asked to contact the candidate about the job
+/// - Database operation fails
+pub async fn request_candidate_contact(
+    auth_user: AuthUser,
+    State(app_state): State<AppState>,
+    Path((job_id, candidate_id)): Path<(i32, Uuid)>,
+    Json(payload): Json<CreateContactRequestPayload>,
+) -> AppResult<Json<ContactRequest>> {
+    info!(
+        "Requesting candidate contact: job_id={}, candidate_id={}, user_id={}",
+        job_id, candidate_id, auth_user.user_id
+    );
+
+    payload.validate()?;
+
+    let company_id = require_employer(&app_state.db_pool, auth_user.user_id).await?;
+    let job = sqlx::query!(
+        r#"
+        SELECT job_title, company
+        FROM jobs
+        WHERE id = $1 AND company_id = $2 AND status = 'open'
+        "#,
+        job_id,
+        company_id
+    )
+    .fetch_optional(&app_state.db_pool)
+    .await?
+    .ok_or(AppError::NotFound)?;
+
+    let discoverable = sqlx::query_scalar!(
+        r#"
+        SELECT EXISTS(
+            SELECT 1 FROM users
+            WHERE id = $1 AND discoverable AND profile_completed = TRUE AND role = 'job_seeker'
+        ) as "exists!"
+        "#,
+        candidate_id
+    )
+    .fetch_one(&app_state.db_pool)
+    .await?;
+    if !discoverable {
+        return Err(AppError::NotFound);
+    }
+
+    let message = payload
+        .message
+        .map(|message| message.trim().to_string())
+        .filter(|message| !message.is_empty());
+
+    let mut tx = app_state.db_pool.begin().await?;
+    let request = sqlx::query_as!(
+        ContactRequest,
+        r#"
+        INSERT INTO contact_requests (job_id, candidate_id, requested_by, message)
+        VALUES ($1, $2, $3, $4)
+        RETURNING id, job_id, candidate_id, requested_by, message,
+            status as "status: ContactRequestStatus", created_at, responded_at
+        "#,
+        job_id,
+        candidate_id,
+        auth_user.user_id,
+        message
+    )
+    .fetch_one(&mut *tx)
+    .await?;
+
+    sqlx::query!(
+        r#"
+        INSERT INTO notifications (user_id, title, message, type, job_id)
+        VALUES ($1, $2, $3, 'contact_request', $4)
+        "#,
+        candidate_id,
+        format!("{} would like to contact you", job.company),
+        format!(
+            "{} found your profile a match for {} and would like to see your contact details.",
+            job.company, job.job_title
+        ),
+        job_id
+    )
+    .execute(&mut *tx)
+    .await?;
+    tx.commit().await?;
+
+    info!(
+        "Contact request {} sent to candidate {} for job {}",
+        request.id, candidate_id, job_id
+    );
+
+    Ok(Json(request))
+}
+
+/// Lists the contact requests employers sent the authenticated user, newest
+/// first.
+///
+/// # Errors
+///
+/// Returns an error if:
+/// - User is not authenticated
+/// - Database operation fails
+pub async fn get_contact_requests(
+    auth_user: AuthUser,
+    State(app_state): State<AppState>,
+) -> AppResult<Json<Vec<ContactRequestDetails>>> {
+    info!("Fetching contact requests for user: {}", auth_user.user_id);
+
+    let requests = sqlx::query!(
+        r#"
+        SELECT
+            r.id, r.job_id, r.candidate_id, r.requested_by, r.message,
+            r.status as "status: ContactRequestStatus", r.created_at, r.responded_at,
+            j.job_title, j.company
+        FROM contact_requests r
+        JOIN jobs j ON j.id = r.job_id
+        WHERE r.candidate_id = $1
+        ORDER BY r.created_at DESC, r.id DESC
+        "#,
+        auth_user.user_id
+    )
+    .fetch_all(&app_state.db_pool)
+    .await?
+    .into_iter()
+    .map(|row| ContactRequestDetails {
+        request: ContactRequest {
+            id: row.id,
+            job_id: row.job_id,
+            candidate_id: row.candidate_id,
+            requested_by: row.requested_by,
+            message: row.message,
+            status: row.status,
+            created_at: row.created_at,
+            responded_at: row.responded_at,
+        },
+        job_title: row.job_title,
+        company: row.company,
+    })
+    .collect::<Vec<_>>();
+
+    debug!(
+        "Retrieved {} contact requests for user: {}",
+        requests.len(),
+        auth_user.user_id
+    );
+
+    Ok(Json(requests))
+}
+
+/// Accepts or declines a contact request.
+///
+/// Accepting shows the user's name and email to the company in the job's
+/// candidate list; declining (also after accepting) hides them again. The
+/// employer who sent the request is notified when the answer changes.
+///
+/// # Path Parameters
+///
+/// - `request_id` - ID of the contact request
+///
+/// # Errors
+///
+/// Returns an error if:
+/// - User is not authenticated
+/// - Status is not `accepted` or `declined`
+/// - Request doesn't exist or wasn't sent to the user
+/// - Database operation fails
+pub async fn respond_to_contact_request(
+    auth_user: AuthUser,
+    State(app_state): State<AppState>,
+    Path(request_id): Path<i32>,
+    Json(payload): Json<RespondContactRequestPayload>,
+) -> AppResult<Json<ContactRequest>> {
+    info!(
+        "Responding to contact request: request_id={}, user_id={}, status={:?}",
+        request_id, auth_user.user_id, payload.status
+    );
+
+    if payload.status == ContactRequestStatus::Pending {
+        return Err(AppError::BadRequest(
+            "status must be accepted or declined".to_string(),
+        ));
+    }
+
+    let mut tx = app_state.db_pool.begin().await?;
+    let previous = sqlx::query_scalar!(
+        r#"
+        SELECT status as "status: ContactRequestStatus"
+        FROM contact_requests
+        WHERE id = $1 AND candidate_id = $2
+        FOR UPDATE
+        "#,
+        request_id,
+        auth_user.user_id
+    )
+    .fetch_optional(&mut *tx)
+    .await?
+    .ok_or(AppError::NotFound)?;
+
+    let request = sqlx::query_as!(
+        ContactRequest,
+        r#"
+        UPDATE contact_requests
+        SET status = $1, responded_at = NOW()
+        WHERE id = $2
+        RETURNING id, job_id, candidate_id, requested_by, message,
+            status as "status: ContactRequestStatus", created_at, responded_at
+        "#,
+        payload.status as ContactRequestStatus,
+        request_id
+    )
+    .fetch_one(&mut *tx)
+    .await?;
+
+    if previous != payload.status
+        && let Some(requested_by) = request.requested_by
+    {
+        let answer = match payload.status {
+            ContactRequestStatus::Accepted => "accepted",
+            _ => "declined",
+        };
+        sqlx::query!(
+            r#"
+            INSERT INTO notifications (user_id, title, message, type, job_id)
+            SELECT $1, $2, 'A candidate ' || $3::text || ' your request to contact them about ' || job_title || '.',
+                'contact_response', id
+            FROM jobs
+            WHERE id = $4
+            "#,
+            requested_by,
+            format!("Contact request {}", answer),
+            answer,
+            request.job_id
+        )
+        .execute(&mut *tx)
+        .await?;
+    }
+    tx.commit().await?;
+
+    Ok(Json(request))
+}
@@ -0,0 +1,246 @@
+//! Career track handlers.
+//!
+//! Lists the career track taxonomy used by profiles, matching and skill
+//! trends (see [`crate::career_tracks`]), and lets admins add and edit tracks.
+//! Tracks are never deleted, since profiles and snapshots refer to them;
+//! deactivate them instead.
+
+use super::companies::load_membership;
+use super::types::{CareerTrackQueryParams, CreateCareerTrackPayload, UpdateCareerTrackPayload};
+use crate::AppState;
+use crate::auth::AuthUser;
+use crate::career_tracks::{TrackTaxonomy, load_taxonomy, normalize_keywords};
+use crate::errors::{AppError, AppResult};
+use crate::models::{CareerTrack, UserRole};
+use crate::skills::load_skill_taxonomy;
+use axum::{
+    Json,
+    extract::{Path, Query, State},
+};
+use sqlx::PgPool;
+use tracing::{debug, info};
+use uuid::Uuid;
+use validator::Validate;
+
+/// Lists career tracks, top-level tracks first.
+///
+/// # Query Parameters
+///
+/// - `include_inactive` - Whether to include inactive tracks (default: false)
+///
+/// # Errors
+///
+/// Returns an error if the database operation fails.
+pub async fn get_career_tracks(
+    State(app_state): State<AppState>,
+    Query(params): Query<CareerTrackQueryParams>,
+) -> AppResult<Json<Vec<CareerTrack>>> {
+    let include_inactive = params.include_inactive.unwrap_or(false);
+    debug!("Listing career tracks: include_inactive={}", include_inactive);
+
+    let tracks = load_taxonomy(&app_state.db_pool)
+        .await?
+        .tracks()
+        .iter()
+        .filter(|track| include_inactive || track.active)
+        .cloned()
+        .collect();
+
+    Ok(Json(tracks))
+}
+
+/// Adds a career track or sub-track (admin only).
+///
+/// Keywords are lowercased and related skills saved under their canonical
+/// names; duplicates of either are dropped.
+///
+/// # Errors
+///
+/// Returns an error if:
+/// - User is not authenticated
+/// - User is not an admin
+/// - Validation fails, or the slug is not lowercase letters, digits and
+///   underscores
+/// - A track with the slug already exists
+/// - The parent track doesn't exist or is itself a sub-track
+/// - Database operation fails
+pub async fn create_career_track(
+    auth_user: AuthUser,
+    State(app_state): State<AppState>,
+    Json(payload): Json<CreateCareerTrackPayload>,
+) -> AppResult<Json<CareerTrack>> {
+    info!("Creating career track '{}' by user {}", payload.slug, auth_user.user_id);
+
+    require_admin(&app_state.db_pool, auth_user.user_id).await?;
+    payload.validate()?;
+    validate_slug(&payload.slug)?;
+
+    let tracks = load_taxonomy(&app_state.db_pool).await?;
+    if tracks.get(&payload.slug).is_some() {
+        return Err(AppError::BadRequest(format!(
+            "Career track '{}' already exists",
+            payload.slug
+        )));
+    }
+    if let Some(parent_slug) = payload.parent_slug.as_deref() {
+        validate_parent(&tracks, &payload.slug, parent_slug)?;
+    }
+    let related_skills = load_skill_taxonomy(&app_state.db_pool)
+        .await?
+        .normalize(&payload.related_skills.unwrap_or_default());
+
+    let track = sqlx::query_as!(
+        CareerTrack,
+        r#"
+        INSERT INTO career_tracks
+            (slug, display_name, cv_label, parent_slug, keywords, related_skills, active)
+        VALUES ($1, $2, $3, $4, $5, $6, $7)
+        RETURNING
+            slug, display_name, cv_label, parent_slug, keywords, related_skills, active,
+            created_at, updated_at
+        "#,
+        payload.slug,
+        payload.display_name.trim(),
+        payload.cv_label.as_deref().map(str::trim),
+        payload.parent_slug,
+        &normalize_keywords(payload.keywords.unwrap_or_default()),
+        &related_skills,
+        payload.active.unwrap_or(true)
+    )
+    .fetch_one(&app_state.db_pool)
+    .await?;
+
+    info!("Career track created: {}", track.slug);
+
+    Ok(Json(track))
+}
+
+/// Edits a career track (admin only).
+///
+/// Only fields present in the payload are changed; `keywords` and
+/// `related_skills` replace the existing lists. Deactivated tracks stay on
+/// the profiles that chose them.
+///
+/// # Path Parameters
+///
+/// - `slug` - Slug of the track
+///
+/// # Errors
+///
+/// Returns an error if:
+/// - User is not authenticated
+/// - User is not an admin
+/// - Track doesn't exist
+/// - Validation fails
+/// - The new parent doesn't exist or is a sub-track, or the track has
+///   sub-tracks of its own
+/// - Database operation fails
+pub async fn update_career_track(
+    auth_user: AuthUser,
+    State(app_state): State<AppState>,
+    Path(slug): Path<String>,
+    Json(payload): Json<UpdateCareerTrackPayload>,
+) -> AppResult<Json<CareerTrack>> {
+    info!("Updating career track '{}' by user {}", slug, auth_user.user_id);
+
+    require_admin(&app_state.db_pool, auth_user.user_id).await?;
+    payload.validate()?;
+
+    let tracks = load_taxonomy(&app_state.db_pool).await?;
+    if tracks.get(&slug).is_none() {
+        return Err(AppError::NotFound);
+    }
+    if let Some(parent_slug) = payload.parent_slug.as_deref().filter(|p| !p.is_empty()) {
+        validate_parent(&tracks, &slug, parent_slug)?;
+    }
+
+    let keywords = payload.keywords.map(normalize_keywords);
+    let related_skills = match &payload.related_skills {
+        Some(skills) => Some(load_skill_taxonomy(&app_state.db_pool).await?.normalize(skills)),
+        None => None,
+    };
+
+    // An empty parent_slug clears the parent; a missing one keeps it
+    let track = sqlx::query_as!(
+        CareerTrack,
+        r#"
+        UPDATE career_tracks
+        SET display_name = COALESCE($1, display_name),
+            cv_label = COALESCE($2, cv_label),
+            parent_slug = CASE WHEN $3::text IS NULL THEN parent_slug ELSE NULLIF($3, '') END,
+            keywords = COALESCE($4, keywords),
+            related_skills = COALESCE($5, related_skills),
+            active = COALESCE($6, active),
+            updated_at = CURRENT_TIMESTAMP
+        WHERE slug = $7
+        RETURNING
+            slug, display_name, cv_label, parent_slug, keywords, related_skills, active,
+            created_at, updated_at
+        "#,
+        payload.display_name.as_deref().map(str::trim),
+        payload.cv_label.as_deref().map(str::trim),
+        payload.parent_slug,
+        keywords.as_deref(),
+        related_skills.as_deref(),
+        payload.active,
+        slug
+    )
+    .fetch_optional(&app_state.db_pool)
+    .await?
+    .ok_or(AppError::NotFound)?;
+
+    info!("Career track updated: {}", track.slug);
+
+    Ok(Json(track))
+}
+
+/// Checks that the user is an admin.
+async fn require_admin(pool: &PgPool, user_id: Uuid) -> AppResult<()> {
+    let membership = load_membership(pool, user_id).await?;
+    if membership.role != UserRole::Admin {
+        return Err(AppError::Forbidden);
+    }
+    Ok(())
+}
+
+/// Checks that a slug is a lowercase letter followed by lowercase letters,
+/// digits or underscores.
+fn validate_slug(slug: &str) -> AppResult<()> {
+    let mut chars = slug.chars();
+    let valid = chars.next().is_some_and(|c| c.is_ascii_lowercase())
+        && chars.all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '_');
+    if !valid {
+        return Err(AppError::ValidationError(format!(
+            "Invalid slug '{}': use lowercase letters, digits and underscores, starting with a letter",
+            slug
+        )));
+    }
+    Ok(())
+}
+
+/// Checks that `parent_slug` can be the parent of `slug`: tracks nest one
+/// level deep, so the parent must be a top-level track and `slug` must not
+/// have sub-tracks of its own.
+fn validate_parent(tracks: &TrackTaxonomy, slug: &str, parent_slug: &str) -> AppResult<()> {
+    if parent_slug == slug {
+        return Err(AppError::ValidationError(
+            "A career track cannot be its own parent".to_string(),
+        ));
+    }
+    let parent = tracks.get(parent_slug).ok_or_else(|| {
+        AppError::ValidationError(format!("Unknown parent track: {}", parent_slug))
+    })?;
+    if let Some(grandparent) = &parent.parent_slug {
+        return Err(AppError::ValidationError(format!(
+            "'{}' is a sub-track of '{}' and cannot have sub-tracks",
+            parent_slug, grandparent
+        )));
+    }
+    if tracks.with_sub_tracks(slug).len() > 1 {
+        return Err(AppError::ValidationError(format!(
+            "'{}' has sub-tracks and cannot become a sub-track",
+            slug
+        )));
+    }
+    Ok(())
+}
@@ -0,0 +1,580 @@
+//! Company profile and employer membership handlers.
+//!
+//! Employers are users that belong to a company. Creating a company makes the
+//! requesting user its first employer member; existing members invite further
+//! members, who join once they accept. Company pages are public and list the
+//! company's open roles.
+
+use super::types::{
+    AddCompanyMemberPayload, CompanyInvitationDetails, CompanyPage, CreateCompanyPayload,
+    RespondCompanyInvitationPayload, UpdateCompanyPayload, UpdateVerificationPayload,
+};
+use crate::AppState;
+use crate::auth::AuthUser;
+use crate::errors::{AppError, AppResult};
+use crate::models::{
+    Company, CompanyInvitation, CompanyInvitationStatus, ExperienceLevel, Job, JobStatus, JobType, SalaryCurrency, SalaryPeriod, UserRole,
+    VerificationStatus, WorkArrangement,
+};
+use axum::{
+    Json,
+    extract::{Path, State},
+};
+use sqlx::PgPool;
+use tracing::{debug, info, warn};
+use uuid::Uuid;
+use validator::Validate;
+
+/// Role and company membership of an authenticated user.
+pub(super) struct Membership {
+    /// Account role
+    pub role: UserRole,
+    /// Company the user belongs to, if any
+    pub company_id: Option<i32>,
+}
+
+impl Membership {
+    /// Returns true if the user may manage the given company.
+    pub fn can_manage(&self, company_id: i32) -> bool {
+        self.role == UserRole::Admin
+            || (self.role == UserRole::Employer && self.company_id == Some(company_id))
+    }
+}
+
+/// Loads the role and company membership of a user.
+pub(super) async fn load_membership(pool: &PgPool, user_id: Uuid) -> AppResult<Membership> {
+    let row = sqlx::query!(
+        r#"
+        SELECT role as "role: UserRole", company_id
+        FROM users
+        WHERE id = $1
+        "#,
+        user_id
+    )
+    .fetch_optional(pool)
+    .await?
+    .ok_or(AppError::Unauthorized)?;
+
+    Ok(Membership {
+        role: row.role,
+        company_id: row.company_id,
+    })
+}
+
+/// Loads the membership of a user and checks that they are an employer of a company.
+///
+/// Returns the company ID the employer belongs to.
+pub(super) async fn require_employer(pool: &PgPool, user_id: Uuid) -> AppResult<i32> {
+    let membership = load_membership(pool, user_id).await?;
+    match (membership.role, membership.company_id) {
+        (UserRole::Employer | UserRole::Admin, Some(company_id)) => Ok(company_id),
+        _ => {
+            warn!("User {} is not an employer of any company", user_id);
+            Err(AppError::Forbidden)
+        }
+    }
+}
+
+/// Fetches a company by ID.
+async fn fetch_company(pool: &PgPool, company_id: i32) -> AppResult<Company> {
+    sqlx::query_as!(
+        Company,
+        r#"
+        SELECT
+            id, name, logo_url, website, industry, company_size, locations, description,
+            verification_status as "verification_status: VerificationStatus",
+            created_at
+        FROM companies
+        WHERE id = $1
+        "#,
+        company_id
+    )
+    .fetch_optional(pool)
+    .await?
+    .ok_or(AppError::NotFound)
+}
+
+/// Creates a company profile and makes the requesting user its employer.
+///
+/// New companies start with `pending` verification status.
+///
+/// # Errors
+///
+/// Returns an error if:
+/// - User is not authenticated
+/// - Validation fails
+/// - User already belongs to a company
+/// - Database operation fails
+pub async fn create_company(
+    auth_user: AuthUser,
+    State(app_state): State<AppState>,
+    Json(payload): Json<CreateCompanyPayload>,
+) -> AppResult<Json<Company>> {
+    info!("Creating company '{}' for user: {}", payload.name, auth_user.user_id);
+
+    payload.validate()?;
+
+    let membership = load_membership(&app_state.db_pool, auth_user.user_id).await?;
+    if membership.company_id.is_some() {
+        return Err(AppError::BadRequest(
+            "You already belong to a company".to_string(),
+        ));
+    }
+
+    let mut tx = app_state.db_pool.begin().await?;
+
+    let company = sqlx::query_as!(
+        Company,
+        r#"
+        INSERT INTO companies (name, logo_url, website, industry, company_size, locations, description)
+        VALUES ($1, $2, $3, $4, $5, $6, $7)
+        RETURNING
+            id, name, logo_url, website, industry, company_size, locations, description,
+            verification_status as "verification_status: VerificationStatus",
+            created_at
+        "#,
+        payload.name,
+        payload.logo_url,
+        payload.website,
+        payload.industry,
+        payload.company_size,
+        &payload.locations.unwrap_or_default(),
+        payload.description
+    )
+    .fetch_one(&mut *tx)
+    .await?;
+
+    // Admins keep their role; everyone else becomes an employer
+    sqlx::query!(
+        r#"
+        UPDATE users
+        SET role = CASE WHEN role = 'admin' THEN role ELSE 'employer' END,
+            company_id = $1,
+            updated_at = CURRENT_TIMESTAMP
+        WHERE id = $2
+        "#,
+        company.id,
+        auth_user.user_id
+    )
+    .execute(&mut *tx)
+    .await?;
+
+    tx.commit().await?;
+
+    info!("Company created: company_id={}, owner={}", company.id, auth_user.user_id);
+
+    Ok(Json(company))
+}
+
+/// Public company page with the company's open roles.
+///
+/// This endpoint does not require authentication.
+///
+/// # Path Parameters
+///
+/// - `company_id` - ID of the company
+///
+/// # Errors
+///
+/// Returns an error if:
+/// - Company doesn't exist
+/// - Database operation fails
+pub async fn get_company_page(
+    State(app_state): State<AppState>,
+    Path(company_id): Path<i32>,
+) -> AppResult<Json<CompanyPage>> {
+    info!("Fetching company page: company_id={}", company_id);
+
+    let company = fetch_company(&app_state.db_pool, company_id).await?;
+
+    let open_jobs = sqlx::query_as!(
+        Job,
+        r#"
+        SELECT
+            id, job_title, company, location,
+            work_arrangement as "work_arrangement: WorkArrangement",
+            country, division, district, city, latitude, longitude,
+            job_description, required_skills,
+            preferred_skills, skill_weights as "skill_weights: _",
+            experience_level as "experience_level: ExperienceLevel",
+            experience_years_min, experience_years_max,
+            job_type as "job_type: JobType",
+            salary_min, salary_max, salary_currency as "salary_currency: SalaryCurrency",
+            salary_period as "salary_period: SalaryPeriod", salary_negotiable,
+            salary_min_monthly_bdt, salary_max_monthly_bdt,
+            responsibilities, requirements, benefits, company_id,
+            status as "status: JobStatus", application_deadline, expires_at, created_at
+        FROM jobs
+        WHERE company_id = $1 AND status = 'open'
+        ORDER BY created_at DESC
+        "#,
+        company_id
+    )
+    .fetch_all(&app_state.db_pool)
+    .await?;
+
+    debug!("Company {} has {} open jobs", company_id, open_jobs.len());
+
+    Ok(Json(CompanyPage { company, open_jobs }))
+}
+
+/// Updates a company profile.
+///
+/// Only employers of the company (or admins) may update it. Renaming the
+/// company also updates the company name shown on its job postings.
+///
+/// # Errors
+///
+/// Returns an error if:
+/// - User is not authenticated
+/// - User is not a member of the company
+/// - Company doesn't exist
+/// - Database operation fails
+pub async fn update_company(
+    auth_user: AuthUser,
+    State(app_state): State<AppState>,
+    Path(company_id): Path<i32>,
+    Json(payload): Json<UpdateCompanyPayload>,
+) -> AppResult<Json<Company>> {
+    info!("Updating company: company_id={}, user_id={}", company_id, auth_user.user_id);
+
+    payload.validate()?;
+
+    let membership = load_membership(&app_state.db_pool, auth_user.user_id).await?;
+    if !membership.can_manage(company_id) {
+        return Err(AppError::Forbidden);
+    }
+
+    let mut tx = app_state.db_pool.begin().await?;
+
+    let company = sqlx::query_as!(
+        Company,
+        r#"
+        UPDATE companies
+        SET name = COALESCE($1, name),
+            logo_url = COALESCE($2, logo_url),
+            website = COALESCE($3, website),
+            industry = COALESCE($4, industry),
+            company_size = COALESCE($5, company_size),
+            locations = COALESCE($6, locations),
+            description = COALESCE($7, description),
+            updated_at = CURRENT_TIMESTAMP
+        WHERE id = $8
+        RETURNING
+            id, name, logo_url, website, industry, company_size, locations, description,
+            verification_status as "verification_status: VerificationStatus",
+            created_at
+        "#,
+        payload.name,
+        payload.logo_url,
+        payload.website,
+        payload.industry,
+        payload.company_size,
+        payload.locations.as_deref(),
+        payload.description,
+        company_id
+    )
+    .fetch_optional(&mut *tx)
+    .await?
+    .ok_or(AppError::NotFound)?;
+
+    if payload.name.is_some() {
+        sqlx::query!(
+            "UPDATE jobs SET company = $1 WHERE company_id = $2",
+            company.name,
+            company_id
+        )
+        .execute(&mut *tx)
+        .await?;
+    }
+
+    tx.commit().await?;
+
+    info!("Company updated successfully: company_id={}", company_id);
+
+    Ok(Json(company))
+}
+
+/// Invites an existing user to a company as an employer.
+///
+/// The user is notified and joins the company, becoming an employer, only
+/// once they accept (see [`respond_to_company_invitation`]). A declined
+/// invitation can be sent again.
+///
+/// # Errors
+///
+/// Returns an error if:
+/// - User is not authenticated
+/// - Requesting user is not a member of the company
+/// - No user exists with the given email
+/// - Target user already belongs to a company
+/// - The user already has a pending invitation to the company
+/// - Database operation fails
+pub async fn add_company_member(
+    auth_user: AuthUser,
+    State(app_state): State<AppState>,
+    Path(company_id): Path<i32>,
+    Json(payload): Json<AddCompanyMemberPayload>,
+) -> AppResult<Json<CompanyInvitation>> {
+    info!("Inviting {} to company {}", payload.email, company_id);
+
+    payload.validate()?;
+
+    let membership = load_membership(&app_state.db_pool, auth_user.user_id).await?;
+    if !membership.can_manage(company_id) {
+        return Err(AppError::Forbidden);
+    }
+
+    let member = sqlx::query!(
+        "SELECT id, company_id FROM users WHERE email = $1",
+        payload.email
+    )
+    .fetch_optional(&app_state.db_pool)
+    .await?
+    .ok_or(AppError::NotFound)?;
+
+    match member.company_id {
+        Some(id) if id == company_id => {
+            return Err(AppError::BadRequest(
+                "User is already a member of this company".to_string(),
+            ));
+        }
+        Some(_) => {
+            return Err(AppError::BadRequest(
+                "User already belongs to another company".to_string(),
+            ));
+        }
+        None => {}
+    }
+
+    let mut tx = app_state.db_pool.begin().await?;
+    // A declined or earlier accepted invitation is sent again; a pending one is a conflict
+    let invitation = sqlx::query_as!(
+        CompanyInvitation,
+        r#"
+        INSERT INTO company_invitations (company_id, user_id, invited_by)
+        VALUES ($1, $2, $3)
+        ON CONFLICT (company_id, user_id) DO UPDATE SET
+            invited_by = EXCLUDED.invited_by,
+            status = 'pending',
+            created_at = NOW(),
+            responded_at = NULL
+        WHERE company_invitations.status <> 'pending'
+        RETURNING id, company_id, user_id, invited_by,
+            status as "status: CompanyInvitationStatus", created_at, responded_at
+        "#,
+        company_id,
+        member.id,
+        auth_user.user_id
+    )
+    .fetch_optional(&mut *tx)
+    .await?
+    .ok_or_else(|| {
+        AppError::BadRequest("User already has a pending invitation to this company".to_string())
+    })?;
+
+    sqlx::query!(
+        r#"
+        INSERT INTO notifications (user_id, title, message, type)
+        SELECT $1, 'Invitation to join ' || name,
+            'You have been invited to join ' || name || ' as an employer. Accept to post and manage its jobs.',
+            'company_invitation'
+        FROM companies
+        WHERE id = $2
+        "#,
+        member.id,
+        company_id
+    )
+    .execute(&mut *tx)
+    .await?;
+    tx.commit().await?;
+
+    info!(
+        "Invitation {} sent to user {} for company {}",
+        invitation.id, member.id, company_id
+    );
+
+    Ok(Json(invitation))
+}
+
+/// Lists the authenticated user's invitations to join companies, newest
+/// first.
+///
+/// # Errors
+///
+/// Returns an error if:
+/// - User is not authenticated
+/// - Database operation fails
+pub async fn get_company_invitations(
+    auth_user: AuthUser,
+    State(app_state): State<AppState>,
+) -> AppResult<Json<Vec<CompanyInvitationDetails>>> {
+    info!("Fetching company invitations for user: {}", auth_user.user_id);
+
+    let invitations = sqlx::query!(
+        r#"
+        SELECT
+            i.id, i.company_id, i.user_id, i.invited_by,
+            i.status as "status: CompanyInvitationStatus", i.created_at, i.responded_at,
+            c.name as company_name
+        FROM company_invitations i
+        JOIN companies c ON c.id = i.company_id
+        WHERE i.user_id = $1
+        ORDER BY i.created_at DESC, i.id DESC
+        "#,
+        auth_user.user_id
+    )
+    .fetch_all(&app_state.db_pool)
+    .await?
+    .into_iter()
+    .map(|row| CompanyInvitationDetails {
+        invitation: CompanyInvitation {
+            id: row.id,
+            company_id: row.company_id,
+            user_id: row.user_id,
+            invited_by: row.invited_by,
+            status: row.status,
+            created_at: row.created_at,
+            responded_at: row.responded_at,
+        },
+        company_name: row.company_name,
+    })
+    .collect::<Vec<_>>();
+
+    debug!(
+        "Retrieved {} company invitations for user: {}",
+        invitations.len(),
+        auth_user.user_id
+    );
+
+    Ok(Json(invitations))
+}
+
+/// Accepts or declines an invitation to join a company.
+///
+/// Accepting makes the user an employer of the company (admins keep their
+/// role).
+///
+/// # Path Parameters
+///
+/// - `invitation_id` - ID of the invitation
+///
+/// # Errors
+///
+/// Returns an error if:
+/// - User is not authenticated
+/// - Status is not `accepted` or `declined`
+/// - Invitation doesn't exist, wasn't sent to the user or was already answered
+/// - Accepting while the user belongs to another company
+/// - Database operation fails
+pub async fn respond_to_company_invitation(
+    auth_user: AuthUser,
+    State(app_state): State<AppState>,
+    Path(invitation_id): Path<i32>,
+    Json(payload): Json<RespondCompanyInvitationPayload>,
+) -> AppResult<Json<CompanyInvitation>> {
+    info!(
+        "Responding to company invitation: invitation_id={}, user_id={}, status={:?}",
+        invitation_id, auth_user.user_id, payload.status
+    );
+
+    if payload.status == CompanyInvitationStatus::Pending {
+        return Err(AppError::BadRequest(
+            "status must be accepted or declined".to_string(),
+        ));
+    }
+
+    let mut tx = app_state.db_pool.begin().await?;
+    let invitation = sqlx::query_as!(
+        CompanyInvitation,
+        r#"
+        UPDATE company_invitations
+        SET status = $1, responded_at = NOW()
+        WHERE id = $2 AND user_id = $3 AND status = 'pending'
+        RETURNING id, company_id, user_id, invited_by,
+            status as "status: CompanyInvitationStatus", created_at, responded_at
+        "#,
+        payload.status as CompanyInvitationStatus,
+        invitation_id,
+        auth_user.user_id
+    )
+    .fetch_optional(&mut *tx)
+    .await?
+    .ok_or(AppError::NotFound)?;
+
+    if invitation.status == CompanyInvitationStatus::Accepted {
+        let joined = sqlx::query!(
+            r#"
+            UPDATE users
+            SET role = CASE WHEN role = 'admin' THEN role ELSE 'employer' END,
+                company_id = $1,
+                updated_at = CURRENT_TIMESTAMP
+            WHERE id = $2 AND (company_id IS NULL OR company_id = $1)
+            "#,
+            invitation.company_id,
+            auth_user.user_id
+        )
+        .execute(&mut *tx)
+        .await?;
+        if joined.rows_affected() == 0 {
+            return Err(AppError::BadRequest(
+                "You already belong to another company".to_string(),
+            ));
+        }
+        info!(
+            "User {} joined company {}",
+            auth_user.user_id, invitation.company_id
+        );
+    }
+    tx.commit().await?;
+
+    Ok(Json(invitation))
+}
+
+/// Sets the verification status of a company.
+///
+/// Admin only.
+///
+/// # Errors
+///
+/// Returns an error if:
+/// - User is not authenticated
+/// - User is not an admin
+/// - Company doesn't exist
+/// - Database operation fails
+pub async fn update_company_verification(
+    auth_user: AuthUser,
+    State(app_state): State<AppState>,
+    Path(company_id): Path<i32>,
+    Json(payload): Json<UpdateVerificationPayload>,
+) -> AppResult<Json<Company>> {
+    info!(
+        "Updating verification for company {}: {:?}",
+        company_id, payload.verification_status
+    );
+
+    let membership = load_membership(&app_state.db_pool, auth_user.user_id).await?;
+    if membership.role != UserRole::Admin {
+        return Err(AppError::Forbidden);
+    }
+
+    let company = sqlx::query_as!(
+        Company,
+        r#"
+        UPDATE companies
+        SET verification_status = $1, updated_at = CURRENT_TIMESTAMP
+        WHERE id = $2
+        RETURNING
+            id, name, logo_url, website, industry, company_size, locations, description,
+            verification_status as "verification_status: VerificationStatus",
+            created_at
+        "#,
+        payload.verification_status as _,
+        company_id
+    )
+    .fetch_optional(&app_state.db_pool)
+    .await?
+    .ok_or(AppError::NotFound)?;
+
+    Ok(Json(company))
+}
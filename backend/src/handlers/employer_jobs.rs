@@ -0,0 +1,572 @@
+//! Job posting management for employers.
+//!
+//! Employers can post, list, update and remove jobs that belong to their company,
+//! and bulk import jobs from CSV or JSON files.
+//!
+//! Jobs start as `draft` or `open`. Employers close a job by setting its status
+//! to `closed`; open jobs past their application deadline or expiry date are
+//! marked `expired` (see [`crate::job_expiry`]).
+//!
+//! The division, district, city and coordinates of a job are resolved from its
+//! location text (see [`crate::locations`]), and its required and preferred
+//! skills and skill weights are saved under their canonical names (see
+//! [`crate::skills`]).
+
+use super::companies::require_employer;
+use super::types::{CreateJobPayload, UpdateJobPayload};
+use crate::AppState;
+use crate::auth::AuthUser;
+use crate::errors::{AppError, AppResult};
+use crate::job_import::{self, ColumnMapping, ImportFormat, ImportOptions, ImportReport};
+use crate::locations::{detect_work_arrangement, resolve_location};
+use crate::models::{
+    ExperienceLevel, Job, JobStatus, JobType, SalaryCurrency, SalaryPeriod, WorkArrangement,
+};
+use crate::skills::load_skill_taxonomy;
+use axum::{
+    Json,
+    extract::{Multipart, Path, State},
+};
+use chrono::{DateTime, Utc};
+use sqlx::types::Json as SqlJson;
+use std::collections::HashMap;
+use tracing::{debug, info, warn};
+use validator::Validate;
+
+/// Checks that a salary range is well-formed.
+fn validate_salary_range(salary_min: Option<i32>, salary_max: Option<i32>) -> AppResult<()> {
+    if salary_min.is_some_and(|min| min < 0) || salary_max.is_some_and(|max| max < 0) {
+        return Err(AppError::ValidationError(
+            "Salary cannot be negative".to_string(),
+        ));
+    }
+    if let (Some(min), Some(max)) = (salary_min, salary_max)
+        && min > max
+    {
+        return Err(AppError::ValidationError(
+            "salary_min cannot be greater than salary_max".to_string(),
+        ));
+    }
+    Ok(())
+}
+
+/// Checks that an experience range isn't inverted.
+fn validate_experience_years(min_years: Option<i32>, max_years: Option<i32>) -> AppResult<()> {
+    if let (Some(min), Some(max)) = (min_years, max_years)
+        && min > max
+    {
+        return Err(AppError::ValidationError(
+            "experience_years_min cannot be greater than experience_years_max".to_string(),
+        ));
+    }
+    Ok(())
+}
+
+/// Checks a job's status and lifecycle dates.
+///
+/// `expired` is only ever set by the expiry task, and new jobs start as
+/// `draft` or `open`. Dates that are set must lie in the future.
+fn validate_lifecycle(
+    status: Option<JobStatus>,
+    application_deadline: Option<DateTime<Utc>>,
+    expires_at: Option<DateTime<Utc>>,
+    creating: bool,
+) -> AppResult<()> {
+    match status {
+        Some(JobStatus::Expired) => {
+            return Err(AppError::ValidationError(
+                "status cannot be set to expired".to_string(),
+            ));
+        }
+        Some(JobStatus::Closed) if creating => {
+            return Err(AppError::ValidationError(
+                "New jobs must be draft or open".to_string(),
+            ));
+        }
+        _ => {}
+    }
+
+    let now = Utc::now();
+    if application_deadline.is_some_and(|deadline| deadline <= now) {
+        return Err(AppError::ValidationError(
+            "application_deadline must be in the future".to_string(),
+        ));
+    }
+    if expires_at.is_some_and(|expiry| expiry <= now) {
+        return Err(AppError::ValidationError(
+            "expires_at must be in the future".to_string(),
+        ));
+    }
+    if let (Some(deadline), Some(expiry)) = (application_deadline, expires_at)
+        && deadline > expiry
+    {
+        return Err(AppError::ValidationError(
+            "application_deadline cannot be after expires_at".to_string(),
+        ));
+    }
+    Ok(())
+}
+
+/// Posts a new job on behalf of the employer's company.
+///
+/// Skills in both `required_skills` and `preferred_skills` count as
+/// required. Skills without a weight weigh 3 when required and 1 when
+/// preferred.
+///
+/// # Errors
+///
+/// Returns an error if:
+/// - User is not authenticated
+/// - User is not an employer of a company
+/// - Validation fails
+/// - A skill weight is outside 1-5 or is for a skill the job doesn't list
+/// - Database operation fails
+pub async fn create_job(
+    auth_user: AuthUser,
+    State(app_state): State<AppState>,
+    Json(payload): Json<CreateJobPayload>,
+) -> AppResult<Json<Job>> {
+    info!("Creating job '{}' for employer: {}", payload.job_title, auth_user.user_id);
+
+    payload.validate()?;
+    validate_salary_range(payload.salary_min, payload.salary_max)?;
+    validate_experience_years(payload.experience_years_min, payload.experience_years_max)?;
+    validate_lifecycle(
+        payload.status,
+        payload.application_deadline,
+        payload.expires_at,
+        true,
+    )?;
+
+    let company_id = require_employer(&app_state.db_pool, auth_user.user_id).await?;
+    let location = resolve_location(&payload.location);
+    let skills = load_skill_taxonomy(&app_state.db_pool).await?.job_skills(
+        &payload.required_skills,
+        &payload.preferred_skills.unwrap_or_default(),
+        &payload.skill_weights.unwrap_or_default(),
+    )?;
+
+    let job = sqlx::query_as!(
+        Job,
+        r#"
+        INSERT INTO jobs (
+            job_title, company, location, job_description, required_skills,
+            experience_level, job_type, salary_min, salary_max,
+            responsibilities, requirements, benefits, company_id, posted_by,
+            status, application_deadline, expires_at,
+            salary_currency, salary_period, salary_negotiable,
+            work_arrangement, country, division, district, city, latitude, longitude,
+            experience_years_min, experience_years_max, preferred_skills, skill_weights
+        )
+        SELECT $1, c.name, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, c.id, $13,
+            $14, $15, $16, $17, $18, $19, $20, $21, $22, $23, $24, $25, $26, $27, $28, $29, $30
+        FROM companies c
+        WHERE c.id = $12
+        RETURNING
+            id, job_title, company, location,
+            work_arrangement as "work_arrangement: WorkArrangement",
+            country, division, district, city, latitude, longitude,
+            job_description, required_skills,
+            preferred_skills, skill_weights as "skill_weights: _",
+            experience_level as "experience_level: ExperienceLevel",
+            experience_years_min, experience_years_max,
+            job_type as "job_type: JobType",
+            salary_min, salary_max, salary_currency as "salary_currency: SalaryCurrency",
+            salary_period as "salary_period: SalaryPeriod", salary_negotiable,
+            salary_min_monthly_bdt, salary_max_monthly_bdt,
+            responsibilities, requirements, benefits, company_id,
+            status as "status: JobStatus", application_deadline, expires_at, created_at
+        "#,
+        payload.job_title,
+        payload.location,
+        payload.job_description,
+        &skills.required,
+        payload.experience_level as _,
+        payload.job_type as _,
+        payload.salary_min,
+        payload.salary_max,
+        &payload.responsibilities.unwrap_or_default(),
+        &payload.requirements.unwrap_or_default(),
+        &payload.benefits.unwrap_or_default(),
+        company_id,
+        auth_user.user_id,
+        payload.status.unwrap_or(JobStatus::Open) as _,
+        payload.application_deadline,
+        payload.expires_at,
+        payload.salary_currency.unwrap_or(SalaryCurrency::Bdt) as _,
+        payload.salary_period.unwrap_or(SalaryPeriod::Month) as _,
+        payload.salary_negotiable.unwrap_or(false),
+        payload.work_arrangement.unwrap_or(location.work_arrangement) as _,
+        location.country,
+        location.division,
+        location.district,
+        location.city,
+        location.latitude,
+        location.longitude,
+        payload.experience_years_min,
+        payload.experience_years_max,
+        &skills.preferred,
+        SqlJson(&skills.weights) as _
+    )
+    .fetch_one(&app_state.db_pool)
+    .await?;
+
+    info!("Job created: job_id={}, company_id={}", job.id, company_id);
+
+    Ok(Json(job))
+}
+
+/// Lists all jobs posted by the employer's company.
+///
+/// # Errors
+///
+/// Returns an error if:
+/// - User is not authenticated
+/// - User is not an employer of a company
+/// - Database operation fails
+pub async fn get_company_jobs(
+    auth_user: AuthUser,
+    State(app_state): State<AppState>,
+) -> AppResult<Json<Vec<Job>>> {
+    let company_id = require_employer(&app_state.db_pool, auth_user.user_id).await?;
+
+    info!("Fetching jobs for company: {}", company_id);
+
+    let jobs = sqlx::query_as!(
+        Job,
+        r#"
+        SELECT
+            id, job_title, company, location,
+            work_arrangement as "work_arrangement: WorkArrangement",
+            country, division, district, city, latitude, longitude,
+            job_description, required_skills,
+            preferred_skills, skill_weights as "skill_weights: _",
+            experience_level as "experience_level: ExperienceLevel",
+            experience_years_min, experience_years_max,
+            job_type as "job_type: JobType",
+            salary_min, salary_max, salary_currency as "salary_currency: SalaryCurrency",
+            salary_period as "salary_period: SalaryPeriod", salary_negotiable,
+            salary_min_monthly_bdt, salary_max_monthly_bdt,
+            responsibilities, requirements, benefits, company_id,
+            status as "status: JobStatus", application_deadline, expires_at, created_at
+        FROM jobs
+        WHERE company_id = $1
+        ORDER BY created_at DESC
+        "#,
+        company_id
+    )
+    .fetch_all(&app_state.db_pool)
+    .await?;
+
+    debug!("Retrieved {} jobs for company: {}", jobs.len(), company_id);
+
+    Ok(Json(jobs))
+}
+
+/// Updates a job posted by the employer's company.
+///
+/// Only the fields provided in the payload are changed. A job reopened while
+/// its deadline or expiry date has passed stays `expired`. New skill lists
+/// drop the weights of removed skills.
+///
+/// # Errors
+///
+/// Returns an error if:
+/// - User is not authenticated
+/// - User is not an employer of a company
+/// - Job doesn't exist or belongs to another company
+/// - A skill weight is outside 1-5 or is for a skill the job doesn't list
+/// - Database operation fails
+pub async fn update_job(
+    auth_user: AuthUser,
+    State(app_state): State<AppState>,
+    Path(job_id): Path<i32>,
+    Json(payload): Json<UpdateJobPayload>,
+) -> AppResult<Json<Job>> {
+    info!("Updating job: job_id={}, user_id={}", job_id, auth_user.user_id);
+
+    payload.validate()?;
+    validate_salary_range(payload.salary_min, payload.salary_max)?;
+    validate_experience_years(payload.experience_years_min, payload.experience_years_max)?;
+    validate_lifecycle(
+        payload.status,
+        payload.application_deadline,
+        payload.expires_at,
+        false,
+    )?;
+
+    let company_id = require_employer(&app_state.db_pool, auth_user.user_id).await?;
+    // A new location replaces every resolved field, even with NULL
+    let location = payload.location.as_deref().map(resolve_location);
+    let work_arrangement = payload.work_arrangement.or_else(|| {
+        payload
+            .location
+            .as_deref()
+            .and_then(detect_work_arrangement)
+    });
+    let skills = if payload.required_skills.is_some()
+        || payload.preferred_skills.is_some()
+        || payload.skill_weights.is_some()
+    {
+        // Omitted lists and weights keep their current values
+        let current = sqlx::query!(
+            r#"
+            SELECT required_skills, preferred_skills,
+                skill_weights as "skill_weights: SqlJson<HashMap<String, i32>>"
+            FROM jobs
+            WHERE id = $1 AND company_id = $2
+            "#,
+            job_id,
+            company_id
+        )
+        .fetch_optional(&app_state.db_pool)
+        .await?
+        .ok_or(AppError::NotFound)?;
+        let taxonomy = load_skill_taxonomy(&app_state.db_pool).await?;
+        let required = payload.required_skills.unwrap_or(current.required_skills);
+        let preferred = payload.preferred_skills.unwrap_or(current.preferred_skills);
+        let weights = match payload.skill_weights {
+            Some(weights) => weights,
+            None => {
+                let listed = [required.as_slice(), preferred.as_slice()].concat();
+                taxonomy.restrict(&current.skill_weights, &listed)
+            }
+        };
+        Some(taxonomy.job_skills(&required, &preferred, &weights)?)
+    } else {
+        None
+    };
+
+    let job = sqlx::query_as!(
+        Job,
+        r#"
+        UPDATE jobs
+        SET job_title = COALESCE($1, job_title),
+            location = COALESCE($2, location),
+            job_description = COALESCE($3, job_description),
+            required_skills = COALESCE($4, required_skills),
+            experience_level = COALESCE($5, experience_level),
+            experience_years_min = COALESCE($27, experience_years_min),
+            experience_years_max = COALESCE($28, experience_years_max),
+            preferred_skills = COALESCE($29, preferred_skills),
+            skill_weights = COALESCE($30, skill_weights),
+            job_type = COALESCE($6, job_type),
+            salary_min = COALESCE($7, salary_min),
+            salary_max = COALESCE($8, salary_max),
+            salary_currency = COALESCE($17, salary_currency),
+            salary_period = COALESCE($18, salary_period),
+            salary_negotiable = COALESCE($19, salary_negotiable),
+            work_arrangement = COALESCE($20, work_arrangement),
+            country = CASE WHEN $2::text IS NULL THEN country ELSE $21 END,
+            division = CASE WHEN $2::text IS NULL THEN division ELSE $22 END,
+            district = CASE WHEN $2::text IS NULL THEN district ELSE $23 END,
+            city = CASE WHEN $2::text IS NULL THEN city ELSE $24 END,
+            latitude = CASE WHEN $2::text IS NULL THEN latitude ELSE $25 END,
+            longitude = CASE WHEN $2::text IS NULL THEN longitude ELSE $26 END,
+            responsibilities = COALESCE($9, responsibilities),
+            requirements = COALESCE($10, requirements),
+            benefits = COALESCE($11, benefits),
+            application_deadline = COALESCE($15, application_deadline),
+            expires_at = COALESCE($16, expires_at),
+            status = CASE
+                WHEN COALESCE($14, status) = 'open'
+                    AND (COALESCE($15, application_deadline) <= CURRENT_TIMESTAMP
+                         OR COALESCE($16, expires_at) <= CURRENT_TIMESTAMP)
+                THEN 'expired'
+                ELSE COALESCE($14, status)
+            END
+        WHERE id = $12 AND company_id = $13
+        RETURNING
+            id, job_title, company, location,
+            work_arrangement as "work_arrangement: WorkArrangement",
+            country, division, district, city, latitude, longitude,
+            job_description, required_skills,
+            preferred_skills, skill_weights as "skill_weights: _",
+            experience_level as "experience_level: ExperienceLevel",
+            experience_years_min, experience_years_max,
+            job_type as "job_type: JobType",
+            salary_min, salary_max, salary_currency as "salary_currency: SalaryCurrency",
+            salary_period as "salary_period: SalaryPeriod", salary_negotiable,
+            salary_min_monthly_bdt, salary_max_monthly_bdt,
+            responsibilities, requirements, benefits, company_id,
+            status as "status: JobStatus", application_deadline, expires_at, created_at
+        "#,
+        payload.job_title,
+        payload.location,
+        payload.job_description,
+        skills.as_ref().map(|skills| skills.required.as_slice()),
+        payload.experience_level as _,
+        payload.job_type as _,
+        payload.salary_min,
+        payload.salary_max,
+        payload.responsibilities.as_deref(),
+        payload.requirements.as_deref(),
+        payload.benefits.as_deref(),
+        job_id,
+        company_id,
+        payload.status as _,
+        payload.application_deadline,
+        payload.expires_at,
+        payload.salary_currency as _,
+        payload.salary_period as _,
+        payload.salary_negotiable,
+        work_arrangement as _,
+        location.as_ref().and_then(|l| l.country.clone()),
+        location.as_ref().and_then(|l| l.division.clone()),
+        location.as_ref().and_then(|l| l.district.clone()),
+        location.as_ref().and_then(|l| l.city.clone()),
+        location.as_ref().and_then(|l| l.latitude),
+        location.as_ref().and_then(|l| l.longitude),
+        payload.experience_years_min,
+        payload.experience_years_max,
+        skills.as_ref().map(|skills| skills.preferred.as_slice()),
+        skills.as_ref().map(|skills| SqlJson(&skills.weights)) as _
+    )
+    .fetch_optional(&app_state.db_pool)
+    .await
+    .map_err(|e| match e.as_database_error() {
+        // One bound updated past the other, stored one
+        Some(db_err) if db_err.constraint() == Some("jobs_experience_years_range") => {
+            AppError::ValidationError(
+                "experience_years_min cannot be greater than experience_years_max".to_string(),
+            )
+        }
+        _ => AppError::from(e),
+    })?
+    .ok_or(AppError::NotFound)?;
+
+    info!("Job updated successfully: job_id={}", job_id);
+
+    Ok(Json(job))
+}
+
+/// Removes a job posted by the employer's company.
+///
+/// # Errors
+///
+/// Returns an error if:
+/// - User is not authenticated
+/// - User is not an employer of a company
+/// - Job doesn't exist or belongs to another company
+/// - Database operation fails
+pub async fn delete_job(
+    auth_user: AuthUser,
+    State(app_state): State<AppState>,
+    Path(job_id): Path<i32>,
+) -> AppResult<Json<serde_json::Value>> {
+    info!("Deleting job: job_id={}, user_id={}", job_id, auth_user.user_id);
+
+    let company_id = require_employer(&app_state.db_pool, auth_user.user_id).await?;
+
+    let result = sqlx::query!(
+        "DELETE FROM jobs WHERE id = $1 AND company_id = $2",
+        job_id,
+        company_id
+    )
+    .execute(&app_state.db_pool)
+    .await?;
+
+    if result.rows_affected() == 0 {
+        return Err(AppError::NotFound);
+    }
+
+    info!("Job deleted successfully: job_id={}", job_id);
+
+    Ok(Json(serde_json::json!({
+        "message": "Job deleted successfully"
+    })))
+}
+
+/// Maximum size of an import file.
+pub(super) const MAX_IMPORT_FILE_SIZE: usize = 10 * 1024 * 1024; // 10MB
+
+/// Bulk imports jobs for the employer's company from a CSV or JSON file.
+///
+/// Expects a multipart form with:
+/// - `file` - CSV file with a header row, or JSON array of objects
+/// - `mapping` - Optional JSON object mapping job fields to column names
+/// - `format` - Optional `csv` or `json` (default: from the file extension)
+/// - `dry_run` - Optional `true` to validate without writing
+///
+/// Rows with an `external_ref` update the company's job with the same
+/// reference; other rows create new jobs. Invalid rows are skipped and
+/// reported with their errors.
+///
+/// # Errors
+///
+/// Returns an error if:
+/// - User is not authenticated
+/// - User is not an employer of a company
+/// - The form, file or mapping is invalid
+/// - Database operation fails
+pub async fn import_jobs(
+    auth_user: AuthUser,
+    State(app_state): State<AppState>,
+    mut multipart: Multipart,
+) -> AppResult<Json<ImportReport>> {
+    info!("Processing job import for employer: {}", auth_user.user_id);
+
+    let company_id = require_employer(&app_state.db_pool, auth_user.user_id).await?;
+
+    let mut file = None;
+    let mut mapping = ColumnMapping::default();
+    let mut format = None;
+    let mut dry_run = false;
+
+    while let Some(field) = multipart.next_field().await.map_err(|e| {
+        warn!("Failed to read multipart field: {}", e);
+        AppError::BadRequest("Invalid multipart data".to_string())
+    })? {
+        let field_name = field.name().unwrap_or("").to_string();
+        let file_name = field.file_name().map(str::to_string);
+        let data = field.bytes().await.map_err(|e| {
+            warn!("Failed to read import field '{}': {}", field_name, e);
+            AppError::BadRequest(format!("Failed to read field '{}'", field_name))
+        })?;
+        let text = || String::from_utf8_lossy(&data).trim().to_string();
+
+        match field_name.as_str() {
+            "file" => {
+                if data.len() > MAX_IMPORT_FILE_SIZE {
+                    return Err(AppError::BadRequest(format!(
+                        "Import file exceeds {}MB limit",
+                        MAX_IMPORT_FILE_SIZE / 1024 / 1024
+                    )));
+                }
+                format = format.or_else(|| file_name.as_deref().and_then(ImportFormat::from_file_name));
+                file = Some(data);
+            }
+            "mapping" if !data.is_empty() => mapping = ColumnMapping::from_json(&text())?,
+            "format" => format = Some(text().parse().map_err(AppError::BadRequest)?),
+            "dry_run" => dry_run = matches!(text().to_lowercase().as_str(), "true" | "1" | "yes"),
+            _ => {}
+        }
+    }
+
+    let file = file.ok_or_else(|| AppError::BadRequest("No import file provided".to_string()))?;
+    let format = format.ok_or_else(|| {
+        AppError::BadRequest("Cannot tell the file format; set format to csv or json".to_string())
+    })?;
+
+    let rows = job_import::parse_rows(format, &file)?;
+    debug!("Parsed {} import rows for company {}", rows.len(), company_id);
+
+    let report = job_import::import_jobs(
+        &app_state.db_pool,
+        rows,
+        &mapping,
+        &ImportOptions {
+            company_id: Some(company_id),
+            posted_by: Some(auth_user.user_id),
+            dry_run,
+        },
+    )
+    .await?;
+
+    info!(
+        "Job import for company {}: {} created, {} updated, {} failed (dry run: {})",
+        company_id, report.created, report.updated, report.failed, report.dry_run
+    );
+
+    Ok(Json(report))
+}
@@ -0,0 +1,239 @@
+//! Job export handlers for partner sites.
+//!
+//! Serves schema.org `JobPosting` JSON-LD per job and feeds of open jobs in
+//! RSS, Atom or JSON Feed format (see [`crate::job_export`]).
+
+use super::types::JobFeedParams;
+use crate::AppState;
+use crate::career_tracks::load_taxonomy;
+use crate::errors::{AppError, AppResult};
+use crate::job_export::{
+    ExportedJob, HiringOrganization, content_type, frontend_url, job_posting, render_feed,
+};
+use crate::job_sources::FeedFormat;
+use crate::locations::{canonical_preferences, matches_preferences};
+use crate::models::{
+    ExperienceLevel, Job, JobStatus, JobType, SalaryCurrency, SalaryPeriod, WorkArrangement,
+};
+use axum::{
+    extract::{OriginalUri, Path, Query, State},
+    http::{HeaderMap, StatusCode, header},
+    response::{IntoResponse, Response},
+};
+use sqlx::PgPool;
+use std::collections::HashMap;
+use tracing::info;
+
+/// Default number of jobs in a feed.
+const DEFAULT_FEED_LIMIT: usize = 50;
+
+/// Maximum number of jobs in a feed.
+const MAX_FEED_LIMIT: usize = 200;
+
+/// How long partners may cache exports, in seconds.
+const CACHE_MAX_AGE_SECS: u32 = 300;
+
+/// Gets the schema.org `JobPosting` JSON-LD of an open job.
+///
+/// # Path Parameters
+///
+/// - `job_id` - ID of the job
+///
+/// # Returns
+///
+/// An `application/ld+json` document, ready to embed in a
+/// `<script type="application/ld+json">` tag.
+///
+/// # Errors
+///
+/// Returns an error if:
+/// - Job doesn't exist or is not open
+/// - Database operation fails
+pub async fn get_job_posting(
+    State(app_state): State<AppState>,
+    Path(job_id): Path<i32>,
+) -> AppResult<Response> {
+    info!("Exporting JSON-LD for job {}", job_id);
+
+    let job = load_open_jobs(&app_state.db_pool, Some(job_id))
+        .await?
+        .into_iter()
+        .next()
+        .ok_or(AppError::NotFound)?;
+    let organizations = load_organizations(&app_state.db_pool, std::slice::from_ref(&job)).await?;
+
+    let exported = ExportedJob {
+        job: &job,
+        organization: job.company_id.and_then(|id| organizations.get(&id)),
+    };
+    let posting = job_posting(&exported, &frontend_url());
+
+    Ok(export_response("application/ld+json; charset=utf-8", posting.to_string()))
+}
+
+/// Gets a feed of open jobs, newest first.
+///
+/// # Query Parameters
+///
+/// - `format` - `rss` (default), `atom` or `json` (JSON Feed 1.1)
+/// - `track` - Only jobs in this career track or its sub-tracks (inferred
+///   from job titles)
+/// - `location` - Comma-separated divisions, districts or cities, or `Remote`;
+///   remote jobs match every location
+/// - `limit` - Maximum jobs in the feed (default: 50, max: 200)
+///
+/// # Errors
+///
+/// Returns an error if:
+/// - `track` is not an active career track
+/// - `location` names an unknown place
+/// - Database operation fails
+pub async fn get_job_feed(
+    State(app_state): State<AppState>,
+    OriginalUri(uri): OriginalUri,
+    headers: HeaderMap,
+    Query(params): Query<JobFeedParams>,
+) -> AppResult<Response> {
+    let format = params.format.unwrap_or(FeedFormat::Rss);
+    info!(
+        "Exporting job feed: format={:?}, track={:?}, location={:?}",
+        format, params.track, params.location
+    );
+
+    let locations = match params.location.as_deref() {
+        Some(location) => {
+            let names: Vec<String> = location
+                .split(',')
+                .map(str::to_string)
+                .filter(|name| !name.trim().is_empty())
+                .collect();
+            canonical_preferences(&names)?
+        }
+        None => Vec::new(),
+    };
+    let tracks = load_taxonomy(&app_state.db_pool).await?;
+    let track_slugs = tracks.resolve_filter(params.track.as_deref())?;
+    let limit = params.limit.unwrap_or(DEFAULT_FEED_LIMIT).clamp(1, MAX_FEED_LIMIT);
+
+    let jobs: Vec<Job> = load_open_jobs(&app_state.db_pool, None)
+        .await?
+        .into_iter()
+        .filter(|job| {
+            track_slugs.as_ref().is_none_or(|slugs| {
+                tracks
+                    .infer(&job.job_title)
+                    .is_some_and(|track| slugs.contains(&track.slug))
+            })
+        })
+        .filter(|job| {
+            locations.is_empty()
+                || matches_preferences(
+                    &locations,
+                    job.work_arrangement,
+                    job.division.as_deref(),
+                    job.district.as_deref(),
+                    job.city.as_deref(),
+                )
+        })
+        .take(limit)
+        .collect();
+    let organizations = load_organizations(&app_state.db_pool, &jobs).await?;
+
+    let exported: Vec<ExportedJob> = jobs
+        .iter()
+        .map(|job| ExportedJob {
+            job,
+            organization: job.company_id.and_then(|id| organizations.get(&id)),
+        })
+        .collect();
+    let feed = render_feed(format, &exported, &frontend_url(), &request_url(&headers, &uri));
+    info!("Exported {} jobs", exported.len());
+
+    Ok(export_response(content_type(format), feed))
+}
+
+/// Loads open jobs, newest first, optionally only the job with ID `job_id`.
+async fn load_open_jobs(pool: &PgPool, job_id: Option<i32>) -> AppResult<Vec<Job>> {
+    let jobs = sqlx::query_as!(
+        Job,
+        r#"
+        SELECT
+            id, job_title, company, location,
+            work_arrangement as "work_arrangement: WorkArrangement",
+            country, division, district, city, latitude, longitude,
+            job_description, required_skills,
+            preferred_skills, skill_weights as "skill_weights: _",
+            experience_level as "experience_level: ExperienceLevel",
+            experience_years_min, experience_years_max,
+            job_type as "job_type: JobType",
+            salary_min, salary_max, salary_currency as "salary_currency: SalaryCurrency",
+            salary_period as "salary_period: SalaryPeriod", salary_negotiable,
+            salary_min_monthly_bdt, salary_max_monthly_bdt,
+            responsibilities, requirements, benefits, company_id,
+            status as "status: JobStatus", application_deadline, expires_at, created_at
+        FROM jobs
+        WHERE status = 'open' AND ($1::INT IS NULL OR id = $1)
+        ORDER BY created_at DESC, id DESC
+        "#,
+        job_id
+    )
+    .fetch_all(pool)
+    .await?;
+
+    Ok(jobs)
+}
+
+/// Loads the website and logo of the companies the jobs belong to.
+async fn load_organizations(
+    pool: &PgPool,
+    jobs: &[Job],
+) -> AppResult<HashMap<i32, HiringOrganization>> {
+    let company_ids: Vec<i32> = jobs.iter().filter_map(|job| job.company_id).collect();
+    if company_ids.is_empty() {
+        return Ok(HashMap::new());
+    }
+
+    let organizations = sqlx::query!(
+        "SELECT id, website, logo_url FROM companies WHERE id = ANY($1)",
+        &company_ids
+    )
+    .fetch_all(pool)
+    .await?
+    .into_iter()
+    .map(|row| {
+        let organization = HiringOrganization {
+            website: row.website,
+            logo_url: row.logo_url,
+        };
+        (row.id, organization)
+    })
+    .collect();
+
+    Ok(organizations)
+}
+
+/// Reconstructs the URL a request was made to, honouring proxy headers.
+fn request_url(headers: &HeaderMap, uri: &axum::http::Uri) -> String {
+    let header_value = |name: &str| headers.get(name).and_then(|v| v.to_str().ok());
+    let scheme = header_value("x-forwarded-proto").unwrap_or("http");
+    let host = header_value("x-forwarded-host")
+        .or_else(|| header_value(header::HOST.as_str()))
+        .unwrap_or("localhost");
+    format!("{}://{}{}", scheme, host, uri)
+}
+
+/// Builds a cacheable export response.
+fn export_response(content_type: &str, body: String) -> Response {
+    (
+        StatusCode::OK,
+        [
+            (header::CONTENT_TYPE, content_type.to_string()),
+            (
+                header::CACHE_CONTROL,
+                format!("public, max-age={}", CACHE_MAX_AGE_SECS),
+            ),
+        ],
+        body,
+    )
+        .into_response()
+}
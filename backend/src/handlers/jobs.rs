@@ -1,16 +1,43 @@
 //! Job recommendation handlers.
 
-use super::types::{JobQueryParams, JobRecommendation, PlatformLinks};
+use super::saved_jobs::saved_job_ids;
+use super::types::{
+    JobQueryParams, JobRecommendation, JobSearchHit, JobSearchParams, JobSearchResponse,
+    JobSortBy, MatchBreakdownResponse, MatchExplanationResponse, MatchStrategyParams,
+    PlatformLinks, SkillBreakdown,
+};
 use crate::AppState;
-use crate::ai_matching::{calculate_enhanced_match, generate_ai_explanation};
+use crate::ai_matching::{CandidateSkills, EnhancedMatchAnalysis, ExperienceRange, MatchInput};
 use crate::auth::AuthUser;
-use crate::errors::AppResult;
-use crate::models::{CareerTrack, ExperienceLevel, Job, JobType, User};
+use crate::career_tracks::{TrackTaxonomy, load_taxonomy};
+use crate::embeddings::{EmbeddingProvider, ProfileText};
+use crate::embeddings::store::job_similarities;
+use crate::errors::{AppError, AppResult};
+use crate::job_search::{
+    JOB_COLUMNS, JobFilters, SEMANTIC_NEIGHBOURS, SemanticQuery, push_relevance,
+    push_semantic_similarity,
+};
+use crate::locations::{matches_preferences, parse_division, parse_radius};
+use crate::match_explanations::{
+    CandidateProfile, ExplanationSource, JobMatch, explain_matches, explain_match, load_candidate,
+};
+use crate::match_feedback::{Impression, record_impressions};
+use crate::match_strategy::MatchStrategy;
+use crate::models::{
+    CostIndicator, ExperienceLevel, Job, JobStatus, JobType, LearningResource, SalaryCurrency,
+    SalaryPeriod, User, UserRole, WorkArrangement,
+};
+use crate::skills::{SkillTaxonomy, load_skill_taxonomy};
 use axum::{
     Json,
-    extract::{Query, State},
+    extract::{Path, Query, State},
 };
-use tracing::{debug, info};
+use chrono::{DateTime, Utc};
+use sqlx::{FromRow, Postgres, QueryBuilder};
+use std::collections::HashMap;
+use std::sync::Arc;
+use tracing::{debug, info, warn};
+use uuid::Uuid;
 
 /// Gets job recommendations for the authenticated user.
 ///
@@ -21,7 +48,34 @@ use tracing::{debug, info};
 ///
 /// - `experience_level` - Optional filter by experience level
 /// - `job_type` - Optional filter by job type
-/// - `limit` - Maximum results to return (default: 10)
+/// - `closing_within_days` - Only jobs whose application deadline falls within
+///   the next N days
+/// - `division` - Only jobs in this division
+/// - `near` / `radius_km` - Only jobs within `radius_km` (default: 25) of a
+///   place or `latitude,longitude`
+/// - `work_arrangement` - `on_site`, `hybrid` or `remote`
+/// - `ai_explanations` - Whether to generate AI explanations (default: true);
+///   cached ones are always used
+/// - `strategy` - Score with this match strategy instead of the user's
+/// - `weights` - Override match weights, e.g.
+///   `skills:0.6,experience:0.2,track:0.1,semantic:0.1`
+/// - `limit` - Maximum number of jobs to score (default: 50)
+///
+/// Only open jobs are recommended, leaving out those the user marked as not
+/// interesting. Remote jobs match every location filter. The jobs scored are
+/// those sharing the most required, then preferred, skills with the profile,
+/// newest first among equals.
+///
+/// Scores use the user's match strategy: the default one, or their arm of
+/// the running A/B experiment (see [`crate::match_strategy`]).
+/// Strategies that weigh semantic similarity compare embeddings of the
+/// profile and each job (see [`crate::embeddings`]). Unless the strategy or
+/// weights are overridden, the jobs are recorded with their score components
+/// for offline evaluation (see [`crate::match_feedback`]).
+///
+/// Match explanations come from the cache or the AI model for the
+/// best-ranked jobs within the AI budget; the rest, and any not ready within
+/// the wait, get heuristic explanations (see [`crate::match_explanations`]).
 ///
 /// # Returns
 ///
@@ -31,11 +85,18 @@ use tracing::{debug, info};
 /// - Match score percentage
 /// - Matched skills
 /// - Missing skills
+/// - The match explanation and whether it came from AI or the heuristic
+/// - Whether the user has saved the job
+/// - Whether the job is in one of the user's preferred locations, which
+///   breaks ties between equal match scores
 ///
 /// # Errors
 ///
 /// Returns an error if:
 /// - User is not authenticated
+/// - `closing_within_days` is not positive
+/// - `division` or `near` is not a known location, or `radius_km` is invalid
+/// - `strategy` is unknown or `weights` is invalid
 /// - Database operation fails
 pub async fn get_job_recommendations(
     auth_user: AuthUser,
@@ -47,8 +108,31 @@ pub async fn get_job_recommendations(
         auth_user.user_id
     );
     debug!(
-        "Query params: experience_level={:?}, limit={:?}",
-        params.experience_level, params.limit
+        "Query params: experience_level={:?}, job_type={:?}, closing_within_days={:?}, limit={:?}",
+        params.experience_level, params.job_type, params.closing_within_days, params.limit
+    );
+
+    if params.closing_within_days.is_some_and(|days| days <= 0) {
+        return Err(AppError::BadRequest(
+            "closing_within_days must be positive".to_string(),
+        ));
+    }
+    let division = parse_division(params.division.as_deref())?;
+    let near = parse_radius(params.near.as_deref(), params.radius_km)?;
+    let selected = app_state
+        .match_strategies
+        .select(
+            &app_state.db_pool,
+            auth_user.user_id,
+            params.strategy.as_deref(),
+            params.weights.as_deref(),
+        )
+        .await?;
+    let strategy = selected.strategy;
+    debug!(
+        "Match strategy: {} (overridden: {})",
+        strategy.name(),
+        selected.overridden
     );
 
     // Get user profile
@@ -57,10 +141,11 @@ pub async fn get_job_recommendations(
         r#"
         SELECT 
             id, full_name, email, education_level,
-            experience_level as "experience_level: ExperienceLevel",
-            preferred_track as "preferred_track: CareerTrack",
+            experience_level as "experience_level: ExperienceLevel", experience_years,
+            preferred_track,
             profile_completed as "profile_completed!",
-            skills, projects, target_roles, raw_cv_text, password_hash
+            skills, skill_proficiency as "skill_proficiency: _", projects, target_roles, raw_cv_text, password_hash,
+            role as "role: UserRole", company_id, preferred_locations, discoverable
         FROM users 
         WHERE id = $1
         "#,
@@ -72,115 +157,95 @@ pub async fn get_job_recommendations(
     // Fetch jobs matching criteria
     let limit = params.limit.unwrap_or(50);
 
-    // Fetch all jobs (no longer filtering by experience level to show all available jobs)
-    // Match scoring will prioritize jobs that match user's experience level
-    let jobs = if let Some(exp_level) = params.experience_level {
-        // If explicitly filtered by experience level, honor that filter
-        sqlx::query_as!(
-            Job,
-            r#"
-            SELECT 
-                id, job_title, company, location, job_description, required_skills,
-                experience_level as "experience_level: ExperienceLevel",
-                job_type as "job_type: JobType",
-                salary_min, salary_max, responsibilities, requirements, benefits
-            FROM jobs 
-            WHERE experience_level = $1
-            LIMIT $2
-            "#,
-            exp_level as _,
-            limit
-        )
-        .fetch_all(&app_state.db_pool)
-        .await?
-    } else {
-        // Otherwise, return all jobs regardless of user's experience level
-        // Match scoring will rank them appropriately
-        sqlx::query_as!(
-            Job,
-            r#"
-            SELECT 
-                id, job_title, company, location, job_description, required_skills,
-                experience_level as "experience_level: ExperienceLevel",
-                job_type as "job_type: JobType",
-                salary_min, salary_max, responsibilities, requirements, benefits
-            FROM jobs 
-            LIMIT $1
-            "#,
-            limit
-        )
-        .fetch_all(&app_state.db_pool)
-        .await?
-    };
+    // Experience level and job type are only applied when explicitly requested;
+    // otherwise all jobs are returned and match scoring ranks them appropriately
+    let user_skills: Vec<String> = user.skills.iter().map(|s| s.to_lowercase()).collect();
+    let jobs = sqlx::query_as!(
+        Job,
+        r#"
+        SELECT 
+            id, job_title, company, location,
+            work_arrangement as "work_arrangement: WorkArrangement",
+            country, division, district, city, latitude, longitude,
+            job_description, required_skills,
+            preferred_skills, skill_weights as "skill_weights: _",
+            experience_level as "experience_level: ExperienceLevel",
+            experience_years_min, experience_years_max,
+            job_type as "job_type: JobType",
+            salary_min, salary_max, salary_currency as "salary_currency: SalaryCurrency",
+            salary_period as "salary_period: SalaryPeriod", salary_negotiable,
+            salary_min_monthly_bdt, salary_max_monthly_bdt,
+            responsibilities, requirements, benefits, company_id,
+            status as "status: JobStatus", application_deadline, expires_at, created_at
+        FROM jobs 
+        WHERE status = 'open'
+          AND ($1::experience_level IS NULL OR experience_level = $1)
+          AND ($2::job_type IS NULL OR job_type = $2)
+          AND ($3::int IS NULL OR (
+              application_deadline > CURRENT_TIMESTAMP
+              AND application_deadline <= CURRENT_TIMESTAMP + make_interval(days => $3)
+          ))
+          AND ($5::text IS NULL OR work_arrangement = 'remote' OR division = $5)
+          AND ($6::float8 IS NULL OR work_arrangement = 'remote'
+               OR distance_km(latitude, longitude, $6, $7) <= $8)
+          AND ($9::work_arrangement IS NULL OR work_arrangement = $9)
+          AND NOT EXISTS (
+              SELECT 1 FROM match_feedback f
+              WHERE f.user_id = $10 AND f.job_id = jobs.id AND f.event = 'not_interested'
+          )
+        ORDER BY
+            (SELECT COUNT(*) FROM unnest(required_skills) s WHERE lower(s) = ANY($11)) DESC,
+            (SELECT COUNT(*) FROM unnest(preferred_skills) s WHERE lower(s) = ANY($11)) DESC,
+            published_at DESC NULLS LAST, id
+        LIMIT $4
+        "#,
+        params.experience_level as _,
+        params.job_type as _,
+        params.closing_within_days,
+        limit,
+        division,
+        near.map(|n| n.latitude),
+        near.map(|n| n.longitude),
+        near.map(|n| n.km),
+        params.work_arrangement as _,
+        auth_user.user_id,
+        &user_skills
+    )
+    .fetch_all(&app_state.db_pool)
+    .await?;
+
+    let saved_ids = saved_job_ids(&app_state.db_pool, auth_user.user_id).await?;
+    let tracks = load_taxonomy(&app_state.db_pool).await?;
+    let skills = load_skill_taxonomy(&app_state.db_pool).await?;
+    let similarities = semantic_similarities(
+        &app_state,
+        strategy.as_ref(),
+        user.id,
+        ProfileText::from(&user),
+        &jobs,
+        &skills,
+        &tracks,
+    )
+    .await;
 
     // Calculate match scores
     let mut recommendations: Vec<JobRecommendation> = Vec::new();
+    let mut impressions: Vec<Impression> = Vec::new();
 
     for job in jobs {
-        let user_skills_set: std::collections::HashSet<_> = user.skills.iter().collect();
-        let job_skills_set: std::collections::HashSet<_> = job.required_skills.iter().collect();
-
-        let matched: Vec<String> = user_skills_set
-            .intersection(&job_skills_set)
-            .map(|s| s.to_string())
-            .collect();
-
-        let missing: Vec<String> = job_skills_set
-            .difference(&user_skills_set)
-            .map(|s| s.to_string())
-            .collect();
-
-        // Calculate enhanced match using heuristic
-        let enhanced = calculate_enhanced_match(
-            &user.skills,
-            &job.required_skills,
-            user.experience_level.as_ref().map(|e| match e {
-                ExperienceLevel::Fresher => "fresher",
-                ExperienceLevel::Junior => "junior",
-                ExperienceLevel::Mid => "mid",
-            }),
-            match job.experience_level {
-                ExperienceLevel::Fresher => "fresher",
-                ExperienceLevel::Junior => "junior",
-                ExperienceLevel::Mid => "mid",
-            },
-            user.preferred_track.as_ref().map(|t| match t {
-                CareerTrack::WebDevelopment => "web_development",
-                CareerTrack::Data => "data",
-                CareerTrack::Design => "design",
-                CareerTrack::Marketing => "marketing",
-            }),
-            &job.job_title,
-        );
-
-        // Try to enhance explanation with AI (falls back to heuristic if AI fails)
-        let ai_enhanced_explanation = generate_ai_explanation(
-            &user.skills,
-            &job.required_skills,
-            user.experience_level.as_ref().map(|e| match e {
-                ExperienceLevel::Fresher => "fresher",
-                ExperienceLevel::Junior => "junior",
-                ExperienceLevel::Mid => "mid",
-            }),
-            match job.experience_level {
-                ExperienceLevel::Fresher => "fresher",
-                ExperienceLevel::Junior => "junior",
-                ExperienceLevel::Mid => "mid",
+        // Calculate enhanced match using the user's strategy
+        let input = MatchInput::new(
+            CandidateSkills {
+                skills: &user.skills,
+                proficiency: &user.skill_proficiency,
             },
-            user.preferred_track.as_ref().map(|t| match t {
-                CareerTrack::WebDevelopment => "web_development",
-                CareerTrack::Data => "data",
-                CareerTrack::Design => "design",
-                CareerTrack::Marketing => "marketing",
-            }),
-            &job.job_title,
-            &job.job_description,
-            enhanced.match_score,
-            enhanced.skill_overlap,
-            enhanced.experience_alignment,
-            enhanced.track_alignment,
+            ExperienceRange::candidate(user.experience_level, user.experience_years),
+            user.preferred_track.as_deref(),
+            &job,
         )
-        .await;
+        .with_semantic_similarity(similarities.get(&job.id).copied());
+        let enhanced = strategy.score(&input, &skills, &tracks);
+        impressions.push(Impression::new(job.id, &enhanced));
 
         // Generate platform links
         let encoded_title = urlencoding::encode(&job.job_title);
@@ -206,26 +271,79 @@ pub async fn get_job_recommendations(
             rojgari: None,
         };
 
+        let saved = saved_ids.contains(&job.id);
+        let in_preferred_location = matches_preferences(
+            &user.preferred_locations,
+            job.work_arrangement,
+            job.division.as_deref(),
+            job.district.as_deref(),
+            job.city.as_deref(),
+        );
+
         recommendations.push(JobRecommendation {
             job,
             match_score: enhanced.match_score,
-            matched_skills: matched,
-            missing_skills: missing,
-            match_explanation: ai_enhanced_explanation,
+            matched_skills: enhanced.matched_skills(),
+            missing_skills: enhanced.missing_skills(true),
+            missing_preferred_skills: enhanced.missing_skills(false),
+            match_explanation: enhanced.match_explanation,
+            explanation_source: ExplanationSource::Heuristic,
             strengths: enhanced.strengths,
             improvement_areas: enhanced.improvement_areas,
             experience_alignment: enhanced.experience_alignment,
             track_alignment: enhanced.track_alignment,
             skill_overlap: enhanced.skill_overlap,
+            semantic_similarity: enhanced.semantic_similarity,
+            match_strategy: strategy.name().to_string(),
             platform_links,
+            saved,
+            in_preferred_location,
         });
     }
 
     // Note: We don't filter by minimum match score to allow users to see all available jobs
     // Even with low match scores, users can still apply and learn from job requirements
 
-    // Sort by match score descending
-    recommendations.sort_by(|a, b| b.match_score.partial_cmp(&a.match_score).unwrap());
+    // Sort by match score descending, preferred locations first on ties
+    recommendations.sort_by(|a, b| {
+        b.match_score
+            .partial_cmp(&a.match_score)
+            .unwrap()
+            .then(b.in_preferred_location.cmp(&a.in_preferred_location))
+    });
+
+    // Scores from overridden strategies or weights are not evaluated
+    if !selected.overridden
+        && let Err(e) = record_impressions(
+            &app_state.db_pool,
+            auth_user.user_id,
+            strategy.name(),
+            &impressions,
+        )
+        .await
+    {
+        warn!("Failed to record impressions for user {}: {}", auth_user.user_id, e);
+    }
+
+    // Replace heuristic explanations with cached or fresh AI ones, best matches first
+    let candidate = load_candidate(&app_state.db_pool, auth_user.user_id).await?;
+    let job_matches: Vec<JobMatch> = recommendations
+        .iter()
+        .map(|rec| job_match(&rec.job, rec.match_score, strategy.as_ref()))
+        .collect();
+    let mut ai_explanations = explain_matches(
+        &app_state.db_pool,
+        &candidate,
+        &job_matches,
+        params.ai_explanations.unwrap_or(true),
+    )
+    .await?;
+    for rec in &mut recommendations {
+        if let Some(explanation) = ai_explanations.remove(&rec.job.id) {
+            rec.match_explanation = explanation;
+            rec.explanation_source = ExplanationSource::Ai;
+        }
+    }
 
     info!(
         "Returning {} job recommendations for user: {}",
@@ -238,3 +356,658 @@ pub async fn get_job_recommendations(
 
     Ok(Json(recommendations))
 }
+
+/// Gets the match explanation of a job for the authenticated user.
+///
+/// Lets clients show recommendations straight away and fetch AI
+/// explanations lazily, one job at a time.
+///
+/// # Path Parameters
+///
+/// - `job_id` - ID of the job
+///
+/// # Query Parameters
+///
+/// - `strategy` - Score with this match strategy instead of the user's
+/// - `weights` - Override match weights, e.g.
+///   `skills:0.6,experience:0.2,track:0.1,semantic:0.1`
+///
+/// # Returns
+///
+/// The cached AI explanation, or a freshly generated one (which may take up
+/// to 30 seconds). Falls back to the heuristic explanation when no AI model
+/// is configured or generation fails; heuristic explanations aren't cached.
+///
+/// # Errors
+///
+/// Returns an error if:
+/// - User is not authenticated
+/// - Job doesn't exist or is a draft
+/// - `strategy` is unknown or `weights` is invalid
+/// - Database operation fails
+pub async fn get_match_explanation(
+    auth_user: AuthUser,
+    State(app_state): State<AppState>,
+    Path(job_id): Path<i32>,
+    Query(params): Query<MatchStrategyParams>,
+) -> AppResult<Json<MatchExplanationResponse>> {
+    info!(
+        "Fetching match explanation: job_id={}, user_id={}",
+        job_id, auth_user.user_id
+    );
+
+    let job = fetch_listed_job(&app_state, job_id).await?;
+    let skills = load_skill_taxonomy(&app_state.db_pool).await?;
+    let tracks = load_taxonomy(&app_state.db_pool).await?;
+    let scored = score_job(&app_state, auth_user.user_id, &job, &params, &skills, &tracks).await?;
+    let (strategy, candidate, enhanced) = (scored.strategy, scored.candidate, scored.analysis);
+
+    let ai_explanation = explain_match(
+        &app_state.db_pool,
+        &candidate,
+        &job_match(&job, enhanced.match_score, strategy.as_ref()),
+    )
+    .await?;
+    let (match_explanation, explanation_source, cached) = match ai_explanation {
+        Some((explanation, cached)) => (explanation, ExplanationSource::Ai, cached),
+        None => (enhanced.match_explanation, ExplanationSource::Heuristic, false),
+    };
+    debug!(
+        "Match explanation for job {}: source={:?}, cached={}",
+        job_id, explanation_source, cached
+    );
+
+    Ok(Json(MatchExplanationResponse {
+        job_id,
+        match_score: enhanced.match_score,
+        match_explanation,
+        explanation_source,
+        cached,
+        strengths: enhanced.strengths,
+        improvement_areas: enhanced.improvement_areas,
+        match_strategy: strategy.name().to_string(),
+    }))
+}
+
+/// Gets how each of a job's skills counts towards the user's match score.
+///
+/// Lists every required and preferred skill with whether the user has it
+/// (`matched`), has it below the expected proficiency or only through a
+/// more specific skill (`partial`), or lacks it (`missing`); its weight and
+/// the points it earned of those it is worth; and, for skills not fully
+/// matched, learning resources and an estimate of the hours of study needed.
+///
+/// # Path Parameters
+///
+/// - `job_id` - ID of the job
+///
+/// # Query Parameters
+///
+/// - `strategy` - Score with this match strategy instead of the user's
+/// - `weights` - Override match weights, e.g.
+///   `skills:0.6,experience:0.2,track:0.1,semantic:0.1`
+///
+/// # Returns
+///
+/// The match score, the factor scores and weights, and the skill breakdown,
+/// required skills first.
+///
+/// # Errors
+///
+/// Returns an error if:
+/// - User is not authenticated
+/// - Job doesn't exist or is a draft
+/// - `strategy` is unknown or `weights` is invalid
+/// - Database operation fails
+pub async fn get_match_breakdown(
+    auth_user: AuthUser,
+    State(app_state): State<AppState>,
+    Path(job_id): Path<i32>,
+    Query(params): Query<MatchStrategyParams>,
+) -> AppResult<Json<MatchBreakdownResponse>> {
+    info!(
+        "Fetching match breakdown: job_id={}, user_id={}",
+        job_id, auth_user.user_id
+    );
+
+    let job = fetch_listed_job(&app_state, job_id).await?;
+    let skills = load_skill_taxonomy(&app_state.db_pool).await?;
+    let tracks = load_taxonomy(&app_state.db_pool).await?;
+    let scored = score_job(&app_state, auth_user.user_id, &job, &params, &skills, &tracks).await?;
+    let analysis = scored.analysis;
+
+    let resources = sqlx::query_as!(
+        LearningResource,
+        r#"
+        SELECT id, title, platform, url, related_skills, cost as "cost: CostIndicator"
+        FROM learning_resources
+        ORDER BY id
+        "#
+    )
+    .fetch_all(&app_state.db_pool)
+    .await?;
+    let known = skills.keys_with_parents(&scored.candidate.skills);
+
+    let breakdown: Vec<SkillBreakdown> = analysis
+        .skill_credits
+        .iter()
+        .map(|credit| {
+            let (contribution, max_contribution) = analysis.skill_contribution(credit);
+            // Knowing the parent skill (Python for Django) halves the estimate
+            let head_start = skills
+                .find(&credit.skill)
+                .and_then(|skill| skill.parent_slug.as_deref())
+                .is_some_and(|parent| known.contains(parent));
+            let learning_hours = credit.learning_hours(head_start);
+            SkillBreakdown {
+                credit: credit.clone(),
+                contribution,
+                max_contribution,
+                learning_hours,
+                resources: if learning_hours > 0 {
+                    resources_teaching(&skills, &resources, &credit.skill)
+                } else {
+                    Vec::new()
+                },
+            }
+        })
+        .collect();
+    let learning_hours = breakdown.iter().map(|skill| skill.learning_hours).sum();
+
+    debug!(
+        "Match breakdown for job {}: {} skills, {}h to close gaps",
+        job_id,
+        breakdown.len(),
+        learning_hours
+    );
+
+    Ok(Json(MatchBreakdownResponse {
+        job_id,
+        match_score: analysis.match_score,
+        match_strategy: scored.strategy.name().to_string(),
+        weights: analysis.weights,
+        skill_overlap: analysis.skill_overlap,
+        experience_alignment: analysis.experience_alignment,
+        track_alignment: analysis.track_alignment,
+        semantic_similarity: analysis.semantic_similarity,
+        skills: breakdown,
+        learning_hours,
+    }))
+}
+
+/// Learning resources teaching a skill, most focused first and free before
+/// paid among equally focused ones.
+fn resources_teaching(
+    skills: &SkillTaxonomy,
+    resources: &[LearningResource],
+    skill: &str,
+) -> Vec<LearningResource> {
+    let key = skills.key(skill);
+    let mut teaching: Vec<&LearningResource> = resources
+        .iter()
+        .filter(|resource| resource.related_skills.iter().any(|taught| skills.key(taught) == key))
+        .collect();
+    teaching.sort_by_key(|resource| {
+        (resource.related_skills.len(), resource.cost != CostIndicator::Free)
+    });
+    teaching
+        .into_iter()
+        .take(MAX_BREAKDOWN_RESOURCES)
+        .cloned()
+        .collect()
+}
+
+/// Learning resources suggested per skill in a match breakdown.
+const MAX_BREAKDOWN_RESOURCES: usize = 3;
+
+/// A job scored for a user.
+struct ScoredJob {
+    strategy: Arc<dyn MatchStrategy>,
+    candidate: CandidateProfile,
+    analysis: EnhancedMatchAnalysis,
+}
+
+/// Loads a job that isn't a draft.
+async fn fetch_listed_job(app_state: &AppState, job_id: i32) -> AppResult<Job> {
+    let job = sqlx::query_as!(
+        Job,
+        r#"
+        SELECT
+            id, job_title, company, location,
+            work_arrangement as "work_arrangement: WorkArrangement",
+            country, division, district, city, latitude, longitude,
+            job_description, required_skills,
+            preferred_skills, skill_weights as "skill_weights: _",
+            experience_level as "experience_level: ExperienceLevel",
+            experience_years_min, experience_years_max,
+            job_type as "job_type: JobType",
+            salary_min, salary_max, salary_currency as "salary_currency: SalaryCurrency",
+            salary_period as "salary_period: SalaryPeriod", salary_negotiable,
+            salary_min_monthly_bdt, salary_max_monthly_bdt,
+            responsibilities, requirements, benefits, company_id,
+            status as "status: JobStatus", application_deadline, expires_at, created_at
+        FROM jobs
+        WHERE id = $1 AND status != 'draft'
+        "#,
+        job_id
+    )
+    .fetch_optional(&app_state.db_pool)
+    .await?
+    .ok_or(AppError::NotFound)?;
+
+    Ok(job)
+}
+
+/// Scores a job for a user with their match strategy, or the one chosen by
+/// `params`.
+async fn score_job(
+    app_state: &AppState,
+    user_id: Uuid,
+    job: &Job,
+    params: &MatchStrategyParams,
+    skills: &SkillTaxonomy,
+    tracks: &TrackTaxonomy,
+) -> AppResult<ScoredJob> {
+    let candidate = load_candidate(&app_state.db_pool, user_id).await?;
+    let strategy = app_state
+        .match_strategies
+        .select(
+            &app_state.db_pool,
+            user_id,
+            params.strategy.as_deref(),
+            params.weights.as_deref(),
+        )
+        .await?
+        .strategy;
+    let similarity = match semantic_provider(app_state, strategy.as_ref()) {
+        Some(_) => {
+            let jobs = std::slice::from_ref(job);
+            let profile = ProfileText::from(&candidate);
+            let similarities = semantic_similarities(
+                app_state,
+                strategy.as_ref(),
+                user_id,
+                profile,
+                jobs,
+                skills,
+                tracks,
+            );
+            similarities.await.remove(&job.id)
+        }
+        None => None,
+    };
+    let input = MatchInput::new(
+        CandidateSkills {
+            skills: &candidate.skills,
+            proficiency: &candidate.skill_proficiency,
+        },
+        candidate.experience(),
+        candidate.preferred_track.as_deref(),
+        job,
+    )
+    .with_semantic_similarity(similarity);
+    let analysis = strategy.score(&input, skills, tracks);
+
+    Ok(ScoredJob {
+        strategy,
+        candidate,
+        analysis,
+    })
+}
+
+/// The embedding provider, if semantic matching is configured and the
+/// strategy weighs semantic similarity.
+fn semantic_provider<'a>(
+    app_state: &'a AppState,
+    strategy: &dyn MatchStrategy,
+) -> Option<&'a dyn EmbeddingProvider> {
+    app_state
+        .embeddings
+        .as_deref()
+        .filter(|_| strategy.weights().semantic > 0.0)
+}
+
+/// Semantic similarity of the user's profile to each job, by job ID.
+///
+/// Empty unless the strategy weighs semantic similarity. Embedding failures
+/// are logged and leave jobs to be scored without it.
+async fn semantic_similarities(
+    app_state: &AppState,
+    strategy: &dyn MatchStrategy,
+    user_id: Uuid,
+    profile: ProfileText<'_>,
+    jobs: &[Job],
+    skills: &SkillTaxonomy,
+    tracks: &TrackTaxonomy,
+) -> HashMap<i32, f64> {
+    let Some(provider) = semantic_provider(app_state, strategy) else {
+        return HashMap::new();
+    };
+    let similarities =
+        job_similarities(&app_state.db_pool, provider, user_id, profile, jobs, skills, tracks);
+    match similarities.await {
+        Ok(similarities) => similarities,
+        Err(e) => {
+            warn!("Semantic similarity unavailable for user {}: {}", user_id, e);
+            HashMap::new()
+        }
+    }
+}
+
+/// Describes a job scored with a strategy for match explanations.
+fn job_match(job: &Job, match_score: f64, strategy: &dyn MatchStrategy) -> JobMatch {
+    JobMatch {
+        job_id: job.id,
+        job_title: job.job_title.clone(),
+        required_skills: job.required_skills.clone(),
+        experience: ExperienceRange::job(
+            job.experience_level,
+            job.experience_years_min,
+            job.experience_years_max,
+        ),
+        job_description: job.job_description.clone(),
+        match_score,
+        strategy_hash: strategy.hash(),
+    }
+}
+
+/// Default page size for job search.
+const DEFAULT_SEARCH_LIMIT: i64 = 20;
+
+/// Maximum page size for job search.
+const MAX_SEARCH_LIMIT: i64 = 100;
+
+/// A job row together with its full-text rank.
+#[derive(FromRow)]
+struct JobSearchRow {
+    #[sqlx(flatten)]
+    job: Job,
+    relevance: f32,
+    semantic_similarity: Option<f32>,
+}
+
+/// Position of the last result on a page, used for keyset pagination.
+///
+/// Encoded as `"<sort>:<key>:<id>"` so a cursor can't be replayed against a
+/// different sort order.
+enum SearchCursor {
+    Relevance(f32, i32),
+    Date(DateTime<Utc>, i32),
+    Salary(i32, i32),
+}
+
+impl SearchCursor {
+    /// Builds the cursor pointing after the given result.
+    fn after(sort: JobSortBy, hit: &JobSearchHit) -> Self {
+        let id = hit.job.id;
+        match sort {
+            JobSortBy::Relevance => SearchCursor::Relevance(hit.relevance, id),
+            JobSortBy::Date => {
+                SearchCursor::Date(hit.job.created_at.unwrap_or(DateTime::UNIX_EPOCH), id)
+            }
+            JobSortBy::Salary => SearchCursor::Salary(
+                hit.job
+                    .salary_max_monthly_bdt
+                    .or(hit.job.salary_min_monthly_bdt)
+                    .unwrap_or(0),
+                id,
+            ),
+        }
+    }
+
+    /// Encodes the cursor as an opaque string.
+    fn encode(&self) -> String {
+        match self {
+            SearchCursor::Relevance(rank, id) => format!("relevance:{}:{}", rank, id),
+            SearchCursor::Date(at, id) => format!("date:{}:{}", at.timestamp_micros(), id),
+            SearchCursor::Salary(salary, id) => format!("salary:{}:{}", salary, id),
+        }
+    }
+
+    /// Parses a cursor previously produced by [`SearchCursor::encode`].
+    fn decode(raw: &str, sort: JobSortBy) -> AppResult<Self> {
+        let invalid = || AppError::BadRequest("Invalid search cursor".to_string());
+
+        let mut parts = raw.splitn(3, ':');
+        let (Some(kind), Some(key), Some(id)) = (parts.next(), parts.next(), parts.next()) else {
+            return Err(invalid());
+        };
+        let id: i32 = id.parse().map_err(|_| invalid())?;
+
+        match (kind, sort) {
+            ("relevance", JobSortBy::Relevance) => Ok(SearchCursor::Relevance(
+                key.parse().map_err(|_| invalid())?,
+                id,
+            )),
+            ("date", JobSortBy::Date) => {
+                let micros: i64 = key.parse().map_err(|_| invalid())?;
+                let at = DateTime::from_timestamp_micros(micros).ok_or_else(invalid)?;
+                Ok(SearchCursor::Date(at, id))
+            }
+            ("salary", JobSortBy::Salary) => Ok(SearchCursor::Salary(
+                key.parse().map_err(|_| invalid())?,
+                id,
+            )),
+            _ => Err(invalid()),
+        }
+    }
+}
+
+/// Searches jobs by keyword with filters, sorting and cursor pagination.
+///
+/// The query is matched against job title, required skills, description and
+/// requirements (in that order of weight) using PostgreSQL full-text search.
+/// Semantic search also matches jobs whose embedding is close to the query's,
+/// so with a language model "python backend" finds Django roles, and adds
+/// the similarity to the relevance rank. Only open jobs are returned. This endpoint does not
+/// require authentication.
+///
+/// # Query Parameters
+///
+/// - `q` - Free-text query (supports quoted phrases, `or` and `-exclusions`)
+/// - `semantic` - `true` for semantic search (needs `q`)
+/// - `job_type` - Optional filter by job type
+/// - `experience_level` - Optional filter by experience level
+/// - `location` - Optional case-insensitive location substring
+/// - `division` - Only jobs in this division
+/// - `near` / `radius_km` - Only jobs within `radius_km` (default: 25) of a
+///   place or `latitude,longitude`
+/// - `work_arrangement` - `on_site`, `hybrid` or `remote`
+/// - `salary_min` / `salary_max` - Optional salary bounds in BDT per month; jobs
+///   paid in other currencies or periods are compared after conversion
+/// - `posted_within_days` - Only jobs posted in the last N days
+/// - `closing_within_days` - Only jobs whose application deadline falls within
+///   the next N days
+/// - `sort` - `relevance` (default), `date` or `salary` (monthly BDT)
+/// - `cursor` - `next_cursor` from the previous page
+/// - `limit` - Page size (default: 20, max: 100)
+///
+/// # Errors
+///
+/// Returns an error if:
+/// - Filter values are invalid
+/// - Semantic search is requested but not configured
+/// - Cursor is malformed or was issued for a different sort order
+/// - The query cannot be embedded
+/// - Database operation fails
+pub async fn search_jobs(
+    State(app_state): State<AppState>,
+    Query(params): Query<JobSearchParams>,
+) -> AppResult<Json<JobSearchResponse>> {
+    info!("Searching jobs: q={:?}, sort={:?}", params.q, params.sort);
+
+    if params.posted_within_days.is_some_and(|days| days <= 0) {
+        return Err(AppError::BadRequest(
+            "posted_within_days must be positive".to_string(),
+        ));
+    }
+    if params.closing_within_days.is_some_and(|days| days <= 0) {
+        return Err(AppError::BadRequest(
+            "closing_within_days must be positive".to_string(),
+        ));
+    }
+    if let (Some(min), Some(max)) = (params.salary_min, params.salary_max)
+        && min > max
+    {
+        return Err(AppError::BadRequest(
+            "salary_min cannot be greater than salary_max".to_string(),
+        ));
+    }
+
+    let limit = params
+        .limit
+        .unwrap_or(DEFAULT_SEARCH_LIMIT)
+        .clamp(1, MAX_SEARCH_LIMIT);
+    let query_text = params.q.as_deref().map(str::trim).filter(|q| !q.is_empty());
+    let semantic = match (params.semantic, query_text) {
+        (true, Some(text)) => {
+            let (Some(provider), Some(job_vectors)) =
+                (app_state.embeddings.as_deref(), app_state.job_vectors.as_deref())
+            else {
+                return Err(AppError::BadRequest("Semantic search is not enabled".to_string()));
+            };
+            let vector = provider
+                .embed(&[text.to_string()])
+                .await?
+                .pop()
+                .unwrap_or_default();
+            job_vectors.sync(&app_state.db_pool).await?;
+            Some(SemanticQuery {
+                similarities: job_vectors
+                    .nearest(&vector, SEMANTIC_NEIGHBOURS)
+                    .into_iter()
+                    .collect(),
+            })
+        }
+        _ => None,
+    };
+
+    let filters = JobFilters {
+        q: params.q.clone(),
+        semantic,
+        job_type: params.job_type,
+        experience_level: params.experience_level,
+        location: params.location.clone(),
+        division: parse_division(params.division.as_deref())?,
+        near: parse_radius(params.near.as_deref(), params.radius_km)?,
+        work_arrangement: params.work_arrangement,
+        salary_min: params.salary_min,
+        salary_max: params.salary_max,
+        posted_within_days: params.posted_within_days,
+        published_after: None,
+        closing_within_days: params.closing_within_days,
+    };
+    let q = filters.query();
+
+    // Relevance only means something when there is a query to rank against
+    let sort = match (params.sort, q) {
+        (JobSortBy::Relevance, None) => JobSortBy::Date,
+        (sort, _) => sort,
+    };
+    let cursor = params
+        .cursor
+        .as_deref()
+        .map(|raw| SearchCursor::decode(raw, sort))
+        .transpose()?;
+
+    let mut count_query = QueryBuilder::<Postgres>::new("SELECT COUNT(*) FROM jobs WHERE TRUE");
+    filters.push_conditions(&mut count_query);
+    let total_count: i64 = count_query
+        .build_query_scalar()
+        .fetch_one(&app_state.db_pool)
+        .await?;
+
+    let mut page_query = QueryBuilder::<Postgres>::new("SELECT ");
+    page_query.push(JOB_COLUMNS).push(", ");
+    match q {
+        Some(q) => {
+            push_relevance(&mut page_query, q, filters.semantic.as_ref());
+            page_query.push(" AS relevance, ");
+            match &filters.semantic {
+                Some(semantic) => push_semantic_similarity(&mut page_query, semantic),
+                None => {
+                    page_query.push("NULL::REAL");
+                }
+            }
+            page_query.push(" AS semantic_similarity");
+        }
+        None => {
+            page_query.push("0::REAL AS relevance, NULL::REAL AS semantic_similarity");
+        }
+    }
+    page_query.push(" FROM jobs WHERE TRUE");
+    filters.push_conditions(&mut page_query);
+
+    // Relevance is only sorted by with a query (see above)
+    let push_sort_key = |builder: &mut QueryBuilder<'_, Postgres>| match (sort, q) {
+        (JobSortBy::Relevance, Some(q)) => {
+            push_relevance(builder, q, filters.semantic.as_ref());
+        }
+        (JobSortBy::Date | JobSortBy::Relevance, _) => {
+            builder.push("COALESCE(created_at, 'epoch'::TIMESTAMPTZ)");
+        }
+        (JobSortBy::Salary, _) => {
+            builder.push("COALESCE(salary_max_monthly_bdt, salary_min_monthly_bdt, 0)");
+        }
+    };
+
+    if let Some(cursor) = cursor {
+        page_query.push(" AND (");
+        push_sort_key(&mut page_query);
+        page_query.push(", id) < (");
+        match cursor {
+            SearchCursor::Relevance(rank, id) => {
+                page_query.push_bind(rank).push("::REAL, ").push_bind(id);
+            }
+            SearchCursor::Date(at, id) => {
+                page_query.push_bind(at).push(", ").push_bind(id);
+            }
+            SearchCursor::Salary(salary, id) => {
+                page_query.push_bind(salary).push(", ").push_bind(id);
+            }
+        }
+        page_query.push(")");
+    }
+
+    page_query.push(" ORDER BY ");
+    push_sort_key(&mut page_query);
+    page_query.push(" DESC, id DESC LIMIT ").push_bind(limit + 1);
+
+    let rows: Vec<JobSearchRow> = page_query
+        .build_query_as()
+        .fetch_all(&app_state.db_pool)
+        .await?;
+
+    let has_more = rows.len() as i64 > limit;
+    let results: Vec<JobSearchHit> = rows
+        .into_iter()
+        .take(limit as usize)
+        .map(|row| JobSearchHit {
+            job: row.job,
+            relevance: row.relevance,
+            semantic_similarity: row
+                .semantic_similarity
+                .map(|similarity| (similarity.max(0.0) as f64) * 100.0),
+        })
+        .collect();
+
+    let next_cursor = if has_more {
+        results
+            .last()
+            .map(|hit| SearchCursor::after(sort, hit).encode())
+    } else {
+        None
+    };
+
+    debug!(
+        "Job search returned {} of {} matches",
+        results.len(),
+        total_count
+    );
+
+    Ok(Json(JobSearchResponse {
+        results,
+        total_count,
+        next_cursor,
+    }))
+}
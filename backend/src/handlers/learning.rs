@@ -1,17 +1,25 @@
 //! Learning resource and skill gap analysis handlers.
 
 use axum::{extract::{State, Path}, Json};
-use tracing::{info, debug};
-use crate::models::{User, Job, LearningResource, ExperienceLevel, CareerTrack, JobType, CostIndicator};
+use tracing::{info, debug, warn};
+use crate::models::{User, Job, JobStatus, LearningResource, ExperienceLevel, JobType, CostIndicator, UserRole, SalaryCurrency, SalaryPeriod, WorkArrangement};
 use crate::errors::AppResult;
 use crate::auth::AuthUser;
+use crate::career_tracks::load_taxonomy;
+use crate::embeddings::store::resource_similarities;
 use crate::AppState;
+use crate::skill_trends::{RECENT_TREND_DAYS, TrendFilters, skill_movers};
+use crate::skills::load_skill_taxonomy;
 use super::types::{ResourceRecommendation, SkillGapAnalysis};
 
 /// Gets learning resource recommendations for the authenticated user.
 /// 
-/// Recommends resources that teach skills the user doesn't currently have.
+/// Recommends resources that teach skills the user doesn't currently have,
+/// comparing skills through the skill taxonomy.
 /// Resources are scored based on how many new skills they offer.
+/// Among equally relevant resources, those teaching more skills rising in
+/// demand (in the user's track, if set) come first, then those semantically
+/// closest to the user's profile when embeddings are configured.
 /// 
 /// # Returns
 /// 
@@ -20,6 +28,8 @@ use super::types::{ResourceRecommendation, SkillGapAnalysis};
 /// - The resource details
 /// - Relevance score
 /// - Skills the resource can teach
+/// - Which of those skills are rising in demand
+/// - Semantic similarity to the user's profile, if available
 /// 
 /// # Errors
 /// 
@@ -38,10 +48,11 @@ pub async fn get_learning_recommendations(
         r#"
         SELECT 
             id, full_name, email, education_level,
-            experience_level as "experience_level: ExperienceLevel",
-            preferred_track as "preferred_track: CareerTrack",
+            experience_level as "experience_level: ExperienceLevel", experience_years,
+            preferred_track,
             profile_completed as "profile_completed!",
-            skills, projects, target_roles, raw_cv_text, password_hash
+            skills, skill_proficiency as "skill_proficiency: _", projects, target_roles, raw_cv_text, password_hash,
+            role as "role: UserRole", company_id, preferred_locations, discoverable
         FROM users 
         WHERE id = $1
         "#,
@@ -64,44 +75,89 @@ pub async fn get_learning_recommendations(
     .fetch_all(&app_state.db_pool)
     .await?;
 
-    // Calculate relevance scores (case-insensitive comparison)
-    let user_skills_lower: std::collections::HashSet<String> = user.skills
-        .iter()
-        .map(|s| s.to_lowercase())
-        .collect();
+    // Skills rising in demand in the user's track
+    let tracks = load_taxonomy(&app_state.db_pool).await?;
+    let user_tracks = user
+        .preferred_track
+        .as_ref()
+        .map(|track| tracks.with_sub_tracks(track));
+    let skills = load_skill_taxonomy(&app_state.db_pool).await?;
+    let rising_skills: std::collections::HashSet<String> = skill_movers(
+        &app_state.db_pool,
+        &TrendFilters {
+            tracks: user_tracks,
+            ..Default::default()
+        },
+        RECENT_TREND_DAYS,
+        20,
+    )
+    .await?
+    .risers
+    .into_iter()
+    .map(|c| skills.key(&c.skill))
+    .collect();
+
+    // Semantic similarity breaks the remaining ties; it is skipped if embedding fails
+    let similarities = match app_state.embeddings.as_deref() {
+        Some(provider) => resource_similarities(
+            &app_state.db_pool,
+            provider,
+            &user,
+            &resources,
+            &skills,
+            &tracks,
+        )
+        .await
+        .unwrap_or_else(|e| {
+            warn!("Semantic similarity unavailable for user {}: {}", user.id, e);
+            Default::default()
+        }),
+        None => Default::default(),
+    };
 
     let mut recommendations: Vec<ResourceRecommendation> = resources.into_iter()
         .map(|resource| {
-            // Create case-insensitive mapping for resource skills
-            let resource_skills_lower: std::collections::HashMap<String, String> = resource.related_skills
-                .iter()
-                .map(|s| (s.to_lowercase(), s.clone()))
-                .collect();
-            let resource_skills_set: std::collections::HashSet<String> = resource_skills_lower.keys().cloned().collect();
-            
-            // Skills user doesn't have but resource teaches (case-insensitive comparison)
-            let new_skills: Vec<String> = resource_skills_set
-                .difference(&user_skills_lower)
-                .filter_map(|lower_key| resource_skills_lower.get(lower_key).cloned())
-                .collect();
+            // Skills the resource teaches that the user doesn't have yet,
+            // compared through the skill taxonomy
+            let comparison = skills.compare(&user.skills, &resource.related_skills);
+            let new_skills = comparison.missing;
             
             // Relevance based on how many new skills it teaches
-            let relevance_score = if !resource.related_skills.is_empty() {
-                (new_skills.len() as f64 / resource.related_skills.len() as f64) * 100.0
+            let taught = new_skills.len() + comparison.matched.len();
+            let relevance_score = if taught > 0 {
+                (new_skills.len() as f64 / taught as f64) * 100.0
             } else {
                 0.0
             };
 
+            let trending_skills: Vec<String> = new_skills
+                .iter()
+                .filter(|s| rising_skills.contains(&skills.key(s)))
+                .cloned()
+                .collect();
+
             ResourceRecommendation {
+                semantic_similarity: similarities.get(&resource.id).copied(),
                 resource,
                 relevance_score,
                 target_skills: new_skills,
+                trending_skills,
             }
         })
         .filter(|r| r.relevance_score > 0.0) // Only show resources that teach new skills
         .collect();
 
-    recommendations.sort_by(|a, b| b.relevance_score.partial_cmp(&a.relevance_score).unwrap());
+    recommendations.sort_by(|a, b| {
+        b.relevance_score
+            .partial_cmp(&a.relevance_score)
+            .unwrap()
+            .then(b.trending_skills.len().cmp(&a.trending_skills.len()))
+            .then(
+                b.semantic_similarity
+                    .unwrap_or(0.0)
+                    .total_cmp(&a.semantic_similarity.unwrap_or(0.0)),
+            )
+    });
 
     let result: Vec<_> = recommendations.into_iter().take(10).collect();
     info!("Returning {} learning recommendations for user: {}", result.len(), auth_user.user_id);
@@ -113,6 +169,8 @@ pub async fn get_learning_recommendations(
 /// 
 /// Compares the user's skills against requirements for a specific job role,
 /// identifying which skills they have and which they need to acquire.
+/// Skills are compared through the skill taxonomy, so "JS" matches
+/// "JavaScript" and knowing React covers a required JavaScript.
 /// 
 /// # Path Parameters
 /// 
@@ -147,10 +205,11 @@ pub async fn analyze_skill_gap(
         r#"
         SELECT 
             id, full_name, email, education_level,
-            experience_level as "experience_level: ExperienceLevel",
-            preferred_track as "preferred_track: CareerTrack",
+            experience_level as "experience_level: ExperienceLevel", experience_years,
+            preferred_track,
             profile_completed as "profile_completed!",
-            skills, projects, target_roles, raw_cv_text, password_hash
+            skills, skill_proficiency as "skill_proficiency: _", projects, target_roles, raw_cv_text, password_hash,
+            role as "role: UserRole", company_id, preferred_locations, discoverable
         FROM users 
         WHERE id = $1
         "#,
@@ -165,12 +224,21 @@ pub async fn analyze_skill_gap(
         Job,
         r#"
         SELECT 
-            id, job_title, company, location, job_description, required_skills,
+            id, job_title, company, location,
+            work_arrangement as "work_arrangement: WorkArrangement",
+            country, division, district, city, latitude, longitude,
+            job_description, required_skills,
+            preferred_skills, skill_weights as "skill_weights: _",
             experience_level as "experience_level: ExperienceLevel",
+            experience_years_min, experience_years_max,
             job_type as "job_type: JobType",
-            salary_min, salary_max, responsibilities, requirements, benefits
+            salary_min, salary_max, salary_currency as "salary_currency: SalaryCurrency",
+            salary_period as "salary_period: SalaryPeriod", salary_negotiable,
+            salary_min_monthly_bdt, salary_max_monthly_bdt,
+            responsibilities, requirements, benefits, company_id,
+            status as "status: JobStatus", application_deadline, expires_at, created_at
         FROM jobs 
-        WHERE LOWER(job_title) LIKE LOWER($1)
+        WHERE LOWER(job_title) LIKE LOWER($1) AND status = 'open'
         LIMIT 5
         "#,
         format!("%{}%", target_role)
@@ -178,40 +246,19 @@ pub async fn analyze_skill_gap(
     .fetch_all(&app_state.db_pool)
     .await?;
 
-    // Aggregate all required skills from matching jobs
-    let mut all_required_skills = std::collections::HashSet::new();
-    for job in &jobs {
-        for skill in &job.required_skills {
-            all_required_skills.insert(skill.clone());
-        }
-    }
-
-    let required_skills: Vec<String> = all_required_skills.into_iter().collect();
-    
-    // Create case-insensitive skill sets for comparison
-    let user_skills_lower: std::collections::HashMap<String, String> = user.skills
+    // Aggregate all required skills from matching jobs, merging aliases
+    let skills = load_skill_taxonomy(&app_state.db_pool).await?;
+    let all_required_skills: Vec<String> = jobs
         .iter()
-        .map(|s| (s.to_lowercase(), s.clone()))
+        .flat_map(|job| job.required_skills.iter().cloned())
         .collect();
-    let required_skills_lower: std::collections::HashMap<String, String> = required_skills
-        .iter()
-        .map(|s| (s.to_lowercase(), s.clone()))
-        .collect();
-    
-    let user_skills_set: std::collections::HashSet<String> = user_skills_lower.keys().cloned().collect();
-    let required_skills_set: std::collections::HashSet<String> = required_skills_lower.keys().cloned().collect();
+    let required_skills = skills.normalize(&all_required_skills);
 
-    // Find matching skills (case-insensitive comparison, but preserve user's original case)
-    let matching_skills: Vec<String> = user_skills_set
-        .intersection(&required_skills_set)
-        .filter_map(|lower_key| user_skills_lower.get(lower_key).cloned())
-        .collect();
-
-    // Find skill gaps (case-insensitive comparison, but preserve original case)
-    let skill_gaps: Vec<String> = required_skills_set
-        .difference(&user_skills_set)
-        .filter_map(|lower_key| required_skills_lower.get(lower_key).cloned())
-        .collect();
+    // Required skills the user has, directly, by alias or through a more
+    // specific skill, and those they still need
+    let comparison = skills.compare(&user.skills, &required_skills);
+    let matching_skills = comparison.matched;
+    let skill_gaps = comparison.missing;
 
     let match_percentage = if !required_skills.is_empty() {
         (matching_skills.len() as f64 / required_skills.len() as f64) * 100.0
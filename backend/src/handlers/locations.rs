@@ -0,0 +1,41 @@
+//! Location gazetteer handlers.
+//!
+//! Exposes the bundled Bangladesh gazetteer (see [`crate::locations`]) so
+//! clients can offer division, district and preferred-location pickers.
+
+use super::types::{GazetteerDistrict, GazetteerDivision};
+use crate::locations::{CITIES, DISTRICTS, DIVISIONS};
+use axum::Json;
+use tracing::info;
+
+/// Lists the divisions of Bangladesh with their districts and well-known
+/// cities.
+///
+/// Any of these names (or "Remote") can be used as a preferred location, a
+/// `division` filter or a `near` place.
+pub async fn get_locations() -> Json<Vec<GazetteerDivision>> {
+    info!("Fetching location gazetteer");
+
+    let divisions = DIVISIONS
+        .iter()
+        .map(|division| GazetteerDivision {
+            name: division.name.to_string(),
+            districts: DISTRICTS
+                .iter()
+                .filter(|d| d.division == division.name)
+                .map(|district| GazetteerDistrict {
+                    name: district.name.to_string(),
+                    latitude: district.latitude,
+                    longitude: district.longitude,
+                    cities: CITIES
+                        .iter()
+                        .filter(|c| c.district == district.name)
+                        .map(|c| c.name.to_string())
+                        .collect(),
+                })
+                .collect(),
+        })
+        .collect();
+
+    Json(divisions)
+}
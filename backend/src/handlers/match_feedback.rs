@@ -0,0 +1,333 @@
+//! Match feedback handlers.
+//!
+//! Records explicit feedback on recommended jobs and reports the offline
+//! evaluation of match strategies from all feedback, with tuned weights
+//! (see [`crate::match_feedback`]).
+
+use super::companies::load_membership;
+use super::types::{
+    MatchFeedbackPayload, MatchFeedbackReport, MatchFeedbackReportParams, StrategyFeedbackReport,
+    TunedStrategyResponse, TunedWeights,
+};
+use crate::AppState;
+use crate::ai_matching::MatchWeights;
+use crate::auth::AuthUser;
+use crate::errors::{AppError, AppResult};
+use crate::match_feedback::{
+    RankingMetrics, StrategyFeedback, evaluate, load_feedback, record_feedback, tune_weights,
+};
+use crate::models::{MatchFeedbackEvent, UserRole};
+use axum::{
+    Json,
+    extract::{Path, Query, State},
+};
+use tokio::task::spawn_blocking;
+use tracing::{error, info};
+use uuid::Uuid;
+
+/// Default days of feedback evaluated.
+const DEFAULT_DAYS: i32 = 90;
+
+/// Maximum days of feedback evaluated.
+const MAX_DAYS: i32 = 365;
+
+/// Default cut-off rank of precision@k and NDCG@k.
+const DEFAULT_K: usize = 10;
+
+/// Maximum cut-off rank.
+const MAX_K: usize = 50;
+
+/// Default number of users with positive feedback needed to tune weights.
+const DEFAULT_MIN_USERS: usize = 10;
+
+/// Records explicit feedback on a recommended job.
+///
+/// Jobs marked `not_interested` are no longer recommended to the user.
+///
+/// # Path Parameters
+///
+/// - `job_id` - ID of the job
+///
+/// # Errors
+///
+/// Returns an error if:
+/// - User is not authenticated
+/// - Event is not `view` or `not_interested`
+/// - `reason` is missing for `not_interested` or given for `view`
+/// - Job doesn't exist or is a draft
+/// - Database operation fails
+pub async fn record_match_feedback(
+    auth_user: AuthUser,
+    State(app_state): State<AppState>,
+    Path(job_id): Path<i32>,
+    Json(payload): Json<MatchFeedbackPayload>,
+) -> AppResult<Json<serde_json::Value>> {
+    info!(
+        "Recording match feedback: user_id={}, job_id={}, event={:?}",
+        auth_user.user_id, job_id, payload.event
+    );
+
+    match (payload.event, payload.reason) {
+        (MatchFeedbackEvent::View, None) | (MatchFeedbackEvent::NotInterested, Some(_)) => {}
+        (MatchFeedbackEvent::View, Some(_)) => {
+            return Err(AppError::BadRequest(
+                "reason is only given for not_interested".to_string(),
+            ));
+        }
+        (MatchFeedbackEvent::NotInterested, None) => {
+            return Err(AppError::BadRequest(
+                "reason is required for not_interested".to_string(),
+            ));
+        }
+        _ => {
+            return Err(AppError::BadRequest(
+                "event must be view or not_interested; saves and applications are recorded when made"
+                    .to_string(),
+            ));
+        }
+    }
+
+    let exists = sqlx::query_scalar!(
+        r#"SELECT EXISTS(SELECT 1 FROM jobs WHERE id = $1 AND status <> 'draft') as "exists!""#,
+        job_id
+    )
+    .fetch_one(&app_state.db_pool)
+    .await?;
+    if !exists {
+        return Err(AppError::NotFound);
+    }
+
+    record_feedback(
+        &app_state.db_pool,
+        auth_user.user_id,
+        job_id,
+        payload.event,
+        payload.reason,
+        None,
+    )
+    .await?;
+
+    Ok(Json(serde_json::json!({
+        "message": "Feedback recorded"
+    })))
+}
+
+/// Reports how well match strategies ranked the jobs users acted on
+/// (admin only).
+///
+/// Each job shown in recommendations is graded by the feedback on it: 0 for
+/// "not interested" or no feedback, 1 for a view, 2 for a save, 3 for an
+/// application, 4 for an interview and 5 for an offer. For users with
+/// positive feedback, precision@k counts saved or better jobs among the top
+/// k, and NDCG@k compares the ranking to the ideal one. Weights are tuned by
+/// re-ranking the same jobs under a grid of weights.
+///
+/// # Query Parameters
+///
+/// - `strategy` - Only this strategy (default: all)
+/// - `days` - Days of feedback to evaluate (default: 90, max: 365)
+/// - `k` - Cut-off rank (default: 10, max: 50)
+/// - `min_users` - Users with positive feedback needed to tune weights
+///   (default: 10)
+///
+/// # Returns
+///
+/// Per strategy: users and jobs shown, feedback events and "not interested"
+/// reasons, precision@k and NDCG@k, and the tuned weights when there was
+/// enough feedback.
+///
+/// # Errors
+///
+/// Returns an error if:
+/// - User is not authenticated
+/// - User is not an admin
+/// - `days`, `k` or `min_users` is out of range
+/// - Database operation fails
+pub async fn get_match_feedback_report(
+    auth_user: AuthUser,
+    State(app_state): State<AppState>,
+    Query(params): Query<MatchFeedbackReportParams>,
+) -> AppResult<Json<MatchFeedbackReport>> {
+    info!("Reporting match feedback: user_id={}", auth_user.user_id);
+
+    require_admin(&app_state, auth_user.user_id).await?;
+    let (days, k, min_users) = report_window(&params)?;
+
+    let mut feedback = load_feedback(&app_state.db_pool, days).await?;
+    if let Some(strategy) = &params.strategy {
+        feedback.retain(|f| &f.strategy == strategy);
+    }
+
+    let registry = app_state.match_strategies.clone();
+    let strategies = spawn_blocking(move || {
+        feedback
+            .into_iter()
+            .map(|feedback| {
+                let weights = registry.get(&feedback.strategy).map(|s| s.weights());
+                let metrics = evaluate(&feedback.rankings, k, None);
+                let tuned = (metrics.users >= min_users).then(|| {
+                    let (weights, metrics) = tune_weights(&feedback.rankings, k, weights);
+                    TunedWeights { weights, metrics }
+                });
+                StrategyFeedbackReport {
+                    users: feedback.rankings.len(),
+                    impressions: feedback.impressions(),
+                    strategy: feedback.strategy,
+                    weights,
+                    events: feedback.events,
+                    not_interested_reasons: feedback.reasons,
+                    metrics,
+                    tuned,
+                }
+            })
+            .collect()
+    })
+    .await
+    .map_err(|e| {
+        error!("Task join error during match feedback evaluation: {}", e);
+        AppError::InternalServerError
+    })?;
+
+    Ok(Json(MatchFeedbackReport {
+        days,
+        k,
+        min_users,
+        strategies,
+    }))
+}
+
+/// Exports a match strategy with weights tuned on feedback (admin only).
+///
+/// See [`get_match_feedback_report`] for how weights are tuned.
+///
+/// # Query Parameters
+///
+/// - `strategy` - Strategy to tune (default: the default strategy)
+/// - `days`, `k`, `min_users` - As for the report
+///
+/// # Returns
+///
+/// A strategies file entry named `<strategy>_tuned` with the strategy's
+/// settings and the tuned weights, e.g. to add as an experiment arm, the
+/// weights as a `MATCH_WEIGHTS` value, and NDCG@k and precision@k before
+/// and after tuning.
+///
+/// # Errors
+///
+/// Returns an error if:
+/// - User is not authenticated
+/// - User is not an admin
+/// - `strategy` is unknown, or `days`, `k` or `min_users` is out of range
+/// - Fewer than `min_users` users gave positive feedback
+/// - Database operation fails
+pub async fn get_tuned_strategy(
+    auth_user: AuthUser,
+    State(app_state): State<AppState>,
+    Query(params): Query<MatchFeedbackReportParams>,
+) -> AppResult<Json<TunedStrategyResponse>> {
+    info!("Tuning match strategy: user_id={}", auth_user.user_id);
+
+    require_admin(&app_state, auth_user.user_id).await?;
+    let (days, k, min_users) = report_window(&params)?;
+
+    let registry = &app_state.match_strategies;
+    let strategy = match &params.strategy {
+        Some(name) => registry.get(name).ok_or_else(|| {
+            AppError::BadRequest(format!(
+                "Unknown match strategy '{}', expected one of: {}",
+                name,
+                registry.names().join(", ")
+            ))
+        })?,
+        None => registry.default_strategy(),
+    }
+    .clone();
+
+    let feedback = load_feedback(&app_state.db_pool, days)
+        .await?
+        .into_iter()
+        .find(|f| f.strategy == strategy.name())
+        .unwrap_or_else(|| StrategyFeedback {
+            strategy: strategy.name().to_string(),
+            ..Default::default()
+        });
+    let baseline_weights = strategy.weights();
+    let (baseline, tuned_weights, tuned) = spawn_blocking(move || {
+        tune(&feedback, k, min_users, baseline_weights)
+    })
+    .await
+    .map_err(|e| {
+        error!("Task join error during match strategy tuning: {}", e);
+        AppError::InternalServerError
+    })??;
+
+    let mut entry = serde_json::json!({
+        "name": format!("{}_tuned", strategy.name()),
+        "kind": strategy.kind(),
+        "weights": tuned_weights,
+    });
+    if let (Some(entry), serde_json::Value::Object(settings)) =
+        (entry.as_object_mut(), strategy.settings())
+    {
+        entry.extend(settings);
+    }
+
+    Ok(Json(TunedStrategyResponse {
+        based_on: strategy.name().to_string(),
+        strategy: entry,
+        match_weights: format!(
+            "skills:{},experience:{},track:{},semantic:{}",
+            tuned_weights.skills, tuned_weights.experience, tuned_weights.track, tuned_weights.semantic
+        ),
+        baseline,
+        tuned,
+    }))
+}
+
+/// Baseline metrics, tuned weights and their metrics, given enough users
+/// with positive feedback.
+fn tune(
+    feedback: &StrategyFeedback,
+    k: usize,
+    min_users: usize,
+    baseline_weights: MatchWeights,
+) -> AppResult<(RankingMetrics, MatchWeights, RankingMetrics)> {
+    let baseline = evaluate(&feedback.rankings, k, None);
+    if baseline.users < min_users {
+        return Err(AppError::BadRequest(format!(
+            "Not enough feedback to tune match strategy '{}': {} users with positive feedback, {} needed",
+            feedback.strategy, baseline.users, min_users
+        )));
+    }
+    let (weights, tuned) = tune_weights(&feedback.rankings, k, Some(baseline_weights));
+    Ok((baseline, weights, tuned))
+}
+
+/// Days, k and minimum users of a report, with defaults applied.
+fn report_window(params: &MatchFeedbackReportParams) -> AppResult<(i32, usize, usize)> {
+    let days = params.days.unwrap_or(DEFAULT_DAYS);
+    if !(1..=MAX_DAYS).contains(&days) {
+        return Err(AppError::BadRequest(format!(
+            "days must be between 1 and {}",
+            MAX_DAYS
+        )));
+    }
+    let k = params.k.unwrap_or(DEFAULT_K);
+    if !(1..=MAX_K).contains(&k) {
+        return Err(AppError::BadRequest(format!("k must be between 1 and {}", MAX_K)));
+    }
+    let min_users = params.min_users.unwrap_or(DEFAULT_MIN_USERS);
+    if min_users == 0 {
+        return Err(AppError::BadRequest("min_users must be positive".to_string()));
+    }
+    Ok((days, k, min_users))
+}
+
+/// Fails unless the user is an admin.
+async fn require_admin(app_state: &AppState, user_id: Uuid) -> AppResult<()> {
+    let membership = load_membership(&app_state.db_pool, user_id).await?;
+    if membership.role != UserRole::Admin {
+        return Err(AppError::Forbidden);
+    }
+    Ok(())
+}
@@ -0,0 +1,173 @@
+//! Match strategy handlers.
+//!
+//! Reports the configured match strategies (see [`crate::match_strategy`])
+//! and compares the arms of a match experiment by application outcomes.
+
+use super::companies::load_membership;
+use super::types::{
+    ExperimentArmReport, MatchExperimentReport, MatchStrategiesResponse, MatchStrategyInfo,
+    MatchStrategyReportParams,
+};
+use crate::AppState;
+use crate::auth::AuthUser;
+use crate::errors::{AppError, AppResult};
+use crate::models::UserRole;
+use axum::{
+    Json,
+    extract::{Query, State},
+};
+use std::collections::HashMap;
+use tracing::info;
+
+/// Lists match strategies and reports experiment outcomes (admin only).
+///
+/// For each strategy of the experiment, reports how many users were
+/// assigned to it, how many of them applied and how their applications
+/// went. Applications are counted under the strategy the user was assigned
+/// when applying; requests that overrode the strategy are not counted.
+///
+/// # Query Parameters
+///
+/// - `experiment` - Experiment to report on (default: the running one);
+///   past experiments are reported from stored assignments
+///
+/// # Returns
+///
+/// The default strategy, all configured strategies with their weights, and
+/// the experiment report (None when no experiment is running or requested).
+///
+/// # Errors
+///
+/// Returns an error if:
+/// - User is not authenticated
+/// - User is not an admin
+/// - Database operation fails
+pub async fn get_match_strategies(
+    auth_user: AuthUser,
+    State(app_state): State<AppState>,
+    Query(params): Query<MatchStrategyReportParams>,
+) -> AppResult<Json<MatchStrategiesResponse>> {
+    info!("Reporting match strategies: user_id={}", auth_user.user_id);
+
+    let membership = load_membership(&app_state.db_pool, auth_user.user_id).await?;
+    if membership.role != UserRole::Admin {
+        return Err(AppError::Forbidden);
+    }
+
+    let registry = &app_state.match_strategies;
+    let default_strategy = registry.default_strategy().name().to_string();
+    let strategies = registry
+        .strategies()
+        .iter()
+        .map(|strategy| MatchStrategyInfo {
+            name: strategy.name().to_string(),
+            kind: strategy.kind().to_string(),
+            weights: strategy.weights(),
+            settings: strategy.settings(),
+            is_default: strategy.name() == default_strategy,
+        })
+        .collect();
+
+    let running = registry.experiment();
+    let name = params
+        .experiment
+        .or_else(|| running.map(|experiment| experiment.name.clone()));
+    let experiment = match name {
+        Some(name) => {
+            let arms = running
+                .filter(|experiment| experiment.name == name)
+                .map(|experiment| experiment.arms.as_slice())
+                .unwrap_or_default();
+
+            let users = sqlx::query!(
+                r#"
+                SELECT strategy, COUNT(*) as "users!"
+                FROM match_assignments
+                WHERE experiment = $1
+                GROUP BY strategy
+                "#,
+                name
+            )
+            .fetch_all(&app_state.db_pool)
+            .await?;
+            let applications = sqlx::query!(
+                r#"
+                SELECT
+                    match_strategy as "strategy!", status as "status?",
+                    COUNT(*) as "applications!", COUNT(DISTINCT user_id) as "applicants!"
+                FROM application_tracking
+                WHERE match_experiment = $1 AND match_strategy IS NOT NULL
+                GROUP BY GROUPING SETS ((match_strategy), (match_strategy, status))
+                "#,
+                name
+            )
+            .fetch_all(&app_state.db_pool)
+            .await?;
+
+            // Running arms in configured order, then strategies seen only in the data
+            let mut reports: Vec<ExperimentArmReport> = arms
+                .iter()
+                .map(|arm| empty_arm(&arm.strategy, Some(arm.percent)))
+                .collect();
+            let seen = users
+                .iter()
+                .map(|row| &row.strategy)
+                .chain(applications.iter().map(|row| &row.strategy));
+            for strategy in seen {
+                if !reports.iter().any(|report| &report.strategy == strategy) {
+                    reports.push(empty_arm(strategy, None));
+                }
+            }
+
+            for report in &mut reports {
+                report.users = users
+                    .iter()
+                    .find(|row| row.strategy == report.strategy)
+                    .map_or(0, |row| row.users);
+                for row in applications.iter().filter(|row| row.strategy == report.strategy) {
+                    match &row.status {
+                        Some(status) => {
+                            report.statuses.insert(status.clone(), row.applications);
+                        }
+                        None => {
+                            report.applications = row.applications;
+                            report.applicants = row.applicants;
+                        }
+                    }
+                }
+                if report.users > 0 {
+                    report.application_rate = report.applicants as f64 / report.users as f64;
+                    report.applications_per_user =
+                        report.applications as f64 / report.users as f64;
+                }
+            }
+
+            Some(MatchExperimentReport {
+                running: running.is_some_and(|experiment| experiment.name == name),
+                name,
+                arms: reports,
+            })
+        }
+        None => None,
+    };
+
+    Ok(Json(MatchStrategiesResponse {
+        default_strategy,
+        strategies,
+        experiment,
+    }))
+}
+
+/// An arm report with no users or applications yet.
+fn empty_arm(strategy: &str, percent: Option<u32>) -> ExperimentArmReport {
+    ExperimentArmReport {
+        strategy: strategy.to_string(),
+        percent,
+        users: 0,
+        applicants: 0,
+        applications: 0,
+        application_rate: 0.0,
+        applications_per_user: 0.0,
+        statuses: HashMap::new(),
+    }
+}
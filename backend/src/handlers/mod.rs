@@ -4,20 +4,49 @@
 //! - `auth` - Authentication and registration
 //! - `profile` - User profile management
 //! - `jobs` - Job recommendations
+//! - `external_jobs` - Ingested external job postings
+//! - `job_export` - JSON-LD and feeds of open jobs for partner sites
 //! - `learning` - Learning resources and skill gap analysis
+//! - `career_tracks` - Career track taxonomy (admin-editable)
+//! - `locations` - Bangladesh location gazetteer
+//! - `match_strategies` - Match strategies and experiment outcomes (admin)
+//! - `match_feedback` - Feedback on recommendations and offline evaluation (admin reports)
 //! - `applications` - Application tracking
+//! - `saved_jobs` - Saved jobs (bookmarks)
+//! - `saved_searches` - Saved searches with job alerts
+//! - `similar_jobs` - "More like this" for jobs and external postings
+//! - `skill_trends` - Skill demand trends
+//! - `skills` - Skill taxonomy autocomplete
+//! - `notifications` - In-app notifications
+//! - `companies` - Company profiles, employer membership and invitations
+//! - `employer_jobs` - Job posting management for employers
+//! - `candidates` - Candidate discovery for employers and contact requests
 //! - `progress` - Learning progress tracking
 //! - `types` - Shared request/response types
 
 mod ai;
 mod applications;
 mod auth;
+mod candidates;
+mod career_tracks;
+mod companies;
+mod employer_jobs;
 mod external_jobs;
+mod job_export;
 mod jobs;
 mod learning;
+mod locations;
+mod match_feedback;
+mod match_strategies;
+mod notifications;
 mod oauth;
 mod profile;
 mod progress;
+mod saved_jobs;
+mod saved_searches;
+mod similar_jobs;
+mod skill_trends;
+mod skills;
 mod types;
 
 #[allow(unused_imports)]
@@ -27,7 +56,8 @@ use crate::AppState;
 use crate::errors::AppResult;
 use axum::{
     Router,
-    routing::{get, post, put},
+    extract::DefaultBodyLimit,
+    routing::{delete, get, post, put},
 };
 use tower_http::cors::{Any, CorsLayer};
 
@@ -44,10 +74,12 @@ pub fn create_router(app_state: AppState) -> Router {
     use tracing::info;
 
     info!("Setting up API routes:");
-    info!("  ✓ Public routes: /, /api/register, /api/login");
+    info!("  ✓ Public routes: /, /api/register, /api/login, /api/jobs/search, /api/locations, /api/career-tracks, /api/skills/autocomplete, /api/skills/trends, /api/feeds/jobs");
     info!("  ✓ OAuth routes: /api/auth/google, /api/auth/github");
-    info!("  ✓ Protected routes: profile (+ CV upload), jobs, learning, applications, progress");
-    info!("  ✓ External jobs: ReliefWeb NGO, govt portals, local boards");
+    info!("  ✓ Protected routes: profile (+ CV upload), jobs (+ similar jobs, match explanations, match breakdowns, match feedback), learning, applications, saved jobs, saved searches, notifications, contact requests, progress");
+    info!("  ✓ External jobs: ReliefWeb NGO, govt portals, local boards (deduplicated)");
+    info!("  ✓ Employer routes: /api/companies (+ invitations), /api/employer/jobs (+ bulk import, candidates)");
+    info!("  ✓ Admin routes: /api/career-tracks (create, edit), /api/match-strategies, /api/match-feedback");
     info!("  ✓ AI routes: /api/ai/assist, /api/roadmaps");
 
     Router::new()
@@ -71,11 +103,109 @@ pub fn create_router(app_state: AppState) -> Router {
             "/api/jobs/recommendations",
             get(jobs::get_job_recommendations),
         )
+        .route("/api/jobs/{id}/similar", get(similar_jobs::get_similar_jobs))
+        .route(
+            "/api/jobs/{id}/match-explanation",
+            get(jobs::get_match_explanation),
+        )
+        .route(
+            "/api/jobs/{id}/match-breakdown",
+            get(jobs::get_match_breakdown),
+        )
+        .route(
+            "/api/jobs/{id}/feedback",
+            post(match_feedback::record_match_feedback),
+        )
+        // Public routes - Job Search
+        .route("/api/jobs/search", get(jobs::search_jobs))
+        .route("/api/locations", get(locations::get_locations))
+        .route("/api/career-tracks", get(career_tracks::get_career_tracks))
+        .route("/api/jobs/{id}/jsonld", get(job_export::get_job_posting))
+        .route("/api/feeds/jobs", get(job_export::get_job_feed))
+        .route("/api/skills/autocomplete", get(skills::autocomplete_skills))
+        .route("/api/skills/trends", get(skill_trends::get_skill_trends))
+        .route("/api/skills/trends/movers", get(skill_trends::get_skill_movers))
         // Protected routes - External Jobs Integration
         .route("/api/jobs/external", get(external_jobs::get_external_jobs))
         .route("/api/jobs/ngo", get(external_jobs::get_ngo_jobs))
         .route("/api/jobs/govt", get(external_jobs::get_govt_jobs))
         .route("/api/jobs/local", get(external_jobs::get_local_jobs))
+        .route(
+            "/api/jobs/external/sources",
+            get(external_jobs::get_source_status),
+        )
+        .route(
+            "/api/jobs/external/merges",
+            get(external_jobs::get_merge_decisions),
+        )
+        .route(
+            "/api/jobs/external/{id}/similar",
+            get(similar_jobs::get_similar_external_jobs),
+        )
+        // Public routes - Company pages
+        .route("/api/companies/{id}", get(companies::get_company_page))
+        // Protected routes - Companies & Employers
+        .route("/api/companies", post(companies::create_company))
+        .route("/api/companies/{id}", put(companies::update_company))
+        .route(
+            "/api/companies/{id}/members",
+            post(companies::add_company_member),
+        )
+        .route(
+            "/api/companies/{id}/verification",
+            put(companies::update_company_verification),
+        )
+        .route(
+            "/api/company-invitations",
+            get(companies::get_company_invitations),
+        )
+        .route(
+            "/api/company-invitations/{id}",
+            put(companies::respond_to_company_invitation),
+        )
+        // Protected routes - Career Tracks (admin only)
+        .route("/api/career-tracks", post(career_tracks::create_career_track))
+        .route(
+            "/api/career-tracks/{slug}",
+            put(career_tracks::update_career_track),
+        )
+        // Protected routes - Match Strategies (admin only)
+        .route(
+            "/api/match-strategies",
+            get(match_strategies::get_match_strategies),
+        )
+        // Protected routes - Match Feedback (admin only)
+        .route(
+            "/api/match-feedback/report",
+            get(match_feedback::get_match_feedback_report),
+        )
+        .route(
+            "/api/match-feedback/tuned-strategy",
+            get(match_feedback::get_tuned_strategy),
+        )
+        .route("/api/employer/jobs", post(employer_jobs::create_job))
+        .route("/api/employer/jobs", get(employer_jobs::get_company_jobs))
+        .route("/api/employer/jobs/{id}", put(employer_jobs::update_job))
+        .route("/api/employer/jobs/{id}", delete(employer_jobs::delete_job))
+        .route(
+            "/api/employer/jobs/import",
+            post(employer_jobs::import_jobs)
+                .layer(DefaultBodyLimit::max(employer_jobs::MAX_IMPORT_FILE_SIZE + 64 * 1024)),
+        )
+        // Protected routes - Candidate Discovery
+        .route(
+            "/api/employer/jobs/{id}/candidates",
+            get(candidates::get_job_candidates),
+        )
+        .route(
+            "/api/employer/jobs/{id}/candidates/{candidate_id}/contact",
+            post(candidates::request_candidate_contact),
+        )
+        .route("/api/contact-requests", get(candidates::get_contact_requests))
+        .route(
+            "/api/contact-requests/{id}",
+            put(candidates::respond_to_contact_request),
+        )
         // Protected routes - Learning Resources
         .route(
             "/api/learning/recommendations",
@@ -93,6 +223,34 @@ pub fn create_router(app_state: AppState) -> Router {
             "/api/applications/{id}",
             put(applications::update_application),
         )
+        // Protected routes - Saved Jobs
+        .route("/api/saved-jobs", post(saved_jobs::save_job))
+        .route("/api/saved-jobs", get(saved_jobs::get_saved_jobs))
+        .route("/api/saved-jobs/{id}", put(saved_jobs::update_saved_job))
+        .route("/api/saved-jobs/{id}", delete(saved_jobs::delete_saved_job))
+        // Protected routes - Saved Searches & Notifications
+        .route(
+            "/api/saved-searches",
+            post(saved_searches::create_saved_search),
+        )
+        .route("/api/saved-searches", get(saved_searches::get_saved_searches))
+        .route(
+            "/api/saved-searches/{id}",
+            put(saved_searches::update_saved_search),
+        )
+        .route(
+            "/api/saved-searches/{id}",
+            delete(saved_searches::delete_saved_search),
+        )
+        .route("/api/notifications", get(notifications::get_notifications))
+        .route(
+            "/api/notifications/read-all",
+            put(notifications::mark_all_notifications_read),
+        )
+        .route(
+            "/api/notifications/{id}/read",
+            put(notifications::mark_notification_read),
+        )
         // Protected routes - Progress Tracking
         .route(
             "/api/progress/resource/{id}/start",
@@ -111,7 +269,7 @@ pub fn create_router(app_state: AppState) -> Router {
         .route("/api/ai/roadmaps/{id}", get(ai::get_roadmap_by_id))
         .route(
             "/api/ai/roadmaps/{id}",
-            axum::routing::delete(ai::delete_roadmap),
+            delete(ai::delete_roadmap),
         )
         .route(
             "/api/ai/roadmaps/{id}/progress",
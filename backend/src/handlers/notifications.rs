@@ -0,0 +1,130 @@
+//! In-app notification handlers.
+//!
+//! Notifications are written by background tasks (e.g. job alerts for saved
+//! searches) and read by the user here.
+
+use super::types::NotificationQueryParams;
+use crate::AppState;
+use crate::auth::AuthUser;
+use crate::errors::{AppError, AppResult};
+use crate::models::Notification;
+use axum::{
+    Json,
+    extract::{Path, Query, State},
+};
+use tracing::{debug, info};
+
+/// Lists the authenticated user's notifications, newest first.
+///
+/// # Query Parameters
+///
+/// - `unread_only` - Only return unread notifications (default: false)
+/// - `limit` - Maximum results to return (default: 50, max: 200)
+///
+/// # Errors
+///
+/// Returns an error if:
+/// - User is not authenticated
+/// - Database operation fails
+pub async fn get_notifications(
+    auth_user: AuthUser,
+    State(app_state): State<AppState>,
+    Query(params): Query<NotificationQueryParams>,
+) -> AppResult<Json<Vec<Notification>>> {
+    info!("Fetching notifications for user: {}", auth_user.user_id);
+
+    let limit = params.limit.unwrap_or(50).clamp(1, 200);
+
+    let notifications = sqlx::query_as!(
+        Notification,
+        r#"
+        SELECT
+            id, user_id, title, message, type as notification_type,
+            is_read as "is_read!", created_at as "created_at!",
+            job_id, saved_search_id
+        FROM notifications
+        WHERE user_id = $1
+          AND (NOT $2 OR NOT is_read)
+        ORDER BY created_at DESC, id DESC
+        LIMIT $3
+        "#,
+        auth_user.user_id,
+        params.unread_only.unwrap_or(false),
+        limit
+    )
+    .fetch_all(&app_state.db_pool)
+    .await?;
+
+    debug!(
+        "Retrieved {} notifications for user: {}",
+        notifications.len(),
+        auth_user.user_id
+    );
+
+    Ok(Json(notifications))
+}
+
+/// Marks a notification as read.
+///
+/// # Path Parameters
+///
+/// - `notification_id` - ID of the notification
+///
+/// # Errors
+///
+/// Returns an error if:
+/// - User is not authenticated
+/// - Notification doesn't exist or doesn't belong to user
+/// - Database operation fails
+pub async fn mark_notification_read(
+    auth_user: AuthUser,
+    State(app_state): State<AppState>,
+    Path(notification_id): Path<i32>,
+) -> AppResult<Json<serde_json::Value>> {
+    debug!(
+        "Marking notification {} read for user: {}",
+        notification_id, auth_user.user_id
+    );
+
+    let result = sqlx::query!(
+        "UPDATE notifications SET is_read = TRUE WHERE id = $1 AND user_id = $2",
+        notification_id,
+        auth_user.user_id
+    )
+    .execute(&app_state.db_pool)
+    .await?;
+
+    if result.rows_affected() == 0 {
+        return Err(AppError::NotFound);
+    }
+
+    Ok(Json(serde_json::json!({
+        "message": "Notification marked as read"
+    })))
+}
+
+/// Marks all of the user's notifications as read.
+///
+/// # Errors
+///
+/// Returns an error if:
+/// - User is not authenticated
+/// - Database operation fails
+pub async fn mark_all_notifications_read(
+    auth_user: AuthUser,
+    State(app_state): State<AppState>,
+) -> AppResult<Json<serde_json::Value>> {
+    info!("Marking all notifications read for user: {}", auth_user.user_id);
+
+    let result = sqlx::query!(
+        "UPDATE notifications SET is_read = TRUE WHERE user_id = $1 AND NOT is_read",
+        auth_user.user_id
+    )
+    .execute(&app_state.db_pool)
+    .await?;
+
+    Ok(Json(serde_json::json!({
+        "message": "Notifications marked as read",
+        "updated": result.rows_affected()
+    })))
+}
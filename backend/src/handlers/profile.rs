@@ -3,8 +3,11 @@
 use super::types::{UpdateProfilePayload, UserProfile};
 use crate::AppState;
 use crate::auth::AuthUser;
+use crate::career_tracks::load_taxonomy;
 use crate::errors::{AppError, AppResult};
-use crate::models::{CareerTrack, ExperienceLevel, User};
+use crate::locations::canonical_preferences;
+use crate::models::{ExperienceLevel, User, UserRole};
+use crate::skills::load_skill_taxonomy;
 use axum::{
     Json,
     extract::{Multipart, State},
@@ -12,6 +15,7 @@ use axum::{
     response::{IntoResponse, Response},
 };
 use printpdf::*;
+use sqlx::types::Json as SqlJson;
 use std::io::Write;
 use tempfile::NamedTempFile;
 use tracing::{debug, error, info, warn};
@@ -39,10 +43,11 @@ pub async fn get_profile(
         r#"
         SELECT 
             id, full_name, email, education_level,
-            experience_level as "experience_level: ExperienceLevel",
-            preferred_track as "preferred_track: CareerTrack",
+            experience_level as "experience_level: ExperienceLevel", experience_years,
+            preferred_track,
             profile_completed as "profile_completed!",
-            skills, projects, target_roles, raw_cv_text, password_hash
+            skills, skill_proficiency as "skill_proficiency: _", projects, target_roles, raw_cv_text, password_hash,
+            role as "role: UserRole", company_id, preferred_locations, discoverable
         FROM users 
         WHERE id = $1
         "#,
@@ -67,10 +72,16 @@ pub async fn get_profile(
         profile_completed: user.profile_completed,
         education_level: user.education_level,
         experience_level: user.experience_level,
+        experience_years: user.experience_years,
         preferred_track: user.preferred_track,
         skills: user.skills,
+        skill_proficiency: user.skill_proficiency.0,
         projects: user.projects,
         target_roles: user.target_roles,
+        role: user.role,
+        company_id: user.company_id,
+        preferred_locations: user.preferred_locations,
+        discoverable: user.discoverable,
     }))
 }
 
@@ -79,9 +90,11 @@ pub async fn get_profile(
 /// This is the second step in the user onboarding flow. After registering with
 /// just name, email, and password, users call this endpoint to provide:
 /// - Education level
-/// - Experience level (required)
+/// - Experience level (required) and years of experience
 /// - Preferred career track (required)
-/// - Skills, projects, and target roles (optional)
+/// - Skills with optional proficiencies, projects, and target roles (optional)
+///
+/// Skills are saved under their canonical names ("JS" becomes "JavaScript").
 ///
 /// Sets `profile_completed = TRUE` to indicate the user has finished onboarding.
 ///
@@ -90,6 +103,8 @@ pub async fn get_profile(
 /// Returns an error if:
 /// - User is not authenticated
 /// - Validation fails (missing required fields)
+/// - `preferred_track` is not an active career track
+/// - `skill_proficiency` names a skill not in `skills`
 /// - Database operation fails
 pub async fn complete_profile(
     auth_user: AuthUser,
@@ -106,9 +121,17 @@ pub async fn complete_profile(
         e
     })?;
 
+    let tracks = load_taxonomy(&app_state.db_pool).await?;
+    let preferred_track = &tracks.resolve(&payload.preferred_track)?.slug;
+    let skill_taxonomy = load_skill_taxonomy(&app_state.db_pool).await?;
+    let skills = skill_taxonomy.normalize(&payload.skills.unwrap_or_default());
+    let skill_proficiency = payload.skill_proficiency.unwrap_or_default();
+    skill_taxonomy.require_listed(&skill_proficiency, &skills)?;
+    let skill_proficiency = skill_taxonomy.restrict(&skill_proficiency, &skills);
+
     debug!(
-        "Profile data: experience_level={:?}, preferred_track={:?}",
-        payload.experience_level, payload.preferred_track
+        "Profile data: experience_level={:?}, preferred_track={}",
+        payload.experience_level, preferred_track
     );
 
     sqlx::query!(
@@ -120,17 +143,21 @@ pub async fn complete_profile(
             skills = $4,
             projects = $5,
             target_roles = $6,
+            experience_years = $8,
+            skill_proficiency = $9,
             profile_completed = TRUE,
             updated_at = CURRENT_TIMESTAMP
         WHERE id = $7
         "#,
         payload.education_level,
         payload.experience_level as _,
-        payload.preferred_track as _,
-        &payload.skills.unwrap_or_default(),
+        preferred_track,
+        &skills,
         &payload.projects.unwrap_or_default(),
         &payload.target_roles.unwrap_or_default(),
-        auth_user.user_id
+        auth_user.user_id,
+        payload.experience_years,
+        SqlJson(&skill_proficiency) as _
     )
     .execute(&app_state.db_pool)
     .await
@@ -157,12 +184,19 @@ pub async fn complete_profile(
 /// Allows users to modify their profile information after completing onboarding.
 /// Updates only the fields provided in the payload. Omitted fields remain unchanged.
 /// Can update experience level, preferred track, and all other profile fields.
+/// Skills are saved under their canonical names; changing them drops the
+/// proficiencies of removed skills. Setting `discoverable` lets employers
+/// find the user as an anonymized candidate for their jobs (see
+/// `GET /api/employer/jobs/{id}/candidates`).
 ///
 /// # Errors
 ///
 /// Returns an error if:
 /// - User is not authenticated
 /// - Validation fails
+/// - `preferred_track` is not an active career track
+/// - A preferred location is not in the gazetteer
+/// - `skill_proficiency` names a skill not on the (updated) profile
 /// - Database operation fails
 pub async fn update_profile(
     auth_user: AuthUser,
@@ -179,6 +213,33 @@ pub async fn update_profile(
         e
     })?;
 
+    // Checked up front so an unknown track, location or skill leaves the profile unchanged
+    let preferred_track = match payload.preferred_track.as_deref() {
+        Some(track) => Some(load_taxonomy(&app_state.db_pool).await?.resolve(track)?.slug.clone()),
+        None => None,
+    };
+    let preferred_locations = payload
+        .preferred_locations
+        .as_deref()
+        .map(canonical_preferences)
+        .transpose()?;
+    let skill_taxonomy = load_skill_taxonomy(&app_state.db_pool).await?;
+    let skills = payload.skills.map(|skills| skill_taxonomy.normalize(&skills));
+    // Proficiencies must be for the new skills, or the current ones
+    let skill_proficiency = match payload.skill_proficiency {
+        Some(skill_proficiency) => {
+            let listed = match &skills {
+                Some(skills) => skills.clone(),
+                None => sqlx::query_scalar!("SELECT skills FROM users WHERE id = $1", auth_user.user_id)
+                    .fetch_one(&app_state.db_pool)
+                    .await?,
+            };
+            skill_taxonomy.require_listed(&skill_proficiency, &listed)?;
+            Some(skill_taxonomy.restrict(&skill_proficiency, &listed))
+        }
+        None => None,
+    };
+
     let mut updated_fields = Vec::new();
 
     // Simple approach: update each field if provided
@@ -203,7 +264,11 @@ pub async fn update_profile(
         .execute(&app_state.db_pool)
         .await?;
     }
-    if let Some(experience_level) = payload.experience_level {
+    // Years without a level move the user to the level those years fall in
+    let experience_level = payload
+        .experience_level
+        .or(payload.experience_years.map(ExperienceLevel::from_years));
+    if let Some(experience_level) = experience_level {
         updated_fields.push("experience_level");
         sqlx::query!(
             "UPDATE users SET experience_level = $1, updated_at = CURRENT_TIMESTAMP WHERE id = $2",
@@ -213,26 +278,58 @@ pub async fn update_profile(
         .execute(&app_state.db_pool)
         .await?;
     }
-    if let Some(preferred_track) = payload.preferred_track {
+    if let Some(experience_years) = payload.experience_years {
+        updated_fields.push("experience_years");
+        sqlx::query!(
+            "UPDATE users SET experience_years = $1, updated_at = CURRENT_TIMESTAMP WHERE id = $2",
+            experience_years,
+            auth_user.user_id
+        )
+        .execute(&app_state.db_pool)
+        .await?;
+    }
+    if let Some(preferred_track) = preferred_track {
         updated_fields.push("preferred_track");
         sqlx::query!(
             "UPDATE users SET preferred_track = $1, updated_at = CURRENT_TIMESTAMP WHERE id = $2",
-            preferred_track as _,
+            preferred_track,
             auth_user.user_id
         )
         .execute(&app_state.db_pool)
         .await?;
     }
-    if let Some(skills) = payload.skills {
+    if let Some(skills) = skills {
         updated_fields.push("skills");
+        // Proficiencies are keyed by canonical name, like the skills themselves
         sqlx::query!(
-            "UPDATE users SET skills = $1, updated_at = CURRENT_TIMESTAMP WHERE id = $2",
+            r#"
+            UPDATE users
+            SET skills = $1,
+                skill_proficiency = COALESCE(
+                    (SELECT jsonb_object_agg(key, value)
+                     FROM jsonb_each(skill_proficiency)
+                     WHERE key = ANY($1)),
+                    '{}'
+                ),
+                updated_at = CURRENT_TIMESTAMP
+            WHERE id = $2
+            "#,
             &skills,
             auth_user.user_id
         )
         .execute(&app_state.db_pool)
         .await?;
     }
+    if let Some(skill_proficiency) = skill_proficiency {
+        updated_fields.push("skill_proficiency");
+        sqlx::query!(
+            "UPDATE users SET skill_proficiency = $1, updated_at = CURRENT_TIMESTAMP WHERE id = $2",
+            SqlJson(&skill_proficiency) as _,
+            auth_user.user_id
+        )
+        .execute(&app_state.db_pool)
+        .await?;
+    }
     if let Some(projects) = payload.projects {
         updated_fields.push("projects");
         sqlx::query!(
@@ -253,6 +350,26 @@ pub async fn update_profile(
         .execute(&app_state.db_pool)
         .await?;
     }
+    if let Some(preferred_locations) = preferred_locations {
+        updated_fields.push("preferred_locations");
+        sqlx::query!(
+            "UPDATE users SET preferred_locations = $1, updated_at = CURRENT_TIMESTAMP WHERE id = $2",
+            &preferred_locations,
+            auth_user.user_id
+        )
+        .execute(&app_state.db_pool)
+        .await?;
+    }
+    if let Some(discoverable) = payload.discoverable {
+        updated_fields.push("discoverable");
+        sqlx::query!(
+            "UPDATE users SET discoverable = $1, updated_at = CURRENT_TIMESTAMP WHERE id = $2",
+            discoverable,
+            auth_user.user_id
+        )
+        .execute(&app_state.db_pool)
+        .await?;
+    }
     if let Some(raw_cv_text) = payload.raw_cv_text {
         updated_fields.push("raw_cv_text");
         sqlx::query!(
@@ -486,10 +603,11 @@ pub async fn generate_cv(
         r#"
         SELECT 
             id, full_name, email, education_level,
-            experience_level as "experience_level: ExperienceLevel",
-            preferred_track as "preferred_track: CareerTrack",
+            experience_level as "experience_level: ExperienceLevel", experience_years,
+            preferred_track,
             profile_completed as "profile_completed!",
-            skills, projects, target_roles, raw_cv_text, password_hash
+            skills, skill_proficiency as "skill_proficiency: _", projects, target_roles, raw_cv_text, password_hash,
+            role as "role: UserRole", company_id, preferred_locations, discoverable
         FROM users 
         WHERE id = $1
         "#,
@@ -501,12 +619,13 @@ pub async fn generate_cv(
         error!("User not found for CV generation: {}", auth_user.user_id);
         AppError::NotFound
     })?;
+    let tracks = load_taxonomy(&app_state.db_pool).await?;
 
     debug!("Generating CV for: {}", user.full_name);
 
     // Create PDF document
     let (doc, page1, layer1) = PdfDocument::new(
-        &format!("{}_CV", user.full_name.replace(' ', "_")),
+        format!("{}_CV", user.full_name.replace(' ', "_")),
         Mm(210.0), // A4 width
         Mm(297.0), // A4 height
         "Layer 1",
@@ -565,6 +684,8 @@ pub async fn generate_cv(
                 Some(ExperienceLevel::Fresher) => "Fresher (0-1 years)",
                 Some(ExperienceLevel::Junior) => "Junior (1-3 years)",
                 Some(ExperienceLevel::Mid) => "Mid-level (3-5 years)",
+                Some(ExperienceLevel::Senior) => "Senior (5-8 years)",
+                Some(ExperienceLevel::Lead) => "Lead (8+ years)",
                 None => "Not specified",
             }
         ),
@@ -572,19 +693,23 @@ pub async fn generate_cv(
         11.0,
         &mut y_position,
     );
+    if let Some(years) = user.experience_years {
+        add_text(
+            &format!("Years of Experience: {}", years),
+            &font,
+            11.0,
+            &mut y_position,
+        );
+    }
 
     // Career Track
     add_section_title("Career Track", &mut y_position);
     add_text(
         &format!(
             "Preferred Track: {}",
-            match &user.preferred_track {
-                Some(CareerTrack::WebDevelopment) => "Web Development",
-                Some(CareerTrack::Data) => "Data Science & Analytics",
-                Some(CareerTrack::Design) => "UI/UX Design",
-                Some(CareerTrack::Marketing) => "Digital Marketing",
-                None => "Not specified",
-            }
+            user.preferred_track
+                .as_deref()
+                .map_or("Not specified", |track| tracks.cv_label(track))
         ),
         &font,
         11.0,
@@ -0,0 +1,331 @@
+//! Saved job (bookmark) handlers.
+//!
+//! Users can save internal jobs and external listings for later, label them
+//! with tags and keep personal notes, without creating an application record.
+//! External jobs are not stored in the `jobs` table, so a snapshot of the
+//! listing is kept with the bookmark.
+
+use super::types::{SaveJobPayload, SavedJobDetails, SavedJobQueryParams, UpdateSavedJobPayload};
+use crate::AppState;
+use crate::auth::AuthUser;
+use crate::errors::{AppError, AppResult};
+use crate::match_feedback::record_outcome;
+use crate::models::{
+    ExperienceLevel, Job, JobStatus, JobType, MatchFeedbackEvent, SalaryCurrency, SalaryPeriod,
+    SavedJob, WorkArrangement,
+};
+use axum::{
+    Json,
+    extract::{Path, Query, State},
+};
+use sqlx::PgPool;
+use std::collections::{HashMap, HashSet};
+use tracing::{debug, info};
+use uuid::Uuid;
+use validator::Validate;
+
+/// Maximum length of a single tag.
+const MAX_TAG_LENGTH: usize = 50;
+
+/// Trims, lowercases and de-duplicates tags, preserving their order.
+fn normalize_tags(tags: Vec<String>) -> AppResult<Vec<String>> {
+    let mut seen = HashSet::new();
+    let mut normalized = Vec::new();
+
+    for tag in tags {
+        let tag = tag.trim().to_lowercase();
+        if tag.is_empty() {
+            continue;
+        }
+        if tag.chars().count() > MAX_TAG_LENGTH {
+            return Err(AppError::ValidationError(format!(
+                "Tags cannot exceed {} characters",
+                MAX_TAG_LENGTH
+            )));
+        }
+        if seen.insert(tag.clone()) {
+            normalized.push(tag);
+        }
+    }
+
+    Ok(normalized)
+}
+
+/// Returns the IDs of internal jobs the user has saved.
+///
+/// Used to flag saved jobs in recommendation responses.
+pub(super) async fn saved_job_ids(pool: &PgPool, user_id: Uuid) -> AppResult<HashSet<i32>> {
+    let ids = sqlx::query_scalar!(
+        "SELECT job_id FROM saved_jobs WHERE user_id = $1 AND job_id IS NOT NULL",
+        user_id
+    )
+    .fetch_all(pool)
+    .await?;
+
+    Ok(ids.into_iter().flatten().collect())
+}
+
+/// Saves an internal or external job for later.
+///
+/// Saving an internal job counts as feedback on its recommendation (see
+/// [`crate::match_feedback`]).
+///
+/// # Errors
+///
+/// Returns an error if:
+/// - User is not authenticated
+/// - Neither or both of `job_id` and `external_job` are provided
+/// - Validation fails
+/// - Internal job doesn't exist
+/// - Job is already saved (409)
+/// - Database operation fails
+pub async fn save_job(
+    auth_user: AuthUser,
+    State(app_state): State<AppState>,
+    Json(payload): Json<SaveJobPayload>,
+) -> AppResult<Json<SavedJob>> {
+    info!("Saving job for user: {}", auth_user.user_id);
+
+    payload.validate()?;
+    let tags = normalize_tags(payload.tags.unwrap_or_default())?;
+
+    let (job_id, external_job_id, external_job) = match (payload.job_id, payload.external_job) {
+        (Some(job_id), None) => {
+            let exists = sqlx::query_scalar!(
+                r#"SELECT EXISTS(SELECT 1 FROM jobs WHERE id = $1 AND status <> 'draft') as "exists!""#,
+                job_id
+            )
+            .fetch_one(&app_state.db_pool)
+            .await?;
+            if !exists {
+                return Err(AppError::NotFound);
+            }
+            (Some(job_id), None, None)
+        }
+        (None, Some(external)) => {
+            if external.id.trim().is_empty() {
+                return Err(AppError::ValidationError(
+                    "External job ID is required".to_string(),
+                ));
+            }
+            let snapshot = serde_json::to_value(&external)
+                .map_err(|_| AppError::InternalServerError)?;
+            (None, Some(external.id), Some(snapshot))
+        }
+        _ => {
+            return Err(AppError::BadRequest(
+                "Provide exactly one of job_id or external_job".to_string(),
+            ));
+        }
+    };
+
+    let saved_job = sqlx::query_as!(
+        SavedJob,
+        r#"
+        INSERT INTO saved_jobs (user_id, job_id, external_job_id, external_job, tags, notes)
+        VALUES ($1, $2, $3, $4, $5, $6)
+        RETURNING id, user_id, job_id, external_job_id, external_job, tags, notes,
+                  created_at, updated_at
+        "#,
+        auth_user.user_id,
+        job_id,
+        external_job_id,
+        external_job,
+        &tags,
+        payload.notes
+    )
+    .fetch_one(&app_state.db_pool)
+    .await?;
+
+    if let Some(job_id) = saved_job.job_id {
+        record_outcome(
+            &app_state.db_pool,
+            auth_user.user_id,
+            job_id,
+            MatchFeedbackEvent::Save,
+            None,
+        )
+        .await;
+    }
+
+    info!(
+        "Job saved: saved_job_id={}, user_id={}",
+        saved_job.id, auth_user.user_id
+    );
+
+    Ok(Json(saved_job))
+}
+
+/// Lists the authenticated user's saved jobs, most recently saved first.
+///
+/// # Query Parameters
+///
+/// - `tag` - Optional filter by tag
+///
+/// # Errors
+///
+/// Returns an error if:
+/// - User is not authenticated
+/// - Database operation fails
+pub async fn get_saved_jobs(
+    auth_user: AuthUser,
+    State(app_state): State<AppState>,
+    Query(params): Query<SavedJobQueryParams>,
+) -> AppResult<Json<Vec<SavedJobDetails>>> {
+    info!("Fetching saved jobs for user: {}", auth_user.user_id);
+
+    let tag = params
+        .tag
+        .map(|t| t.trim().to_lowercase())
+        .filter(|t| !t.is_empty());
+
+    let saved_jobs = sqlx::query_as!(
+        SavedJob,
+        r#"
+        SELECT id, user_id, job_id, external_job_id, external_job, tags, notes,
+               created_at, updated_at
+        FROM saved_jobs
+        WHERE user_id = $1
+          AND ($2::TEXT IS NULL OR $2 = ANY(tags))
+        ORDER BY created_at DESC, id DESC
+        "#,
+        auth_user.user_id,
+        tag
+    )
+    .fetch_all(&app_state.db_pool)
+    .await?;
+
+    let job_ids: Vec<i32> = saved_jobs.iter().filter_map(|s| s.job_id).collect();
+    let mut jobs: HashMap<i32, Job> = sqlx::query_as!(
+        Job,
+        r#"
+        SELECT
+            id, job_title, company, location,
+            work_arrangement as "work_arrangement: WorkArrangement",
+            country, division, district, city, latitude, longitude,
+            job_description, required_skills,
+            preferred_skills, skill_weights as "skill_weights: _",
+            experience_level as "experience_level: ExperienceLevel",
+            experience_years_min, experience_years_max,
+            job_type as "job_type: JobType",
+            salary_min, salary_max, salary_currency as "salary_currency: SalaryCurrency",
+            salary_period as "salary_period: SalaryPeriod", salary_negotiable,
+            salary_min_monthly_bdt, salary_max_monthly_bdt,
+            responsibilities, requirements, benefits, company_id,
+            status as "status: JobStatus", application_deadline, expires_at, created_at
+        FROM jobs
+        WHERE id = ANY($1)
+        "#,
+        &job_ids
+    )
+    .fetch_all(&app_state.db_pool)
+    .await?
+    .into_iter()
+    .map(|job| (job.id, job))
+    .collect();
+
+    let details: Vec<SavedJobDetails> = saved_jobs
+        .into_iter()
+        .map(|saved_job| {
+            let job = saved_job.job_id.and_then(|id| jobs.remove(&id));
+            SavedJobDetails { saved_job, job }
+        })
+        .collect();
+
+    debug!(
+        "Retrieved {} saved jobs for user: {}",
+        details.len(),
+        auth_user.user_id
+    );
+
+    Ok(Json(details))
+}
+
+/// Updates the tags and/or notes of a saved job.
+///
+/// # Path Parameters
+///
+/// - `saved_job_id` - ID of the saved job
+///
+/// # Errors
+///
+/// Returns an error if:
+/// - User is not authenticated
+/// - Validation fails
+/// - Saved job doesn't exist or doesn't belong to user
+/// - Database operation fails
+pub async fn update_saved_job(
+    auth_user: AuthUser,
+    State(app_state): State<AppState>,
+    Path(saved_job_id): Path<i32>,
+    Json(payload): Json<UpdateSavedJobPayload>,
+) -> AppResult<Json<SavedJob>> {
+    info!(
+        "Updating saved job: saved_job_id={}, user_id={}",
+        saved_job_id, auth_user.user_id
+    );
+
+    payload.validate()?;
+    let tags = payload.tags.map(normalize_tags).transpose()?;
+
+    let saved_job = sqlx::query_as!(
+        SavedJob,
+        r#"
+        UPDATE saved_jobs
+        SET tags = COALESCE($1, tags),
+            notes = COALESCE($2, notes),
+            updated_at = CURRENT_TIMESTAMP
+        WHERE id = $3 AND user_id = $4
+        RETURNING id, user_id, job_id, external_job_id, external_job, tags, notes,
+                  created_at, updated_at
+        "#,
+        tags.as_deref(),
+        payload.notes,
+        saved_job_id,
+        auth_user.user_id
+    )
+    .fetch_optional(&app_state.db_pool)
+    .await?
+    .ok_or(AppError::NotFound)?;
+
+    Ok(Json(saved_job))
+}
+
+/// Removes a job from the user's saved jobs.
+///
+/// # Path Parameters
+///
+/// - `saved_job_id` - ID of the saved job
+///
+/// # Errors
+///
+/// Returns an error if:
+/// - User is not authenticated
+/// - Saved job doesn't exist or doesn't belong to user
+/// - Database operation fails
+pub async fn delete_saved_job(
+    auth_user: AuthUser,
+    State(app_state): State<AppState>,
+    Path(saved_job_id): Path<i32>,
+) -> AppResult<Json<serde_json::Value>> {
+    info!(
+        "Removing saved job: saved_job_id={}, user_id={}",
+        saved_job_id, auth_user.user_id
+    );
+
+    let result = sqlx::query!(
+        "DELETE FROM saved_jobs WHERE id = $1 AND user_id = $2",
+        saved_job_id,
+        auth_user.user_id
+    )
+    .execute(&app_state.db_pool)
+    .await?;
+
+    if result.rows_affected() == 0 {
+        return Err(AppError::NotFound);
+    }
+
+    Ok(Json(serde_json::json!({
+        "message": "Saved job removed successfully"
+    })))
+}
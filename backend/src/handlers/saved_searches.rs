@@ -0,0 +1,248 @@
+//! Saved search handlers.
+//!
+//! A saved search stores a job query and filters (or just a minimum profile
+//! match score). The background job alert task checks active searches for new
+//! postings and notifies the owner; see [`crate::job_alerts`].
+
+use super::types::{CreateSavedSearchPayload, UpdateSavedSearchPayload};
+use crate::AppState;
+use crate::auth::AuthUser;
+use crate::errors::{AppError, AppResult};
+use crate::models::{ExperienceLevel, JobType, SavedSearch};
+use axum::{
+    Json,
+    extract::{Path, State},
+};
+use tracing::{debug, info};
+use validator::Validate;
+
+/// Maximum number of saved searches per user.
+const MAX_SAVED_SEARCHES: i64 = 25;
+
+/// Checks that a salary range is well-formed.
+fn validate_salary_range(salary_min: Option<i32>, salary_max: Option<i32>) -> AppResult<()> {
+    if let (Some(min), Some(max)) = (salary_min, salary_max)
+        && min > max
+    {
+        return Err(AppError::ValidationError(
+            "salary_min cannot be greater than salary_max".to_string(),
+        ));
+    }
+    Ok(())
+}
+
+/// Saves a job search and starts alerting on new matching postings.
+///
+/// Only jobs posted after the search is saved raise alerts.
+///
+/// # Errors
+///
+/// Returns an error if:
+/// - User is not authenticated
+/// - Validation fails
+/// - User already has the maximum number of saved searches
+/// - Database operation fails
+pub async fn create_saved_search(
+    auth_user: AuthUser,
+    State(app_state): State<AppState>,
+    Json(payload): Json<CreateSavedSearchPayload>,
+) -> AppResult<Json<SavedSearch>> {
+    info!("Creating saved search '{}' for user: {}", payload.name, auth_user.user_id);
+
+    payload.validate()?;
+    validate_salary_range(payload.salary_min, payload.salary_max)?;
+
+    let count = sqlx::query_scalar!(
+        r#"SELECT COUNT(*) as "count!" FROM saved_searches WHERE user_id = $1"#,
+        auth_user.user_id
+    )
+    .fetch_one(&app_state.db_pool)
+    .await?;
+    if count >= MAX_SAVED_SEARCHES {
+        return Err(AppError::BadRequest(format!(
+            "You can save at most {} searches",
+            MAX_SAVED_SEARCHES
+        )));
+    }
+
+    let search = sqlx::query_as!(
+        SavedSearch,
+        r#"
+        INSERT INTO saved_searches (
+            user_id, name, query, job_type, experience_level, location,
+            salary_min, salary_max, min_match_score, email_digest
+        )
+        VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10)
+        RETURNING
+            id, user_id, name, query,
+            job_type as "job_type: JobType",
+            experience_level as "experience_level: ExperienceLevel",
+            location, salary_min, salary_max, min_match_score,
+            email_digest, is_active, last_checked_at, created_at, updated_at
+        "#,
+        auth_user.user_id,
+        payload.name,
+        payload.query,
+        payload.job_type as _,
+        payload.experience_level as _,
+        payload.location,
+        payload.salary_min,
+        payload.salary_max,
+        payload.min_match_score,
+        payload.email_digest.unwrap_or(false)
+    )
+    .fetch_one(&app_state.db_pool)
+    .await?;
+
+    info!("Saved search created: id={}, user_id={}", search.id, auth_user.user_id);
+
+    Ok(Json(search))
+}
+
+/// Lists the authenticated user's saved searches.
+///
+/// # Errors
+///
+/// Returns an error if:
+/// - User is not authenticated
+/// - Database operation fails
+pub async fn get_saved_searches(
+    auth_user: AuthUser,
+    State(app_state): State<AppState>,
+) -> AppResult<Json<Vec<SavedSearch>>> {
+    info!("Fetching saved searches for user: {}", auth_user.user_id);
+
+    let searches = sqlx::query_as!(
+        SavedSearch,
+        r#"
+        SELECT
+            id, user_id, name, query,
+            job_type as "job_type: JobType",
+            experience_level as "experience_level: ExperienceLevel",
+            location, salary_min, salary_max, min_match_score,
+            email_digest, is_active, last_checked_at, created_at, updated_at
+        FROM saved_searches
+        WHERE user_id = $1
+        ORDER BY created_at DESC
+        "#,
+        auth_user.user_id
+    )
+    .fetch_all(&app_state.db_pool)
+    .await?;
+
+    debug!("Retrieved {} saved searches for user: {}", searches.len(), auth_user.user_id);
+
+    Ok(Json(searches))
+}
+
+/// Updates a saved search. Only the fields provided in the payload are changed.
+///
+/// Resuming a paused search (`is_active: true`) only alerts on jobs posted
+/// after it was resumed.
+///
+/// # Path Parameters
+///
+/// - `search_id` - ID of the saved search
+///
+/// # Errors
+///
+/// Returns an error if:
+/// - User is not authenticated
+/// - Validation fails
+/// - Saved search doesn't exist or doesn't belong to user
+/// - Database operation fails
+pub async fn update_saved_search(
+    auth_user: AuthUser,
+    State(app_state): State<AppState>,
+    Path(search_id): Path<i32>,
+    Json(payload): Json<UpdateSavedSearchPayload>,
+) -> AppResult<Json<SavedSearch>> {
+    info!("Updating saved search: id={}, user_id={}", search_id, auth_user.user_id);
+
+    payload.validate()?;
+    validate_salary_range(payload.salary_min, payload.salary_max)?;
+
+    let search = sqlx::query_as!(
+        SavedSearch,
+        r#"
+        UPDATE saved_searches
+        SET name = COALESCE($1, name),
+            query = COALESCE($2, query),
+            job_type = COALESCE($3, job_type),
+            experience_level = COALESCE($4, experience_level),
+            location = COALESCE($5, location),
+            salary_min = COALESCE($6, salary_min),
+            salary_max = COALESCE($7, salary_max),
+            min_match_score = COALESCE($8, min_match_score),
+            email_digest = COALESCE($9, email_digest),
+            last_checked_at = CASE
+                WHEN $10 AND NOT is_active THEN CURRENT_TIMESTAMP
+                ELSE last_checked_at
+            END,
+            is_active = COALESCE($10, is_active),
+            updated_at = CURRENT_TIMESTAMP
+        WHERE id = $11 AND user_id = $12
+        RETURNING
+            id, user_id, name, query,
+            job_type as "job_type: JobType",
+            experience_level as "experience_level: ExperienceLevel",
+            location, salary_min, salary_max, min_match_score,
+            email_digest, is_active, last_checked_at, created_at, updated_at
+        "#,
+        payload.name,
+        payload.query,
+        payload.job_type as _,
+        payload.experience_level as _,
+        payload.location,
+        payload.salary_min,
+        payload.salary_max,
+        payload.min_match_score,
+        payload.email_digest,
+        payload.is_active,
+        search_id,
+        auth_user.user_id
+    )
+    .fetch_optional(&app_state.db_pool)
+    .await?
+    .ok_or(AppError::NotFound)?;
+
+    info!("Saved search updated: id={}", search_id);
+
+    Ok(Json(search))
+}
+
+/// Deletes a saved search and the alerts it raised.
+///
+/// # Path Parameters
+///
+/// - `search_id` - ID of the saved search
+///
+/// # Errors
+///
+/// Returns an error if:
+/// - User is not authenticated
+/// - Saved search doesn't exist or doesn't belong to user
+/// - Database operation fails
+pub async fn delete_saved_search(
+    auth_user: AuthUser,
+    State(app_state): State<AppState>,
+    Path(search_id): Path<i32>,
+) -> AppResult<Json<serde_json::Value>> {
+    info!("Deleting saved search: id={}, user_id={}", search_id, auth_user.user_id);
+
+    let result = sqlx::query!(
+        "DELETE FROM saved_searches WHERE id = $1 AND user_id = $2",
+        search_id,
+        auth_user.user_id
+    )
+    .execute(&app_state.db_pool)
+    .await?;
+
+    if result.rows_affected() == 0 {
+        return Err(AppError::NotFound);
+    }
+
+    Ok(Json(serde_json::json!({
+        "message": "Saved search deleted successfully"
+    })))
+}
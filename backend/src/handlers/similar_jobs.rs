@@ -0,0 +1,246 @@
+//! Similar job handlers.
+//!
+//! Ranks open jobs and current external postings by how similar they are to
+//! the job being viewed: required-skill overlap, title similarity, experience
+//! level and location (see [`crate::ai_matching::calculate_job_similarity`]).
+
+use super::external_jobs::load_external_jobs;
+use super::types::{ExternalJobQueryParams, SimilarJob, SimilarJobsParams};
+use crate::AppState;
+use crate::ai_matching::{ExperienceRange, JobSimilarityInput, calculate_job_similarity};
+use crate::auth::AuthUser;
+use crate::errors::{AppError, AppResult};
+use crate::models::{
+    ExperienceLevel, ExternalJob, Job, JobStatus, JobType, SalaryCurrency, SalaryPeriod,
+    WorkArrangement,
+};
+use crate::skills::{SkillTaxonomy, load_skill_taxonomy};
+use axum::{
+    Json,
+    extract::{Path, Query, State},
+};
+use sqlx::PgPool;
+use tracing::{debug, info};
+
+/// Default number of similar jobs returned.
+const DEFAULT_LIMIT: i64 = 10;
+
+/// Maximum number of similar jobs returned.
+const MAX_LIMIT: i64 = 50;
+
+/// Gets jobs similar to a job posted on CareerBridge.
+///
+/// # Path Parameters
+///
+/// - `job_id` - ID of the job being viewed
+///
+/// # Query Parameters
+///
+/// - `limit` - Maximum results to return (default: 10, max: 50)
+/// - `include_external` - Whether to include external postings (default: true)
+///
+/// # Returns
+///
+/// Open jobs and current external postings sharing at least one required
+/// skill or title word with the job, most similar first.
+///
+/// # Errors
+///
+/// Returns an error if:
+/// - User is not authenticated
+/// - Job doesn't exist or is a draft
+/// - Database operation fails
+pub async fn get_similar_jobs(
+    auth_user: AuthUser,
+    State(app_state): State<AppState>,
+    Path(job_id): Path<i32>,
+    Query(params): Query<SimilarJobsParams>,
+) -> AppResult<Json<Vec<SimilarJob>>> {
+    info!(
+        "Fetching similar jobs: job_id={}, user_id={}",
+        job_id, auth_user.user_id
+    );
+
+    let (source, jobs): (Vec<Job>, Vec<Job>) = load_jobs(&app_state.db_pool, Some(job_id))
+        .await?
+        .into_iter()
+        .partition(|job| job.id == job_id);
+    let source = source
+        .into_iter()
+        .next()
+        .filter(|job| job.status != JobStatus::Draft)
+        .ok_or(AppError::NotFound)?;
+
+    let external_jobs = if params.include_external.unwrap_or(true) {
+        load_external_jobs(&app_state.db_pool, None, &ExternalJobQueryParams::default()).await?
+    } else {
+        Vec::new()
+    };
+
+    let skills = load_skill_taxonomy(&app_state.db_pool).await?;
+    let similar = rank_similar(&skills, &job_input(&source), jobs, external_jobs, &params);
+    info!("Found {} similar jobs for job {}", similar.len(), job_id);
+
+    Ok(Json(similar))
+}
+
+/// Gets jobs similar to a stored external posting.
+///
+/// # Path Parameters
+///
+/// - `external_id` - Source-prefixed ID of the posting being viewed, e.g.
+///   `reliefweb_123`; the ID of any source listing a deduplicated posting works
+///
+/// # Query Parameters
+///
+/// - `limit` - Maximum results to return (default: 10, max: 50)
+/// - `include_external` - Whether to include other external postings
+///   (default: true)
+///
+/// # Errors
+///
+/// Returns an error if:
+/// - User is not authenticated
+/// - Posting doesn't exist or has expired
+/// - Database operation fails
+pub async fn get_similar_external_jobs(
+    auth_user: AuthUser,
+    State(app_state): State<AppState>,
+    Path(external_id): Path<String>,
+    Query(params): Query<SimilarJobsParams>,
+) -> AppResult<Json<Vec<SimilarJob>>> {
+    info!(
+        "Fetching similar jobs: external_id={}, user_id={}",
+        external_id, auth_user.user_id
+    );
+
+    let (source, external_jobs): (Vec<ExternalJob>, Vec<ExternalJob>) =
+        load_external_jobs(&app_state.db_pool, None, &ExternalJobQueryParams::default())
+            .await?
+            .into_iter()
+            .partition(|job| {
+                job.id == external_id || job.sources.iter().any(|link| link.id == external_id)
+            });
+    let source = source.into_iter().next().ok_or(AppError::NotFound)?;
+
+    let jobs = load_jobs(&app_state.db_pool, None).await?;
+    let external_jobs = if params.include_external.unwrap_or(true) {
+        external_jobs
+    } else {
+        Vec::new()
+    };
+
+    let skills = load_skill_taxonomy(&app_state.db_pool).await?;
+    let similar = rank_similar(&skills, &external_input(&source), jobs, external_jobs, &params);
+    info!("Found {} similar jobs for posting {}", similar.len(), external_id);
+
+    Ok(Json(similar))
+}
+
+/// Loads open jobs, plus the job with ID `include_id` whatever its status.
+async fn load_jobs(pool: &PgPool, include_id: Option<i32>) -> AppResult<Vec<Job>> {
+    let jobs = sqlx::query_as!(
+        Job,
+        r#"
+        SELECT
+            id, job_title, company, location,
+            work_arrangement as "work_arrangement: WorkArrangement",
+            country, division, district, city, latitude, longitude,
+            job_description, required_skills,
+            preferred_skills, skill_weights as "skill_weights: _",
+            experience_level as "experience_level: ExperienceLevel",
+            experience_years_min, experience_years_max,
+            job_type as "job_type: JobType",
+            salary_min, salary_max, salary_currency as "salary_currency: SalaryCurrency",
+            salary_period as "salary_period: SalaryPeriod", salary_negotiable,
+            salary_min_monthly_bdt, salary_max_monthly_bdt,
+            responsibilities, requirements, benefits, company_id,
+            status as "status: JobStatus", application_deadline, expires_at, created_at
+        FROM jobs
+        WHERE status = 'open' OR id = $1
+        "#,
+        include_id
+    )
+    .fetch_all(pool)
+    .await?;
+
+    Ok(jobs)
+}
+
+/// Scores every candidate against `source` and returns the most similar.
+///
+/// Candidates sharing neither a skill nor a title word are left out.
+fn rank_similar(
+    skills: &SkillTaxonomy,
+    source: &JobSimilarityInput,
+    jobs: Vec<Job>,
+    external_jobs: Vec<ExternalJob>,
+    params: &SimilarJobsParams,
+) -> Vec<SimilarJob> {
+    let limit = params.limit.unwrap_or(DEFAULT_LIMIT).clamp(1, MAX_LIMIT) as usize;
+    debug!(
+        "Scoring {} jobs and {} external postings",
+        jobs.len(),
+        external_jobs.len()
+    );
+
+    let scored_jobs = jobs.into_iter().map(|job| {
+        let similarity = calculate_job_similarity(skills, source, &job_input(&job));
+        (similarity, Some(job), None)
+    });
+    let scored_external = external_jobs.into_iter().map(|job| {
+        let similarity = calculate_job_similarity(skills, source, &external_input(&job));
+        (similarity, None, Some(job))
+    });
+
+    let mut similar: Vec<SimilarJob> = scored_jobs
+        .chain(scored_external)
+        .filter(|(similarity, _, _)| {
+            similarity.skill_overlap > 0.0 || similarity.title_similarity > 0.0
+        })
+        .map(|(similarity, job, external_job)| SimilarJob {
+            job,
+            external_job,
+            similarity: similarity.similarity,
+            skill_overlap: similarity.skill_overlap,
+            title_similarity: similarity.title_similarity,
+            experience_alignment: similarity.experience_alignment,
+            location_alignment: similarity.location_alignment,
+            shared_skills: similarity.shared_skills,
+        })
+        .collect();
+
+    // Stable sort keeps CareerBridge jobs ahead of external postings on ties
+    similar.sort_by(|a, b| b.similarity.total_cmp(&a.similarity));
+    similar.truncate(limit);
+    similar
+}
+
+/// Describes a CareerBridge job for similarity scoring.
+fn job_input(job: &Job) -> JobSimilarityInput<'_> {
+    JobSimilarityInput {
+        title: &job.job_title,
+        skills: &job.required_skills,
+        experience: Some(ExperienceRange::job(
+            job.experience_level,
+            job.experience_years_min,
+            job.experience_years_max,
+        )),
+        work_arrangement: job.work_arrangement,
+        division: job.division.as_deref(),
+        district: job.district.as_deref(),
+    }
+}
+
+/// Describes an external posting for similarity scoring.
+fn external_input(job: &ExternalJob) -> JobSimilarityInput<'_> {
+    let location = job.location_details.as_ref();
+    JobSimilarityInput {
+        title: &job.title,
+        skills: &job.skills,
+        experience: job.experience_level.as_deref().and_then(ExperienceRange::parse),
+        work_arrangement: location.map_or(WorkArrangement::OnSite, |l| l.work_arrangement),
+        division: location.and_then(|l| l.division.as_deref()),
+        district: location.and_then(|l| l.district.as_deref()),
+    }
+}
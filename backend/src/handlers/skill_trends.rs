@@ -0,0 +1,129 @@
+//! Skill demand trend handlers.
+//!
+//! Serves the periodic skill demand snapshots recorded by
+//! [`crate::skill_trends`]: demand over time and the skills rising or falling
+//! the most. Demand is reported both as a count of open jobs and as a share of
+//! open jobs in the selected slice.
+
+use super::types::{SkillMoversParams, SkillTrendParams};
+use crate::AppState;
+use crate::career_tracks::load_taxonomy;
+use crate::errors::AppResult;
+use crate::models::{SkillDemandMovers, SkillDemandTrends};
+use crate::skill_trends::{
+    RECENT_TREND_DAYS, TrendFilters, parse_trend_location, skill_movers, skill_trends,
+    validate_days,
+};
+use crate::skills::load_skill_taxonomy;
+use axum::{
+    Json,
+    extract::{Query, State},
+};
+use tracing::info;
+
+/// Default trend window, in days.
+const DEFAULT_TREND_DAYS: i32 = 90;
+
+/// Default number of skills or movers returned.
+const DEFAULT_LIMIT: usize = 10;
+
+/// Maximum number of skills or movers returned.
+const MAX_LIMIT: usize = 50;
+
+/// Gets skill demand over time.
+///
+/// # Query Parameters
+///
+/// - `skills` - Comma-separated skills or skill aliases (default: the most
+///   demanded skills at the latest snapshot)
+/// - `track` - Only jobs in this career track or its sub-tracks (inferred
+///   from job titles)
+/// - `experience_level` - Only jobs at this experience level
+/// - `location` - Only jobs in this division, or `Remote`
+/// - `days` - Window in days (default: 90, max: 730)
+/// - `limit` - Number of skills when `skills` is not given (default: 10, max: 50)
+///
+/// # Returns
+///
+/// One series per skill with a point per snapshot date in the window.
+///
+/// # Errors
+///
+/// Returns an error if:
+/// - `days` is out of range
+/// - `track` is not an active career track
+/// - `location` is not a division or `Remote`
+/// - Database operation fails
+pub async fn get_skill_trends(
+    State(app_state): State<AppState>,
+    Query(params): Query<SkillTrendParams>,
+) -> AppResult<Json<SkillDemandTrends>> {
+    info!(
+        "Fetching skill trends: skills={:?}, track={:?}, experience_level={:?}, location={:?}",
+        params.skills, params.track, params.experience_level, params.location
+    );
+
+    let days = validate_days(params.days.unwrap_or(DEFAULT_TREND_DAYS))?;
+    let filters = TrendFilters {
+        tracks: load_taxonomy(&app_state.db_pool)
+            .await?
+            .resolve_filter(params.track.as_deref())?,
+        experience_level: params.experience_level,
+        location: parse_trend_location(params.location.as_deref())?,
+    };
+    // Aliases ("JS") are looked up under their canonical names
+    let taxonomy = load_skill_taxonomy(&app_state.db_pool).await?;
+    let skills: Vec<String> = params
+        .skills
+        .as_deref()
+        .map(|s| s.split(',').map(|skill| taxonomy.canonical_name(skill)).collect())
+        .unwrap_or_default();
+    let limit = params.limit.unwrap_or(DEFAULT_LIMIT as i64).clamp(1, MAX_LIMIT as i64);
+
+    let trends = skill_trends(&app_state.db_pool, &filters, &skills, days, limit).await?;
+    Ok(Json(trends))
+}
+
+/// Gets the skills whose share of open jobs rose or fell the most.
+///
+/// Compares the latest snapshot with the one `days` days earlier, or with the
+/// oldest snapshot when there is less history.
+///
+/// # Query Parameters
+///
+/// - `track` - Only jobs in this career track or its sub-tracks (inferred
+///   from job titles)
+/// - `experience_level` - Only jobs at this experience level
+/// - `location` - Only jobs in this division, or `Remote`
+/// - `days` - Comparison window in days (default: 30, max: 730)
+/// - `limit` - Risers and fallers to return (default: 10, max: 50)
+///
+/// # Errors
+///
+/// Returns an error if:
+/// - `days` is out of range
+/// - `track` is not an active career track
+/// - `location` is not a division or `Remote`
+/// - Database operation fails
+pub async fn get_skill_movers(
+    State(app_state): State<AppState>,
+    Query(params): Query<SkillMoversParams>,
+) -> AppResult<Json<SkillDemandMovers>> {
+    info!(
+        "Fetching skill movers: track={:?}, experience_level={:?}, location={:?}, days={:?}",
+        params.track, params.experience_level, params.location, params.days
+    );
+
+    let days = validate_days(params.days.unwrap_or(RECENT_TREND_DAYS))?;
+    let filters = TrendFilters {
+        tracks: load_taxonomy(&app_state.db_pool)
+            .await?
+            .resolve_filter(params.track.as_deref())?,
+        experience_level: params.experience_level,
+        location: parse_trend_location(params.location.as_deref())?,
+    };
+    let limit = params.limit.unwrap_or(DEFAULT_LIMIT).clamp(1, MAX_LIMIT);
+
+    let movers = skill_movers(&app_state.db_pool, &filters, days, limit).await?;
+    Ok(Json(movers))
+}
@@ -0,0 +1,61 @@
+//! Skill taxonomy handlers.
+//!
+//! Suggests canonical skills as users type, so profiles and job postings use
+//! the names the taxonomy knows (see [`crate::skills`]).
+
+use super::types::SkillAutocompleteParams;
+use crate::AppState;
+use crate::errors::AppResult;
+use crate::models::Skill;
+use crate::skills::load_skill_taxonomy;
+use axum::{
+    Json,
+    extract::{Query, State},
+};
+use tracing::debug;
+
+/// Default number of suggestions.
+const DEFAULT_LIMIT: i64 = 10;
+
+/// Maximum number of suggestions.
+const MAX_LIMIT: i64 = 50;
+
+/// Suggests skills whose name or an alias contains the query.
+///
+/// Names starting with the query come first, then aliases starting with it
+/// (so `js` suggests JavaScript), then other matches. Case, spaces and
+/// punctuation are ignored.
+///
+/// # Query Parameters
+///
+/// - `q` - Start or part of a skill name or alias
+/// - `category` - Only skills in this category (e.g. `frontend`, `data`)
+/// - `limit` - Maximum suggestions to return (default: 10, max: 50)
+///
+/// # Returns
+///
+/// Matching skills with their canonical name, category, parent skill and
+/// aliases; empty when `q` has no letters or digits.
+///
+/// # Errors
+///
+/// Returns an error if the database operation fails.
+pub async fn autocomplete_skills(
+    State(app_state): State<AppState>,
+    Query(params): Query<SkillAutocompleteParams>,
+) -> AppResult<Json<Vec<Skill>>> {
+    debug!(
+        "Autocompleting skills: q={}, category={:?}",
+        params.q, params.category
+    );
+
+    let limit = params.limit.unwrap_or(DEFAULT_LIMIT).clamp(1, MAX_LIMIT) as usize;
+    let suggestions = load_skill_taxonomy(&app_state.db_pool)
+        .await?
+        .suggest(&params.q, params.category.as_deref(), limit)
+        .into_iter()
+        .cloned()
+        .collect();
+
+    Ok(Json(suggestions))
+}
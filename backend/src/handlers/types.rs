@@ -3,10 +3,16 @@
 //! This module contains all payload structures, query parameters,
 //! and response types used across the API endpoints.
 
+use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 use validator::Validate;
 use uuid::Uuid;
 use crate::models::*;
+use crate::job_sources::FeedFormat;
+use crate::match_explanations::ExplanationSource;
+use crate::ai_matching::{MatchWeights, SkillCredit};
+use crate::match_feedback::RankingMetrics;
 
 /// User registration payload - simplified for initial registration.
 #[derive(Debug, Deserialize, Validate)]
@@ -57,14 +63,26 @@ pub struct UserProfile {
     pub education_level: Option<String>,
     /// Experience level
     pub experience_level: Option<ExperienceLevel>,
-    /// Preferred career track
-    pub preferred_track: Option<CareerTrack>,
+    /// Years of professional experience
+    pub experience_years: Option<i32>,
+    /// Slug of the preferred career track
+    pub preferred_track: Option<String>,
     /// User's skills
     pub skills: Vec<String>,
+    /// Proficiency per skill, by canonical skill name
+    pub skill_proficiency: HashMap<String, SkillProficiency>,
     /// User's projects
     pub projects: Vec<String>,
     /// Target job roles
     pub target_roles: Vec<String>,
+    /// Account role
+    pub role: UserRole,
+    /// Company the user belongs to (employers only)
+    pub company_id: Option<i32>,
+    /// Divisions, districts or cities the user wants to work in, or "Remote"
+    pub preferred_locations: Vec<String>,
+    /// Whether employers can find the user as a candidate
+    pub discoverable: bool,
 }
 
 /// Profile completion payload for onboarding.
@@ -74,10 +92,15 @@ pub struct CompleteProfilePayload {
     pub education_level: Option<String>,
     /// Experience level
     pub experience_level: ExperienceLevel,
-    /// Preferred career track
-    pub preferred_track: CareerTrack,
+    /// Years of professional experience (optional, refines the level)
+    #[validate(range(min = 0, max = 60, message = "experience_years must be between 0 and 60"))]
+    pub experience_years: Option<i32>,
+    /// Preferred career track, by slug or display name (see `GET /api/career-tracks`)
+    pub preferred_track: String,
     /// User's skills (optional)
     pub skills: Option<Vec<String>>,
+    /// Proficiency of listed skills (optional)
+    pub skill_proficiency: Option<HashMap<String, SkillProficiency>>,
     /// User's projects (optional)
     pub projects: Option<Vec<String>>,
     /// Target job roles (optional)
@@ -93,16 +116,26 @@ pub struct UpdateProfilePayload {
     pub education_level: Option<String>,
     /// Updated experience level
     pub experience_level: Option<ExperienceLevel>,
-    /// Updated preferred track
-    pub preferred_track: Option<CareerTrack>,
-    /// Updated skills list
+    /// Updated years of professional experience; also sets the matching
+    /// experience level unless one is given
+    #[validate(range(min = 0, max = 60, message = "experience_years must be between 0 and 60"))]
+    pub experience_years: Option<i32>,
+    /// Updated preferred track, by slug or display name
+    pub preferred_track: Option<String>,
+    /// Updated skills list; proficiencies of removed skills are dropped
     pub skills: Option<Vec<String>>,
+    /// Updated skill proficiencies (replaces all proficiencies)
+    pub skill_proficiency: Option<HashMap<String, SkillProficiency>>,
     /// Updated projects list
     pub projects: Option<Vec<String>>,
     /// Updated target roles
     pub target_roles: Option<Vec<String>>,
     /// Raw CV/resume text
     pub raw_cv_text: Option<String>,
+    /// Updated preferred locations: divisions, districts or cities, or "Remote"
+    pub preferred_locations: Option<Vec<String>>,
+    /// Whether employers can find the user, anonymized, as a candidate
+    pub discoverable: Option<bool>,
 }
 
 /// Query parameters for job recommendations.
@@ -111,12 +144,102 @@ pub struct JobQueryParams {
     /// Filter by experience level
     pub experience_level: Option<ExperienceLevel>,
     /// Filter by job type
-    #[allow(dead_code)]
     pub job_type: Option<JobType>,
-    /// Maximum number of results to return
+    /// Only jobs whose application deadline falls within the next N days
+    pub closing_within_days: Option<i32>,
+    /// Only jobs in this division (remote jobs always match)
+    pub division: Option<String>,
+    /// Only jobs near this place or `latitude,longitude` (remote jobs always match)
+    pub near: Option<String>,
+    /// Radius around `near`, in kilometres (default: 25)
+    pub radius_km: Option<f64>,
+    /// Filter by work arrangement
+    pub work_arrangement: Option<WorkArrangement>,
+    /// Whether to generate AI match explanations (default: true)
+    pub ai_explanations: Option<bool>,
+    /// Score with this match strategy instead of the user's
+    pub strategy: Option<String>,
+    /// Override match weights, e.g. `skills:0.6,experience:0.2,track:0.1,semantic:0.1`
+    pub weights: Option<String>,
+    /// Maximum number of jobs to score (default: 50)
     pub limit: Option<i64>,
 }
 
+/// Sort order for job search results.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum JobSortBy {
+    /// Best full-text match first (falls back to date when no query is given)
+    #[default]
+    Relevance,
+    /// Most recently posted first
+    Date,
+    /// Highest salary first
+    Salary,
+}
+
+/// Query parameters for full-text job search.
+#[derive(Debug, Deserialize)]
+pub struct JobSearchParams {
+    /// Free-text query matched against title, skills, description and requirements
+    pub q: Option<String>,
+    /// Also match and rank jobs by semantic similarity to `q`
+    #[serde(default)]
+    pub semantic: bool,
+    /// Filter by job type
+    pub job_type: Option<JobType>,
+    /// Filter by experience level
+    pub experience_level: Option<ExperienceLevel>,
+    /// Case-insensitive substring match on location
+    pub location: Option<String>,
+    /// Only jobs in this division (remote jobs always match)
+    pub division: Option<String>,
+    /// Only jobs near this place or `latitude,longitude` (remote jobs always match)
+    pub near: Option<String>,
+    /// Radius around `near`, in kilometres (default: 25)
+    pub radius_km: Option<f64>,
+    /// Filter by work arrangement
+    pub work_arrangement: Option<WorkArrangement>,
+    /// Only jobs paying at least this much, in BDT per month
+    pub salary_min: Option<i32>,
+    /// Only jobs whose salary range starts at or below this, in BDT per month
+    pub salary_max: Option<i32>,
+    /// Only jobs posted within the last N days
+    pub posted_within_days: Option<i32>,
+    /// Only jobs whose application deadline falls within the next N days
+    pub closing_within_days: Option<i32>,
+    /// Sort order (default: relevance)
+    #[serde(default)]
+    pub sort: JobSortBy,
+    /// Opaque cursor from a previous response's `next_cursor`
+    pub cursor: Option<String>,
+    /// Page size (default: 20, max: 100)
+    pub limit: Option<i64>,
+}
+
+/// A single job search result.
+#[derive(Debug, Serialize)]
+pub struct JobSearchHit {
+    /// The job listing
+    pub job: Job,
+    /// Full-text relevance rank (0 when no query was given), plus the
+    /// semantic similarity in semantic search
+    pub relevance: f32,
+    /// Semantic similarity to the query (0-100), in semantic search
+    pub semantic_similarity: Option<f64>,
+}
+
+/// A page of job search results.
+#[derive(Debug, Serialize)]
+pub struct JobSearchResponse {
+    /// Jobs on this page
+    pub results: Vec<JobSearchHit>,
+    /// Total number of jobs matching the filters
+    pub total_count: i64,
+    /// Cursor for the next page (None on the last page)
+    pub next_cursor: Option<String>,
+}
+
 /// Job recommendation with match analysis.
 #[derive(Debug, Serialize)]
 pub struct JobRecommendation {
@@ -124,12 +247,16 @@ pub struct JobRecommendation {
     pub job: Job,
     /// Match score as percentage (0-100)
     pub match_score: f64,
-    /// Skills that match between user and job
+    /// Skills that match between user and job (required, then preferred)
     pub matched_skills: Vec<String>,
     /// Skills required by job that user doesn't have
     pub missing_skills: Vec<String>,
+    /// Nice-to-have skills of the job that user doesn't have
+    pub missing_preferred_skills: Vec<String>,
     /// Human-readable explanation of the match
     pub match_explanation: String,
+    /// Whether the explanation came from AI or the heuristic
+    pub explanation_source: ExplanationSource,
     /// Key strengths (why it's a good match)
     pub strengths: Vec<String>,
     /// Areas for improvement
@@ -140,8 +267,86 @@ pub struct JobRecommendation {
     pub track_alignment: f64,
     /// Skill overlap score (0-100)
     pub skill_overlap: f64,
+    /// Semantic similarity of profile and job (0-100), when the match
+    /// strategy weighs it
+    pub semantic_similarity: Option<f64>,
+    /// Match strategy the score was calculated with
+    pub match_strategy: String,
     /// Platform links for applying
     pub platform_links: PlatformLinks,
+    /// Whether the user has saved this job
+    pub saved: bool,
+    /// Whether the job is in one of the user's preferred locations (or remote)
+    pub in_preferred_location: bool,
+}
+
+/// Match explanation of a single job.
+#[derive(Debug, Serialize)]
+pub struct MatchExplanationResponse {
+    pub job_id: i32,
+    /// Match score as percentage (0-100)
+    pub match_score: f64,
+    /// Human-readable explanation of the match
+    pub match_explanation: String,
+    /// Whether the explanation came from AI or the heuristic
+    pub explanation_source: ExplanationSource,
+    /// Whether the AI explanation was served from the cache
+    pub cached: bool,
+    /// Key strengths (why it's a good match)
+    pub strengths: Vec<String>,
+    /// Areas for improvement
+    pub improvement_areas: Vec<String>,
+    /// Match strategy the score was calculated with
+    pub match_strategy: String,
+}
+
+/// How each of a job's skills counts towards a user's match score.
+#[derive(Debug, Serialize)]
+pub struct MatchBreakdownResponse {
+    pub job_id: i32,
+    /// Match score as percentage (0-100)
+    pub match_score: f64,
+    /// Match strategy the score was calculated with
+    pub match_strategy: String,
+    /// Weights the factors were combined with
+    pub weights: MatchWeights,
+    /// Skill overlap score (0-100)
+    pub skill_overlap: f64,
+    /// Experience level alignment score (0-100)
+    pub experience_alignment: f64,
+    /// Track alignment score (0-100)
+    pub track_alignment: f64,
+    /// Semantic similarity of profile and job (0-100), when weighted
+    pub semantic_similarity: Option<f64>,
+    /// The job's skills, required first
+    pub skills: Vec<SkillBreakdown>,
+    /// Estimated hours of study to close every skill gap
+    pub learning_hours: i32,
+}
+
+/// One of a job's skills in a match breakdown.
+#[derive(Debug, Serialize)]
+pub struct SkillBreakdown {
+    /// Status, weight, proficiencies and credit earned
+    #[serde(flatten)]
+    pub credit: SkillCredit,
+    /// Points of the match score the skill earned
+    pub contribution: f64,
+    /// Points the skill is worth at full credit
+    pub max_contribution: f64,
+    /// Estimated hours of study to earn the full credit (0 when earned)
+    pub learning_hours: i32,
+    /// Learning resources teaching the skill, when it isn't fully matched
+    pub resources: Vec<LearningResource>,
+}
+
+/// Query parameters choosing the match strategy of a single match.
+#[derive(Debug, Deserialize)]
+pub struct MatchStrategyParams {
+    /// Score with this match strategy instead of the user's
+    pub strategy: Option<String>,
+    /// Override match weights, e.g. `skills:0.6,experience:0.2,track:0.1,semantic:0.1`
+    pub weights: Option<String>,
 }
 
 #[derive(Debug, Serialize)]
@@ -162,6 +367,11 @@ pub struct ResourceRecommendation {
     pub relevance_score: f64,
     /// Skills this resource can help develop
     pub target_skills: Vec<String>,
+    /// Target skills whose demand rose over the last 30 days
+    pub trending_skills: Vec<String>,
+    /// Semantic similarity of profile and resource (0-100), when semantic
+    /// matching is enabled
+    pub semantic_similarity: Option<f64>,
 }
 
 /// Skill gap analysis for a target role.
@@ -201,6 +411,550 @@ pub struct UpdateApplicationPayload {
     pub notes: Option<String>,
 }
 
+/// Payload for saving a job for later.
+///
+/// Exactly one of `job_id` or `external_job` must be provided.
+#[derive(Debug, Deserialize, Validate)]
+pub struct SaveJobPayload {
+    /// ID of an internal job to save
+    pub job_id: Option<i32>,
+    /// External job listing to save (as returned by the external job endpoints)
+    pub external_job: Option<ExternalJob>,
+    /// Optional labels (e.g. "remote", "dream-company")
+    #[validate(length(max = 20, message = "At most 20 tags are allowed"))]
+    pub tags: Option<Vec<String>>,
+    /// Optional personal notes
+    #[validate(length(max = 5000, message = "Notes cannot exceed 5000 characters"))]
+    pub notes: Option<String>,
+}
+
+/// Payload for updating a saved job's tags or notes.
+#[derive(Debug, Deserialize, Validate)]
+pub struct UpdateSavedJobPayload {
+    /// Replacement labels
+    #[validate(length(max = 20, message = "At most 20 tags are allowed"))]
+    pub tags: Option<Vec<String>>,
+    /// Replacement notes
+    #[validate(length(max = 5000, message = "Notes cannot exceed 5000 characters"))]
+    pub notes: Option<String>,
+}
+
+/// Query parameters for listing saved jobs.
+#[derive(Debug, Deserialize)]
+pub struct SavedJobQueryParams {
+    /// Only return saved jobs with this tag
+    pub tag: Option<String>,
+}
+
+/// A saved job together with the internal job it points at.
+#[derive(Debug, Serialize)]
+pub struct SavedJobDetails {
+    /// The bookmark (tags, notes and external job snapshot)
+    pub saved_job: SavedJob,
+    /// Internal job details (None for external jobs)
+    pub job: Option<Job>,
+}
+
+/// Payload for saving a job search with alerts.
+#[derive(Debug, Deserialize, Validate)]
+pub struct CreateSavedSearchPayload {
+    /// Display name for the search
+    #[validate(length(min = 1, max = 255, message = "Name is required"))]
+    pub name: String,
+    /// Free-text query (same syntax as `GET /api/jobs/search`)
+    #[validate(length(max = 500, message = "Query cannot exceed 500 characters"))]
+    pub query: Option<String>,
+    /// Job type filter
+    pub job_type: Option<JobType>,
+    /// Experience level filter
+    pub experience_level: Option<ExperienceLevel>,
+    /// Location substring filter
+    pub location: Option<String>,
+    /// Minimum salary filter, in BDT per month
+    pub salary_min: Option<i32>,
+    /// Maximum salary filter, in BDT per month
+    pub salary_max: Option<i32>,
+    /// Only alert on jobs matching your profile at or above this percentage
+    #[validate(range(min = 0.0, max = 100.0, message = "min_match_score must be between 0 and 100"))]
+    pub min_match_score: Option<f64>,
+    /// Also send alerts by email (default: false)
+    pub email_digest: Option<bool>,
+}
+
+/// Payload for updating a saved search. Only provided fields are changed.
+#[derive(Debug, Deserialize, Validate)]
+pub struct UpdateSavedSearchPayload {
+    /// Display name for the search
+    #[validate(length(min = 1, max = 255, message = "Name cannot be empty"))]
+    pub name: Option<String>,
+    /// Free-text query
+    #[validate(length(max = 500, message = "Query cannot exceed 500 characters"))]
+    pub query: Option<String>,
+    /// Job type filter
+    pub job_type: Option<JobType>,
+    /// Experience level filter
+    pub experience_level: Option<ExperienceLevel>,
+    /// Location substring filter
+    pub location: Option<String>,
+    /// Minimum salary filter, in BDT per month
+    pub salary_min: Option<i32>,
+    /// Maximum salary filter, in BDT per month
+    pub salary_max: Option<i32>,
+    /// Minimum profile match percentage
+    #[validate(range(min = 0.0, max = 100.0, message = "min_match_score must be between 0 and 100"))]
+    pub min_match_score: Option<f64>,
+    /// Send alerts by email
+    pub email_digest: Option<bool>,
+    /// Pause or resume alerts
+    pub is_active: Option<bool>,
+}
+
+/// Query parameters for listing notifications.
+#[derive(Debug, Deserialize)]
+pub struct NotificationQueryParams {
+    /// Only return unread notifications (default: false)
+    pub unread_only: Option<bool>,
+    /// Maximum results to return (default: 50)
+    pub limit: Option<i64>,
+}
+
+/// A division of the location gazetteer with its districts.
+#[derive(Debug, Serialize)]
+pub struct GazetteerDivision {
+    /// Division name
+    pub name: String,
+    /// Districts in the division
+    pub districts: Vec<GazetteerDistrict>,
+}
+
+/// A district of the location gazetteer.
+#[derive(Debug, Serialize)]
+pub struct GazetteerDistrict {
+    /// District name
+    pub name: String,
+    /// Latitude of the district headquarters
+    pub latitude: f64,
+    /// Longitude of the district headquarters
+    pub longitude: f64,
+    /// Well-known cities and areas in the district
+    pub cities: Vec<String>,
+}
+
+/// Query parameters for similar jobs.
+#[derive(Debug, Deserialize)]
+pub struct SimilarJobsParams {
+    /// Maximum results to return (default: 10, max: 50)
+    pub limit: Option<i64>,
+    /// Whether to include stored external postings (default: true)
+    pub include_external: Option<bool>,
+}
+
+/// A job or external posting similar to the one being viewed.
+///
+/// Exactly one of `job` and `external_job` is set.
+#[derive(Debug, Serialize)]
+pub struct SimilarJob {
+    /// The similar job, when it is posted on CareerBridge
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub job: Option<Job>,
+    /// The similar posting, when it comes from an external source
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub external_job: Option<ExternalJob>,
+    /// Overall similarity (0-100)
+    pub similarity: f64,
+    /// Required skill overlap (0-100)
+    pub skill_overlap: f64,
+    /// Title word overlap (0-100)
+    pub title_similarity: f64,
+    /// Experience level alignment (0-100)
+    pub experience_alignment: f64,
+    /// Location alignment (0-100)
+    pub location_alignment: f64,
+    /// Skills both postings require
+    pub shared_skills: Vec<String>,
+}
+
+/// Query parameters for skill demand trends.
+#[derive(Debug, Deserialize)]
+pub struct SkillTrendParams {
+    /// Comma-separated skills; defaults to the most demanded skills
+    pub skills: Option<String>,
+    /// Only jobs in this career track (slug) or its sub-tracks
+    pub track: Option<String>,
+    /// Only jobs at this experience level
+    pub experience_level: Option<ExperienceLevel>,
+    /// Only jobs in this division, or `Remote`
+    pub location: Option<String>,
+    /// Window in days (default: 90, max: 730)
+    pub days: Option<i32>,
+    /// Number of skills when `skills` is not given (default: 10, max: 50)
+    pub limit: Option<i64>,
+}
+
+/// Query parameters for the skills rising and falling in demand.
+#[derive(Debug, Deserialize)]
+pub struct SkillMoversParams {
+    /// Only jobs in this career track (slug) or its sub-tracks
+    pub track: Option<String>,
+    /// Only jobs at this experience level
+    pub experience_level: Option<ExperienceLevel>,
+    /// Only jobs in this division, or `Remote`
+    pub location: Option<String>,
+    /// Compare with the snapshot this many days earlier (default: 30, max: 730)
+    pub days: Option<i32>,
+    /// Risers and fallers to return (default: 10, max: 50)
+    pub limit: Option<usize>,
+}
+
+/// Query parameters for the open jobs feed.
+#[derive(Debug, Deserialize)]
+pub struct JobFeedParams {
+    /// `rss` (default), `atom` or `json`
+    pub format: Option<FeedFormat>,
+    /// Only jobs in this career track (slug) or its sub-tracks, inferred
+    /// from job titles
+    pub track: Option<String>,
+    /// Comma-separated divisions, districts or cities, or `Remote`
+    pub location: Option<String>,
+    /// Maximum jobs in the feed (default: 50, max: 200)
+    pub limit: Option<usize>,
+}
+
+/// Query parameters for listing career tracks.
+#[derive(Debug, Deserialize)]
+pub struct CareerTrackQueryParams {
+    /// Whether to include inactive tracks (default: false)
+    pub include_inactive: Option<bool>,
+}
+
+/// Payload for adding a career track or sub-track (admin only).
+#[derive(Debug, Deserialize, Validate)]
+pub struct CreateCareerTrackPayload {
+    /// Unique identifier: a lowercase letter, then lowercase letters, digits
+    /// or underscores
+    #[validate(length(min = 1, max = 50, message = "slug must be 1-50 characters"))]
+    pub slug: String,
+    /// Name shown to users
+    #[validate(length(min = 1, max = 100, message = "display_name must be 1-100 characters"))]
+    pub display_name: String,
+    /// Label on generated CVs (default: the display name)
+    #[validate(length(max = 100, message = "cv_label must be at most 100 characters"))]
+    pub cv_label: Option<String>,
+    /// Parent track, to add a sub-track
+    pub parent_slug: Option<String>,
+    /// Job title keywords that identify the track
+    pub keywords: Option<Vec<String>>,
+    /// Skills typical of the track
+    pub related_skills: Option<Vec<String>>,
+    /// Whether the track can be chosen (default: true)
+    pub active: Option<bool>,
+}
+
+/// Payload for editing a career track (admin only; all fields optional).
+#[derive(Debug, Deserialize, Validate)]
+pub struct UpdateCareerTrackPayload {
+    /// Updated display name
+    #[validate(length(min = 1, max = 100, message = "display_name must be 1-100 characters"))]
+    pub display_name: Option<String>,
+    /// Updated CV label
+    #[validate(length(max = 100, message = "cv_label must be at most 100 characters"))]
+    pub cv_label: Option<String>,
+    /// Updated parent track; an empty string makes the track top-level
+    pub parent_slug: Option<String>,
+    /// Replacement title keywords
+    pub keywords: Option<Vec<String>>,
+    /// Replacement related skills
+    pub related_skills: Option<Vec<String>>,
+    /// Whether the track can be chosen
+    pub active: Option<bool>,
+}
+
+/// Query parameters for the match strategy report (admin only).
+#[derive(Debug, Deserialize)]
+pub struct MatchStrategyReportParams {
+    /// Experiment to report on (default: the running one)
+    pub experiment: Option<String>,
+}
+
+/// A configured match strategy.
+#[derive(Debug, Serialize)]
+pub struct MatchStrategyInfo {
+    /// Strategy name
+    pub name: String,
+    /// Kind of strategy (`heuristic`)
+    pub kind: String,
+    /// Normalized weights of skills, experience, track and semantic similarity
+    pub weights: MatchWeights,
+    /// Kind-specific settings
+    pub settings: serde_json::Value,
+    /// Whether users outside the experiment are scored with it
+    pub is_default: bool,
+}
+
+/// Outcomes of one strategy in a match experiment.
+#[derive(Debug, Serialize)]
+pub struct ExperimentArmReport {
+    /// Strategy name
+    pub strategy: String,
+    /// Share of users assigned to it (None for arms no longer running)
+    pub percent: Option<u32>,
+    /// Users assigned to the strategy
+    pub users: i64,
+    /// Assigned users who applied to at least one job
+    pub applicants: i64,
+    /// Applications made while assigned to the strategy
+    pub applications: i64,
+    /// Share of assigned users who applied (0-1)
+    pub application_rate: f64,
+    /// Applications per assigned user
+    pub applications_per_user: f64,
+    /// Applications by current status (e.g. `applied`, `interview`)
+    pub statuses: HashMap<String, i64>,
+}
+
+/// Outcomes of a match experiment, per strategy.
+#[derive(Debug, Serialize)]
+pub struct MatchExperimentReport {
+    /// Experiment name
+    pub name: String,
+    /// Whether the experiment is the one running
+    pub running: bool,
+    /// Outcomes per strategy, running arms first
+    pub arms: Vec<ExperimentArmReport>,
+}
+
+/// Configured match strategies and experiment outcomes.
+#[derive(Debug, Serialize)]
+pub struct MatchStrategiesResponse {
+    /// Strategy for users outside the experiment
+    pub default_strategy: String,
+    /// All configured strategies
+    pub strategies: Vec<MatchStrategyInfo>,
+    /// Report of the requested or running experiment, if any
+    pub experiment: Option<MatchExperimentReport>,
+}
+
+/// Payload for explicit feedback on a recommended job.
+#[derive(Debug, Deserialize)]
+pub struct MatchFeedbackPayload {
+    /// `view` or `not_interested`; saves and applications are recorded
+    /// by their own endpoints
+    pub event: MatchFeedbackEvent,
+    /// Why the user is not interested (required for `not_interested`)
+    pub reason: Option<NotInterestedReason>,
+}
+
+/// Query parameters for match feedback reports (admin only).
+#[derive(Debug, Deserialize)]
+pub struct MatchFeedbackReportParams {
+    /// Only this strategy (default: all; for the tuned strategy, the
+    /// default strategy)
+    pub strategy: Option<String>,
+    /// Days of feedback to evaluate (default: 90, max: 365)
+    pub days: Option<i32>,
+    /// Cut-off rank of precision@k and NDCG@k (default: 10, max: 50)
+    pub k: Option<usize>,
+    /// Users with positive feedback needed to tune weights (default: 10)
+    pub min_users: Option<usize>,
+}
+
+/// Weights that would have ranked recommended jobs better.
+#[derive(Debug, Serialize)]
+pub struct TunedWeights {
+    /// Weights summing to 1
+    pub weights: MatchWeights,
+    /// Ranking quality of the shown jobs re-ranked with the weights
+    pub metrics: RankingMetrics,
+}
+
+/// Feedback on and ranking quality of one match strategy.
+#[derive(Debug, Serialize)]
+pub struct StrategyFeedbackReport {
+    /// Strategy name
+    pub strategy: String,
+    /// Current weights (None if the strategy is no longer configured)
+    pub weights: Option<MatchWeights>,
+    /// Users shown recommendations scored with the strategy
+    pub users: usize,
+    /// Jobs shown
+    pub impressions: usize,
+    /// Feedback events by kind
+    pub events: HashMap<MatchFeedbackEvent, i64>,
+    /// Reasons users gave for not being interested
+    pub not_interested_reasons: HashMap<NotInterestedReason, i64>,
+    /// Ranking quality of the jobs as they were shown
+    pub metrics: RankingMetrics,
+    /// Best weights found, with enough users with positive feedback
+    pub tuned: Option<TunedWeights>,
+}
+
+/// Offline evaluation of match strategies from feedback.
+#[derive(Debug, Serialize)]
+pub struct MatchFeedbackReport {
+    /// Days of feedback evaluated
+    pub days: i32,
+    /// Cut-off rank of the metrics
+    pub k: usize,
+    /// Users with positive feedback needed to tune weights
+    pub min_users: usize,
+    /// Evaluation per strategy
+    pub strategies: Vec<StrategyFeedbackReport>,
+}
+
+/// A match strategy with tuned weights, ready for the strategies file.
+#[derive(Debug, Serialize)]
+pub struct TunedStrategyResponse {
+    /// Strategy the weights were tuned for
+    pub based_on: String,
+    /// Entry for `MATCH_STRATEGIES_FILE`, named `<strategy>_tuned`
+    pub strategy: serde_json::Value,
+    /// The weights as a `MATCH_WEIGHTS` value
+    pub match_weights: String,
+    /// Ranking quality of the jobs as they were shown
+    pub baseline: RankingMetrics,
+    /// Ranking quality with the tuned weights
+    pub tuned: RankingMetrics,
+}
+
+/// Query parameters for candidates of a job.
+#[derive(Debug, Deserialize)]
+pub struct CandidateQueryParams {
+    /// Maximum candidates to return (default: 20, max: 100)
+    pub limit: Option<usize>,
+    /// Only candidates matching at or above this percentage
+    pub min_score: Option<f64>,
+}
+
+/// Profile of a candidate without anything identifying them.
+#[derive(Debug, Serialize)]
+pub struct AnonymizedProfile {
+    /// Highest education level
+    pub education_level: Option<String>,
+    /// Experience level
+    pub experience_level: Option<ExperienceLevel>,
+    /// Years of professional experience
+    pub experience_years: Option<i32>,
+    /// Slug of the preferred career track
+    pub preferred_track: Option<String>,
+    /// Skills
+    pub skills: Vec<String>,
+    /// Proficiency per skill
+    pub skill_proficiency: HashMap<String, SkillProficiency>,
+    /// Preferred work locations
+    pub preferred_locations: Vec<String>,
+}
+
+/// Contact details of a candidate who accepted a contact request.
+#[derive(Debug, Serialize)]
+pub struct CandidateContact {
+    /// Full name
+    pub full_name: String,
+    /// Email address
+    pub email: String,
+    /// Target job roles
+    pub target_roles: Vec<String>,
+    /// Completed projects
+    pub projects: Vec<String>,
+}
+
+/// A discoverable candidate ranked for a job.
+#[derive(Debug, Serialize)]
+pub struct CandidateMatch {
+    /// Candidate ID, to send a contact request
+    pub candidate_id: Uuid,
+    /// Overall match score (0-100)
+    pub match_score: f64,
+    /// Skill overlap score (0-100)
+    pub skill_overlap: f64,
+    /// Experience alignment score (0-100)
+    pub experience_alignment: f64,
+    /// Track alignment score (0-100)
+    pub track_alignment: f64,
+    /// Semantic similarity of profile and job (0-100), when it was scored
+    pub semantic_similarity: Option<f64>,
+    /// Job skills the candidate has
+    pub matched_skills: Vec<String>,
+    /// Required job skills the candidate lacks
+    pub missing_required_skills: Vec<String>,
+    /// Preferred job skills the candidate lacks
+    pub missing_preferred_skills: Vec<String>,
+    /// The candidate's profile, anonymized
+    pub profile: AnonymizedProfile,
+    /// Status of the company's contact request for this job, if sent
+    pub contact_status: Option<ContactRequestStatus>,
+    /// Name and email, once the candidate accepted a contact request
+    pub contact: Option<CandidateContact>,
+}
+
+/// Candidates ranked for a job.
+#[derive(Debug, Serialize)]
+pub struct JobCandidatesResponse {
+    /// Job the candidates were ranked for
+    pub job_id: i32,
+    /// Match strategy the candidates were scored with
+    pub match_strategy: String,
+    /// Discoverable candidates in total, of which at most 500 are scored
+    pub total_candidates: usize,
+    /// Best matching candidates first
+    pub candidates: Vec<CandidateMatch>,
+}
+
+/// Payload for asking to contact a candidate about a job.
+#[derive(Debug, Deserialize, Validate)]
+pub struct CreateContactRequestPayload {
+    /// Message to the candidate
+    #[validate(length(max = 1000, message = "Message cannot exceed 1000 characters"))]
+    pub message: Option<String>,
+}
+
+/// Payload for answering a contact request.
+#[derive(Debug, Deserialize)]
+pub struct RespondContactRequestPayload {
+    /// `accepted` or `declined`
+    pub status: ContactRequestStatus,
+}
+
+/// A contact request as shown to the candidate.
+#[derive(Debug, Serialize)]
+pub struct ContactRequestDetails {
+    /// The request
+    #[serde(flatten)]
+    pub request: ContactRequest,
+    /// Title of the job
+    pub job_title: String,
+    /// Company posting the job
+    pub company: String,
+}
+
+/// Query parameters for skill autocomplete.
+#[derive(Debug, Deserialize)]
+pub struct SkillAutocompleteParams {
+    /// Start or part of a skill name or alias
+    pub q: String,
+    /// Only skills in this category (e.g. `frontend`, `data`)
+    pub category: Option<String>,
+    /// Maximum suggestions to return (default: 10, max: 50)
+    pub limit: Option<i64>,
+}
+
+/// Query parameters for listing external jobs.
+#[derive(Debug, Default, Deserialize)]
+pub struct ExternalJobQueryParams {
+    /// Only postings paying at least this much, in BDT per month
+    pub salary_min: Option<i32>,
+    /// Only postings whose salary range starts at or below this, in BDT per month
+    pub salary_max: Option<i32>,
+}
+
+/// Query parameters for the external job deduplication audit log.
+#[derive(Debug, Deserialize)]
+pub struct MergeQueryParams {
+    /// Only return decisions that merged (`true`) or kept postings apart (`false`)
+    pub merged: Option<bool>,
+    /// Maximum results to return (default: 100, max: 500)
+    pub limit: Option<i64>,
+}
+
 /// Payload for updating learning resource progress.
 #[derive(Debug, Deserialize)]
 pub struct UpdateProgressPayload {
@@ -227,3 +981,199 @@ pub struct OAuthLoginResponse {
     /// Whether this is a newly created user
     pub is_new_user: bool,
 }
+
+/// Payload for creating a company profile.
+///
+/// The creating user becomes an employer member of the company.
+#[derive(Debug, Deserialize, Validate)]
+pub struct CreateCompanyPayload {
+    /// Company name
+    #[validate(length(min = 1, max = 255, message = "Company name is required"))]
+    pub name: String,
+    /// URL of the company logo
+    #[validate(url(message = "Invalid logo URL"))]
+    pub logo_url: Option<String>,
+    /// Company website
+    #[validate(url(message = "Invalid website URL"))]
+    pub website: Option<String>,
+    /// Industry or sector
+    pub industry: Option<String>,
+    /// Headcount bracket (e.g., "11-50")
+    #[validate(length(max = 50, message = "Company size must be at most 50 characters"))]
+    pub company_size: Option<String>,
+    /// Office locations
+    pub locations: Option<Vec<String>>,
+    /// Short description of the organisation
+    pub description: Option<String>,
+}
+
+/// Payload for updating a company profile (all fields optional).
+#[derive(Debug, Deserialize, Validate)]
+pub struct UpdateCompanyPayload {
+    /// Updated company name
+    #[validate(length(min = 1, max = 255, message = "Company name cannot be empty"))]
+    pub name: Option<String>,
+    /// Updated logo URL
+    #[validate(url(message = "Invalid logo URL"))]
+    pub logo_url: Option<String>,
+    /// Updated website
+    #[validate(url(message = "Invalid website URL"))]
+    pub website: Option<String>,
+    /// Updated industry
+    pub industry: Option<String>,
+    /// Updated headcount bracket
+    #[validate(length(max = 50, message = "Company size must be at most 50 characters"))]
+    pub company_size: Option<String>,
+    /// Updated office locations
+    pub locations: Option<Vec<String>>,
+    /// Updated description
+    pub description: Option<String>,
+}
+
+/// Payload for inviting an existing user to a company as an employer.
+#[derive(Debug, Deserialize, Validate)]
+pub struct AddCompanyMemberPayload {
+    /// Email address of the user to invite
+    #[validate(email(message = "Invalid email format"))]
+    pub email: String,
+}
+
+/// Payload for answering an invitation to join a company.
+#[derive(Debug, Deserialize)]
+pub struct RespondCompanyInvitationPayload {
+    /// `accepted` or `declined`
+    pub status: CompanyInvitationStatus,
+}
+
+/// An invitation to join a company as shown to the invited user.
+#[derive(Debug, Serialize)]
+pub struct CompanyInvitationDetails {
+    /// The invitation
+    #[serde(flatten)]
+    pub invitation: CompanyInvitation,
+    /// Name of the company
+    pub company_name: String,
+}
+
+/// Payload for changing a company's verification status (admin only).
+#[derive(Debug, Deserialize)]
+pub struct UpdateVerificationPayload {
+    /// New verification status
+    pub verification_status: VerificationStatus,
+}
+
+/// Public company page with its open roles.
+#[derive(Debug, Serialize)]
+pub struct CompanyPage {
+    /// The company profile
+    pub company: Company,
+    /// Jobs currently posted by the company
+    pub open_jobs: Vec<Job>,
+}
+
+/// Payload for posting a new job on behalf of the employer's company.
+#[derive(Debug, Deserialize, Validate)]
+pub struct CreateJobPayload {
+    /// Job title
+    #[validate(length(min = 1, max = 255, message = "Job title is required"))]
+    pub job_title: String,
+    /// Job location
+    #[validate(length(min = 1, max = 255, message = "Location is required"))]
+    pub location: String,
+    /// On-site, hybrid or remote (default: detected from `location`, else on-site)
+    pub work_arrangement: Option<WorkArrangement>,
+    /// Detailed job description
+    #[validate(length(min = 1, message = "Job description is required"))]
+    pub job_description: String,
+    /// Must-have skills for the job
+    pub required_skills: Vec<String>,
+    /// Nice-to-have skills for the job
+    pub preferred_skills: Option<Vec<String>>,
+    /// Weight (1-5) of listed skills (default: 3 for required, 1 for preferred)
+    pub skill_weights: Option<HashMap<String, i32>>,
+    /// Required experience level
+    pub experience_level: ExperienceLevel,
+    /// Minimum years of experience (default: the level's range)
+    #[validate(range(min = 0, max = 60, message = "experience_years_min must be between 0 and 60"))]
+    pub experience_years_min: Option<i32>,
+    /// Maximum years of experience (omit for "or more")
+    #[validate(range(min = 0, max = 60, message = "experience_years_max must be between 0 and 60"))]
+    pub experience_years_max: Option<i32>,
+    /// Type of employment
+    pub job_type: JobType,
+    /// Minimum salary offered
+    pub salary_min: Option<i32>,
+    /// Maximum salary offered
+    pub salary_max: Option<i32>,
+    /// Currency of the salary (default: BDT)
+    pub salary_currency: Option<SalaryCurrency>,
+    /// Pay period of the salary (default: month)
+    pub salary_period: Option<SalaryPeriod>,
+    /// Whether the salary is negotiable (default: false)
+    pub salary_negotiable: Option<bool>,
+    /// List of job responsibilities
+    pub responsibilities: Option<Vec<String>>,
+    /// List of job requirements
+    pub requirements: Option<Vec<String>>,
+    /// List of benefits offered
+    pub benefits: Option<Vec<String>>,
+    /// `draft` or `open` (default: open)
+    pub status: Option<JobStatus>,
+    /// Last moment applications are accepted
+    pub application_deadline: Option<DateTime<Utc>>,
+    /// When the listing is taken down
+    pub expires_at: Option<DateTime<Utc>>,
+}
+
+/// Payload for updating an employer's job posting (all fields optional).
+#[derive(Debug, Deserialize, Validate)]
+pub struct UpdateJobPayload {
+    /// Updated job title
+    #[validate(length(min = 1, max = 255, message = "Job title cannot be empty"))]
+    pub job_title: Option<String>,
+    /// Updated location
+    #[validate(length(min = 1, max = 255, message = "Location cannot be empty"))]
+    pub location: Option<String>,
+    /// Updated work arrangement
+    pub work_arrangement: Option<WorkArrangement>,
+    /// Updated description
+    pub job_description: Option<String>,
+    /// Updated required skills
+    pub required_skills: Option<Vec<String>>,
+    /// Updated preferred skills
+    pub preferred_skills: Option<Vec<String>>,
+    /// Updated skill weights (replaces all weights)
+    pub skill_weights: Option<HashMap<String, i32>>,
+    /// Updated experience level
+    pub experience_level: Option<ExperienceLevel>,
+    /// Updated minimum years of experience
+    #[validate(range(min = 0, max = 60, message = "experience_years_min must be between 0 and 60"))]
+    pub experience_years_min: Option<i32>,
+    /// Updated maximum years of experience
+    #[validate(range(min = 0, max = 60, message = "experience_years_max must be between 0 and 60"))]
+    pub experience_years_max: Option<i32>,
+    /// Updated job type
+    pub job_type: Option<JobType>,
+    /// Updated minimum salary
+    pub salary_min: Option<i32>,
+    /// Updated maximum salary
+    pub salary_max: Option<i32>,
+    /// Updated salary currency
+    pub salary_currency: Option<SalaryCurrency>,
+    /// Updated salary pay period
+    pub salary_period: Option<SalaryPeriod>,
+    /// Updated negotiable flag
+    pub salary_negotiable: Option<bool>,
+    /// Updated responsibilities
+    pub responsibilities: Option<Vec<String>>,
+    /// Updated requirements
+    pub requirements: Option<Vec<String>>,
+    /// Updated benefits
+    pub benefits: Option<Vec<String>>,
+    /// Updated status: `draft`, `open` or `closed`
+    pub status: Option<JobStatus>,
+    /// Updated application deadline
+    pub application_deadline: Option<DateTime<Utc>>,
+    /// Updated expiry date
+    pub expires_at: Option<DateTime<Utc>>,
+}
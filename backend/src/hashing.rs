@@ -0,0 +1,12 @@
+//! Stable hashing.
+//!
+//! Values hashed here are persisted (embedding content hashes) or must agree
+//! between instances (experiment buckets), so the hash cannot change across
+//! releases and platforms the way `std`'s hasher may.
+
+/// FNV-1a hash of a byte sequence.
+pub fn fnv1a<'a>(bytes: impl IntoIterator<Item = &'a u8>) -> u64 {
+    bytes.into_iter().fold(0xcbf2_9ce4_8422_2325, |hash, byte| {
+        (hash ^ u64::from(*byte)).wrapping_mul(0x0100_0000_01b3)
+    })
+}
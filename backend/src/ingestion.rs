@@ -0,0 +1,295 @@
+//! Scheduled ingestion of external job postings.
+//!
+//! A background task pulls every source in the [`SourceRegistry`] on an interval and
+//! upserts the postings into `external_jobs`, keyed by source and external
+//! ID. Postings that a source has not listed for a while are marked expired,
+//! and each fetch outcome is recorded in `external_job_sources` so the API
+//! can flag postings from failing sources as stale. Postings from sources
+//! that are no longer enabled are expired. Free-text salaries and locations are
+//! parsed into structured columns (see [`crate::salary`] and [`crate::locations`]), and skills are
+//! saved under their canonical names (see [`crate::skills`]). Each run ends by clustering
+//! duplicate postings across sources (see [`crate::job_dedup`]).
+
+use crate::errors::AppResult;
+use crate::job_dedup::run_dedup;
+use crate::locations::resolve_location;
+use crate::salary::parse_salary;
+use crate::skills::load_skill_taxonomy;
+use crate::job_sources::{DynJobSource, FetchedJob, SourceRegistry};
+use chrono::{DateTime, Utc};
+use sqlx::PgPool;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::task::JoinHandle;
+use tokio::time::MissedTickBehavior;
+use tracing::{debug, error, info, warn};
+
+/// Ingestion settings.
+#[derive(Debug, Clone)]
+pub struct IngestionConfig {
+    /// Time between ingestion runs
+    pub interval: Duration,
+    /// Postings not seen for this many days are marked expired
+    pub expire_after_days: i32,
+}
+
+/// Spawns the periodic ingestion task.
+///
+/// Each source's health is checked once, then the first run starts
+/// immediately so a fresh database is populated at startup.
+pub fn spawn_ingestion(
+    pool: PgPool,
+    registry: Arc<SourceRegistry>,
+    config: IngestionConfig,
+) -> JoinHandle<()> {
+    tokio::spawn(async move {
+        for source in registry.sources() {
+            match source.health().await {
+                Ok(()) => info!("✓ Job source {} is reachable", source.key()),
+                Err(e) => warn!("⚠ Job source {} is unhealthy: {}", source.key(), e),
+            }
+        }
+
+        let mut ticker = tokio::time::interval(config.interval);
+        ticker.set_missed_tick_behavior(MissedTickBehavior::Delay);
+
+        loop {
+            ticker.tick().await;
+            run_ingestion(&pool, &registry, config.expire_after_days).await;
+        }
+    })
+}
+
+/// Ingests every source once, then deduplicates the stored postings.
+///
+/// Failures are recorded per source and never abort the other sources.
+pub async fn run_ingestion(pool: &PgPool, registry: &SourceRegistry, expire_after_days: i32) {
+    match expire_disabled_sources(pool, &registry.keys()).await {
+        Ok(0) => {}
+        Ok(expired) => info!("Expired {} postings from disabled sources", expired),
+        Err(e) => error!("Failed to expire postings from disabled sources: {}", e),
+    }
+
+    for source in registry.sources() {
+        let source = source.as_ref();
+        let started = Utc::now();
+        let outcome = match source.fetch_jobs().await {
+            Ok(batch) => store_postings(pool, source, &batch.jobs, started, expire_after_days)
+                .await
+                .map(|expired| (batch.jobs.len(), batch.rejected, expired)),
+            Err(e) => Err(e),
+        };
+
+        let recorded = match outcome {
+            Ok((fetched, rejected, expired)) => {
+                info!(
+                    "Ingested {} postings from {} ({} rejected, {} expired)",
+                    fetched,
+                    source.key(),
+                    rejected,
+                    expired
+                );
+                record_success(pool, source, started, fetched).await
+            }
+            Err(e) => {
+                warn!("Ingestion from {} failed: {}", source.key(), e);
+                record_failure(pool, source, started, &e.to_string()).await
+            }
+        };
+
+        if let Err(e) = recorded {
+            error!("Failed to record ingestion status for {}: {}", source.key(), e);
+        }
+    }
+
+    if let Err(e) = run_dedup(pool).await {
+        error!("Failed to deduplicate external postings: {}", e);
+    }
+}
+
+/// Expires current postings from sources that are not enabled.
+///
+/// Returns the number of postings marked expired.
+async fn expire_disabled_sources(pool: &PgPool, enabled: &[String]) -> AppResult<u64> {
+    let result = sqlx::query!(
+        r#"
+        UPDATE external_jobs
+        SET expired_at = CURRENT_TIMESTAMP
+        WHERE expired_at IS NULL AND source <> ALL($1)
+        "#,
+        enabled
+    )
+    .execute(pool)
+    .await?;
+
+    Ok(result.rows_affected())
+}
+
+/// Upserts fetched postings and expires ones the source no longer lists.
+///
+/// Returns the number of postings newly marked expired.
+async fn store_postings(
+    pool: &PgPool,
+    source: &dyn DynJobSource,
+    jobs: &[FetchedJob],
+    seen_at: DateTime<Utc>,
+    expire_after_days: i32,
+) -> AppResult<u64> {
+    let skills = load_skill_taxonomy(pool).await?;
+    let mut tx = pool.begin().await?;
+
+    for FetchedJob { category, job } in jobs {
+        let salary = job.salary.as_deref().and_then(parse_salary);
+        let location = resolve_location(&job.location);
+        let job_skills = skills.normalize(&job.skills);
+
+        sqlx::query!(
+            r#"
+            INSERT INTO external_jobs (
+                source, external_id, category, title, company, location, description,
+                url, posted_date, source_label, job_type, experience_level, skills, salary,
+                salary_min, salary_max, salary_currency, salary_period, salary_negotiable,
+                work_arrangement, country, division, district, city, latitude, longitude,
+                first_seen_at, last_seen_at
+            )
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14,
+                $16, $17, $18, $19, COALESCE($20, FALSE),
+                $21, $22, $23, $24, $25, $26, $27, $15, $15)
+            ON CONFLICT (source, external_id) DO UPDATE
+            SET category = EXCLUDED.category,
+                title = EXCLUDED.title,
+                company = EXCLUDED.company,
+                location = EXCLUDED.location,
+                description = EXCLUDED.description,
+                url = EXCLUDED.url,
+                posted_date = EXCLUDED.posted_date,
+                source_label = EXCLUDED.source_label,
+                job_type = EXCLUDED.job_type,
+                experience_level = EXCLUDED.experience_level,
+                skills = EXCLUDED.skills,
+                salary = EXCLUDED.salary,
+                salary_min = EXCLUDED.salary_min,
+                salary_max = EXCLUDED.salary_max,
+                salary_currency = EXCLUDED.salary_currency,
+                salary_period = EXCLUDED.salary_period,
+                salary_negotiable = EXCLUDED.salary_negotiable,
+                work_arrangement = EXCLUDED.work_arrangement,
+                country = EXCLUDED.country,
+                division = EXCLUDED.division,
+                district = EXCLUDED.district,
+                city = EXCLUDED.city,
+                latitude = EXCLUDED.latitude,
+                longitude = EXCLUDED.longitude,
+                last_seen_at = EXCLUDED.last_seen_at,
+                expired_at = NULL
+            "#,
+            source.key(),
+            job.id,
+            *category as _,
+            job.title,
+            job.company,
+            job.location,
+            job.description,
+            job.url,
+            job.posted_date,
+            job.source,
+            job.job_type,
+            job.experience_level,
+            &job_skills,
+            job.salary,
+            seen_at,
+            salary.as_ref().and_then(|s| s.min),
+            salary.as_ref().and_then(|s| s.max),
+            salary.as_ref().map(|s| s.currency) as _,
+            salary.as_ref().map(|s| s.period) as _,
+            salary.as_ref().map(|s| s.negotiable),
+            location.work_arrangement as _,
+            location.country,
+            location.division,
+            location.district,
+            location.city,
+            location.latitude,
+            location.longitude
+        )
+        .execute(&mut *tx)
+        .await?;
+    }
+
+    let expired = sqlx::query!(
+        r#"
+        UPDATE external_jobs
+        SET expired_at = $2
+        WHERE source = $1
+          AND expired_at IS NULL
+          AND last_seen_at < $2::timestamptz - make_interval(days => $3)
+        "#,
+        source.key(),
+        seen_at,
+        expire_after_days
+    )
+    .execute(&mut *tx)
+    .await?
+    .rows_affected();
+
+    tx.commit().await?;
+
+    debug!("Stored {} postings from {}", jobs.len(), source.key());
+
+    Ok(expired)
+}
+
+/// Records a successful fetch.
+async fn record_success(
+    pool: &PgPool,
+    source: &dyn DynJobSource,
+    at: DateTime<Utc>,
+    fetched: usize,
+) -> AppResult<()> {
+    sqlx::query!(
+        r#"
+        INSERT INTO external_job_sources (
+            source, last_attempt_at, last_success_at, last_error, consecutive_failures, jobs_fetched
+        )
+        VALUES ($1, $2, $2, NULL, 0, $3)
+        ON CONFLICT (source) DO UPDATE
+        SET last_attempt_at = EXCLUDED.last_attempt_at,
+            last_success_at = EXCLUDED.last_success_at,
+            last_error = NULL,
+            consecutive_failures = 0,
+            jobs_fetched = EXCLUDED.jobs_fetched
+        "#,
+        source.key(),
+        at,
+        fetched as i32
+    )
+    .execute(pool)
+    .await?;
+
+    Ok(())
+}
+
+/// Records a failed fetch. Previously ingested postings are kept.
+async fn record_failure(
+    pool: &PgPool,
+    source: &dyn DynJobSource,
+    at: DateTime<Utc>,
+    error: &str,
+) -> AppResult<()> {
+    sqlx::query!(
+        r#"
+        INSERT INTO external_job_sources (source, last_attempt_at, last_error, consecutive_failures)
+        VALUES ($1, $2, $3, 1)
+        ON CONFLICT (source) DO UPDATE
+        SET last_attempt_at = EXCLUDED.last_attempt_at,
+            last_error = EXCLUDED.last_error,
+            consecutive_failures = external_job_sources.consecutive_failures + 1
+        "#,
+        source.key(),
+        at,
+        error
+    )
+    .execute(pool)
+    .await?;
+
+    Ok(())
+}
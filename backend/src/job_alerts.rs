@@ -0,0 +1,365 @@
+//! Background job alerts for saved searches.
+//!
+//! A periodic task checks every active saved search for jobs opened since it
+//! was last checked, writes a `job_alert` notification per new match and, when
+//! a mailer is configured, emails a digest of unsent alerts to users who
+//! opted in.
+
+use crate::ai_matching::{CandidateSkills, ExperienceRange, MatchInput};
+use crate::errors::AppResult;
+use crate::job_search::{JOB_COLUMNS, JobFilters};
+use crate::mailer::Mailer;
+use crate::match_strategy::{MatchStrategies, MatchStrategy};
+use crate::career_tracks::{TrackTaxonomy, load_taxonomy};
+use crate::models::{ExperienceLevel, Job, JobType, SavedSearch, SkillProficiency};
+use crate::skills::{SkillTaxonomy, load_skill_taxonomy};
+use chrono::{DateTime, Duration, Utc};
+use sqlx::types::Json as SqlJson;
+use sqlx::{FromRow, PgPool, Postgres, QueryBuilder};
+use std::collections::HashMap;
+use std::sync::Arc;
+use tokio::task::JoinHandle;
+use tokio::time::MissedTickBehavior;
+use tracing::{debug, error, info, warn};
+use uuid::Uuid;
+
+/// How far before `last_checked_at` each check looks back.
+///
+/// Covers postings whose transaction committed after a previous run started.
+/// Repeats are dropped by the unique (saved_search_id, job_id) index.
+const CHECK_OVERLAP_MINUTES: i64 = 5;
+
+/// Maximum number of new jobs announced per saved search per run.
+///
+/// Jobs past the limit are announced by the following runs.
+const MAX_ALERTS_PER_SEARCH: u64 = 20;
+
+/// Number of jobs loaded at a time while checking a saved search.
+const JOBS_PAGE_SIZE: i64 = 100;
+
+/// Unsent alerts older than this are not included in email digests.
+const DIGEST_MAX_AGE_DAYS: i32 = 7;
+
+/// Outcome of a single job alert run.
+#[derive(Debug, Default)]
+pub struct JobAlertRun {
+    /// Number of active saved searches checked
+    pub searches_checked: usize,
+    /// Number of notifications created
+    pub alerts_created: u64,
+    /// Number of digest emails sent
+    pub digests_sent: usize,
+}
+
+/// Job checked against a saved search, with the time it was opened.
+#[derive(FromRow)]
+struct PublishedJob {
+    #[sqlx(flatten)]
+    job: Job,
+    published_at: DateTime<Utc>,
+}
+
+/// Profile fields needed to score a job for a user.
+struct MatchProfile {
+    skills: Vec<String>,
+    skill_proficiency: HashMap<String, SkillProficiency>,
+    experience: Option<ExperienceRange>,
+    preferred_track: Option<String>,
+    /// The user's match strategy (see [`MatchStrategies::for_user`])
+    strategy: Arc<dyn MatchStrategy>,
+}
+
+/// Spawns the periodic job alert task.
+pub fn spawn_job_alerts(
+    pool: PgPool,
+    mailer: Option<Mailer>,
+    strategies: Arc<MatchStrategies>,
+    interval: std::time::Duration,
+) -> JoinHandle<()> {
+    tokio::spawn(async move {
+        let mut ticker = tokio::time::interval(interval);
+        ticker.set_missed_tick_behavior(MissedTickBehavior::Delay);
+
+        loop {
+            ticker.tick().await;
+            match run_job_alerts(&pool, mailer.as_ref(), &strategies).await {
+                Ok(run) if run.alerts_created > 0 || run.digests_sent > 0 => info!(
+                    "Job alerts: checked {} searches, created {} alerts, sent {} digests",
+                    run.searches_checked, run.alerts_created, run.digests_sent
+                ),
+                Ok(run) => debug!("Job alerts: checked {} searches, nothing new", run.searches_checked),
+                Err(e) => error!("Job alert run failed: {}", e),
+            }
+        }
+    })
+}
+
+/// Checks all active saved searches once and sends pending digests.
+///
+/// # Errors
+///
+/// Returns an error if the saved searches cannot be loaded. Failures for a
+/// single search or digest are logged and skipped.
+pub async fn run_job_alerts(
+    pool: &PgPool,
+    mailer: Option<&Mailer>,
+    strategies: &MatchStrategies,
+) -> AppResult<JobAlertRun> {
+    let run_started = Utc::now();
+
+    let searches = sqlx::query_as!(
+        SavedSearch,
+        r#"
+        SELECT
+            id, user_id, name, query,
+            job_type as "job_type: JobType",
+            experience_level as "experience_level: ExperienceLevel",
+            location, salary_min, salary_max, min_match_score,
+            email_digest, is_active, last_checked_at, created_at, updated_at
+        FROM saved_searches
+        WHERE is_active
+        ORDER BY id
+        "#
+    )
+    .fetch_all(pool)
+    .await?;
+
+    let mut run = JobAlertRun {
+        searches_checked: searches.len(),
+        ..Default::default()
+    };
+    let tracks = load_taxonomy(pool).await?;
+    let skills = load_skill_taxonomy(pool).await?;
+
+    for search in &searches {
+        match check_saved_search(pool, strategies, &skills, &tracks, search, run_started).await {
+            Ok(created) => run.alerts_created += created,
+            Err(e) => warn!("Failed to check saved search {}: {}", search.id, e),
+        }
+    }
+
+    if let Some(mailer) = mailer {
+        run.digests_sent = send_digests(pool, mailer).await?;
+    }
+
+    Ok(run)
+}
+
+/// Loads the profile fields and strategy used for match scoring.
+async fn load_match_profile(
+    pool: &PgPool,
+    strategies: &MatchStrategies,
+    user_id: Uuid,
+) -> AppResult<MatchProfile> {
+    let row = sqlx::query!(
+        r#"
+        SELECT
+            skills, skill_proficiency as "skill_proficiency: SqlJson<HashMap<String, SkillProficiency>>",
+            experience_level as "experience_level: ExperienceLevel", experience_years,
+            preferred_track
+        FROM users
+        WHERE id = $1
+        "#,
+        user_id
+    )
+    .fetch_one(pool)
+    .await?;
+
+    Ok(MatchProfile {
+        skills: row.skills,
+        skill_proficiency: row.skill_proficiency.0,
+        experience: ExperienceRange::candidate(row.experience_level, row.experience_years),
+        preferred_track: row.preferred_track,
+        strategy: strategies.for_user(pool, user_id).await?,
+    })
+}
+
+/// Announces jobs opened since the search was last checked.
+///
+/// Jobs are checked oldest first, a page at a time, until none are left or
+/// [`MAX_ALERTS_PER_SEARCH`] alerts were created. In the latter case the
+/// search is marked as checked up to the last job announced, so the next run
+/// picks up where this one stopped.
+///
+/// Returns the number of notifications created.
+async fn check_saved_search(
+    pool: &PgPool,
+    strategies: &MatchStrategies,
+    skills: &SkillTaxonomy,
+    tracks: &TrackTaxonomy,
+    search: &SavedSearch,
+    run_started: DateTime<Utc>,
+) -> AppResult<u64> {
+    let mut filters = JobFilters::from(search);
+    // Never look back past the moment the search was saved
+    let lookback = search.last_checked_at - Duration::minutes(CHECK_OVERLAP_MINUTES);
+    filters.published_after = Some(search.created_at.map_or(lookback, |saved| lookback.max(saved)));
+
+    let mut profile = None;
+    let mut checked_until = run_started;
+    let mut after: Option<(DateTime<Utc>, i32)> = None;
+    let mut created = 0;
+
+    'pages: loop {
+        let mut query = QueryBuilder::<Postgres>::new("SELECT ");
+        query
+            .push(JOB_COLUMNS)
+            .push(", published_at FROM jobs WHERE published_at <= ")
+            .push_bind(run_started);
+        if let Some((published_at, id)) = after {
+            query
+                .push(" AND (published_at, id) > (")
+                .push_bind(published_at)
+                .push(", ")
+                .push_bind(id)
+                .push(")");
+        }
+        filters.push_conditions(&mut query);
+        query
+            .push(" ORDER BY published_at, id LIMIT ")
+            .push_bind(JOBS_PAGE_SIZE);
+
+        let page: Vec<PublishedJob> = query.build_query_as().fetch_all(pool).await?;
+        let last_page = page.len() < JOBS_PAGE_SIZE as usize;
+
+        if profile.is_none() && search.min_match_score.is_some() && !page.is_empty() {
+            profile = Some(load_match_profile(pool, strategies, search.user_id).await?);
+        }
+
+        for PublishedJob { job, published_at } in page {
+            after = Some((published_at, job.id));
+
+            let score = profile.as_ref().map(|profile| {
+                let input = MatchInput::new(
+                    CandidateSkills {
+                        skills: &profile.skills,
+                        proficiency: &profile.skill_proficiency,
+                    },
+                    profile.experience,
+                    profile.preferred_track.as_deref(),
+                    &job,
+                );
+                profile.strategy.score(&input, skills, tracks).match_score
+            });
+
+            if let (Some(min), Some(score)) = (search.min_match_score, score)
+                && score < min
+            {
+                continue;
+            }
+
+            let title = format!("New job for \"{}\"", search.name);
+            let message = match score {
+                Some(score) => format!(
+                    "{} at {} ({}) - {:.0}% match",
+                    job.job_title, job.company, job.location, score
+                ),
+                None => format!("{} at {} ({})", job.job_title, job.company, job.location),
+            };
+
+            let result = sqlx::query!(
+                r#"
+                INSERT INTO notifications (user_id, title, message, type, job_id, saved_search_id)
+                VALUES ($1, $2, $3, 'job_alert', $4, $5)
+                ON CONFLICT (saved_search_id, job_id) DO NOTHING
+                "#,
+                search.user_id,
+                title,
+                message,
+                job.id,
+                search.id
+            )
+            .execute(pool)
+            .await?;
+
+            created += result.rows_affected();
+            if created >= MAX_ALERTS_PER_SEARCH {
+                checked_until = published_at;
+                break 'pages;
+            }
+        }
+
+        if last_page {
+            break;
+        }
+    }
+
+    sqlx::query!(
+        "UPDATE saved_searches SET last_checked_at = $1 WHERE id = $2",
+        checked_until,
+        search.id
+    )
+    .execute(pool)
+    .await?;
+
+    if created > 0 {
+        debug!("Saved search {} raised {} alerts", search.id, created);
+    }
+
+    Ok(created)
+}
+
+/// Emails one digest per user covering their unsent job alerts.
+///
+/// Alerts are marked as emailed only after the digest was accepted by the
+/// SMTP server, so failed digests are retried on the next run.
+///
+/// Returns the number of digests sent.
+async fn send_digests(pool: &PgPool, mailer: &Mailer) -> AppResult<usize> {
+    let pending = sqlx::query!(
+        r#"
+        SELECT n.id, n.user_id, n.message, s.name as search_name, u.email, u.full_name
+        FROM notifications n
+        JOIN saved_searches s ON s.id = n.saved_search_id
+        JOIN users u ON u.id = n.user_id
+        WHERE n.type = 'job_alert'
+          AND n.emailed_at IS NULL
+          AND s.email_digest
+          AND n.created_at >= CURRENT_TIMESTAMP - make_interval(days => $1)
+        ORDER BY n.user_id, s.name, n.created_at
+        "#,
+        DIGEST_MAX_AGE_DAYS
+    )
+    .fetch_all(pool)
+    .await?;
+
+    let mut sent = 0;
+    for user_alerts in pending.chunk_by(|a, b| a.user_id == b.user_id) {
+        let first = &user_alerts[0];
+
+        let mut body = format!(
+            "Hi {},\n\nNew jobs matching your saved searches:\n",
+            first.full_name
+        );
+        let mut current_search = None;
+        for alert in user_alerts {
+            if current_search != Some(&alert.search_name) {
+                body.push_str(&format!("\n{}\n", alert.search_name));
+                current_search = Some(&alert.search_name);
+            }
+            body.push_str(&format!("  - {}\n", alert.message));
+        }
+        body.push_str("\nOpen CareerBridge to see details and apply.\n");
+
+        let subject = match user_alerts.len() {
+            1 => "1 new job alert on CareerBridge".to_string(),
+            n => format!("{} new job alerts on CareerBridge", n),
+        };
+        if let Err(e) = mailer.send(&first.email, &subject, body).await {
+            warn!("Failed to send job alert digest to user {}: {}", first.user_id, e);
+            continue;
+        }
+
+        let ids: Vec<i32> = user_alerts.iter().map(|a| a.id).collect();
+        sqlx::query!(
+            "UPDATE notifications SET emailed_at = CURRENT_TIMESTAMP WHERE id = ANY($1)",
+            &ids
+        )
+        .execute(pool)
+        .await?;
+
+        sent += 1;
+    }
+
+    Ok(sent)
+}
@@ -0,0 +1,297 @@
+//! Cross-source deduplication of external job postings.
+//!
+//! The same vacancy is often listed by several sources (ReliefWeb, job boards,
+//! the employer's own site). After each ingestion run, current postings are
+//! fingerprinted on their normalized title, company and location. Postings
+//! from different sources sharing a fingerprint are clustered when their
+//! descriptions are similar enough, and the earliest-seen posting of each
+//! cluster becomes canonical: the API lists only canonical postings, together
+//! with the links of every posting in their cluster. Postings from the same
+//! source are never merged, as a source lists each vacancy once; two of them
+//! with the same fingerprint are separate openings.
+//!
+//! Comparisons are appended to `external_job_merges`, whether or not they led
+//! to a merge, whenever a pair's decision changes, so the history of
+//! clustering decisions can be audited.
+
+use crate::errors::AppResult;
+use sqlx::PgPool;
+use std::collections::{BTreeMap, HashSet};
+use tracing::{debug, info};
+
+/// Minimum description similarity (Jaccard index of word sets) for two
+/// postings with the same fingerprint to be merged.
+const SIMILARITY_THRESHOLD: f32 = 0.5;
+
+/// Descriptions with fewer distinct words than this are not compared;
+/// postings with such descriptions are never merged.
+const MIN_DESCRIPTION_WORDS: usize = 10;
+
+/// Words dropped from company names, so "Acme Ltd." matches "ACME Limited".
+const COMPANY_NOISE_WORDS: [&str; 10] = [
+    "the", "ltd", "limited", "plc", "inc", "pvt", "private", "co", "company", "bangladesh",
+];
+
+/// Location parts dropped before fingerprinting, so "Dhaka, Bangladesh"
+/// matches "Dhaka".
+const LOCATION_NOISE_WORDS: [&str; 3] = ["bangladesh", "division", "district"];
+
+/// Outcome of a deduplication run.
+#[derive(Debug, Default)]
+pub struct DedupRun {
+    /// Number of current postings considered
+    pub postings: usize,
+    /// Number of canonical postings after clustering
+    pub clusters: usize,
+    /// Number of postings merged into another posting
+    pub duplicates: usize,
+}
+
+/// A current posting as loaded for deduplication.
+struct Posting {
+    id: i32,
+    source: String,
+    title: String,
+    company: String,
+    location: String,
+    description: String,
+}
+
+/// A comparison between a posting and a cluster's canonical posting.
+struct Decision {
+    canonical_id: i32,
+    duplicate_id: i32,
+    fingerprint: String,
+    similarity: Option<f32>,
+    merged: bool,
+}
+
+/// Clusters current external postings and stores the canonical assignments.
+///
+/// Expired postings are never canonical and never duplicates; their previous
+/// assignments are cleared so a remaining duplicate becomes canonical.
+pub async fn run_dedup(pool: &PgPool) -> AppResult<DedupRun> {
+    let postings = sqlx::query_as!(
+        Posting,
+        r#"
+        SELECT id, source, title, company, location, description
+        FROM external_jobs
+        WHERE expired_at IS NULL
+        ORDER BY first_seen_at, id
+        "#
+    )
+    .fetch_all(pool)
+    .await?;
+
+    // Group by fingerprint, keeping the first-seen order within each group
+    let mut groups: BTreeMap<String, Vec<&Posting>> = BTreeMap::new();
+    for posting in &postings {
+        groups.entry(fingerprint(posting)).or_default().push(posting);
+    }
+
+    let mut ids = Vec::with_capacity(postings.len());
+    let mut fingerprints = Vec::with_capacity(postings.len());
+    let mut canonical_ids: Vec<Option<i32>> = Vec::with_capacity(postings.len());
+    let mut decisions = Vec::new();
+    let mut run = DedupRun {
+        postings: postings.len(),
+        ..Default::default()
+    };
+
+    for (key, group) in &groups {
+        // Canonical postings of this group's clusters, their word sets and
+        // the sources of their postings
+        let mut clusters: Vec<(&Posting, HashSet<String>, HashSet<&str>)> = Vec::new();
+
+        for posting in group {
+            let words = description_words(&posting.description);
+            let mut canonical = None;
+
+            for (candidate, candidate_words, sources) in &mut clusters {
+                if sources.contains(posting.source.as_str()) {
+                    continue;
+                }
+                let similarity = similarity(&words, candidate_words);
+                let merged = similarity.is_some_and(|s| s >= SIMILARITY_THRESHOLD);
+                decisions.push(Decision {
+                    canonical_id: candidate.id,
+                    duplicate_id: posting.id,
+                    fingerprint: key.clone(),
+                    similarity,
+                    merged,
+                });
+                if merged {
+                    canonical = Some(candidate.id);
+                    sources.insert(posting.source.as_str());
+                    break;
+                }
+            }
+
+            ids.push(posting.id);
+            fingerprints.push(key.clone());
+            canonical_ids.push(canonical);
+
+            if canonical.is_some() {
+                run.duplicates += 1;
+            } else {
+                clusters.push((posting, words, HashSet::from([posting.source.as_str()])));
+            }
+        }
+
+        run.clusters += clusters.len();
+    }
+
+    store_clusters(pool, &ids, &fingerprints, &canonical_ids, &decisions).await?;
+
+    info!(
+        "Deduplicated {} external postings into {} ({} duplicates)",
+        run.postings, run.clusters, run.duplicates
+    );
+
+    Ok(run)
+}
+
+/// Stores canonical assignments and records the decisions.
+async fn store_clusters(
+    pool: &PgPool,
+    ids: &[i32],
+    fingerprints: &[String],
+    canonical_ids: &[Option<i32>],
+    decisions: &[Decision],
+) -> AppResult<()> {
+    let mut tx = pool.begin().await?;
+
+    sqlx::query!(
+        r#"
+        UPDATE external_jobs
+        SET canonical_id = NULL
+        WHERE expired_at IS NOT NULL AND canonical_id IS NOT NULL
+        "#
+    )
+    .execute(&mut *tx)
+    .await?;
+
+    sqlx::query!(
+        r#"
+        UPDATE external_jobs j
+        SET fingerprint = u.fingerprint, canonical_id = u.canonical_id
+        FROM UNNEST($1::int[], $2::text[], $3::int[]) AS u(id, fingerprint, canonical_id)
+        WHERE j.id = u.id
+          AND (j.fingerprint IS DISTINCT FROM u.fingerprint
+               OR j.canonical_id IS DISTINCT FROM u.canonical_id)
+        "#,
+        ids,
+        fingerprints,
+        canonical_ids as &[Option<i32>]
+    )
+    .execute(&mut *tx)
+    .await?;
+
+    // A pair's decision is appended when it differs from the last one recorded
+    let canonical: Vec<i32> = decisions.iter().map(|d| d.canonical_id).collect();
+    let duplicate: Vec<i32> = decisions.iter().map(|d| d.duplicate_id).collect();
+    let fingerprint: Vec<String> = decisions.iter().map(|d| d.fingerprint.clone()).collect();
+    let similarity: Vec<Option<f32>> = decisions.iter().map(|d| d.similarity).collect();
+    let merged: Vec<bool> = decisions.iter().map(|d| d.merged).collect();
+
+    let recorded = sqlx::query!(
+        r#"
+        INSERT INTO external_job_merges (canonical_id, duplicate_id, fingerprint, similarity, merged)
+        SELECT u.canonical_id, u.duplicate_id, u.fingerprint, u.similarity, u.merged
+        FROM UNNEST($1::int[], $2::int[], $3::text[], $4::real[], $5::bool[])
+            AS u(canonical_id, duplicate_id, fingerprint, similarity, merged)
+        WHERE (
+            SELECT m.merged
+            FROM external_job_merges m
+            WHERE m.canonical_id = u.canonical_id AND m.duplicate_id = u.duplicate_id
+            ORDER BY m.decided_at DESC, m.id DESC
+            LIMIT 1
+        ) IS DISTINCT FROM u.merged
+        "#,
+        &canonical,
+        &duplicate,
+        &fingerprint,
+        &similarity as &[Option<f32>],
+        &merged
+    )
+    .execute(&mut *tx)
+    .await?
+    .rows_affected();
+
+    tx.commit().await?;
+
+    debug!("Recorded {} new deduplication decisions", recorded);
+
+    Ok(())
+}
+
+/// Fingerprint of a posting: normalized title, company and location.
+fn fingerprint(posting: &Posting) -> String {
+    format!(
+        "{}|{}|{}",
+        normalize_words(&posting.title, &[]),
+        normalize_words(&posting.company, &COMPANY_NOISE_WORDS),
+        normalize_location(&posting.location)
+    )
+}
+
+/// Lowercased alphanumeric words without noise words, sorted and deduplicated
+/// so word order and punctuation do not matter.
+fn normalize_words(text: &str, noise: &[&str]) -> String {
+    let mut words: Vec<String> = text
+        .split(|c: char| !c.is_alphanumeric())
+        .filter(|w| !w.is_empty())
+        .map(str::to_lowercase)
+        .filter(|w| !noise.contains(&w.as_str()))
+        .collect();
+    words.sort();
+    words.dedup();
+    words.join(" ")
+}
+
+/// Normalized city of a location: its first part that is not a country or
+/// administrative suffix.
+fn normalize_location(location: &str) -> String {
+    location
+        .split([',', '/', '-', '('])
+        .map(|part| normalize_words(part, &LOCATION_NOISE_WORDS))
+        .find(|part| !part.is_empty())
+        .unwrap_or_default()
+}
+
+/// Distinct words of a description, ignoring HTML tag names and short words.
+fn description_words(description: &str) -> HashSet<String> {
+    let mut words = HashSet::new();
+    let mut in_tag = false;
+    let mut word = String::new();
+
+    for c in description.chars().chain(std::iter::once(' ')) {
+        match c {
+            '<' => in_tag = true,
+            '>' => in_tag = false,
+            _ => {}
+        }
+
+        if !in_tag && c.is_alphanumeric() {
+            word.extend(c.to_lowercase());
+        } else if word.chars().count() >= 3 {
+            words.insert(std::mem::take(&mut word));
+        } else {
+            word.clear();
+        }
+    }
+
+    words
+}
+
+/// Jaccard similarity of two descriptions' word sets, or `None` when either
+/// description is too short to compare.
+fn similarity(a: &HashSet<String>, b: &HashSet<String>) -> Option<f32> {
+    if a.len() < MIN_DESCRIPTION_WORDS || b.len() < MIN_DESCRIPTION_WORDS {
+        return None;
+    }
+
+    let shared = a.intersection(b).count();
+    let total = a.len() + b.len() - shared;
+    Some(shared as f32 / total as f32)
+}
@@ -0,0 +1,49 @@
+//! Automatic expiry of job postings.
+//!
+//! A periodic task marks open jobs `expired` once their application deadline
+//! or expiry date has passed, so they drop out of search, recommendations,
+//! alerts and skill-gap analysis, which only consider open jobs.
+
+use crate::errors::AppResult;
+use sqlx::PgPool;
+use tokio::task::JoinHandle;
+use tokio::time::MissedTickBehavior;
+use tracing::{error, info};
+
+/// Spawns the periodic job expiry task.
+pub fn spawn_job_expiry(pool: PgPool, interval: std::time::Duration) -> JoinHandle<()> {
+    tokio::spawn(async move {
+        let mut ticker = tokio::time::interval(interval);
+        ticker.set_missed_tick_behavior(MissedTickBehavior::Delay);
+
+        loop {
+            ticker.tick().await;
+            if let Err(e) = expire_jobs(&pool).await {
+                error!("Job expiry run failed: {}", e);
+            }
+        }
+    })
+}
+
+/// Marks open jobs past their application deadline or expiry date as expired.
+///
+/// Returns the number of jobs expired.
+pub async fn expire_jobs(pool: &PgPool) -> AppResult<u64> {
+    let expired = sqlx::query!(
+        r#"
+        UPDATE jobs
+        SET status = 'expired'
+        WHERE status = 'open'
+          AND (application_deadline <= CURRENT_TIMESTAMP OR expires_at <= CURRENT_TIMESTAMP)
+        "#
+    )
+    .execute(pool)
+    .await?
+    .rows_affected();
+
+    if expired > 0 {
+        info!("Expired {} jobs past their deadline or expiry date", expired);
+    }
+
+    Ok(expired)
+}
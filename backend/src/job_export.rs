@@ -0,0 +1,427 @@
+//! Job export for partner sites.
+//!
+//! Open jobs are published as schema.org `JobPosting` JSON-LD, one document
+//! per job, and as RSS 2.0, Atom 1.0 and JSON Feed 1.1 feeds. Every format is
+//! generated from the [`Job`] model: salary as `baseSalary`, [`JobType`] as
+//! `employmentType`, and the structured location as `jobLocation` (or
+//! `TELECOMMUTE` for remote jobs). JSON Feed items carry the JSON-LD of their
+//! job in a `_job_posting` extension.
+//!
+//! Links point at the frontend (`FRONTEND_URL`).
+
+use crate::job_sources::FeedFormat;
+use crate::locations::COUNTRY;
+use crate::models::{Job, JobType, SalaryCurrency, SalaryPeriod, WorkArrangement};
+use serde_json::{Map, Value, json};
+
+/// Feed title.
+const FEED_TITLE: &str = "CareerBridge Jobs";
+
+/// Feed description.
+const FEED_DESCRIPTION: &str = "Open jobs posted on CareerBridge";
+
+/// Hiring organisation details beyond the company name stored on the job.
+#[derive(Debug, Default, Clone)]
+pub struct HiringOrganization {
+    /// Company website
+    pub website: Option<String>,
+    /// URL of the company logo
+    pub logo_url: Option<String>,
+}
+
+/// A job with what export needs besides the model.
+pub struct ExportedJob<'a> {
+    /// The job
+    pub job: &'a Job,
+    /// The job's company, when it is linked to a company profile
+    pub organization: Option<&'a HiringOrganization>,
+}
+
+/// Base URL of the frontend, without a trailing slash.
+pub fn frontend_url() -> String {
+    std::env::var("FRONTEND_URL")
+        .unwrap_or_else(|_| "http://localhost:3001".to_string())
+        .trim_end_matches('/')
+        .to_string()
+}
+
+/// Link to a job on the frontend.
+pub fn job_url(base_url: &str, job_id: i32) -> String {
+    format!("{}/jobs?job={}", base_url, job_id)
+}
+
+/// Builds the schema.org `JobPosting` JSON-LD for a job.
+pub fn job_posting(exported: &ExportedJob, base_url: &str) -> Value {
+    let job = exported.job;
+    let mut posting = Map::new();
+    posting.insert("@context".into(), json!("https://schema.org/"));
+    posting.insert("@type".into(), json!("JobPosting"));
+    posting.insert("title".into(), json!(job.job_title));
+    posting.insert("description".into(), json!(job.job_description));
+    posting.insert(
+        "identifier".into(),
+        json!({ "@type": "PropertyValue", "name": job.company, "value": job.id.to_string() }),
+    );
+    posting.insert("url".into(), json!(job_url(base_url, job.id)));
+    if let Some(created_at) = job.created_at {
+        posting.insert("datePosted".into(), json!(created_at.to_rfc3339()));
+    }
+    let valid_through = match (job.application_deadline, job.expires_at) {
+        (Some(deadline), Some(expires)) => Some(deadline.min(expires)),
+        (deadline, expires) => deadline.or(expires),
+    };
+    if let Some(valid_through) = valid_through {
+        posting.insert("validThrough".into(), json!(valid_through.to_rfc3339()));
+    }
+    posting.insert("employmentType".into(), json!(employment_type(job.job_type)));
+
+    let mut organization = Map::new();
+    organization.insert("@type".into(), json!("Organization"));
+    organization.insert("name".into(), json!(job.company));
+    if let Some(details) = exported.organization {
+        if let Some(website) = &details.website {
+            organization.insert("sameAs".into(), json!(website));
+        }
+        if let Some(logo_url) = &details.logo_url {
+            organization.insert("logo".into(), json!(logo_url));
+        }
+    }
+    posting.insert("hiringOrganization".into(), Value::Object(organization));
+
+    if job.work_arrangement == WorkArrangement::Remote {
+        posting.insert("jobLocationType".into(), json!("TELECOMMUTE"));
+        posting.insert(
+            "applicantLocationRequirements".into(),
+            json!({ "@type": "Country", "name": job.country.as_deref().unwrap_or(COUNTRY) }),
+        );
+    } else {
+        posting.insert("jobLocation".into(), job_location(job));
+    }
+
+    if let Some(salary) = base_salary(job) {
+        posting.insert("baseSalary".into(), salary);
+    }
+    if !job.required_skills.is_empty() {
+        posting.insert("skills".into(), json!(job.required_skills.join(", ")));
+    }
+    if !job.responsibilities.is_empty() {
+        posting.insert("responsibilities".into(), json!(job.responsibilities.join("\n")));
+    }
+    if !job.requirements.is_empty() {
+        posting.insert("qualifications".into(), json!(job.requirements.join("\n")));
+    }
+    if !job.benefits.is_empty() {
+        posting.insert("jobBenefits".into(), json!(job.benefits.join("\n")));
+    }
+
+    Value::Object(posting)
+}
+
+/// Renders jobs as a feed in the given format.
+///
+/// `feed_url` is the URL the feed is served at, used for self links.
+pub fn render_feed(
+    format: FeedFormat,
+    jobs: &[ExportedJob],
+    base_url: &str,
+    feed_url: &str,
+) -> String {
+    match format {
+        FeedFormat::Rss => rss(jobs, base_url, feed_url),
+        FeedFormat::Atom => atom(jobs, base_url, feed_url),
+        FeedFormat::Json => json_feed(jobs, base_url, feed_url),
+    }
+}
+
+/// Content type of a feed format.
+pub fn content_type(format: FeedFormat) -> &'static str {
+    match format {
+        FeedFormat::Rss => "application/rss+xml; charset=utf-8",
+        FeedFormat::Atom => "application/atom+xml; charset=utf-8",
+        FeedFormat::Json => "application/feed+json; charset=utf-8",
+    }
+}
+
+/// Renders an RSS 2.0 feed.
+fn rss(jobs: &[ExportedJob], base_url: &str, feed_url: &str) -> String {
+    let mut xml = String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
+    xml.push_str("<rss version=\"2.0\" xmlns:atom=\"http://www.w3.org/2005/Atom\">\n<channel>\n");
+    xml.push_str(&format!("<title>{}</title>\n", escape_xml(FEED_TITLE)));
+    xml.push_str(&format!("<link>{}/jobs</link>\n", escape_xml(base_url)));
+    xml.push_str(&format!("<description>{}</description>\n", escape_xml(FEED_DESCRIPTION)));
+    xml.push_str(&format!(
+        "<atom:link href=\"{}\" rel=\"self\" type=\"application/rss+xml\"/>\n",
+        escape_xml(feed_url)
+    ));
+    if let Some(updated) = jobs.iter().filter_map(|j| j.job.created_at).max() {
+        xml.push_str(&format!("<lastBuildDate>{}</lastBuildDate>\n", updated.to_rfc2822()));
+    }
+
+    for ExportedJob { job, .. } in jobs {
+        let url = job_url(base_url, job.id);
+        xml.push_str("<item>\n");
+        xml.push_str(&format!("<title>{}</title>\n", escape_xml(&item_title(job))));
+        xml.push_str(&format!("<link>{}</link>\n", escape_xml(&url)));
+        xml.push_str(&format!(
+            "<guid isPermaLink=\"false\">careerbridge-job-{}</guid>\n",
+            job.id
+        ));
+        if let Some(created_at) = job.created_at {
+            xml.push_str(&format!("<pubDate>{}</pubDate>\n", created_at.to_rfc2822()));
+        }
+        xml.push_str(&format!("<description>{}</description>\n", escape_xml(&summary(job))));
+        for skill in &job.required_skills {
+            xml.push_str(&format!("<category>{}</category>\n", escape_xml(skill)));
+        }
+        xml.push_str("</item>\n");
+    }
+
+    xml.push_str("</channel>\n</rss>\n");
+    xml
+}
+
+/// Renders an Atom 1.0 feed.
+fn atom(jobs: &[ExportedJob], base_url: &str, feed_url: &str) -> String {
+    let updated = jobs
+        .iter()
+        .filter_map(|j| j.job.created_at)
+        .max()
+        .unwrap_or_else(chrono::Utc::now);
+
+    let mut xml = String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
+    xml.push_str("<feed xmlns=\"http://www.w3.org/2005/Atom\">\n");
+    xml.push_str(&format!("<id>{}</id>\n", escape_xml(feed_url)));
+    xml.push_str(&format!("<title>{}</title>\n", escape_xml(FEED_TITLE)));
+    xml.push_str(&format!("<subtitle>{}</subtitle>\n", escape_xml(FEED_DESCRIPTION)));
+    xml.push_str(&format!("<updated>{}</updated>\n", updated.to_rfc3339()));
+    xml.push_str(&format!("<link rel=\"self\" href=\"{}\"/>\n", escape_xml(feed_url)));
+    xml.push_str(&format!(
+        "<link rel=\"alternate\" href=\"{}/jobs\"/>\n",
+        escape_xml(base_url)
+    ));
+
+    for ExportedJob { job, .. } in jobs {
+        let url = job_url(base_url, job.id);
+        let published = job.created_at.unwrap_or(updated).to_rfc3339();
+        xml.push_str("<entry>\n");
+        xml.push_str(&format!("<id>{}</id>\n", escape_xml(&url)));
+        xml.push_str(&format!("<title>{}</title>\n", escape_xml(&item_title(job))));
+        xml.push_str(&format!("<link rel=\"alternate\" href=\"{}\"/>\n", escape_xml(&url)));
+        xml.push_str(&format!("<published>{}</published>\n", published));
+        xml.push_str(&format!("<updated>{}</updated>\n", published));
+        xml.push_str(&format!(
+            "<author><name>{}</name></author>\n",
+            escape_xml(&job.company)
+        ));
+        xml.push_str(&format!("<summary>{}</summary>\n", escape_xml(&summary(job))));
+        for skill in &job.required_skills {
+            xml.push_str(&format!("<category term=\"{}\"/>\n", escape_xml(skill)));
+        }
+        xml.push_str("</entry>\n");
+    }
+
+    xml.push_str("</feed>\n");
+    xml
+}
+
+/// Renders a JSON Feed 1.1.
+fn json_feed(jobs: &[ExportedJob], base_url: &str, feed_url: &str) -> String {
+    let items: Vec<Value> = jobs
+        .iter()
+        .map(|exported| {
+            let job = exported.job;
+            json!({
+                "id": format!("careerbridge-job-{}", job.id),
+                "url": job_url(base_url, job.id),
+                "title": item_title(job),
+                "content_text": summary(job),
+                "date_published": job.created_at.map(|d| d.to_rfc3339()),
+                "authors": [{ "name": job.company }],
+                "tags": job.required_skills,
+                "_job_posting": job_posting(exported, base_url),
+            })
+        })
+        .collect();
+
+    json!({
+        "version": "https://jsonfeed.org/version/1.1",
+        "title": FEED_TITLE,
+        "description": FEED_DESCRIPTION,
+        "home_page_url": format!("{}/jobs", base_url),
+        "feed_url": feed_url,
+        "items": items,
+    })
+    .to_string()
+}
+
+/// Maps a job type to a schema.org employment type.
+fn employment_type(job_type: JobType) -> &'static str {
+    match job_type {
+        JobType::Internship => "INTERN",
+        JobType::PartTime => "PART_TIME",
+        JobType::FullTime => "FULL_TIME",
+        JobType::Freelance => "CONTRACTOR",
+    }
+}
+
+/// Human-readable job type.
+fn job_type_label(job_type: JobType) -> &'static str {
+    match job_type {
+        JobType::Internship => "Internship",
+        JobType::PartTime => "Part-time",
+        JobType::FullTime => "Full-time",
+        JobType::Freelance => "Freelance",
+    }
+}
+
+/// Builds the schema.org `Place` of an on-site or hybrid job.
+fn job_location(job: &Job) -> Value {
+    let mut address = Map::new();
+    address.insert("@type".into(), json!("PostalAddress"));
+    match job.city.as_deref().or(job.district.as_deref()) {
+        Some(locality) => address.insert("addressLocality".into(), json!(locality)),
+        None => address.insert("addressLocality".into(), json!(job.location)),
+    };
+    if let Some(division) = &job.division {
+        address.insert("addressRegion".into(), json!(division));
+    }
+    if let Some(country) = &job.country {
+        let country = if country == COUNTRY { "BD" } else { country.as_str() };
+        address.insert("addressCountry".into(), json!(country));
+    }
+
+    let mut place = Map::new();
+    place.insert("@type".into(), json!("Place"));
+    place.insert("address".into(), Value::Object(address));
+    if let (Some(latitude), Some(longitude)) = (job.latitude, job.longitude) {
+        place.insert(
+            "geo".into(),
+            json!({ "@type": "GeoCoordinates", "latitude": latitude, "longitude": longitude }),
+        );
+    }
+    Value::Object(place)
+}
+
+/// Builds the schema.org `MonetaryAmount` of a job's salary, if it has one.
+fn base_salary(job: &Job) -> Option<Value> {
+    let mut value = Map::new();
+    value.insert("@type".into(), json!("QuantitativeValue"));
+    match (job.salary_min, job.salary_max) {
+        (Some(min), Some(max)) if min == max => {
+            value.insert("value".into(), json!(min));
+        }
+        (None, None) => return None,
+        (min, max) => {
+            if let Some(min) = min {
+                value.insert("minValue".into(), json!(min));
+            }
+            if let Some(max) = max {
+                value.insert("maxValue".into(), json!(max));
+            }
+        }
+    }
+    value.insert("unitText".into(), json!(period_unit(job.salary_period)));
+
+    Some(json!({
+        "@type": "MonetaryAmount",
+        "currency": currency_code(job.salary_currency),
+        "value": Value::Object(value),
+    }))
+}
+
+/// ISO 4217 code of a currency.
+fn currency_code(currency: SalaryCurrency) -> &'static str {
+    match currency {
+        SalaryCurrency::Bdt => "BDT",
+        SalaryCurrency::Usd => "USD",
+        SalaryCurrency::Eur => "EUR",
+        SalaryCurrency::Gbp => "GBP",
+        SalaryCurrency::Inr => "INR",
+    }
+}
+
+/// schema.org unit of a pay period.
+fn period_unit(period: SalaryPeriod) -> &'static str {
+    match period {
+        SalaryPeriod::Hour => "HOUR",
+        SalaryPeriod::Day => "DAY",
+        SalaryPeriod::Week => "WEEK",
+        SalaryPeriod::Month => "MONTH",
+        SalaryPeriod::Year => "YEAR",
+    }
+}
+
+/// Feed item title, e.g. "Backend Engineer at Acme BD".
+fn item_title(job: &Job) -> String {
+    format!("{} at {}", job.job_title, job.company)
+}
+
+/// Plain-text feed item summary: location, job type, salary, then the description.
+fn summary(job: &Job) -> String {
+    let location = match job.work_arrangement {
+        WorkArrangement::Remote => "Remote".to_string(),
+        WorkArrangement::Hybrid => format!("{} (hybrid)", job.location),
+        WorkArrangement::OnSite => job.location.clone(),
+    };
+    let mut facts = vec![location, job_type_label(job.job_type).to_string()];
+    if let Some(salary) = salary_text(job) {
+        facts.push(salary);
+    }
+    format!("{}\n\n{}", facts.join(" · "), job.job_description)
+}
+
+/// Human-readable salary, e.g. "BDT 80,000 - 120,000 per month".
+fn salary_text(job: &Job) -> Option<String> {
+    let period = match job.salary_period {
+        SalaryPeriod::Hour => "hour",
+        SalaryPeriod::Day => "day",
+        SalaryPeriod::Week => "week",
+        SalaryPeriod::Month => "month",
+        SalaryPeriod::Year => "year",
+    };
+    let currency = currency_code(job.salary_currency);
+    let amount = match (job.salary_min, job.salary_max) {
+        (Some(min), Some(max)) if min == max => group_thousands(min),
+        (Some(min), Some(max)) => format!("{} - {}", group_thousands(min), group_thousands(max)),
+        (Some(min), None) => format!("from {}", group_thousands(min)),
+        (None, Some(max)) => format!("up to {}", group_thousands(max)),
+        (None, None) if job.salary_negotiable => return Some("Negotiable salary".to_string()),
+        (None, None) => return None,
+    };
+    let negotiable = if job.salary_negotiable { " (negotiable)" } else { "" };
+    Some(format!("{} {} per {}{}", currency, amount, period, negotiable))
+}
+
+/// Formats an amount with thousands separators, e.g. 120000 as "120,000".
+fn group_thousands(amount: i32) -> String {
+    let digits = amount.unsigned_abs().to_string();
+    let mut grouped = String::new();
+    for (i, c) in digits.chars().enumerate() {
+        if i > 0 && (digits.len() - i).is_multiple_of(3) {
+            grouped.push(',');
+        }
+        grouped.push(c);
+    }
+    if amount < 0 {
+        grouped.insert(0, '-');
+    }
+    grouped
+}
+
+/// Escapes text for XML content and attribute values.
+fn escape_xml(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&apos;"),
+            // Control characters other than tab and newlines are not allowed in XML 1.0
+            c if c.is_control() && !matches!(c, '\t' | '\n' | '\r') => {}
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
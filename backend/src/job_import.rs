@@ -0,0 +1,730 @@
+//! Bulk job import from CSV and JSON files.
+//!
+//! Shared by the `POST /api/employer/jobs/import` endpoint and the
+//! `import_jobs` command-line tool. Each row is mapped onto the job fields
+//! with an optional column mapping, validated like a job posted through the
+//! API, and upserted by its `external_ref` (when present) within the target
+//! company; an updated job keeps its status, so re-importing never reopens a
+//! job the employer closed. Invalid rows are skipped and reported individually; a dry run
+//! validates everything without writing. Open rows whose application deadline
+//! or expiry date has already passed are imported as `expired`. A free-text
+//! `salary` such as "Tk 30k-40k per month" is parsed when `salary_min` and
+//! `salary_max` are not given (see [`crate::salary`]). Locations are resolved
+//! like those of jobs posted through the API (see [`crate::locations`]).
+//! Likewise a free-text `experience` such as "3-5 years" or "Senior" fills
+//! in the years of experience, and the level when `experience_level` is
+//! missing. Required and preferred skills and skill weights (a JSON object,
+//! or text such as "Rust: 5; Docker: 2") are saved under their canonical
+//! names (see [`crate::skills`]).
+
+use crate::ai_matching::ExperienceRange;
+use crate::errors::{AppError, AppResult};
+use crate::handlers::CreateJobPayload;
+use crate::locations::resolve_location;
+use crate::models::{
+    ExperienceLevel, JobStatus, JobType, SalaryCurrency, SalaryPeriod, WorkArrangement,
+};
+use crate::salary::parse_salary;
+use crate::skills::{JobSkillLists, SkillTaxonomy, load_skill_taxonomy};
+use chrono::{DateTime, NaiveDate, Utc};
+use serde::Serialize;
+use serde_json::Value;
+use sqlx::PgPool;
+use sqlx::types::Json as SqlJson;
+use std::collections::{HashMap, HashSet};
+use std::str::FromStr;
+use tracing::info;
+use uuid::Uuid;
+use validator::Validate;
+
+/// Maximum number of rows accepted in one import.
+pub const MAX_IMPORT_ROWS: usize = 5000;
+
+/// Job fields that columns can be mapped to.
+pub const IMPORT_FIELDS: [&str; 26] = [
+    "external_ref",
+    "job_title",
+    "company",
+    "location",
+    "work_arrangement",
+    "job_description",
+    "required_skills",
+    "preferred_skills",
+    "skill_weights",
+    "experience_level",
+    "experience_years_min",
+    "experience_years_max",
+    "experience",
+    "job_type",
+    "salary_min",
+    "salary_max",
+    "salary",
+    "salary_currency",
+    "salary_period",
+    "salary_negotiable",
+    "responsibilities",
+    "requirements",
+    "benefits",
+    "status",
+    "application_deadline",
+    "expires_at",
+];
+
+/// Format of an import file.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ImportFormat {
+    /// Comma-separated values with a header row
+    Csv,
+    /// JSON array of objects
+    Json,
+}
+
+impl ImportFormat {
+    /// Guesses the format from a file name.
+    pub fn from_file_name(file_name: &str) -> Option<Self> {
+        let lower = file_name.to_lowercase();
+        if lower.ends_with(".csv") {
+            Some(ImportFormat::Csv)
+        } else if lower.ends_with(".json") {
+            Some(ImportFormat::Json)
+        } else {
+            None
+        }
+    }
+}
+
+impl FromStr for ImportFormat {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "csv" => Ok(ImportFormat::Csv),
+            "json" => Ok(ImportFormat::Json),
+            _ => Err(format!("Unknown import format: {}", s)),
+        }
+    }
+}
+
+/// Where imported jobs are written.
+#[derive(Debug, Clone)]
+pub struct ImportOptions {
+    /// Company the jobs belong to. Without one, rows must name the company.
+    pub company_id: Option<i32>,
+    /// User recorded as the poster
+    pub posted_by: Option<Uuid>,
+    /// Validate and report without writing
+    pub dry_run: bool,
+}
+
+/// Errors found in one row.
+#[derive(Debug, Serialize)]
+pub struct RowError {
+    /// Row number (1-based, excluding the CSV header)
+    pub row: usize,
+    /// External reference of the row, if any
+    pub external_ref: Option<String>,
+    /// Validation errors
+    pub errors: Vec<String>,
+}
+
+/// Outcome of an import.
+#[derive(Debug, Default, Serialize)]
+pub struct ImportReport {
+    /// Whether the import was a dry run
+    pub dry_run: bool,
+    /// Number of data rows read
+    pub total_rows: usize,
+    /// Jobs created (or that would be created in a dry run)
+    pub created: usize,
+    /// Jobs updated by external reference (or that would be updated)
+    pub updated: usize,
+    /// Rows skipped because of errors
+    pub failed: usize,
+    /// Per-row errors
+    pub errors: Vec<RowError>,
+}
+
+/// A validated row ready to be written.
+struct ImportRow {
+    external_ref: Option<String>,
+    company: Option<String>,
+    job: CreateJobPayload,
+    skills: JobSkillLists,
+}
+
+/// Maps job fields to source column names.
+///
+/// Fields without an entry are read from a column of the same name.
+#[derive(Debug, Clone, Default)]
+pub struct ColumnMapping(HashMap<String, String>);
+
+impl ColumnMapping {
+    /// Creates a mapping from `field -> column` pairs.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a key is not an importable job field.
+    pub fn new(mapping: HashMap<String, String>) -> AppResult<Self> {
+        if let Some(unknown) = mapping.keys().find(|f| !IMPORT_FIELDS.contains(&f.as_str())) {
+            return Err(AppError::BadRequest(format!(
+                "Unknown job field '{}' in column mapping. Valid fields: {}",
+                unknown,
+                IMPORT_FIELDS.join(", ")
+            )));
+        }
+        Ok(Self(mapping))
+    }
+
+    /// Parses a mapping from a JSON object of `field -> column` pairs.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the JSON is invalid or names an unknown field.
+    pub fn from_json(json: &str) -> AppResult<Self> {
+        let mapping = serde_json::from_str(json).map_err(|e| {
+            AppError::BadRequest(format!("Column mapping must be a JSON object: {}", e))
+        })?;
+        Self::new(mapping)
+    }
+
+    fn column<'a>(&'a self, field: &'a str) -> &'a str {
+        self.0.get(field).map_or(field, String::as_str)
+    }
+}
+
+/// Parses an import file into rows keyed by column name.
+///
+/// # Errors
+///
+/// Returns an error if the file is malformed or has too many rows.
+pub fn parse_rows(format: ImportFormat, data: &[u8]) -> AppResult<Vec<HashMap<String, Value>>> {
+    let rows = match format {
+        ImportFormat::Csv => {
+            let mut reader = csv::ReaderBuilder::new()
+                .trim(csv::Trim::All)
+                .flexible(true)
+                .from_reader(data);
+            let headers = reader
+                .headers()
+                .map_err(|e| AppError::BadRequest(format!("Invalid CSV header: {}", e)))?
+                .clone();
+
+            reader
+                .records()
+                .map(|record| {
+                    let record = record
+                        .map_err(|e| AppError::BadRequest(format!("Invalid CSV: {}", e)))?;
+                    Ok(headers
+                        .iter()
+                        .zip(record.iter())
+                        .map(|(h, v)| (h.to_string(), Value::String(v.to_string())))
+                        .collect())
+                })
+                .collect::<AppResult<Vec<_>>>()?
+        }
+        ImportFormat::Json => {
+            let rows: Vec<Value> = serde_json::from_slice(data).map_err(|e| {
+                AppError::BadRequest(format!("JSON import must be an array of objects: {}", e))
+            })?;
+            rows.into_iter()
+                .enumerate()
+                .map(|(i, row)| match row {
+                    Value::Object(object) => Ok(object.into_iter().collect()),
+                    _ => Err(AppError::BadRequest(format!("Row {} is not a JSON object", i + 1))),
+                })
+                .collect::<AppResult<Vec<_>>>()?
+        }
+    };
+
+    if rows.len() > MAX_IMPORT_ROWS {
+        return Err(AppError::BadRequest(format!(
+            "Imports are limited to {} rows",
+            MAX_IMPORT_ROWS
+        )));
+    }
+
+    Ok(rows)
+}
+
+/// Validates rows and writes the valid ones.
+///
+/// Rows are written in a single transaction; a database failure aborts the
+/// whole import.
+///
+/// # Errors
+///
+/// Returns an error if a database operation fails.
+pub async fn import_jobs(
+    pool: &PgPool,
+    rows: Vec<HashMap<String, Value>>,
+    mapping: &ColumnMapping,
+    options: &ImportOptions,
+) -> AppResult<ImportReport> {
+    let mut report = ImportReport {
+        dry_run: options.dry_run,
+        total_rows: rows.len(),
+        ..Default::default()
+    };
+
+    let skills = load_skill_taxonomy(pool).await?;
+    let mut seen_refs = HashMap::new();
+    let mut valid = Vec::new();
+    for (i, row) in rows.iter().enumerate() {
+        let number = i + 1;
+        match validate_row(row, mapping, options, &skills) {
+            Ok(import_row) => {
+                if let Some(external_ref) = &import_row.external_ref
+                    && let Some(first) = seen_refs.insert(external_ref.clone(), number)
+                {
+                    report.errors.push(RowError {
+                        row: number,
+                        external_ref: Some(external_ref.clone()),
+                        errors: vec![format!("Duplicate external_ref (first used in row {})", first)],
+                    });
+                    continue;
+                }
+                valid.push(import_row);
+            }
+            Err(errors) => report.errors.push(RowError {
+                row: number,
+                external_ref: cell_text(row, mapping.column("external_ref")),
+                errors,
+            }),
+        }
+    }
+    report.failed = report.errors.len();
+
+    if options.dry_run {
+        let refs: Vec<String> = valid.iter().filter_map(|r| r.external_ref.clone()).collect();
+        let existing: HashSet<String> = sqlx::query_scalar!(
+            r#"
+            SELECT external_ref as "external_ref!"
+            FROM jobs
+            WHERE COALESCE(company_id, 0) = COALESCE($1, 0)
+              AND external_ref = ANY($2)
+            "#,
+            options.company_id,
+            &refs
+        )
+        .fetch_all(pool)
+        .await?
+        .into_iter()
+        .collect();
+
+        report.updated = refs.iter().filter(|r| existing.contains(*r)).count();
+        report.created = valid.len() - report.updated;
+        return Ok(report);
+    }
+
+    let mut tx = pool.begin().await?;
+    for row in valid {
+        let job = row.job;
+        let location = resolve_location(&job.location);
+        let inserted = sqlx::query_scalar!(
+            r#"
+            INSERT INTO jobs (
+                job_title, company, location, job_description, required_skills,
+                experience_level, job_type, salary_min, salary_max,
+                responsibilities, requirements, benefits, company_id, posted_by, external_ref,
+                status, application_deadline, expires_at,
+                salary_currency, salary_period, salary_negotiable,
+                work_arrangement, country, division, district, city, latitude, longitude,
+                experience_years_min, experience_years_max, preferred_skills, skill_weights
+            )
+            VALUES (
+                $1, COALESCE((SELECT name FROM companies WHERE id = $13), $2), $3, $4, $5,
+                $6, $7, $8, $9, $10, $11, $12, $13, $14, $15, $16, $17, $18, $19, $20, $21,
+                $22, $23, $24, $25, $26, $27, $28, $29, $30, $31, $32
+            )
+            ON CONFLICT ((COALESCE(company_id, 0)), external_ref) WHERE external_ref IS NOT NULL
+            DO UPDATE SET
+                job_title = EXCLUDED.job_title,
+                company = EXCLUDED.company,
+                location = EXCLUDED.location,
+                job_description = EXCLUDED.job_description,
+                required_skills = EXCLUDED.required_skills,
+                preferred_skills = EXCLUDED.preferred_skills,
+                skill_weights = EXCLUDED.skill_weights,
+                experience_level = EXCLUDED.experience_level,
+                experience_years_min = EXCLUDED.experience_years_min,
+                experience_years_max = EXCLUDED.experience_years_max,
+                job_type = EXCLUDED.job_type,
+                salary_min = EXCLUDED.salary_min,
+                salary_max = EXCLUDED.salary_max,
+                salary_currency = EXCLUDED.salary_currency,
+                salary_period = EXCLUDED.salary_period,
+                salary_negotiable = EXCLUDED.salary_negotiable,
+                work_arrangement = EXCLUDED.work_arrangement,
+                country = EXCLUDED.country,
+                division = EXCLUDED.division,
+                district = EXCLUDED.district,
+                city = EXCLUDED.city,
+                latitude = EXCLUDED.latitude,
+                longitude = EXCLUDED.longitude,
+                responsibilities = EXCLUDED.responsibilities,
+                requirements = EXCLUDED.requirements,
+                benefits = EXCLUDED.benefits,
+                application_deadline = EXCLUDED.application_deadline,
+                expires_at = EXCLUDED.expires_at
+            RETURNING (xmax = 0) as "inserted!"
+            "#,
+            job.job_title,
+            row.company,
+            job.location,
+            job.job_description,
+            &row.skills.required,
+            job.experience_level as _,
+            job.job_type as _,
+            job.salary_min,
+            job.salary_max,
+            &job.responsibilities.unwrap_or_default(),
+            &job.requirements.unwrap_or_default(),
+            &job.benefits.unwrap_or_default(),
+            options.company_id,
+            options.posted_by,
+            row.external_ref,
+            job.status.unwrap_or(JobStatus::Open) as _,
+            job.application_deadline,
+            job.expires_at,
+            job.salary_currency.unwrap_or(SalaryCurrency::Bdt) as _,
+            job.salary_period.unwrap_or(SalaryPeriod::Month) as _,
+            job.salary_negotiable.unwrap_or(false),
+            job.work_arrangement.unwrap_or(location.work_arrangement) as _,
+            location.country,
+            location.division,
+            location.district,
+            location.city,
+            location.latitude,
+            location.longitude,
+            job.experience_years_min,
+            job.experience_years_max,
+            &row.skills.preferred,
+            SqlJson(&row.skills.weights) as _
+        )
+        .fetch_one(&mut *tx)
+        .await?;
+
+        if inserted {
+            report.created += 1;
+        } else {
+            report.updated += 1;
+        }
+    }
+    tx.commit().await?;
+
+    info!(
+        "Imported jobs: {} created, {} updated, {} failed",
+        report.created, report.updated, report.failed
+    );
+
+    Ok(report)
+}
+
+/// Maps and validates one row, collecting every error found.
+fn validate_row(
+    row: &HashMap<String, Value>,
+    mapping: &ColumnMapping,
+    options: &ImportOptions,
+    skills: &SkillTaxonomy,
+) -> Result<ImportRow, Vec<String>> {
+    let mut errors = Vec::new();
+    let text = |field: &str| cell_text(row, mapping.column(field));
+    let list = |field: &str, separators: &[char]| {
+        cell_list(row, mapping.column(field), separators).unwrap_or_default()
+    };
+
+    let mut years = |field: &str| {
+        let value = text(field)?;
+        match value.trim().parse::<i32>() {
+            Ok(years) if (0..=60).contains(&years) => Some(years),
+            _ => {
+                errors.push(format!("{} must be a whole number from 0 to 60: {}", field, value));
+                None
+            }
+        }
+    };
+    let mut experience_years_min = years("experience_years_min");
+    let mut experience_years_max = years("experience_years_max");
+    let level_text = text("experience_level");
+    let mut experience_level: Option<ExperienceLevel> =
+        parse_optional(level_text.clone(), &mut errors);
+    // Explicit columns take precedence over what the free text says
+    if experience_years_min.is_none() && experience_years_max.is_none()
+        && let Some(value) = text("experience")
+    {
+        match ExperienceRange::parse(&value) {
+            Some(range) => {
+                experience_years_min = Some(range.min);
+                experience_years_max = range.max;
+                experience_level = experience_level.or(Some(range.level()));
+            }
+            None => errors.push(format!("experience could not be understood: {}", value)),
+        }
+    }
+    let experience_level =
+        experience_level.or(experience_years_min.map(ExperienceLevel::from_years));
+    if level_text.is_none() && experience_level.is_none() {
+        errors.push("experience_level is required".to_string());
+    }
+    if let (Some(min), Some(max)) = (experience_years_min, experience_years_max)
+        && min > max
+    {
+        errors.push("experience_years_min cannot be greater than experience_years_max".to_string());
+    }
+    let job_type: Option<JobType> = parse_required(text("job_type"), "job_type", &mut errors);
+
+    let mut salary = |field: &str| {
+        let value = text(field)?;
+        match value.replace([',', ' '], "").parse::<i32>() {
+            Ok(amount) if amount >= 0 => Some(amount),
+            _ => {
+                errors.push(format!("{} must be a non-negative whole number: {}", field, value));
+                None
+            }
+        }
+    };
+    let mut salary_min = salary("salary_min");
+    let mut salary_max = salary("salary_max");
+    let mut salary_currency: Option<SalaryCurrency> =
+        parse_optional(text("salary_currency"), &mut errors);
+    let mut salary_period: Option<SalaryPeriod> =
+        parse_optional(text("salary_period"), &mut errors);
+    let work_arrangement: Option<WorkArrangement> =
+        parse_optional(text("work_arrangement"), &mut errors);
+    let mut salary_negotiable = match text("salary_negotiable").map(|v| v.to_lowercase()) {
+        Some(v) if matches!(v.as_str(), "true" | "yes" | "1") => Some(true),
+        Some(v) if matches!(v.as_str(), "false" | "no" | "0") => Some(false),
+        Some(v) => {
+            errors.push(format!("salary_negotiable must be true or false: {}", v));
+            None
+        }
+        None => None,
+    };
+    // Explicit columns take precedence over what the free text says
+    if salary_min.is_none() && salary_max.is_none()
+        && let Some(value) = text("salary")
+    {
+        match parse_salary(&value) {
+            Some(parsed) => {
+                salary_min = parsed.min;
+                salary_max = parsed.max;
+                salary_currency = salary_currency.or(Some(parsed.currency));
+                salary_period = salary_period.or(Some(parsed.period));
+                salary_negotiable = salary_negotiable.or(Some(parsed.negotiable));
+            }
+            None => errors.push(format!("salary could not be understood: {}", value)),
+        }
+    }
+    if let (Some(min), Some(max)) = (salary_min, salary_max)
+        && min > max
+    {
+        errors.push("salary_min cannot be greater than salary_max".to_string());
+    }
+
+    let mut status = match text("status").map(|s| s.parse::<JobStatus>()) {
+        Some(Ok(JobStatus::Expired)) => {
+            errors.push("status cannot be set to expired".to_string());
+            None
+        }
+        Some(Ok(status)) => Some(status),
+        Some(Err(e)) => {
+            errors.push(e);
+            None
+        }
+        None => None,
+    };
+    let application_deadline =
+        parse_timestamp(text("application_deadline"), "application_deadline", &mut errors);
+    let expires_at = parse_timestamp(text("expires_at"), "expires_at", &mut errors);
+    if let (Some(deadline), Some(expiry)) = (application_deadline, expires_at)
+        && deadline > expiry
+    {
+        errors.push("application_deadline cannot be after expires_at".to_string());
+    }
+    let now = Utc::now();
+    if status.unwrap_or(JobStatus::Open) == JobStatus::Open
+        && (application_deadline.is_some_and(|d| d <= now) || expires_at.is_some_and(|e| e <= now))
+    {
+        status = Some(JobStatus::Expired);
+    }
+
+    let company = text("company");
+    if options.company_id.is_none() && company.is_none() {
+        errors.push("company is required".to_string());
+    }
+
+    let external_ref = text("external_ref");
+    if external_ref.as_ref().is_some_and(|r| r.len() > 255) {
+        errors.push("external_ref must be at most 255 characters".to_string());
+    }
+
+    let skill_weights = match cell_weights(row, mapping.column("skill_weights")) {
+        Ok(weights) => weights,
+        Err(e) => {
+            errors.push(e);
+            HashMap::new()
+        }
+    };
+
+    let job = CreateJobPayload {
+        job_title: text("job_title").unwrap_or_default(),
+        location: text("location").unwrap_or_default(),
+        work_arrangement,
+        job_description: text("job_description").unwrap_or_default(),
+        required_skills: list("required_skills", &[',', ';']),
+        preferred_skills: Some(list("preferred_skills", &[',', ';'])),
+        skill_weights: Some(skill_weights),
+        // Placeholders only matter when parsing failed, which is already an error
+        experience_level: experience_level.unwrap_or(ExperienceLevel::Fresher),
+        experience_years_min,
+        experience_years_max,
+        job_type: job_type.unwrap_or(JobType::FullTime),
+        salary_min,
+        salary_max,
+        salary_currency,
+        salary_period,
+        salary_negotiable,
+        responsibilities: Some(list("responsibilities", &[';', '\n'])),
+        requirements: Some(list("requirements", &[';', '\n'])),
+        benefits: Some(list("benefits", &[';', '\n'])),
+        status,
+        application_deadline,
+        expires_at,
+    };
+
+    if let Err(validation) = job.validate() {
+        for (field, field_errors) in validation.field_errors() {
+            for error in field_errors {
+                errors.push(
+                    error
+                        .message
+                        .as_ref()
+                        .map_or_else(|| format!("{} is invalid", field), |m| m.to_string()),
+                );
+            }
+        }
+    }
+
+    let job_skills = skills
+        .job_skills(
+            &job.required_skills,
+            job.preferred_skills.as_deref().unwrap_or_default(),
+            job.skill_weights.as_ref().unwrap_or(&HashMap::new()),
+        )
+        .unwrap_or_else(|e| {
+            errors.push(match e {
+                AppError::ValidationError(message) => message,
+                e => e.to_string(),
+            });
+            JobSkillLists::default()
+        });
+
+    if errors.is_empty() {
+        Ok(ImportRow {
+            external_ref,
+            company,
+            job,
+            skills: job_skills,
+        })
+    } else {
+        Err(errors)
+    }
+}
+
+/// Parses a required enum cell with its `FromStr` impl.
+fn parse_required<T: FromStr<Err = String>>(
+    value: Option<String>,
+    field: &str,
+    errors: &mut Vec<String>,
+) -> Option<T> {
+    let Some(value) = value else {
+        errors.push(format!("{} is required", field));
+        return None;
+    };
+    T::from_str(&value).map_err(|e| errors.push(e)).ok()
+}
+
+/// Parses an optional enum cell with its `FromStr` impl.
+fn parse_optional<T: FromStr<Err = String>>(
+    value: Option<String>,
+    errors: &mut Vec<String>,
+) -> Option<T> {
+    T::from_str(&value?).map_err(|e| errors.push(e)).ok()
+}
+
+/// Parses an optional timestamp cell: RFC 3339, or a `YYYY-MM-DD` date meaning
+/// the end of that day (UTC).
+fn parse_timestamp(
+    value: Option<String>,
+    field: &str,
+    errors: &mut Vec<String>,
+) -> Option<DateTime<Utc>> {
+    let value = value?;
+    if let Ok(at) = DateTime::parse_from_rfc3339(&value) {
+        return Some(at.with_timezone(&Utc));
+    }
+    match NaiveDate::parse_from_str(&value, "%Y-%m-%d") {
+        Ok(date) => date.and_hms_opt(23, 59, 59).map(|at| at.and_utc()),
+        Err(_) => {
+            errors.push(format!(
+                "{} must be a date (YYYY-MM-DD) or RFC 3339 timestamp: {}",
+                field, value
+            ));
+            None
+        }
+    }
+}
+
+/// Trimmed text of a cell, or None when missing or empty.
+fn cell_text(row: &HashMap<String, Value>, column: &str) -> Option<String> {
+    let text = match row.get(column)? {
+        Value::String(s) => s.trim().to_string(),
+        Value::Number(n) => n.to_string(),
+        Value::Bool(b) => b.to_string(),
+        _ => return None,
+    };
+    (!text.is_empty()).then_some(text)
+}
+
+/// Skill weights cell: a JSON object of skill -> weight, or text such as
+/// "Rust: 5; Docker: 2".
+fn cell_weights(row: &HashMap<String, Value>, column: &str) -> Result<HashMap<String, i32>, String> {
+    let pairs: Vec<(String, Option<i64>)> = match row.get(column) {
+        None | Some(Value::Null) => return Ok(HashMap::new()),
+        Some(Value::Object(weights)) => weights
+            .iter()
+            .map(|(skill, weight)| (skill.trim().to_string(), weight.as_i64()))
+            .collect(),
+        Some(_) => cell_list(row, column, &[';', ','])
+            .unwrap_or_default()
+            .iter()
+            .map(|pair| match pair.rsplit_once(':') {
+                Some((skill, weight)) => (skill.trim().to_string(), weight.trim().parse().ok()),
+                None => (pair.clone(), None),
+            })
+            .collect(),
+    };
+
+    pairs
+        .into_iter()
+        .map(|(skill, weight)| match weight.and_then(|w| i32::try_from(w).ok()) {
+            Some(weight) if !skill.is_empty() => Ok((skill, weight)),
+            _ => Err(format!("skill_weights entry for '{}' must be a whole number", skill)),
+        })
+        .collect()
+}
+
+/// List cell: a JSON array, or text split on the given separators.
+fn cell_list(row: &HashMap<String, Value>, column: &str, separators: &[char]) -> Option<Vec<String>> {
+    let items: Vec<String> = match row.get(column)? {
+        Value::Array(items) => items
+            .iter()
+            .filter_map(|item| item.as_str().map(|s| s.trim().to_string()))
+            .collect(),
+        _ => cell_text(row, column)?
+            .split(separators)
+            .map(|s| s.trim().to_string())
+            .collect(),
+    };
+    Some(items.into_iter().filter(|s| !s.is_empty()).collect())
+}
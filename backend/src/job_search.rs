@@ -0,0 +1,202 @@
+//! Shared job search filters.
+//!
+//! Used by the `/api/jobs/search` endpoint and by saved-search job alerts so
+//! that an alert matches exactly the jobs the equivalent search would return.
+//! Only open jobs ever match.
+
+use crate::locations::GeoRadius;
+use crate::models::{ExperienceLevel, JobType, SavedSearch, WorkArrangement};
+use chrono::{DateTime, Utc};
+use sqlx::types::Json as SqlJson;
+use sqlx::{Postgres, QueryBuilder};
+use std::collections::HashMap;
+
+/// Columns of a [`Job`](crate::models::Job) row, for runtime-built queries.
+pub const JOB_COLUMNS: &str = "id, job_title, company, location, work_arrangement, country, \
+    division, district, city, latitude, longitude, job_description, \
+    required_skills, preferred_skills, skill_weights, experience_level, experience_years_min, experience_years_max, job_type, \
+    salary_min, salary_max, salary_currency, salary_period, salary_negotiable, salary_min_monthly_bdt, \
+    salary_max_monthly_bdt, responsibilities, requirements, benefits, company_id, status, \
+    application_deadline, expires_at, created_at";
+
+/// Lowest semantic similarity (cosine) at which a job matches a query it
+/// shares no words with.
+pub const MIN_SEMANTIC_SIMILARITY: f32 = 0.3;
+
+/// Number of jobs nearest to a search query that get a semantic similarity;
+/// the others count as unrelated.
+pub const SEMANTIC_NEIGHBOURS: usize = 1000;
+
+/// Jobs closest to a search query's embedding, looked up in the
+/// [`crate::embeddings::JobVectorIndex`].
+#[derive(Debug, Clone)]
+pub struct SemanticQuery {
+    /// Cosine similarity of each of the nearest jobs, by job ID
+    pub similarities: HashMap<i32, f32>,
+}
+
+/// Filters that can be applied to the `jobs` table.
+#[derive(Debug, Clone, Default)]
+pub struct JobFilters {
+    /// Free-text query matched against the job's search vector
+    pub q: Option<String>,
+    /// Embedded query; jobs semantically close to it match too
+    pub semantic: Option<SemanticQuery>,
+    /// Job type
+    pub job_type: Option<JobType>,
+    /// Experience level
+    pub experience_level: Option<ExperienceLevel>,
+    /// Case-insensitive location substring
+    pub location: Option<String>,
+    /// Canonical division name; remote jobs always match
+    pub division: Option<String>,
+    /// Distance around a point; remote jobs always match
+    pub near: Option<GeoRadius>,
+    /// Work arrangement
+    pub work_arrangement: Option<WorkArrangement>,
+    /// Only jobs paying at least this much, in BDT per month
+    pub salary_min: Option<i32>,
+    /// Only jobs whose salary range starts at or below this, in BDT per month
+    pub salary_max: Option<i32>,
+    /// Only jobs posted within the last N days
+    pub posted_within_days: Option<i32>,
+    /// Only jobs opened strictly after this instant (see `jobs.published_at`)
+    pub published_after: Option<DateTime<Utc>>,
+    /// Only jobs whose application deadline falls within the next N days
+    pub closing_within_days: Option<i32>,
+}
+
+impl From<&SavedSearch> for JobFilters {
+    fn from(search: &SavedSearch) -> Self {
+        JobFilters {
+            q: search.query.clone(),
+            semantic: None,
+            job_type: search.job_type,
+            experience_level: search.experience_level,
+            location: search.location.clone(),
+            division: None,
+            near: None,
+            work_arrangement: None,
+            salary_min: search.salary_min,
+            salary_max: search.salary_max,
+            posted_within_days: None,
+            published_after: None,
+            closing_within_days: None,
+        }
+    }
+}
+
+impl JobFilters {
+    /// Returns the trimmed query text, or None if it is blank.
+    pub fn query(&self) -> Option<&str> {
+        self.q.as_deref().map(str::trim).filter(|q| !q.is_empty())
+    }
+
+    /// Appends the filters as `AND ...` conditions, restricted to open jobs.
+    ///
+    /// Expects the builder to end inside a `WHERE` clause, e.g. `WHERE TRUE`.
+    /// The query text, when present, is always the first bind, followed by
+    /// the nearest jobs' similarities for semantic search.
+    pub fn push_conditions(&self, builder: &mut QueryBuilder<'_, Postgres>) {
+        builder.push(" AND status = 'open'");
+        if let Some(q) = self.query() {
+            builder
+                .push(" AND (search_vector @@ websearch_to_tsquery('english', ")
+                .push_bind(q.to_string())
+                .push(")");
+            if let Some(semantic) = &self.semantic {
+                builder.push(" OR ");
+                push_semantic_similarity(builder, semantic);
+                builder.push(" >= ").push_bind(MIN_SEMANTIC_SIMILARITY);
+            }
+            builder.push(")");
+        }
+        if let Some(job_type) = self.job_type {
+            builder.push(" AND job_type = ").push_bind(job_type);
+        }
+        if let Some(level) = self.experience_level {
+            builder.push(" AND experience_level = ").push_bind(level);
+        }
+        let location = self.location.as_deref().map(str::trim);
+        if let Some(location) = location.filter(|l| !l.is_empty()) {
+            builder
+                .push(" AND location ILIKE ")
+                .push_bind(format!("%{}%", location));
+        }
+        if let Some(division) = &self.division {
+            builder
+                .push(" AND (work_arrangement = 'remote' OR division = ")
+                .push_bind(division.clone())
+                .push(")");
+        }
+        if let Some(near) = self.near {
+            builder
+                .push(" AND (work_arrangement = 'remote' OR distance_km(latitude, longitude, ")
+                .push_bind(near.latitude)
+                .push(", ")
+                .push_bind(near.longitude)
+                .push(") <= ")
+                .push_bind(near.km)
+                .push(")");
+        }
+        if let Some(arrangement) = self.work_arrangement {
+            builder.push(" AND work_arrangement = ").push_bind(arrangement);
+        }
+        // Salary filters use range overlap so open-ended ranges still match,
+        // and compare monthly BDT so salaries in other currencies or periods do
+        if let Some(min) = self.salary_min {
+            builder
+                .push(" AND COALESCE(salary_max_monthly_bdt, salary_min_monthly_bdt) >= ")
+                .push_bind(min);
+        }
+        if let Some(max) = self.salary_max {
+            builder
+                .push(" AND COALESCE(salary_min_monthly_bdt, salary_max_monthly_bdt) <= ")
+                .push_bind(max);
+        }
+        if let Some(days) = self.posted_within_days {
+            builder
+                .push(" AND created_at >= CURRENT_TIMESTAMP - make_interval(days => ")
+                .push_bind(days)
+                .push(")");
+        }
+        if let Some(after) = self.published_after {
+            builder.push(" AND published_at > ").push_bind(after);
+        }
+        if let Some(days) = self.closing_within_days {
+            builder
+                .push(" AND application_deadline > CURRENT_TIMESTAMP")
+                .push(" AND application_deadline <= CURRENT_TIMESTAMP + make_interval(days => ")
+                .push_bind(days)
+                .push(")");
+        }
+    }
+}
+
+/// Appends the relevance of each job to a query: its text rank, plus its
+/// cosine similarity in semantic search.
+pub fn push_relevance(
+    builder: &mut QueryBuilder<'_, Postgres>,
+    q: &str,
+    semantic: Option<&SemanticQuery>,
+) {
+    builder
+        .push("(ts_rank(search_vector, websearch_to_tsquery('english', ")
+        .push_bind(q.to_string())
+        .push("))");
+    if let Some(semantic) = semantic {
+        builder.push(" + ");
+        push_semantic_similarity(builder, semantic);
+    }
+    builder.push(")");
+}
+
+/// Appends the cosine similarity of each job to the query (0 when it is not
+/// among the nearest jobs), binding the similarities of the nearest jobs as
+/// a JSON object keyed by job ID.
+pub fn push_semantic_similarity(builder: &mut QueryBuilder<'_, Postgres>, semantic: &SemanticQuery) {
+    builder
+        .push("COALESCE((")
+        .push_bind(SqlJson(semantic.similarities.clone()))
+        .push("::JSONB ->> jobs.id::TEXT)::REAL, 0)");
+}
@@ -0,0 +1,752 @@
+//! Generic RSS, Atom and JSON feed job source.
+//!
+//! Many employers and NGOs publish openings as feeds rather than APIs. Each
+//! feed is described by a [`FeedConfig`] loaded from `JOB_FEEDS_FILE`, so new
+//! boards can be onboarded without code changes.
+//!
+//! Feed items are flattened into JSON-like values and fields are picked with
+//! dotted paths:
+//! - Child elements and object keys by name, e.g. `author.name` or `dc:creator`
+//! - XML attributes with an `@` prefix, e.g. `link.@href`
+//! - Array elements by index, e.g. `authors.0.name` (other segments apply to
+//!   the first element)
+
+use super::{FetchedJob, JobSource};
+use crate::errors::{AppError, AppResult};
+use crate::models::{ExternalJob, ExternalJobCategory};
+use chrono::{DateTime, Utc};
+use serde::Deserialize;
+use serde_json::{Map, Value};
+
+/// Maximum length of a source key (`external_jobs.source`).
+const MAX_KEY_LENGTH: usize = 50;
+
+/// Keys reserved for built-in sources.
+const RESERVED_KEYS: [&str; 2] = ["reliefweb", "fixture"];
+
+/// Feed format.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum FeedFormat {
+    /// RSS 2.0 or RSS 1.0 (RDF)
+    Rss,
+    /// Atom 1.0
+    Atom,
+    /// JSON Feed or any JSON document with an array of items
+    Json,
+}
+
+impl FeedFormat {
+    /// Field paths used when the mapping does not override them.
+    fn default_paths(self) -> &'static DefaultPaths {
+        match self {
+            FeedFormat::Rss => &RSS_PATHS,
+            FeedFormat::Atom => &ATOM_PATHS,
+            FeedFormat::Json => &JSON_FEED_PATHS,
+        }
+    }
+}
+
+/// Default field paths for a feed format.
+struct DefaultPaths {
+    id: &'static [&'static str],
+    title: &'static [&'static str],
+    company: &'static [&'static str],
+    url: &'static [&'static str],
+    posted_date: &'static [&'static str],
+    description: &'static [&'static str],
+    skills: &'static [&'static str],
+}
+
+const RSS_PATHS: DefaultPaths = DefaultPaths {
+    id: &["guid", "link"],
+    title: &["title"],
+    company: &["author", "dc:creator"],
+    url: &["link"],
+    posted_date: &["pubDate", "dc:date"],
+    description: &["description", "content:encoded"],
+    skills: &["category"],
+};
+
+const ATOM_PATHS: DefaultPaths = DefaultPaths {
+    id: &["id"],
+    title: &["title"],
+    company: &["author.name"],
+    url: &["link.@href", "link"],
+    posted_date: &["published", "updated"],
+    description: &["summary", "content"],
+    skills: &["category.@term"],
+};
+
+const JSON_FEED_PATHS: DefaultPaths = DefaultPaths {
+    id: &["id"],
+    title: &["title"],
+    company: &["authors.name", "author.name"],
+    url: &["url", "external_url"],
+    posted_date: &["date_published"],
+    description: &["content_text", "summary", "content_html"],
+    skills: &["tags"],
+};
+
+/// One field path, or several tried in order.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(untagged)]
+pub enum FieldPath {
+    /// Single path
+    One(String),
+    /// Candidate paths; the first one with a value wins
+    Any(Vec<String>),
+}
+
+impl FieldPath {
+    fn paths(&self) -> Vec<&str> {
+        match self {
+            FieldPath::One(path) => vec![path.as_str()],
+            FieldPath::Any(paths) => paths.iter().map(String::as_str).collect(),
+        }
+    }
+}
+
+/// Paths of the feed item fields mapped onto an [`ExternalJob`].
+///
+/// Unset fields use the defaults for the feed format.
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct FieldMapping {
+    /// Stable item ID (defaults to the URL when missing)
+    pub id: Option<FieldPath>,
+    /// Job title
+    pub title: Option<FieldPath>,
+    /// Hiring organisation
+    pub company: Option<FieldPath>,
+    /// Job location
+    pub location: Option<FieldPath>,
+    /// Link to the posting
+    pub url: Option<FieldPath>,
+    /// Posting date
+    pub posted_date: Option<FieldPath>,
+    /// Description (HTML is stripped)
+    pub description: Option<FieldPath>,
+    /// Job type
+    pub job_type: Option<FieldPath>,
+    /// Experience level
+    pub experience_level: Option<FieldPath>,
+    /// Salary
+    pub salary: Option<FieldPath>,
+    /// Skills or categories (a list, or a comma-separated string)
+    pub skills: Option<FieldPath>,
+}
+
+/// Configuration of one feed.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct FeedConfig {
+    /// Source key stored with each posting, e.g. `bdjobs_it`
+    pub key: String,
+    /// Publisher shown to users, e.g. "Bdjobs"
+    pub label: String,
+    /// Feed URL
+    pub url: String,
+    /// Category the feed's postings are listed under
+    pub category: ExternalJobCategory,
+    /// Feed format (detected from the response when unset)
+    #[serde(default)]
+    pub format: Option<FeedFormat>,
+    /// Dotted path to the item array in JSON feeds (default: `items`)
+    #[serde(default)]
+    pub items_path: Option<String>,
+    /// Field mapping overrides
+    #[serde(default)]
+    pub fields: FieldMapping,
+    /// Company used when an item has none
+    #[serde(default)]
+    pub default_company: Option<String>,
+    /// Location used when an item has none (default: "Bangladesh")
+    #[serde(default)]
+    pub default_location: Option<String>,
+    /// Only keep items whose title or description contains one of these
+    #[serde(default)]
+    pub include_keywords: Vec<String>,
+    /// Drop items whose title or description contains any of these
+    #[serde(default)]
+    pub exclude_keywords: Vec<String>,
+    /// Whether the feed is ingested (default: true)
+    #[serde(default = "default_enabled")]
+    pub enabled: bool,
+}
+
+fn default_enabled() -> bool {
+    true
+}
+
+impl FeedConfig {
+    /// Loads feed configurations from a JSON file containing an array of feeds.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file cannot be read or parsed, or a feed is invalid.
+    pub fn load_file(path: &str) -> AppResult<Vec<FeedConfig>> {
+        let contents = std::fs::read_to_string(path).map_err(|e| {
+            AppError::ConfigurationError(format!("Failed to read job feeds file {}: {}", path, e))
+        })?;
+        let feeds: Vec<FeedConfig> = serde_json::from_str(&contents).map_err(|e| {
+            AppError::ConfigurationError(format!("Invalid job feeds file {}: {}", path, e))
+        })?;
+
+        for (i, feed) in feeds.iter().enumerate() {
+            feed.validate()?;
+            if feeds[..i].iter().any(|other| other.key == feed.key) {
+                return Err(AppError::ConfigurationError(format!(
+                    "Duplicate job feed key '{}'",
+                    feed.key
+                )));
+            }
+        }
+
+        Ok(feeds)
+    }
+
+    fn validate(&self) -> AppResult<()> {
+        let valid_key = !self.key.is_empty()
+            && self.key.len() <= MAX_KEY_LENGTH
+            && self
+                .key
+                .chars()
+                .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '_' || c == '-');
+        if !valid_key {
+            return Err(AppError::ConfigurationError(format!(
+                "Job feed key '{}' must be 1-{} lowercase letters, digits, '_' or '-'",
+                self.key, MAX_KEY_LENGTH
+            )));
+        }
+        if RESERVED_KEYS.contains(&self.key.as_str()) {
+            return Err(AppError::ConfigurationError(format!(
+                "Job feed key '{}' is reserved",
+                self.key
+            )));
+        }
+        if self.url.trim().is_empty() {
+            return Err(AppError::ConfigurationError(format!(
+                "Job feed '{}' has no url",
+                self.key
+            )));
+        }
+        Ok(())
+    }
+
+    /// Returns true if the item passes the keyword filters.
+    fn matches_keywords(&self, entry: &FeedEntry) -> bool {
+        let text = format!(
+            "{} {}",
+            entry.title.as_deref().unwrap_or_default(),
+            entry.description.as_deref().unwrap_or_default()
+        )
+        .to_lowercase();
+        let contains = |keyword: &String| text.contains(&keyword.to_lowercase());
+
+        (self.include_keywords.is_empty() || self.include_keywords.iter().any(contains))
+            && !self.exclude_keywords.iter().any(contains)
+    }
+}
+
+/// Feed item fields extracted with the feed's mapping.
+#[derive(Debug, Default)]
+pub struct FeedEntry {
+    id: Option<String>,
+    title: Option<String>,
+    company: Option<String>,
+    location: Option<String>,
+    url: Option<String>,
+    posted_date: Option<String>,
+    description: Option<String>,
+    job_type: Option<String>,
+    experience_level: Option<String>,
+    salary: Option<String>,
+    skills: Vec<String>,
+}
+
+/// Pulls postings from an RSS, Atom or JSON feed.
+pub struct FeedSource {
+    client: reqwest::Client,
+    config: FeedConfig,
+}
+
+impl FeedSource {
+    /// Creates a source for a feed.
+    pub fn new(client: reqwest::Client, config: FeedConfig) -> Self {
+        Self { client, config }
+    }
+
+    async fn download(&self) -> AppResult<String> {
+        let response = self.client.get(&self.config.url).send().await.map_err(|e| {
+            AppError::ExternalServiceError(format!(
+                "Feed {} request failed: {}",
+                self.config.key, e
+            ))
+        })?;
+
+        if !response.status().is_success() {
+            return Err(AppError::ExternalServiceError(format!(
+                "Feed {} returned status: {}",
+                self.config.key,
+                response.status()
+            )));
+        }
+
+        response.text().await.map_err(|e| {
+            AppError::ExternalServiceError(format!(
+                "Failed to read feed {}: {}",
+                self.config.key, e
+            ))
+        })
+    }
+
+    /// Parses a feed document into flattened items.
+    fn parse_items(&self, body: &str) -> AppResult<(FeedFormat, Vec<Value>)> {
+        let trimmed = body.trim_start();
+        let format = self.config.format.unwrap_or_else(|| {
+            if trimmed.starts_with('{') || trimmed.starts_with('[') {
+                FeedFormat::Json
+            } else if trimmed.contains("<feed") {
+                FeedFormat::Atom
+            } else {
+                FeedFormat::Rss
+            }
+        });
+
+        let parse_error = |e: String| {
+            AppError::ExternalServiceError(format!(
+                "Failed to parse feed {}: {}",
+                self.config.key, e
+            ))
+        };
+
+        let items = match format {
+            FeedFormat::Json => {
+                let document: Value =
+                    serde_json::from_str(trimmed).map_err(|e| parse_error(e.to_string()))?;
+                let items = match (&document, self.config.items_path.as_deref()) {
+                    (Value::Array(_), None) => Some(&document),
+                    (_, path) => lookup(&document, path.unwrap_or("items")),
+                };
+                match items {
+                    Some(Value::Array(items)) => items.clone(),
+                    _ => return Err(parse_error("item array not found".to_string())),
+                }
+            }
+            FeedFormat::Rss | FeedFormat::Atom => {
+                let document =
+                    roxmltree::Document::parse(trimmed).map_err(|e| parse_error(e.to_string()))?;
+                let item_tag = if format == FeedFormat::Atom { "entry" } else { "item" };
+                document
+                    .descendants()
+                    .filter(|node| node.is_element() && node.tag_name().name() == item_tag)
+                    .map(element_to_value)
+                    .collect()
+            }
+        };
+
+        Ok((format, items))
+    }
+
+    /// Extracts the mapped fields from a flattened item.
+    fn extract(&self, format: FeedFormat, item: &Value) -> FeedEntry {
+        let fields = &self.config.fields;
+        let text = |mapping: &Option<FieldPath>, fallback: &[&str]| {
+            let paths = mapping.as_ref().map_or_else(|| fallback.to_vec(), FieldPath::paths);
+            paths
+                .into_iter()
+                .filter_map(|path| lookup(item, path).and_then(value_text))
+                .find(|value| !value.is_empty())
+        };
+
+        let defaults = format.default_paths();
+
+        let skill_paths = fields
+            .skills
+            .as_ref()
+            .map_or_else(|| defaults.skills.to_vec(), FieldPath::paths);
+        let skills = skill_paths
+            .into_iter()
+            .filter_map(|path| lookup_all(item, path))
+            .find(|values| !values.is_empty())
+            .unwrap_or_default();
+
+        FeedEntry {
+            id: text(&fields.id, defaults.id),
+            title: text(&fields.title, defaults.title),
+            company: text(&fields.company, defaults.company),
+            location: text(&fields.location, &[]),
+            url: text(&fields.url, defaults.url),
+            posted_date: text(&fields.posted_date, defaults.posted_date),
+            description: text(&fields.description, defaults.description).map(|d| strip_html(&d)),
+            job_type: text(&fields.job_type, &[]),
+            experience_level: text(&fields.experience_level, &[]),
+            salary: text(&fields.salary, &[]),
+            skills,
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl JobSource for FeedSource {
+    type Record = FeedEntry;
+
+    fn key(&self) -> &str {
+        &self.config.key
+    }
+
+    async fn fetch(&self) -> AppResult<Vec<FeedEntry>> {
+        let body = self.download().await?;
+        let (format, items) = self.parse_items(&body)?;
+
+        Ok(items
+            .iter()
+            .map(|item| self.extract(format, item))
+            .filter(|entry| self.config.matches_keywords(entry))
+            .collect())
+    }
+
+    fn normalize(&self, entry: FeedEntry) -> AppResult<FetchedJob> {
+        let (Some(title), Some(url)) = (entry.title, entry.url) else {
+            return Err(AppError::ExternalServiceError(format!(
+                "Feed {} item is missing a title or link",
+                self.config.key
+            )));
+        };
+
+        let id = entry.id.unwrap_or_else(|| url.clone());
+        let company = entry
+            .company
+            .or_else(|| self.config.default_company.clone())
+            .unwrap_or_else(|| self.config.label.clone());
+        let location = entry
+            .location
+            .or_else(|| self.config.default_location.clone())
+            .unwrap_or_else(|| "Bangladesh".to_string());
+
+        Ok(FetchedJob {
+            category: self.config.category,
+            job: ExternalJob {
+                id: format!("{}_{}", self.config.key, id),
+                title,
+                company,
+                location,
+                description: entry.description.unwrap_or_default(),
+                url,
+                posted_date: entry
+                    .posted_date
+                    .map(|date| normalize_date(&date))
+                    .unwrap_or_else(|| Utc::now().format("%Y-%m-%d").to_string()),
+                source: self.config.label.clone(),
+                job_type: entry.job_type,
+                experience_level: entry.experience_level,
+                skills: entry.skills,
+                salary: entry.salary,
+                ..Default::default()
+            },
+        })
+    }
+
+    async fn health(&self) -> AppResult<()> {
+        self.download().await.map(|_| ())
+    }
+}
+
+/// Flattens an XML element into a JSON-like value.
+///
+/// Leaf elements without attributes become strings. Other elements become
+/// objects keyed by child name (repeated children become arrays), `@attr`
+/// for attributes and `#text` for text content.
+fn element_to_value(node: roxmltree::Node) -> Value {
+    let children: Vec<_> = node.children().filter(|c| c.is_element()).collect();
+    let text: String = node
+        .children()
+        .filter(|c| c.is_text())
+        .filter_map(|c| c.text())
+        .collect();
+    let text = text.trim().to_string();
+
+    if children.is_empty() && node.attributes().len() == 0 {
+        return Value::String(text);
+    }
+
+    let mut object = Map::new();
+    for attribute in node.attributes() {
+        object.insert(
+            format!("@{}", attribute.name()),
+            Value::String(attribute.value().to_string()),
+        );
+    }
+    for child in children {
+        let name = match child.tag_name().namespace().and_then(|ns| child.lookup_prefix(ns)) {
+            Some(prefix) if !prefix.is_empty() => format!("{}:{}", prefix, child.tag_name().name()),
+            _ => child.tag_name().name().to_string(),
+        };
+        let value = element_to_value(child);
+        match object.get_mut(&name) {
+            Some(Value::Array(values)) => values.push(value),
+            Some(existing) => *existing = Value::Array(vec![existing.take(), value]),
+            None => {
+                object.insert(name, value);
+            }
+        }
+    }
+    if !text.is_empty() {
+        object.insert("#text".to_string(), Value::String(text));
+    }
+
+    Value::Object(object)
+}
+
+/// Resolves a dotted path in a flattened item.
+fn lookup<'a>(value: &'a Value, path: &str) -> Option<&'a Value> {
+    path.split('.').try_fold(value, |current, segment| match current {
+        Value::Array(items) => match segment.parse::<usize>() {
+            Ok(index) => items.get(index),
+            Err(_) => items.first().and_then(|first| first.get(segment)),
+        },
+        Value::Object(object) => object.get(segment),
+        _ => None,
+    })
+}
+
+/// Resolves a path that may point at several values, e.g. repeated categories.
+///
+/// A single string is split on commas.
+fn lookup_all(item: &Value, path: &str) -> Option<Vec<String>> {
+    let (parent, last) = match path.rsplit_once('.') {
+        Some((parent, last)) => (lookup(item, parent)?, last),
+        None => (item, path),
+    };
+
+    let values: Vec<String> = match parent {
+        Value::Array(elements) => elements
+            .iter()
+            .filter_map(|element| element.get(last).and_then(value_text))
+            .collect(),
+        _ => match parent.get(last)? {
+            Value::Array(elements) => elements.iter().filter_map(value_text).collect(),
+            value => value_text(value)
+                .map(|text| text.split(',').map(|s| s.trim().to_string()).collect())
+                .unwrap_or_default(),
+        },
+    };
+
+    Some(values.into_iter().filter(|v| !v.is_empty()).collect())
+}
+
+/// Text of a flattened value.
+fn value_text(value: &Value) -> Option<String> {
+    match value {
+        Value::String(s) => Some(s.trim().to_string()),
+        Value::Number(n) => Some(n.to_string()),
+        Value::Bool(b) => Some(b.to_string()),
+        Value::Object(object) => object.get("#text").and_then(value_text),
+        Value::Array(items) => items.first().and_then(value_text),
+        Value::Null => None,
+    }
+}
+
+/// Formats RFC 2822 (RSS) and RFC 3339 (Atom, JSON Feed) dates as `YYYY-MM-DD`.
+///
+/// Other formats are kept as published.
+fn normalize_date(date: &str) -> String {
+    DateTime::parse_from_rfc2822(date)
+        .or_else(|_| DateTime::parse_from_rfc3339(date))
+        .map(|d| d.format("%Y-%m-%d").to_string())
+        .unwrap_or_else(|_| date.to_string())
+}
+
+/// Removes HTML tags and common entities and collapses whitespace.
+fn strip_html(html: &str) -> String {
+    let mut text = String::with_capacity(html.len());
+    let mut in_tag = false;
+    for c in html.chars() {
+        match c {
+            '<' => in_tag = true,
+            '>' if in_tag => {
+                in_tag = false;
+                text.push(' ');
+            }
+            _ if !in_tag => text.push(c),
+            _ => {}
+        }
+    }
+
+    text.replace("&nbsp;", " ")
+        .replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&amp;", "&")
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::job_sources::DynJobSource;
+    use crate::job_sources::stand_in::StandIn;
+    use axum::http::StatusCode;
+
+    const RSS: &str = r#"<?xml version="1.0" encoding="UTF-8"?>
+        <rss version="2.0" xmlns:dc="http://purl.org/dc/elements/1.1/">
+          <channel>
+            <title>NGO Jobs</title>
+            <item>
+              <title>Rust Developer</title>
+              <link>https://jobs.example.org/1</link>
+              <guid>job-1</guid>
+              <dc:creator>BRAC</dc:creator>
+              <pubDate>Mon, 03 Mar 2025 09:00:00 +0600</pubDate>
+              <description>&lt;p&gt;Build &amp;amp; ship services&lt;/p&gt;</description>
+              <category>Rust</category>
+              <category>PostgreSQL</category>
+            </item>
+            <item>
+              <title>Accountant</title>
+              <link>https://jobs.example.org/2</link>
+            </item>
+            <item>
+              <title>Developer without a link</title>
+            </item>
+          </channel>
+        </rss>"#;
+
+    const ATOM: &str = r#"<?xml version="1.0" encoding="UTF-8"?>
+        <feed xmlns="http://www.w3.org/2005/Atom">
+          <title>Board</title>
+          <entry>
+            <id>urn:job:7</id>
+            <title>Data Analyst</title>
+            <link href="https://board.example.com/7"/>
+            <author><name>Grameenphone</name></author>
+            <updated>2025-02-10T08:00:00Z</updated>
+            <category term="SQL"/>
+          </entry>
+        </feed>"#;
+
+    const JSON: &str = r#"{
+        "data": {
+            "jobs": [
+                {"ref": 12, "name": "QA Engineer", "link": "https://hr.example.com/12",
+                 "place": "Dhaka", "skills": "Selenium, Python"}
+            ]
+        }
+    }"#;
+
+    fn source(server: &StandIn, config: serde_json::Value) -> FeedSource {
+        let mut config: FeedConfig = serde_json::from_value(config).unwrap();
+        config.url = format!("{}/feed", server.url);
+        FeedSource::new(reqwest::Client::new(), config)
+    }
+
+    #[tokio::test]
+    async fn fetches_and_normalizes_rss_items() {
+        let server = StandIn::serve(StatusCode::OK, "application/rss+xml", RSS).await;
+        let feed = source(
+            &server,
+            serde_json::json!({
+                "key": "ngo_jobs", "label": "NGO Jobs", "url": "", "category": "ngo",
+                "exclude_keywords": ["accountant"]
+            }),
+        );
+
+        let batch = feed.fetch_jobs().await.unwrap();
+
+        assert_eq!(batch.rejected, 1);
+        assert_eq!(batch.jobs.len(), 1);
+        assert_eq!(batch.jobs[0].category, ExternalJobCategory::Ngo);
+        let job = &batch.jobs[0].job;
+        assert_eq!(job.id, "ngo_jobs_job-1");
+        assert_eq!(job.title, "Rust Developer");
+        assert_eq!(job.company, "BRAC");
+        assert_eq!(job.location, "Bangladesh");
+        assert_eq!(job.url, "https://jobs.example.org/1");
+        assert_eq!(job.posted_date, "2025-03-03");
+        assert_eq!(job.description, "Build & ship services");
+        assert_eq!(job.source, "NGO Jobs");
+        assert_eq!(job.skills, ["Rust", "PostgreSQL"]);
+        assert_eq!(server.requests()[0].path(), "/feed");
+    }
+
+    #[tokio::test]
+    async fn detects_atom_feeds() {
+        let server = StandIn::serve(StatusCode::OK, "application/atom+xml", ATOM).await;
+        let feed = source(
+            &server,
+            serde_json::json!({"key": "board", "label": "Board", "url": "", "category": "local"}),
+        );
+
+        let batch = feed.fetch_jobs().await.unwrap();
+
+        let job = &batch.jobs[0].job;
+        assert_eq!(job.id, "board_urn:job:7");
+        assert_eq!(job.url, "https://board.example.com/7");
+        assert_eq!(job.company, "Grameenphone");
+        assert_eq!(job.posted_date, "2025-02-10");
+        assert_eq!(job.skills, ["SQL"]);
+    }
+
+    #[tokio::test]
+    async fn maps_json_fields() {
+        let server = StandIn::serve(StatusCode::OK, "application/json", JSON).await;
+        let feed = source(
+            &server,
+            serde_json::json!({
+                "key": "hr", "label": "HR Portal", "url": "", "category": "local",
+                "items_path": "data.jobs", "default_company": "Example Ltd",
+                "fields": {
+                    "id": "ref", "title": "name", "url": "link",
+                    "location": "place", "skills": "skills"
+                }
+            }),
+        );
+
+        let batch = feed.fetch_jobs().await.unwrap();
+
+        let job = &batch.jobs[0].job;
+        assert_eq!(job.id, "hr_12");
+        assert_eq!(job.title, "QA Engineer");
+        assert_eq!(job.company, "Example Ltd");
+        assert_eq!(job.location, "Dhaka");
+        assert_eq!(job.skills, ["Selenium", "Python"]);
+    }
+
+    #[tokio::test]
+    async fn fetch_fails_on_error_status_or_invalid_document() {
+        let config =
+            serde_json::json!({"key": "down", "label": "Down", "url": "", "category": "ngo"});
+
+        let server = StandIn::serve(StatusCode::NOT_FOUND, "text/plain", "gone").await;
+        let error = source(&server, config.clone())
+            .fetch_jobs()
+            .await
+            .unwrap_err();
+        assert!(matches!(error, AppError::ExternalServiceError(ref m) if m.contains("404")));
+
+        let server = StandIn::serve(StatusCode::OK, "application/rss+xml", "<rss><channel>").await;
+        assert!(source(&server, config).fetch_jobs().await.is_err());
+    }
+
+    #[tokio::test]
+    async fn health_reflects_response_status() {
+        let config =
+            serde_json::json!({"key": "ngo_jobs", "label": "NGO", "url": "", "category": "ngo"});
+
+        let server = StandIn::serve(StatusCode::OK, "application/rss+xml", RSS).await;
+        DynJobSource::health(&source(&server, config.clone()))
+            .await
+            .unwrap();
+
+        let server = StandIn::serve(StatusCode::INTERNAL_SERVER_ERROR, "text/plain", "").await;
+        assert!(
+            DynJobSource::health(&source(&server, config))
+                .await
+                .is_err()
+        );
+    }
+}
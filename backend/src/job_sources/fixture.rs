@@ -0,0 +1,128 @@
+//! Fixture job source with built-in sample listings.
+//!
+//! Stands in for Bangladesh government portals and local job boards, which do
+//! not offer an API yet. Enabled by default in development and disabled in
+//! production unless listed in `EXTERNAL_JOB_SOURCES`.
+
+use super::{FetchedJob, JobSource};
+use crate::errors::AppResult;
+use crate::models::{ExternalJob, ExternalJobCategory};
+
+/// Serves the built-in sample listings.
+pub struct FixtureSource;
+
+#[async_trait::async_trait]
+impl JobSource for FixtureSource {
+    type Record = FetchedJob;
+
+    fn key(&self) -> &str {
+        "fixture"
+    }
+
+    async fn fetch(&self) -> AppResult<Vec<FetchedJob>> {
+        let tag = |category: ExternalJobCategory| {
+            move |job: ExternalJob| FetchedJob { category, job }
+        };
+
+        Ok(get_sample_govt_jobs()
+            .into_iter()
+            .map(tag(ExternalJobCategory::Govt))
+            .chain(get_sample_local_jobs().into_iter().map(tag(ExternalJobCategory::Local)))
+            .collect())
+    }
+
+    fn normalize(&self, record: FetchedJob) -> AppResult<FetchedJob> {
+        Ok(record)
+    }
+
+    async fn health(&self) -> AppResult<()> {
+        Ok(())
+    }
+}
+
+/// Sample data for Bangladesh government job portals
+fn get_sample_govt_jobs() -> Vec<ExternalJob> {
+    vec![
+        ExternalJob {
+            id: "bpsc_001".to_string(),
+            title: "Assistant Engineer - BPSC".to_string(),
+            company: "Bangladesh Public Service Commission".to_string(),
+            location: "Dhaka, Bangladesh".to_string(),
+            description: "Hiring for Assistant Engineer position in various government departments. Bachelor's degree in Engineering required.".to_string(),
+            url: "http://bpsc.gov.bd".to_string(),
+            posted_date: "2025-11-01".to_string(),
+            source: "BPSC".to_string(),
+            job_type: Some("Government".to_string()),
+            experience_level: Some("Entry Level".to_string()),
+            skills: vec!["Engineering".to_string(), "Problem Solving".to_string()],
+            salary: Some("BDT 35,000 - 45,000".to_string()),
+            ..Default::default()
+        },
+        ExternalJob {
+            id: "ngoab_001".to_string(),
+            title: "Program Officer - Development".to_string(),
+            company: "NGO Affairs Bureau Registered Organization".to_string(),
+            location: "Chittagong, Bangladesh".to_string(),
+            description: "Seeking Program Officer for community development projects. Experience in rural development preferred.".to_string(),
+            url: "http://ngoab.gov.bd".to_string(),
+            posted_date: "2025-11-10".to_string(),
+            source: "NGOAB".to_string(),
+            job_type: Some("Full-time".to_string()),
+            experience_level: Some("Mid Level".to_string()),
+            skills: vec!["Project Management".to_string(), "Community Development".to_string(), "Report Writing".to_string()],
+            salary: Some("BDT 40,000 - 60,000".to_string()),
+            ..Default::default()
+        },
+    ]
+}
+
+/// Sample data for local Bangladesh job boards
+fn get_sample_local_jobs() -> Vec<ExternalJob> {
+    vec![
+        ExternalJob {
+            id: "bdjobs_001".to_string(),
+            title: "Software Developer - FinTech".to_string(),
+            company: "bKash Limited".to_string(),
+            location: "Dhaka, Bangladesh".to_string(),
+            description: "Looking for experienced software developers to work on mobile financial services platform. Strong Java/Kotlin skills required.".to_string(),
+            url: "https://bdjobs.com".to_string(),
+            posted_date: "2025-11-12".to_string(),
+            source: "BDJobs".to_string(),
+            job_type: Some("Full-time".to_string()),
+            experience_level: Some("Mid Level".to_string()),
+            skills: vec!["Java".to_string(), "Kotlin".to_string(), "Android".to_string(), "APIs".to_string()],
+            salary: Some("BDT 80,000 - 120,000".to_string()),
+            ..Default::default()
+        },
+        ExternalJob {
+            id: "prothomalo_001".to_string(),
+            title: "Digital Marketing Specialist".to_string(),
+            company: "Grameen Phone".to_string(),
+            location: "Dhaka, Bangladesh".to_string(),
+            description: "Join our marketing team to drive digital campaigns for Bangladesh's leading telecom operator.".to_string(),
+            url: "https://jobs.prothomalo.com".to_string(),
+            posted_date: "2025-11-13".to_string(),
+            source: "Prothom Alo Jobs".to_string(),
+            job_type: Some("Full-time".to_string()),
+            experience_level: Some("Junior".to_string()),
+            skills: vec!["Digital Marketing".to_string(), "SEO".to_string(), "Social Media".to_string(), "Analytics".to_string()],
+            salary: Some("BDT 50,000 - 70,000".to_string()),
+            ..Default::default()
+        },
+        ExternalJob {
+            id: "chakri_001".to_string(),
+            title: "Training Coordinator".to_string(),
+            company: "SEIP - Skills for Employment Investment Program".to_string(),
+            location: "Sylhet, Bangladesh".to_string(),
+            description: "Coordinate vocational training programs for youth employment. Government-funded skill development initiative.".to_string(),
+            url: "https://seip.gov.bd".to_string(),
+            posted_date: "2025-11-08".to_string(),
+            source: "Chakri.com".to_string(),
+            job_type: Some("Contract".to_string()),
+            experience_level: Some("Junior".to_string()),
+            skills: vec!["Training".to_string(), "Coordination".to_string(), "Communication".to_string()],
+            salary: Some("BDT 35,000 - 50,000".to_string()),
+            ..Default::default()
+        },
+    ]
+}
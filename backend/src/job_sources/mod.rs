@@ -0,0 +1,210 @@
+//! External job sources.
+//!
+//! Each provider implements [`JobSource`]: it fetches raw records, normalizes
+//! them into [`ExternalJob`]s and reports whether it is reachable. The
+//! [`SourceRegistry`] holds the sources enabled at startup:
+//! - `reliefweb` - ReliefWeb API (UN/NGO jobs in Bangladesh)
+//! - `fixture` - Built-in sample listings for government portals and local
+//!   job boards, which do not offer an API yet (disabled in production)
+//! - RSS, Atom and JSON feeds configured in `JOB_FEEDS_FILE`
+//!
+//! Sources only fetch and normalize postings; persistence and scheduling live
+//! in [`crate::ingestion`].
+
+mod feed;
+mod fixture;
+mod reliefweb;
+#[cfg(test)]
+mod stand_in;
+
+pub use feed::{FeedConfig, FeedFormat, FeedSource, FieldMapping, FieldPath};
+pub use fixture::FixtureSource;
+pub use reliefweb::{RELIEFWEB_API_URL, ReliefWebSource};
+
+use crate::errors::{AppError, AppResult};
+use crate::models::{ExternalJob, ExternalJobCategory};
+use std::env;
+use std::time::Duration;
+use tracing::{info, warn};
+
+/// Timeout for a single source request.
+const FETCH_TIMEOUT_SECS: u64 = 30;
+
+/// A normalized posting together with the category it is listed under.
+#[derive(Debug, Clone)]
+pub struct FetchedJob {
+    /// Listing category
+    pub category: ExternalJobCategory,
+    /// Normalized posting
+    pub job: ExternalJob,
+}
+
+/// Postings fetched from a source in one run.
+#[derive(Debug, Default)]
+pub struct SourceBatch {
+    /// Successfully normalized postings
+    pub jobs: Vec<FetchedJob>,
+    /// Number of records that could not be normalized
+    pub rejected: usize,
+}
+
+/// An external job provider.
+#[async_trait::async_trait]
+pub trait JobSource: Send + Sync {
+    /// Raw record as returned by the provider.
+    type Record: Send;
+
+    /// Stable key stored with each ingested posting, e.g. `reliefweb`.
+    fn key(&self) -> &str;
+
+    /// Fetches the provider's current records.
+    ///
+    /// Sources never substitute sample data on failure.
+    async fn fetch(&self) -> AppResult<Vec<Self::Record>>;
+
+    /// Converts a raw record into a posting.
+    fn normalize(&self, record: Self::Record) -> AppResult<FetchedJob>;
+
+    /// Checks that the provider is reachable and responding.
+    async fn health(&self) -> AppResult<()>;
+}
+
+/// Object-safe view of a [`JobSource`], used by the registry.
+#[async_trait::async_trait]
+pub trait DynJobSource: Send + Sync {
+    /// Stable source key.
+    fn key(&self) -> &str;
+
+    /// Fetches and normalizes the source's current postings.
+    ///
+    /// Records that fail to normalize are logged and counted as rejected.
+    async fn fetch_jobs(&self) -> AppResult<SourceBatch>;
+
+    /// Checks that the provider is reachable and responding.
+    async fn health(&self) -> AppResult<()>;
+}
+
+#[async_trait::async_trait]
+impl<S: JobSource> DynJobSource for S {
+    fn key(&self) -> &str {
+        JobSource::key(self)
+    }
+
+    async fn fetch_jobs(&self) -> AppResult<SourceBatch> {
+        let records = self.fetch().await?;
+        let total = records.len();
+
+        let mut batch = SourceBatch::default();
+        for record in records {
+            match self.normalize(record) {
+                Ok(job) => batch.jobs.push(job),
+                Err(e) => {
+                    warn!("Skipping record from {}: {}", JobSource::key(self), e);
+                    batch.rejected += 1;
+                }
+            }
+        }
+
+        if total > 0 && batch.jobs.is_empty() {
+            return Err(AppError::ExternalServiceError(format!(
+                "None of the {} records from {} could be normalized",
+                total,
+                JobSource::key(self)
+            )));
+        }
+
+        Ok(batch)
+    }
+
+    async fn health(&self) -> AppResult<()> {
+        JobSource::health(self).await
+    }
+}
+
+/// The external sources enabled for this deployment.
+pub struct SourceRegistry {
+    sources: Vec<Box<dyn DynJobSource>>,
+}
+
+impl SourceRegistry {
+    /// Creates a registry from explicit sources.
+    pub fn new(sources: Vec<Box<dyn DynJobSource>>) -> Self {
+        Self { sources }
+    }
+
+    /// Creates the registry from environment variables.
+    ///
+    /// - `EXTERNAL_JOB_SOURCES` - Comma-separated source keys (default:
+    ///   `reliefweb,fixture`, or `reliefweb` when `RUST_ENV=production`)
+    /// - `RELIEFWEB_API_URL` - ReliefWeb jobs endpoint (default: the public API)
+    /// - `JOB_FEEDS_FILE` - JSON file listing feed sources (see [`FeedConfig`])
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a source key is unknown, the feeds file is invalid
+    /// or the HTTP client cannot be created.
+    pub fn from_env() -> AppResult<Self> {
+        let production = env::var("RUST_ENV").is_ok_and(|e| e == "production");
+        let keys = env::var("EXTERNAL_JOB_SOURCES").unwrap_or_else(|_| {
+            if production { "reliefweb" } else { "reliefweb,fixture" }.to_string()
+        });
+
+        let client = reqwest::Client::builder()
+            .timeout(Duration::from_secs(FETCH_TIMEOUT_SECS))
+            .user_agent("CareerBridge/1.0")
+            .build()
+            .map_err(|e| {
+                AppError::ConfigurationError(format!("Failed to create HTTP client: {}", e))
+            })?;
+
+        let mut sources: Vec<Box<dyn DynJobSource>> = Vec::new();
+        for key in keys.split(',').map(str::trim).filter(|k| !k.is_empty()) {
+            match key {
+                "reliefweb" => {
+                    let url = env::var("RELIEFWEB_API_URL")
+                        .unwrap_or_else(|_| RELIEFWEB_API_URL.to_string());
+                    sources.push(Box::new(ReliefWebSource::new(client.clone(), url)));
+                }
+                "fixture" => {
+                    if production {
+                        warn!("Fixture job source is enabled in production");
+                    }
+                    sources.push(Box::new(FixtureSource));
+                }
+                other => {
+                    return Err(AppError::ConfigurationError(format!(
+                        "Unknown external job source '{}' in EXTERNAL_JOB_SOURCES",
+                        other
+                    )));
+                }
+            }
+        }
+
+        if let Ok(path) = env::var("JOB_FEEDS_FILE") {
+            for feed in FeedConfig::load_file(&path)? {
+                if !feed.enabled {
+                    info!("Job feed {} is disabled", feed.key);
+                    continue;
+                }
+                sources.push(Box::new(FeedSource::new(client.clone(), feed)));
+            }
+        }
+
+        info!(
+            "External job sources: {}",
+            sources.iter().map(|s| s.key()).collect::<Vec<_>>().join(", ")
+        );
+
+        Ok(Self::new(sources))
+    }
+
+    /// Enabled sources, in ingestion order.
+    pub fn sources(&self) -> &[Box<dyn DynJobSource>] {
+        &self.sources
+    }
+
+    /// Keys of the enabled sources.
+    pub fn keys(&self) -> Vec<String> {
+        self.sources.iter().map(|s| s.key().to_string()).collect()
+    }
+}
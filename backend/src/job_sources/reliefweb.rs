@@ -0,0 +1,323 @@
+//! ReliefWeb job source (UN/NGO jobs in Bangladesh).
+
+use super::{FetchedJob, JobSource};
+use crate::errors::{AppError, AppResult};
+use crate::models::{ExternalJob, ExternalJobCategory};
+use serde::Deserialize;
+
+/// Public ReliefWeb jobs endpoint.
+pub const RELIEFWEB_API_URL: &str = "https://api.reliefweb.int/v1/jobs";
+
+/// Number of postings requested per fetch.
+const PAGE_SIZE: &str = "20";
+
+/// Fields requested for each posting.
+const FIELDS: [&str; 8] = [
+    "title",
+    "body",
+    "url_alias",
+    "source",
+    "country",
+    "date",
+    "experience",
+    "career_categories",
+];
+
+#[derive(Debug, Deserialize)]
+struct ReliefWebResponse {
+    data: Vec<ReliefWebJob>,
+}
+
+/// A ReliefWeb job record.
+#[derive(Debug, Deserialize)]
+pub struct ReliefWebJob {
+    id: String,
+    fields: ReliefWebFields,
+}
+
+#[derive(Debug, Deserialize)]
+struct ReliefWebFields {
+    title: String,
+    #[serde(default)]
+    body: Option<String>,
+    #[serde(default)]
+    url_alias: String, // This is the correct field for the public job posting URL
+    #[serde(default)]
+    source: Vec<ReliefWebOrganization>,
+    #[serde(default)]
+    country: Vec<ReliefWebCountry>,
+    #[serde(default)]
+    date: ReliefWebDate,
+    #[serde(default)]
+    experience: Vec<ReliefWebExperience>,
+    #[serde(default)]
+    career_categories: Vec<ReliefWebCareer>,
+}
+
+#[derive(Debug, Deserialize)]
+struct ReliefWebOrganization {
+    #[serde(default)]
+    name: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct ReliefWebCountry {
+    #[serde(default)]
+    name: String,
+}
+
+#[derive(Debug, Deserialize, Default)]
+struct ReliefWebDate {
+    #[serde(default)]
+    created: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct ReliefWebExperience {
+    #[serde(default)]
+    name: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct ReliefWebCareer {
+    #[serde(default)]
+    name: String,
+}
+
+/// Pulls the latest ReliefWeb postings for Bangladesh.
+pub struct ReliefWebSource {
+    client: reqwest::Client,
+    api_url: String,
+}
+
+impl ReliefWebSource {
+    /// Creates a source for the given jobs endpoint.
+    ///
+    /// Pass a local URL to run against a stand-in server.
+    pub fn new(client: reqwest::Client, api_url: impl Into<String>) -> Self {
+        Self {
+            client,
+            api_url: api_url.into(),
+        }
+    }
+
+    /// Queries the jobs endpoint for the newest `limit` postings in Bangladesh.
+    async fn request(&self, limit: &str) -> AppResult<ReliefWebResponse> {
+        let mut query = vec![
+            ("appname", "careerbridge"),
+            ("profile", "list"),
+            ("preset", "latest"),
+            ("limit", limit),
+            ("query[value]", "country.id:13"),
+            ("query[operator]", "AND"),
+        ];
+        query.extend(FIELDS.iter().map(|field| ("fields[include][]", *field)));
+
+        let response = self
+            .client
+            .get(&self.api_url)
+            .query(&query)
+            .send()
+            .await
+            .map_err(|e| {
+                AppError::ExternalServiceError(format!("ReliefWeb request failed: {}", e))
+            })?;
+
+        if !response.status().is_success() {
+            return Err(AppError::ExternalServiceError(format!(
+                "ReliefWeb API returned status: {}",
+                response.status()
+            )));
+        }
+
+        response.json().await.map_err(|e| {
+            AppError::ExternalServiceError(format!("Failed to parse ReliefWeb response: {}", e))
+        })
+    }
+}
+
+#[async_trait::async_trait]
+impl JobSource for ReliefWebSource {
+    type Record = ReliefWebJob;
+
+    fn key(&self) -> &str {
+        "reliefweb"
+    }
+
+    async fn fetch(&self) -> AppResult<Vec<ReliefWebJob>> {
+        Ok(self.request(PAGE_SIZE).await?.data)
+    }
+
+    fn normalize(&self, record: ReliefWebJob) -> AppResult<FetchedJob> {
+        if record.fields.title.trim().is_empty() {
+            return Err(AppError::ExternalServiceError(format!(
+                "ReliefWeb job {} has no title",
+                record.id
+            )));
+        }
+
+        Ok(FetchedJob {
+            category: ExternalJobCategory::Ngo,
+            job: normalize_reliefweb_job(record),
+        })
+    }
+
+    async fn health(&self) -> AppResult<()> {
+        self.request("1").await.map(|_| ())
+    }
+}
+
+/// Converts a ReliefWeb API record into an [`ExternalJob`].
+fn normalize_reliefweb_job(job: ReliefWebJob) -> ExternalJob {
+    let location = job
+        .fields
+        .country
+        .first()
+        .map(|c| c.name.clone())
+        .unwrap_or_else(|| "Bangladesh".to_string());
+
+    let company = job
+        .fields
+        .source
+        .first()
+        .map(|s| s.name.clone())
+        .unwrap_or_else(|| "NGO/UN".to_string());
+
+    let experience = job
+        .fields
+        .experience
+        .first()
+        .map(|e| e.name.clone())
+        .unwrap_or_default();
+
+    let skills: Vec<String> = job
+        .fields
+        .career_categories
+        .iter()
+        .map(|c| c.name.clone())
+        .collect();
+
+    // Use url_alias for the correct public job posting URL
+    let job_url = if !job.fields.url_alias.is_empty() {
+        format!("https://reliefweb.int{}", job.fields.url_alias)
+    } else {
+        "https://reliefweb.int/jobs".to_string()
+    };
+
+    ExternalJob {
+        id: format!("reliefweb_{}", job.id),
+        title: job.fields.title,
+        company,
+        location,
+        description: job.fields.body.unwrap_or_default(),
+        url: job_url,
+        posted_date: job.fields.date.created,
+        source: "ReliefWeb".to_string(),
+        job_type: Some("Full-time".to_string()),
+        experience_level: if experience.is_empty() {
+            None
+        } else {
+            Some(experience)
+        },
+        skills,
+        salary: None,
+        ..Default::default()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::job_sources::DynJobSource;
+    use crate::job_sources::stand_in::StandIn;
+    use axum::http::StatusCode;
+
+    const RESPONSE: &str = r#"{
+        "totalCount": 2,
+        "data": [
+            {
+                "id": "4101",
+                "fields": {
+                    "title": "Programme Officer",
+                    "body": "Coordinate field programmes in Cox's Bazar.",
+                    "url_alias": "/job/4101/programme-officer",
+                    "source": [{"name": "UNHCR"}],
+                    "country": [{"name": "Bangladesh"}],
+                    "date": {"created": "2025-03-01T00:00:00+00:00"},
+                    "experience": [{"name": "3-4 years"}],
+                    "career_categories": [{"name": "Program/Project Management"}]
+                }
+            },
+            {
+                "id": "4102",
+                "fields": {"title": "  "}
+            }
+        ]
+    }"#;
+
+    fn source(server: &StandIn) -> ReliefWebSource {
+        ReliefWebSource::new(reqwest::Client::new(), format!("{}/v1/jobs", server.url))
+    }
+
+    #[tokio::test]
+    async fn fetches_and_normalizes_postings() {
+        let server = StandIn::serve(StatusCode::OK, "application/json", RESPONSE).await;
+
+        let batch = source(&server).fetch_jobs().await.unwrap();
+
+        assert_eq!(batch.rejected, 1);
+        assert_eq!(batch.jobs.len(), 1);
+        let fetched = &batch.jobs[0];
+        assert_eq!(fetched.category, ExternalJobCategory::Ngo);
+        let job = &fetched.job;
+        assert_eq!(job.id, "reliefweb_4101");
+        assert_eq!(job.title, "Programme Officer");
+        assert_eq!(job.company, "UNHCR");
+        assert_eq!(job.location, "Bangladesh");
+        assert_eq!(job.url, "https://reliefweb.int/job/4101/programme-officer");
+        assert_eq!(job.posted_date, "2025-03-01T00:00:00+00:00");
+        assert_eq!(job.experience_level.as_deref(), Some("3-4 years"));
+        assert_eq!(job.skills, ["Program/Project Management"]);
+
+        let requests = server.requests();
+        assert_eq!(requests.len(), 1);
+        assert_eq!(requests[0].path(), "/v1/jobs");
+        let query = requests[0].query().unwrap_or_default();
+        assert!(query.contains("limit=20"));
+        assert!(query.contains("country.id%3A13"));
+    }
+
+    #[tokio::test]
+    async fn fetch_fails_on_error_status() {
+        let server = StandIn::serve(StatusCode::BAD_GATEWAY, "text/plain", "upstream down").await;
+
+        let error = source(&server).fetch_jobs().await.unwrap_err();
+
+        assert!(matches!(error, AppError::ExternalServiceError(ref m) if m.contains("502")));
+    }
+
+    #[tokio::test]
+    async fn fetch_fails_on_malformed_response() {
+        let server = StandIn::serve(StatusCode::OK, "application/json", r#"{"data": "#).await;
+
+        assert!(source(&server).fetch_jobs().await.is_err());
+    }
+
+    #[tokio::test]
+    async fn health_requests_a_single_posting() {
+        let server = StandIn::serve(StatusCode::OK, "application/json", RESPONSE).await;
+
+        DynJobSource::health(&source(&server)).await.unwrap();
+
+        let query = server.requests()[0].query().unwrap_or_default().to_string();
+        assert!(query.contains("limit=1"));
+    }
+
+    #[tokio::test]
+    async fn health_fails_on_error_status() {
+        let server =
+            StandIn::serve(StatusCode::SERVICE_UNAVAILABLE, "text/plain", "maintenance").await;
+
+        assert!(DynJobSource::health(&source(&server)).await.is_err());
+    }
+}
@@ -0,0 +1,37 @@
+//! Local HTTP stand-in for job source tests.
+
+use axum::Router;
+use axum::http::{StatusCode, Uri, header};
+use axum::routing::get;
+use std::sync::{Arc, Mutex};
+use tokio::net::TcpListener;
+
+/// A server on 127.0.0.1 answering every GET with the same canned response.
+pub struct StandIn {
+    /// Base URL of the server
+    pub url: String,
+    requests: Arc<Mutex<Vec<Uri>>>,
+}
+
+impl StandIn {
+    /// Starts a server that answers with the given status, content type and body.
+    pub async fn serve(status: StatusCode, content_type: &'static str, body: &'static str) -> Self {
+        let requests = Arc::new(Mutex::new(Vec::new()));
+        let recorded = requests.clone();
+        let app = Router::new().fallback(get(move |uri: Uri| {
+            recorded.lock().unwrap().push(uri);
+            async move { (status, [(header::CONTENT_TYPE, content_type)], body) }
+        }));
+
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+        tokio::spawn(async move { axum::serve(listener, app).await });
+
+        Self { url, requests }
+    }
+
+    /// URIs requested so far, in order.
+    pub fn requests(&self) -> Vec<Uri> {
+        self.requests.lock().unwrap().clone()
+    }
+}
@@ -6,7 +6,7 @@
 //!
 //! - **User Authentication**: Secure JWT-based authentication with Argon2 password hashing
 //! - **Profile Management**: Create and update user profiles with skills, projects, and career goals
-//! - **Job Recommendations**: AI-powered job matching based on user skills and experience
+//! - **Job Recommendations**: AI-powered job matching based on weighted skills, proficiency and experience
 //! - **Skill Gap Analysis**: Identify skill gaps for target roles and get personalized recommendations
 //! - **Learning Resources**: Curated learning resources with progress tracking
 //! - **Application Tracking**: Track job applications and their status
@@ -25,7 +25,9 @@
 //!
 //! ```rust,no_run
 //! use backend::AppState;
+//! use backend::match_strategy::MatchStrategies;
 //! use sqlx::PgPool;
+//! use std::sync::Arc;
 //!
 //! #[tokio::main]
 //! async fn main() {
@@ -33,7 +35,8 @@
 //!         .await
 //!         .expect("Failed to connect to database");
 //!     
-//!     let app_state = AppState { db_pool };
+//!     let match_strategies = Arc::new(MatchStrategies::from_env().expect("Invalid match strategies"));
+//!     let app_state = AppState { db_pool, ai_service: None, match_strategies, embeddings: None, job_vectors: None };
 //!     let app = backend::handlers::create_router(app_state);
 //!     
 //!     // Server setup...
@@ -44,10 +47,27 @@
 //!
 //! The application uses PostgreSQL with the following main tables:
 //!
+//! - `career_tracks` - Career tracks and sub-tracks (admin editable)
+//! - `skills` - Skill taxonomy with aliases, categories and parent skills
 //! - `users` - User accounts and profiles
+//! - `companies` - Employer organisations
 //! - `jobs` - Job listings
+//! - `salary_exchange_rates` - Exchange rates used to normalize salaries to monthly BDT
 //! - `learning_resources` - Educational content
 //! - `application_tracking` - Job application history
+//! - `match_assignments` - Match strategy each user is assigned in an A/B experiment
+//! - `match_impressions` / `match_feedback` - Recommended jobs with score components, and feedback on them
+//! - `embeddings` - Embedding vectors of jobs, learning resources and profiles for semantic matching
+//! - `saved_jobs` - Bookmarked internal and external jobs
+//! - `saved_searches` - Saved job searches that raise alerts
+//! - `notifications` - In-app notifications (job alerts, contact requests)
+//! - `company_invitations` - Invitations for users to join a company as employers
+//! - `contact_requests` - Employer requests to contact discoverable candidates about a job
+//! - `external_jobs` - Postings ingested from external sources
+//! - `external_job_sources` - Ingestion health per external source
+//! - `external_job_merges` - Audit log of external job deduplication decisions
+//! - `skill_demand_snapshots` / `job_demand_snapshots` - Periodic skill demand snapshots for trends
+//! - `match_explanations` - Cached AI match explanations per user, profile version, job and match strategy
 //! - `user_progress` - Learning progress tracking
 //!
 //! ## API Endpoints
@@ -56,20 +76,66 @@
 //!
 //! - `POST /api/register` - Register a new user
 //! - `POST /api/login` - Authenticate and receive JWT token
+//! - `GET /api/companies/:id` - Public company page with open roles
+//! - `GET /api/jobs/search` - Full-text job search with filters and pagination
+//! - `GET /api/locations` - Bangladesh location gazetteer
+//! - `GET /api/career-tracks` - Career tracks and sub-tracks
+//! - `GET /api/skills/autocomplete` - Skill suggestions by name or alias
+//! - `GET /api/skills/trends` - Skill demand over time (also `/movers` for top risers/fallers)
+//! - `GET /api/jobs/:id/jsonld` - schema.org JobPosting JSON-LD of an open job
+//! - `GET /api/feeds/jobs` - RSS, Atom or JSON Feed of open jobs
 //!
 //! ### Protected Endpoints (require JWT)
 //!
 //! - `GET /api/profile` - Get user profile
 //! - `PUT /api/profile` - Update user profile
 //! - `GET /api/jobs/recommendations` - Get job recommendations
+//! - `GET /api/jobs/:id/similar` - Jobs similar to a job (also `/api/jobs/external/:id/similar`)
+//! - `GET /api/jobs/:id/match-explanation` - AI (or heuristic) explanation of how well a job matches
+//! - `GET /api/jobs/:id/match-breakdown` - Per-skill match status, score contribution and learning suggestions
+//! - `POST /api/jobs/:id/feedback` - View or "not interested" feedback on a recommended job
+//! - `GET /api/jobs/external` - Ingested external jobs (also `/ngo`, `/govt`, `/local`)
+//! - `GET /api/jobs/external/sources` - Ingestion health of external sources
+//! - `GET /api/jobs/external/merges` - Deduplication decisions (admin)
 //! - `GET /api/learning/recommendations` - Get learning resource recommendations
 //! - `GET /api/skill-gap/:target_role` - Analyze skill gaps for a role
 //! - `POST /api/applications` - Create job application
 //! - `GET /api/applications` - List user's applications
 //! - `PUT /api/applications/:id` - Update application status
+//! - `POST /api/saved-jobs` - Save an internal or external job
+//! - `GET /api/saved-jobs` - List saved jobs (optionally by tag)
+//! - `PUT /api/saved-jobs/:id` - Update saved job tags and notes
+//! - `DELETE /api/saved-jobs/:id` - Remove a saved job
+//! - `POST /api/saved-searches` - Save a search and get alerts for new matches
+//! - `GET /api/saved-searches` - List saved searches
+//! - `PUT /api/saved-searches/:id` - Update or pause a saved search
+//! - `DELETE /api/saved-searches/:id` - Delete a saved search
+//! - `GET /api/notifications` - List notifications
+//! - `PUT /api/notifications/:id/read` - Mark a notification read
+//! - `PUT /api/notifications/read-all` - Mark all notifications read
+//! - `GET /api/contact-requests` - Employers' requests to contact the user
+//! - `PUT /api/contact-requests/:id` - Accept or decline a contact request
 //! - `POST /api/progress/resource/:id/start` - Start tracking resource
 //! - `PUT /api/progress/resource/:id` - Update progress
 //! - `GET /api/progress` - Get all progress records
+//! - `POST /api/companies` - Create a company and become its employer
+//! - `PUT /api/companies/:id` - Update a company profile
+//! - `POST /api/companies/:id/members` - Invite a user to join a company as an employer
+//! - `GET /api/company-invitations` - The user's invitations to join companies
+//! - `PUT /api/company-invitations/:id` - Accept or decline an invitation to join a company
+//! - `PUT /api/companies/:id/verification` - Set company verification status (admin)
+//! - `POST /api/employer/jobs` - Post a job for the employer's company
+//! - `GET /api/employer/jobs` - List the employer's company jobs
+//! - `PUT /api/employer/jobs/:id` - Update a company job
+//! - `DELETE /api/employer/jobs/:id` - Remove a company job
+//! - `POST /api/employer/jobs/import` - Bulk import jobs from CSV or JSON
+//! - `GET /api/employer/jobs/:id/candidates` - Discoverable candidates ranked for a company job
+//! - `POST /api/employer/jobs/:id/candidates/:candidate_id/contact` - Ask to contact a candidate
+//! - `POST /api/career-tracks` - Add a career track (admin)
+//! - `PUT /api/career-tracks/:slug` - Edit or deactivate a career track (admin)
+//! - `GET /api/match-strategies` - Match strategies and experiment outcomes (admin)
+//! - `GET /api/match-feedback/report` - Precision@k and NDCG of match strategies from feedback (admin)
+//! - `GET /api/match-feedback/tuned-strategy` - Match strategy with weights tuned on feedback (admin)
 //!
 //! ## Environment Variables
 //!
@@ -77,6 +143,27 @@
 //!
 //! - `DATABASE_URL` - PostgreSQL connection string
 //! - `JWT_SECRET` - Secret key for JWT signing (optional, defaults to development key)
+//!
+//! Optional:
+//!
+//! - `JOB_ALERT_INTERVAL_SECS` - How often saved searches are checked (default: 900, 0 disables)
+//! - `JOB_EXPIRY_INTERVAL_SECS` - How often jobs past their deadline are expired (default: 300, 0 disables)
+//! - `SKILL_SNAPSHOT_INTERVAL_SECS` - How often skill demand snapshots are recorded (default: 86400, 0 disables)
+//! - `EXTERNAL_JOBS_INTERVAL_SECS` - How often external sources are ingested (default: 3600, 0 disables)
+//! - `EXTERNAL_JOBS_EXPIRE_AFTER_DAYS` - Expire postings not seen for this many days (default: 7)
+//! - `EXTERNAL_JOB_SOURCES` - Enabled job sources (default: `reliefweb,fixture`; `reliefweb` in production)
+//! - `RELIEFWEB_API_URL` - ReliefWeb jobs endpoint, e.g. a local stand-in for testing
+//! - `JOB_FEEDS_FILE` - JSON file of RSS/Atom/JSON feed sources (see `job_feeds.example.json`)
+//! - `SMTP_HOST`, `SMTP_PORT`, `SMTP_USERNAME`, `SMTP_PASSWORD`, `SMTP_FROM` - Email for job alert digests
+//! - `HUGGINGFACE_API_KEY` - AI match explanations (heuristic explanations without it)
+//! - `MATCH_EXPLANATION_AI_BUDGET` - AI explanations generated per recommendations request (default: 5, 0 disables)
+//! - `MATCH_EXPLANATION_WAIT_SECS` - How long recommendations wait for AI explanations (default: 5)
+//! - `MATCH_STRATEGIES_FILE` - JSON file of match strategies and an A/B experiment (see `match_strategies.example.json`)
+//! - `MATCH_WEIGHTS` - Match score weights without a strategies file (default: `skills:0.6,experience:0.2,track:0.2`)
+//! - `EMBEDDING_PROVIDER` - Embeddings for semantic matching: `local` (default), `remote`, `lexical` or `none`
+//! - `EMBEDDING_MODEL_DIR` - sentence-transformers model directory for `local` (default: `models/all-MiniLM-L6-v2`)
+//! - `EMBEDDING_API_URL`, `EMBEDDING_API_KEY`, `EMBEDDING_MODEL` - OpenAI-compatible embeddings API for `remote`
+//! - `EMBEDDING_REFRESH_INTERVAL_SECS` - How often changed jobs, resources and profiles are embedded (default: 3600, 0 disables)
 
 use sqlx::PgPool;
 
@@ -87,6 +174,25 @@ pub mod models;
 pub mod security;
 pub mod auth;
 pub mod ai_matching;
+pub mod career_tracks;
+pub mod embeddings;
+pub mod hashing;
+pub mod ingestion;
+pub mod job_alerts;
+pub mod job_dedup;
+pub mod job_expiry;
+pub mod job_export;
+pub mod job_import;
+pub mod job_search;
+pub mod job_sources;
+pub mod locations;
+pub mod mailer;
+pub mod match_explanations;
+pub mod match_feedback;
+pub mod match_strategy;
+pub mod salary;
+pub mod skill_trends;
+pub mod skills;
 
 /// Application state shared across all request handlers.
 /// 
@@ -97,4 +203,10 @@ pub struct AppState {
     pub db_pool: PgPool,
     /// AI service for intelligent features (optional)
     pub ai_service: Option<std::sync::Arc<ai::AIService>>,
+    /// Match scoring strategies and the running A/B experiment
+    pub match_strategies: std::sync::Arc<match_strategy::MatchStrategies>,
+    /// Embedding provider for semantic matching (optional)
+    pub embeddings: Option<std::sync::Arc<dyn embeddings::EmbeddingProvider>>,
+    /// Job vectors of the embedding model for semantic search (set with `embeddings`)
+    pub job_vectors: Option<std::sync::Arc<embeddings::JobVectorIndex>>,
 }
@@ -0,0 +1,601 @@
+//! Structured locations backed by a bundled Bangladesh gazetteer.
+//!
+//! Job and external posting locations are free text ("Dhaka, Bangladesh",
+//! "Ukhia, Cox's Bazar", "Remote"). [`resolve_location`] matches that text
+//! against the gazetteer of the 8 divisions, 64 districts and a set of
+//! well-known cities and areas, and detects remote and hybrid work.
+//!
+//! The resolved division, district and coordinates back the division and
+//! radius filters of job search and recommendations, and users' preferred
+//! locations. Older spellings ("Chittagong", "Comilla", "Jessore", ...) are
+//! accepted as aliases.
+
+use crate::errors::{AppError, AppResult};
+use crate::models::{Location, WorkArrangement};
+use sqlx::PgPool;
+use tracing::info;
+
+/// Country of every place in the gazetteer.
+pub const COUNTRY: &str = "Bangladesh";
+
+/// Preferred location that matches remote jobs.
+pub const REMOTE: &str = "Remote";
+
+/// Default radius of distance filters, in kilometres.
+pub const DEFAULT_RADIUS_KM: f64 = 25.0;
+
+/// Largest accepted radius, in kilometres (covers the whole country).
+pub const MAX_RADIUS_KM: f64 = 1000.0;
+
+/// A division: name and older spellings.
+pub struct Division {
+    pub name: &'static str,
+    pub aliases: &'static [&'static str],
+}
+
+/// A district: name, division, older spellings and headquarters coordinates.
+pub struct District {
+    pub name: &'static str,
+    pub division: &'static str,
+    pub aliases: &'static [&'static str],
+    pub latitude: f64,
+    pub longitude: f64,
+}
+
+/// A city or area within a district.
+pub struct City {
+    pub name: &'static str,
+    pub district: &'static str,
+    pub aliases: &'static [&'static str],
+    pub latitude: f64,
+    pub longitude: f64,
+}
+
+pub const DIVISIONS: [Division; 8] = [
+    Division { name: "Barishal", aliases: &["barisal"] },
+    Division { name: "Chattogram", aliases: &["chittagong"] },
+    Division { name: "Dhaka", aliases: &[] },
+    Division { name: "Khulna", aliases: &[] },
+    Division { name: "Mymensingh", aliases: &[] },
+    Division { name: "Rajshahi", aliases: &[] },
+    Division { name: "Rangpur", aliases: &[] },
+    Division { name: "Sylhet", aliases: &[] },
+];
+
+pub const DISTRICTS: [District; 64] = [
+    // Barishal
+    District { name: "Barguna", division: "Barishal", aliases: &[], latitude: 22.1591, longitude: 90.1262 },
+    District { name: "Barishal", division: "Barishal", aliases: &["barisal"], latitude: 22.7010, longitude: 90.3535 },
+    District { name: "Bhola", division: "Barishal", aliases: &[], latitude: 22.6859, longitude: 90.6482 },
+    District { name: "Jhalokati", division: "Barishal", aliases: &["jhalakati", "jhalakathi"], latitude: 22.6406, longitude: 90.1987 },
+    District { name: "Patuakhali", division: "Barishal", aliases: &[], latitude: 22.3596, longitude: 90.3299 },
+    District { name: "Pirojpur", division: "Barishal", aliases: &[], latitude: 22.5841, longitude: 89.9720 },
+    // Chattogram
+    District { name: "Bandarban", division: "Chattogram", aliases: &[], latitude: 22.1953, longitude: 92.2184 },
+    District { name: "Brahmanbaria", division: "Chattogram", aliases: &[], latitude: 23.9571, longitude: 91.1119 },
+    District { name: "Chandpur", division: "Chattogram", aliases: &[], latitude: 23.2333, longitude: 90.6713 },
+    District { name: "Chattogram", division: "Chattogram", aliases: &["chittagong", "ctg"], latitude: 22.3569, longitude: 91.7832 },
+    District { name: "Cox's Bazar", division: "Chattogram", aliases: &["cox bazar", "coxsbazar"], latitude: 21.4272, longitude: 92.0058 },
+    District { name: "Cumilla", division: "Chattogram", aliases: &["comilla"], latitude: 23.4607, longitude: 91.1809 },
+    District { name: "Feni", division: "Chattogram", aliases: &[], latitude: 23.0159, longitude: 91.3976 },
+    District { name: "Khagrachhari", division: "Chattogram", aliases: &["khagrachari"], latitude: 23.1193, longitude: 91.9847 },
+    District { name: "Lakshmipur", division: "Chattogram", aliases: &["laxmipur"], latitude: 22.9447, longitude: 90.8282 },
+    District { name: "Noakhali", division: "Chattogram", aliases: &[], latitude: 22.8696, longitude: 91.0995 },
+    District { name: "Rangamati", division: "Chattogram", aliases: &[], latitude: 22.6533, longitude: 92.1789 },
+    // Dhaka
+    District { name: "Dhaka", division: "Dhaka", aliases: &["dacca"], latitude: 23.8103, longitude: 90.4125 },
+    District { name: "Faridpur", division: "Dhaka", aliases: &[], latitude: 23.6070, longitude: 89.8429 },
+    District { name: "Gazipur", division: "Dhaka", aliases: &[], latitude: 23.9999, longitude: 90.4203 },
+    District { name: "Gopalganj", division: "Dhaka", aliases: &[], latitude: 23.0050, longitude: 89.8266 },
+    District { name: "Kishoreganj", division: "Dhaka", aliases: &[], latitude: 24.4449, longitude: 90.7766 },
+    District { name: "Madaripur", division: "Dhaka", aliases: &[], latitude: 23.1641, longitude: 90.1897 },
+    District { name: "Manikganj", division: "Dhaka", aliases: &[], latitude: 23.8644, longitude: 90.0047 },
+    District { name: "Munshiganj", division: "Dhaka", aliases: &[], latitude: 23.5422, longitude: 90.5305 },
+    District { name: "Narayanganj", division: "Dhaka", aliases: &[], latitude: 23.6238, longitude: 90.5000 },
+    District { name: "Narsingdi", division: "Dhaka", aliases: &["narshingdi"], latitude: 23.9322, longitude: 90.7150 },
+    District { name: "Rajbari", division: "Dhaka", aliases: &[], latitude: 23.7574, longitude: 89.6445 },
+    District { name: "Shariatpur", division: "Dhaka", aliases: &[], latitude: 23.2423, longitude: 90.4348 },
+    District { name: "Tangail", division: "Dhaka", aliases: &[], latitude: 24.2513, longitude: 89.9167 },
+    // Khulna
+    District { name: "Bagerhat", division: "Khulna", aliases: &[], latitude: 22.6602, longitude: 89.7895 },
+    District { name: "Chuadanga", division: "Khulna", aliases: &[], latitude: 23.6402, longitude: 88.8418 },
+    District { name: "Jashore", division: "Khulna", aliases: &["jessore"], latitude: 23.1664, longitude: 89.2081 },
+    District { name: "Jhenaidah", division: "Khulna", aliases: &["jhenaidaha"], latitude: 23.5450, longitude: 89.1726 },
+    District { name: "Khulna", division: "Khulna", aliases: &[], latitude: 22.8456, longitude: 89.5403 },
+    District { name: "Kushtia", division: "Khulna", aliases: &[], latitude: 23.9013, longitude: 89.1204 },
+    District { name: "Magura", division: "Khulna", aliases: &[], latitude: 23.4873, longitude: 89.4198 },
+    District { name: "Meherpur", division: "Khulna", aliases: &[], latitude: 23.7622, longitude: 88.6318 },
+    District { name: "Narail", division: "Khulna", aliases: &[], latitude: 23.1725, longitude: 89.5127 },
+    District { name: "Satkhira", division: "Khulna", aliases: &[], latitude: 22.7185, longitude: 89.0705 },
+    // Mymensingh
+    District { name: "Jamalpur", division: "Mymensingh", aliases: &[], latitude: 24.9375, longitude: 89.9372 },
+    District { name: "Mymensingh", division: "Mymensingh", aliases: &[], latitude: 24.7471, longitude: 90.4203 },
+    District { name: "Netrokona", division: "Mymensingh", aliases: &["netrakona"], latitude: 24.8703, longitude: 90.7276 },
+    District { name: "Sherpur", division: "Mymensingh", aliases: &[], latitude: 25.0205, longitude: 90.0153 },
+    // Rajshahi
+    District { name: "Bogura", division: "Rajshahi", aliases: &["bogra"], latitude: 24.8465, longitude: 89.3773 },
+    District { name: "Chapai Nawabganj", division: "Rajshahi", aliases: &["chapainawabganj", "nawabganj"], latitude: 24.5965, longitude: 88.2775 },
+    District { name: "Joypurhat", division: "Rajshahi", aliases: &["jaipurhat"], latitude: 25.0968, longitude: 89.0227 },
+    District { name: "Naogaon", division: "Rajshahi", aliases: &[], latitude: 24.7936, longitude: 88.9318 },
+    District { name: "Natore", division: "Rajshahi", aliases: &[], latitude: 24.4206, longitude: 89.0000 },
+    District { name: "Pabna", division: "Rajshahi", aliases: &[], latitude: 24.0064, longitude: 89.2372 },
+    District { name: "Rajshahi", division: "Rajshahi", aliases: &[], latitude: 24.3745, longitude: 88.6042 },
+    District { name: "Sirajganj", division: "Rajshahi", aliases: &[], latitude: 24.4534, longitude: 89.7007 },
+    // Rangpur
+    District { name: "Dinajpur", division: "Rangpur", aliases: &[], latitude: 25.6217, longitude: 88.6354 },
+    District { name: "Gaibandha", division: "Rangpur", aliases: &[], latitude: 25.3288, longitude: 89.5430 },
+    District { name: "Kurigram", division: "Rangpur", aliases: &[], latitude: 25.8054, longitude: 89.6362 },
+    District { name: "Lalmonirhat", division: "Rangpur", aliases: &[], latitude: 25.9923, longitude: 89.2847 },
+    District { name: "Nilphamari", division: "Rangpur", aliases: &[], latitude: 25.9310, longitude: 88.8560 },
+    District { name: "Panchagarh", division: "Rangpur", aliases: &["panchagar"], latitude: 26.3411, longitude: 88.5542 },
+    District { name: "Rangpur", division: "Rangpur", aliases: &[], latitude: 25.7439, longitude: 89.2752 },
+    District { name: "Thakurgaon", division: "Rangpur", aliases: &[], latitude: 26.0336, longitude: 88.4616 },
+    // Sylhet
+    District { name: "Habiganj", division: "Sylhet", aliases: &["hobiganj"], latitude: 24.3749, longitude: 91.4155 },
+    District { name: "Moulvibazar", division: "Sylhet", aliases: &["maulvibazar", "moulvi bazar"], latitude: 24.4829, longitude: 91.7774 },
+    District { name: "Sunamganj", division: "Sylhet", aliases: &[], latitude: 25.0715, longitude: 91.3992 },
+    District { name: "Sylhet", division: "Sylhet", aliases: &[], latitude: 24.8949, longitude: 91.8687 },
+];
+
+/// Cities and areas that postings often name instead of their district.
+pub const CITIES: [City; 25] = [
+    City { name: "Uttara", district: "Dhaka", aliases: &[], latitude: 23.8759, longitude: 90.3795 },
+    City { name: "Gulshan", district: "Dhaka", aliases: &[], latitude: 23.7925, longitude: 90.4078 },
+    City { name: "Banani", district: "Dhaka", aliases: &[], latitude: 23.7937, longitude: 90.4066 },
+    City { name: "Mirpur", district: "Dhaka", aliases: &[], latitude: 23.8223, longitude: 90.3654 },
+    City { name: "Dhanmondi", district: "Dhaka", aliases: &[], latitude: 23.7461, longitude: 90.3742 },
+    City { name: "Motijheel", district: "Dhaka", aliases: &[], latitude: 23.7330, longitude: 90.4172 },
+    City { name: "Mohakhali", district: "Dhaka", aliases: &[], latitude: 23.7781, longitude: 90.4050 },
+    City { name: "Tejgaon", district: "Dhaka", aliases: &[], latitude: 23.7596, longitude: 90.3926 },
+    City { name: "Savar", district: "Dhaka", aliases: &[], latitude: 23.8583, longitude: 90.2667 },
+    City { name: "Ashulia", district: "Dhaka", aliases: &[], latitude: 23.8970, longitude: 90.3210 },
+    City { name: "Keraniganj", district: "Dhaka", aliases: &[], latitude: 23.6980, longitude: 90.3460 },
+    City { name: "Tongi", district: "Gazipur", aliases: &[], latitude: 23.8915, longitude: 90.4023 },
+    City { name: "Sreepur", district: "Gazipur", aliases: &[], latitude: 24.2010, longitude: 90.4830 },
+    City { name: "Kaliakair", district: "Gazipur", aliases: &[], latitude: 24.0700, longitude: 90.2250 },
+    City { name: "Rupganj", district: "Narayanganj", aliases: &[], latitude: 23.7900, longitude: 90.5120 },
+    City { name: "Sonargaon", district: "Narayanganj", aliases: &[], latitude: 23.6450, longitude: 90.6010 },
+    City { name: "Bhaluka", district: "Mymensingh", aliases: &[], latitude: 24.3760, longitude: 90.3800 },
+    City { name: "Patenga", district: "Chattogram", aliases: &[], latitude: 22.2410, longitude: 91.7900 },
+    City { name: "Sitakunda", district: "Chattogram", aliases: &[], latitude: 22.6190, longitude: 91.6600 },
+    City { name: "Ukhia", district: "Cox's Bazar", aliases: &["ukhiya"], latitude: 21.2838, longitude: 92.1046 },
+    City { name: "Teknaf", district: "Cox's Bazar", aliases: &[], latitude: 20.8624, longitude: 92.3058 },
+    City { name: "Kutupalong", district: "Cox's Bazar", aliases: &[], latitude: 21.2110, longitude: 92.1630 },
+    City { name: "Bhasan Char", district: "Noakhali", aliases: &["bhashan char"], latitude: 22.3760, longitude: 91.3960 },
+    City { name: "Sreemangal", district: "Moulvibazar", aliases: &["srimangal"], latitude: 24.3065, longitude: 91.7296 },
+    City { name: "Mongla", district: "Bagerhat", aliases: &[], latitude: 22.4833, longitude: 89.6000 },
+];
+
+/// Phrases that mark a location as remote, in normalized form.
+const REMOTE_PHRASES: [&str; 6] = [
+    "remote",
+    "work from home",
+    "wfh",
+    "home based",
+    "anywhere",
+    "telecommute",
+];
+
+/// Kind of a gazetteer place.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PlaceKind {
+    Division,
+    District,
+    City,
+}
+
+/// A gazetteer place looked up by name.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Place {
+    /// Canonical name
+    pub name: &'static str,
+    /// Whether the place is a division, district or city
+    pub kind: PlaceKind,
+    /// Division the place is in
+    pub division: &'static str,
+    /// District the place is in (None for divisions)
+    pub district: Option<&'static str>,
+    /// Latitude (a division's is its headquarters district's)
+    pub latitude: f64,
+    /// Longitude (a division's is its headquarters district's)
+    pub longitude: f64,
+}
+
+/// A circle around a point, for distance filters.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct GeoRadius {
+    pub latitude: f64,
+    pub longitude: f64,
+    pub km: f64,
+}
+
+/// Resolves free-text location against the gazetteer.
+///
+/// The earliest district or city mentioned wins, refined to a city of that
+/// district when one is also mentioned. Coordinates are only set when a
+/// district or city is found; "X Division" resolves to the division alone.
+/// The work arrangement is on-site unless the text says remote or hybrid.
+pub fn resolve_location(text: &str) -> Location {
+    let normalized = normalize(text);
+    let mut location = Location {
+        country: None,
+        division: None,
+        district: None,
+        city: None,
+        latitude: None,
+        longitude: None,
+        work_arrangement: detect_work_arrangement(text).unwrap_or(WorkArrangement::OnSite),
+    };
+
+    // "Dhaka Division" names the division, not the district
+    let mut place_text = normalized.clone();
+    for division in &DIVISIONS {
+        for name in names(division.name, division.aliases) {
+            place_text = place_text.replace(&format!(" {} division ", name), " ");
+        }
+    }
+
+    let district = DISTRICTS
+        .iter()
+        .filter_map(|d| position(&place_text, d.name, d.aliases).map(|pos| (pos, d)))
+        .min_by_key(|(pos, _)| *pos);
+    let city = CITIES
+        .iter()
+        .filter_map(|c| position(&place_text, c.name, c.aliases).map(|pos| (pos, c)))
+        .min_by_key(|(pos, _)| *pos);
+
+    let city = match (district, city) {
+        (Some((district_pos, district)), Some((city_pos, _))) if district_pos < city_pos => CITIES
+            .iter()
+            .find(|c| c.district == district.name && position(&place_text, c.name, c.aliases).is_some()),
+        (_, city) => city.map(|(_, c)| c),
+    };
+
+    if let Some(city) = city {
+        let district = district_named(city.district);
+        location.division = district.map(|d| d.division.to_string());
+        location.district = Some(city.district.to_string());
+        location.city = Some(city.name.to_string());
+        location.latitude = Some(city.latitude);
+        location.longitude = Some(city.longitude);
+    } else if let Some((_, district)) = district {
+        location.division = Some(district.division.to_string());
+        location.district = Some(district.name.to_string());
+        location.latitude = Some(district.latitude);
+        location.longitude = Some(district.longitude);
+    } else if let Some(division) = DIVISIONS
+        .iter()
+        .filter_map(|d| position(&normalized, d.name, d.aliases).map(|pos| (pos, d)))
+        .min_by_key(|(pos, _)| *pos)
+        .map(|(_, d)| d)
+    {
+        location.division = Some(division.name.to_string());
+    }
+
+    if location.division.is_some() || normalized.contains(" bangladesh ") {
+        location.country = Some(COUNTRY.to_string());
+    }
+
+    location
+}
+
+/// Detects remote or hybrid work in free text, or None when not stated.
+///
+/// Hybrid wins when both are mentioned ("Hybrid, 2 days remote").
+pub fn detect_work_arrangement(text: &str) -> Option<WorkArrangement> {
+    let normalized = normalize(text);
+    if normalized.contains(" hybrid ") {
+        Some(WorkArrangement::Hybrid)
+    } else if REMOTE_PHRASES
+        .iter()
+        .any(|p| normalized.contains(&format!(" {} ", p)))
+    {
+        Some(WorkArrangement::Remote)
+    } else {
+        None
+    }
+}
+
+/// Looks up a place by its exact name or alias, case-insensitively.
+///
+/// Cities are checked before districts and districts before divisions, so
+/// "Dhaka" is the district; "Dhaka Division" is the division.
+pub fn find_place(name: &str) -> Option<Place> {
+    let normalized = normalize(name);
+    let query = normalized.trim();
+
+    if let Some(division) = query.strip_suffix(" division") {
+        return find_division(division);
+    }
+
+    if let Some(city) = CITIES.iter().find(|c| names(c.name, c.aliases).any(|n| n == query)) {
+        let district = district_named(city.district)?;
+        return Some(Place {
+            name: city.name,
+            kind: PlaceKind::City,
+            division: district.division,
+            district: Some(district.name),
+            latitude: city.latitude,
+            longitude: city.longitude,
+        });
+    }
+    if let Some(district) = DISTRICTS.iter().find(|d| names(d.name, d.aliases).any(|n| n == query)) {
+        return Some(Place {
+            name: district.name,
+            kind: PlaceKind::District,
+            division: district.division,
+            district: Some(district.name),
+            latitude: district.latitude,
+            longitude: district.longitude,
+        });
+    }
+    find_division(query)
+}
+
+/// Looks up a division by normalized name or alias.
+fn find_division(query: &str) -> Option<Place> {
+    let division = DIVISIONS
+        .iter()
+        .find(|d| names(d.name, d.aliases).any(|n| n == query))?;
+    // Every division is named after its headquarters district
+    let headquarters = district_named(division.name)?;
+    Some(Place {
+        name: division.name,
+        kind: PlaceKind::Division,
+        division: division.name,
+        district: None,
+        latitude: headquarters.latitude,
+        longitude: headquarters.longitude,
+    })
+}
+
+/// Canonical names of preferred locations (gazetteer places or "Remote"),
+/// without duplicates.
+///
+/// # Errors
+///
+/// Returns an error if a name is not in the gazetteer.
+pub fn canonical_preferences(names: &[String]) -> AppResult<Vec<String>> {
+    let mut canonical: Vec<String> = Vec::with_capacity(names.len());
+    for name in names {
+        let name = if normalize(name).trim() == "remote" {
+            REMOTE
+        } else {
+            find_place(name)
+                .ok_or_else(|| AppError::BadRequest(format!("Unknown location: {}", name.trim())))?
+                .name
+        };
+        if !canonical.iter().any(|c| c == name) {
+            canonical.push(name.to_string());
+        }
+    }
+    Ok(canonical)
+}
+
+/// Whether a job location matches any of the preferred locations.
+///
+/// Remote jobs match every preference, since they can be done from anywhere.
+pub fn matches_preferences(
+    preferences: &[String],
+    work_arrangement: WorkArrangement,
+    division: Option<&str>,
+    district: Option<&str>,
+    city: Option<&str>,
+) -> bool {
+    if work_arrangement == WorkArrangement::Remote {
+        return !preferences.is_empty();
+    }
+    preferences.iter().any(|preference| {
+        [division, district, city]
+            .into_iter()
+            .flatten()
+            .any(|name| name == preference)
+    })
+}
+
+/// Parses the `division` query parameter into a canonical division name.
+///
+/// # Errors
+///
+/// Returns an error if the name is not a division.
+pub fn parse_division(division: Option<&str>) -> AppResult<Option<String>> {
+    let Some(division) = division.map(str::trim).filter(|d| !d.is_empty()) else {
+        return Ok(None);
+    };
+    let normalized = normalize(division);
+    let query = normalized.trim();
+    find_division(query.strip_suffix(" division").unwrap_or(query))
+        .map(|place| Some(place.name.to_string()))
+        .ok_or_else(|| {
+            let names: Vec<&str> = DIVISIONS.iter().map(|d| d.name).collect();
+            AppError::BadRequest(format!(
+                "Unknown division: {}. Valid divisions: {}",
+                division,
+                names.join(", ")
+            ))
+        })
+}
+
+/// Parses the `near` and `radius_km` query parameters.
+///
+/// `near` is a place name or a `latitude,longitude` pair; `radius_km`
+/// defaults to [`DEFAULT_RADIUS_KM`].
+///
+/// # Errors
+///
+/// Returns an error if:
+/// - `near` is neither a known place nor valid coordinates
+/// - `radius_km` is given without `near`, or is not positive or too large
+pub fn parse_radius(near: Option<&str>, radius_km: Option<f64>) -> AppResult<Option<GeoRadius>> {
+    let Some(near) = near.map(str::trim).filter(|n| !n.is_empty()) else {
+        if radius_km.is_some() {
+            return Err(AppError::BadRequest(
+                "radius_km requires near".to_string(),
+            ));
+        }
+        return Ok(None);
+    };
+
+    let km = radius_km.unwrap_or(DEFAULT_RADIUS_KM);
+    if !(km > 0.0 && km <= MAX_RADIUS_KM) {
+        return Err(AppError::BadRequest(format!(
+            "radius_km must be between 0 and {}",
+            MAX_RADIUS_KM
+        )));
+    }
+
+    let (latitude, longitude) = parse_coordinates(near)
+        .or_else(|| find_place(near).map(|place| (place.latitude, place.longitude)))
+        .ok_or_else(|| {
+            AppError::BadRequest(format!(
+                "Unknown location for near: {}. Use a division, district or city, or latitude,longitude",
+                near
+            ))
+        })?;
+
+    Ok(Some(GeoRadius {
+        latitude,
+        longitude,
+        km,
+    }))
+}
+
+/// Parses a `latitude,longitude` pair.
+fn parse_coordinates(text: &str) -> Option<(f64, f64)> {
+    let (latitude, longitude) = text.split_once(',')?;
+    let latitude: f64 = latitude.trim().parse().ok()?;
+    let longitude: f64 = longitude.trim().parse().ok()?;
+    ((-90.0..=90.0).contains(&latitude) && (-180.0..=180.0).contains(&longitude))
+        .then_some((latitude, longitude))
+}
+
+/// Resolves the stored locations of jobs and external postings that have not
+/// been resolved yet, e.g. rows created before structured locations existed.
+///
+/// An explicit remote or hybrid arrangement is never overwritten. Returns the
+/// number of rows updated.
+pub async fn backfill_locations(pool: &PgPool) -> AppResult<u64> {
+    let jobs = sqlx::query!(
+        "SELECT id, location FROM jobs WHERE country IS NULL AND division IS NULL"
+    )
+    .fetch_all(pool)
+    .await?;
+    let external = sqlx::query!(
+        "SELECT id, location FROM external_jobs WHERE country IS NULL AND division IS NULL"
+    )
+    .fetch_all(pool)
+    .await?;
+
+    let updated = store_resolved(
+        pool,
+        "jobs",
+        jobs.into_iter().map(|row| (row.id, row.location)).collect(),
+    )
+    .await?
+        + store_resolved(
+            pool,
+            "external_jobs",
+            external.into_iter().map(|row| (row.id, row.location)).collect(),
+        )
+        .await?;
+
+    if updated > 0 {
+        info!("Resolved {} stored job locations", updated);
+    }
+
+    Ok(updated)
+}
+
+/// Stores resolved locations for the given rows of `jobs` or `external_jobs`.
+async fn store_resolved(pool: &PgPool, table: &str, rows: Vec<(i32, String)>) -> AppResult<u64> {
+    let mut ids = Vec::with_capacity(rows.len());
+    let mut arrangements = Vec::with_capacity(rows.len());
+    let mut countries = Vec::with_capacity(rows.len());
+    let mut divisions = Vec::with_capacity(rows.len());
+    let mut districts = Vec::with_capacity(rows.len());
+    let mut cities = Vec::with_capacity(rows.len());
+    let mut latitudes = Vec::with_capacity(rows.len());
+    let mut longitudes = Vec::with_capacity(rows.len());
+
+    for (id, text) in rows {
+        let location = resolve_location(&text);
+        ids.push(id);
+        arrangements.push(location.work_arrangement);
+        countries.push(location.country);
+        divisions.push(location.division);
+        districts.push(location.district);
+        cities.push(location.city);
+        latitudes.push(location.latitude);
+        longitudes.push(location.longitude);
+    }
+
+    // The table name comes from this module, never from input
+    let query = format!(
+        r#"
+        UPDATE {table} t
+        SET work_arrangement = CASE
+                WHEN t.work_arrangement = 'on_site' THEN u.work_arrangement
+                ELSE t.work_arrangement
+            END,
+            country = u.country, division = u.division, district = u.district,
+            city = u.city, latitude = u.latitude, longitude = u.longitude
+        FROM UNNEST(
+            $1::int[], $2::work_arrangement[], $3::text[], $4::text[], $5::text[],
+            $6::text[], $7::float8[], $8::float8[]
+        ) AS u(id, work_arrangement, country, division, district, city, latitude, longitude)
+        WHERE t.id = u.id
+          AND (u.country IS NOT NULL
+               OR (t.work_arrangement = 'on_site' AND u.work_arrangement <> 'on_site'))
+        "#
+    );
+
+    let result = sqlx::query(&query)
+        .bind(&ids)
+        .bind(&arrangements)
+        .bind(&countries)
+        .bind(&divisions)
+        .bind(&districts)
+        .bind(&cities)
+        .bind(&latitudes)
+        .bind(&longitudes)
+        .execute(pool)
+        .await?;
+
+    Ok(result.rows_affected())
+}
+
+/// District with the given canonical name.
+fn district_named(name: &str) -> Option<&'static District> {
+    DISTRICTS.iter().find(|d| d.name == name)
+}
+
+/// Normalized name and aliases of a place.
+fn names(name: &'static str, aliases: &'static [&'static str]) -> impl Iterator<Item = String> {
+    std::iter::once(normalize(name).trim().to_string())
+        .chain(aliases.iter().map(|a| normalize(a).trim().to_string()))
+}
+
+/// Position of the first mention of a place's name or alias in normalized text.
+fn position(text: &str, name: &'static str, aliases: &'static [&'static str]) -> Option<usize> {
+    names(name, aliases)
+        .filter_map(|n| text.find(&format!(" {} ", n)))
+        .min()
+}
+
+/// Lowercases text, drops apostrophes and turns everything else that is not
+/// alphanumeric into single spaces, with a leading and trailing space so
+/// whole words can be matched with `" word "`.
+fn normalize(text: &str) -> String {
+    let mut normalized = String::from(" ");
+    for c in text.to_lowercase().chars() {
+        if c.is_alphanumeric() {
+            normalized.push(c);
+        } else if c != '\'' && c != '’' && !normalized.ends_with(' ') {
+            normalized.push(' ');
+        }
+    }
+    if !normalized.ends_with(' ') {
+        normalized.push(' ');
+    }
+    normalized
+}
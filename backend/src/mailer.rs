@@ -0,0 +1,89 @@
+//! Outgoing email over SMTP.
+//!
+//! Email is optional: when `SMTP_HOST` is not set the server runs without a
+//! mailer and features that would send email (such as job alert digests)
+//! only write in-app notifications.
+
+use crate::errors::{AppError, AppResult};
+use lettre::message::Mailbox;
+use lettre::transport::smtp::authentication::Credentials;
+use lettre::{AsyncSmtpTransport, AsyncTransport, Message, Tokio1Executor};
+use std::env;
+
+/// SMTP mailer configured from environment variables.
+#[derive(Clone)]
+pub struct Mailer {
+    transport: AsyncSmtpTransport<Tokio1Executor>,
+    from: Mailbox,
+}
+
+impl Mailer {
+    /// Creates a mailer from environment variables.
+    ///
+    /// - `SMTP_HOST` - SMTP relay host (mailer is disabled when unset)
+    /// - `SMTP_PORT` - SMTP port (default: 587, STARTTLS)
+    /// - `SMTP_USERNAME` / `SMTP_PASSWORD` - Optional credentials
+    /// - `SMTP_FROM` - Sender address (default: `CareerBridge <no-reply@careerbridge.app>`)
+    ///
+    /// Returns `Ok(None)` when email is not configured.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the configuration is present but invalid.
+    pub fn from_env() -> AppResult<Option<Self>> {
+        let Ok(host) = env::var("SMTP_HOST") else {
+            return Ok(None);
+        };
+
+        let port = match env::var("SMTP_PORT") {
+            Ok(port) => port.parse::<u16>().map_err(|_| {
+                AppError::ConfigurationError("SMTP_PORT must be a valid port".to_string())
+            })?,
+            Err(_) => 587,
+        };
+
+        let from = env::var("SMTP_FROM")
+            .unwrap_or_else(|_| "CareerBridge <no-reply@careerbridge.app>".to_string())
+            .parse::<Mailbox>()
+            .map_err(|e| AppError::ConfigurationError(format!("Invalid SMTP_FROM: {}", e)))?;
+
+        let mut builder = AsyncSmtpTransport::<Tokio1Executor>::starttls_relay(&host)
+            .map_err(|e| AppError::ConfigurationError(format!("Invalid SMTP_HOST: {}", e)))?
+            .port(port);
+
+        if let (Ok(username), Ok(password)) = (env::var("SMTP_USERNAME"), env::var("SMTP_PASSWORD")) {
+            builder = builder.credentials(Credentials::new(username, password));
+        }
+
+        Ok(Some(Self {
+            transport: builder.build(),
+            from,
+        }))
+    }
+
+    /// Sends a plain-text email.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the recipient address is invalid or the SMTP
+    /// server rejects the message.
+    pub async fn send(&self, to: &str, subject: &str, body: String) -> AppResult<()> {
+        let to = to
+            .parse::<Mailbox>()
+            .map_err(|e| AppError::BadRequest(format!("Invalid recipient address: {}", e)))?;
+
+        let message = Message::builder()
+            .from(self.from.clone())
+            .to(to)
+            .subject(subject)
+            .body(body)
+            .map_err(|e| AppError::ExternalServiceError(format!("Failed to build email: {}", e)))?;
+
+        self.transport
+            .send(message)
+            .await
+            .map_err(|e| AppError::ExternalServiceError(format!("Failed to send email: {}", e)))?;
+
+        Ok(())
+    }
+}
@@ -69,12 +69,131 @@ async fn main() {
         None
     };
     
+    // Load match scoring strategies
+    let match_strategies = std::sync::Arc::new(
+        backend::match_strategy::MatchStrategies::from_env()
+            .expect("Invalid match strategy configuration"),
+    );
+
+    // Configure embeddings for semantic matching
+    let embeddings = backend::embeddings::provider_from_env()
+        .expect("Invalid embedding configuration");
+    let job_vectors = embeddings.as_ref().map(|provider| {
+        std::sync::Arc::new(backend::embeddings::JobVectorIndex::new(provider.model()))
+    });
+
     // Create application state
     let app_state = AppState { 
         db_pool,
         ai_service,
+        match_strategies,
+        embeddings,
+        job_vectors,
     };
 
+    // Resolve locations stored before structured locations existed
+    if let Err(e) = backend::locations::backfill_locations(&app_state.db_pool).await {
+        error!("Failed to resolve stored job locations: {}", e);
+    }
+
+    // Start background job alerts for saved searches
+    let alert_interval_secs = env::var("JOB_ALERT_INTERVAL_SECS")
+        .unwrap_or_else(|_| "900".to_string())
+        .parse::<u64>()
+        .expect("JOB_ALERT_INTERVAL_SECS must be a valid number");
+    if alert_interval_secs > 0 {
+        let mailer = backend::mailer::Mailer::from_env()
+            .expect("Invalid SMTP configuration");
+        if mailer.is_some() {
+            info!("✓ Email digests enabled for job alerts");
+        } else {
+            info!("⚠ SMTP not configured, job alerts are in-app only");
+        }
+        backend::job_alerts::spawn_job_alerts(
+            app_state.db_pool.clone(),
+            mailer,
+            app_state.match_strategies.clone(),
+            std::time::Duration::from_secs(alert_interval_secs),
+        );
+        info!("✓ Job alerts running every {}s", alert_interval_secs);
+    } else {
+        info!("⚠ Job alerts disabled (JOB_ALERT_INTERVAL_SECS=0)");
+    }
+
+    // Start automatic expiry of jobs past their deadline
+    let expiry_interval_secs = env::var("JOB_EXPIRY_INTERVAL_SECS")
+        .unwrap_or_else(|_| "300".to_string())
+        .parse::<u64>()
+        .expect("JOB_EXPIRY_INTERVAL_SECS must be a valid number");
+    if expiry_interval_secs > 0 {
+        backend::job_expiry::spawn_job_expiry(
+            app_state.db_pool.clone(),
+            std::time::Duration::from_secs(expiry_interval_secs),
+        );
+        info!("✓ Job expiry running every {}s", expiry_interval_secs);
+    } else {
+        info!("⚠ Job expiry disabled (JOB_EXPIRY_INTERVAL_SECS=0)");
+    }
+
+    // Start periodic skill demand snapshots for trends
+    let snapshot_interval_secs = env::var("SKILL_SNAPSHOT_INTERVAL_SECS")
+        .unwrap_or_else(|_| "86400".to_string())
+        .parse::<u64>()
+        .expect("SKILL_SNAPSHOT_INTERVAL_SECS must be a valid number");
+    if snapshot_interval_secs > 0 {
+        backend::skill_trends::spawn_skill_snapshots(
+            app_state.db_pool.clone(),
+            std::time::Duration::from_secs(snapshot_interval_secs),
+        );
+        info!("✓ Skill demand snapshots every {}s", snapshot_interval_secs);
+    } else {
+        info!("⚠ Skill demand snapshots disabled (SKILL_SNAPSHOT_INTERVAL_SECS=0)");
+    }
+
+    // Start periodic embedding of jobs, resources and profiles
+    if let (Some(provider), Some(job_vectors)) = (&app_state.embeddings, &app_state.job_vectors) {
+        let embedding_interval_secs = env::var("EMBEDDING_REFRESH_INTERVAL_SECS")
+            .unwrap_or_else(|_| "3600".to_string())
+            .parse::<u64>()
+            .expect("EMBEDDING_REFRESH_INTERVAL_SECS must be a valid number");
+        if embedding_interval_secs > 0 {
+            backend::embeddings::store::spawn_embedding_refresh(
+                app_state.db_pool.clone(),
+                provider.clone(),
+                job_vectors.clone(),
+                std::time::Duration::from_secs(embedding_interval_secs),
+            );
+            info!("✓ Embedding refresh running every {}s", embedding_interval_secs);
+        } else {
+            info!("⚠ Embedding refresh disabled (EMBEDDING_REFRESH_INTERVAL_SECS=0)");
+        }
+    }
+
+    // Start scheduled ingestion of external job sources
+    let ingestion_interval_secs = env::var("EXTERNAL_JOBS_INTERVAL_SECS")
+        .unwrap_or_else(|_| "3600".to_string())
+        .parse::<u64>()
+        .expect("EXTERNAL_JOBS_INTERVAL_SECS must be a valid number");
+    if ingestion_interval_secs > 0 {
+        let expire_after_days = env::var("EXTERNAL_JOBS_EXPIRE_AFTER_DAYS")
+            .unwrap_or_else(|_| "7".to_string())
+            .parse::<i32>()
+            .expect("EXTERNAL_JOBS_EXPIRE_AFTER_DAYS must be a valid number");
+        let registry = backend::job_sources::SourceRegistry::from_env()
+            .expect("Invalid external job source configuration");
+        backend::ingestion::spawn_ingestion(
+            app_state.db_pool.clone(),
+            std::sync::Arc::new(registry),
+            backend::ingestion::IngestionConfig {
+                interval: std::time::Duration::from_secs(ingestion_interval_secs),
+                expire_after_days,
+            },
+        );
+        info!("✓ External job ingestion running every {}s", ingestion_interval_secs);
+    } else {
+        info!("⚠ External job ingestion disabled (EXTERNAL_JOBS_INTERVAL_SECS=0)");
+    }
+
     // Create router
     info!("Configuring routes...");
     let app = handlers::create_router(app_state);
@@ -0,0 +1,331 @@
+//! Match explanations for job recommendations.
+//!
+//! AI explanations come from a Hugging Face model and can take up to 30
+//! seconds each, so they are:
+//! - generated with bounded concurrency, shared by all requests
+//! - cached per user, profile version (`users.updated_at`), job and match
+//!   strategy (see [`crate::match_strategy::MatchStrategy::hash`]), since
+//!   they quote the score; editing a job's title, skills, experience or
+//!   description clears its cache entries
+//! - capped per request: only the best-ranked uncached jobs within the AI
+//!   budget are sent to the model, and only for a short wait
+//!
+//! Jobs without an AI explanation in time keep their heuristic explanation.
+//! Generations still running when the wait ends finish in the background and
+//! are cached for the next request, or can be fetched with
+//! `GET /api/jobs/{id}/match-explanation`.
+
+use crate::ai_matching::{ExperienceRange, generate_ai_explanation_hf};
+use crate::errors::AppResult;
+use crate::models::{ExperienceLevel, SkillProficiency};
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use sqlx::PgPool;
+use sqlx::types::Json as SqlJson;
+use std::collections::HashMap;
+use std::env;
+use std::time::Duration;
+use tokio::sync::Semaphore;
+use tokio::time::{Instant, timeout_at};
+use tracing::{debug, info, warn};
+use uuid::Uuid;
+
+/// Maximum Hugging Face calls in flight across all requests.
+pub const AI_CONCURRENCY: usize = 4;
+
+/// Default number of AI explanations generated per recommendations request.
+const DEFAULT_AI_BUDGET: usize = 5;
+
+/// Default time a recommendations request waits for AI explanations.
+const DEFAULT_AI_WAIT_SECS: u64 = 5;
+
+static AI_PERMITS: Semaphore = Semaphore::const_new(AI_CONCURRENCY);
+
+/// Where a match explanation came from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ExplanationSource {
+    /// Generated by the AI model (possibly cached)
+    Ai,
+    /// Built from the heuristic match analysis
+    Heuristic,
+}
+
+/// The candidate side of a match explanation.
+#[derive(Debug, Clone)]
+pub struct CandidateProfile {
+    pub user_id: Uuid,
+    /// Changes whenever the profile is updated
+    pub version: DateTime<Utc>,
+    pub skills: Vec<String>,
+    /// Proficiency per skill, by canonical name
+    pub skill_proficiency: SqlJson<HashMap<String, SkillProficiency>>,
+    pub experience_level: Option<ExperienceLevel>,
+    pub experience_years: Option<i32>,
+    /// Slug of the preferred career track
+    pub preferred_track: Option<String>,
+    /// Display name of the preferred career track
+    pub preferred_track_name: Option<String>,
+    /// Target job roles, for the profile's embedding
+    pub target_roles: Vec<String>,
+    /// Completed projects, for the profile's embedding
+    pub projects: Vec<String>,
+}
+
+impl CandidateProfile {
+    /// The candidate's experience, as compared with jobs.
+    pub fn experience(&self) -> Option<ExperienceRange> {
+        ExperienceRange::candidate(self.experience_level, self.experience_years)
+    }
+}
+
+/// The job side of a match explanation.
+#[derive(Debug, Clone)]
+pub struct JobMatch {
+    pub job_id: i32,
+    pub job_title: String,
+    pub required_skills: Vec<String>,
+    pub experience: ExperienceRange,
+    pub job_description: String,
+    pub match_score: f64,
+    /// Hash of the match strategy the score was computed with
+    pub strategy_hash: String,
+}
+
+/// Loads what match explanations need from a user's profile.
+pub async fn load_candidate(pool: &PgPool, user_id: Uuid) -> AppResult<CandidateProfile> {
+    let candidate = sqlx::query_as!(
+        CandidateProfile,
+        r#"
+        SELECT
+            u.id as user_id,
+            COALESCE(u.updated_at, u.created_at, to_timestamp(0)) as "version!",
+            u.skills, u.skill_proficiency as "skill_proficiency: _",
+            u.experience_level as "experience_level: ExperienceLevel", u.experience_years,
+            u.preferred_track, t.display_name as "preferred_track_name?",
+            u.target_roles, u.projects
+        FROM users u
+        LEFT JOIN career_tracks t ON t.slug = u.preferred_track
+        WHERE u.id = $1
+        "#,
+        user_id
+    )
+    .fetch_one(pool)
+    .await?;
+
+    Ok(candidate)
+}
+
+/// Returns AI explanations for the given jobs, keyed by job ID.
+///
+/// `jobs` should be ordered best match first: cached explanations are used
+/// for every job, and up to the AI budget of the remaining jobs are sent to
+/// the model, from the top. Jobs missing from the result should fall back to
+/// their heuristic explanation.
+///
+/// # Errors
+///
+/// Returns an error if loading cached explanations fails. Failed AI calls
+/// are logged and left out of the result.
+pub async fn explain_matches(
+    pool: &PgPool,
+    candidate: &CandidateProfile,
+    jobs: &[JobMatch],
+    generate: bool,
+) -> AppResult<HashMap<i32, String>> {
+    let mut explanations = load_cached(pool, candidate, &jobs.iter().collect::<Vec<_>>()).await?;
+
+    let budget = if generate && ai_available() { ai_budget() } else { 0 };
+    let uncached: Vec<&JobMatch> = jobs
+        .iter()
+        .filter(|job| !explanations.contains_key(&job.job_id))
+        .take(budget)
+        .collect();
+    debug!(
+        "{} cached match explanations, generating {}",
+        explanations.len(),
+        uncached.len()
+    );
+    if uncached.is_empty() {
+        return Ok(explanations);
+    }
+
+    // Spawned so generations outliving the wait still get cached
+    let handles: Vec<_> = uncached
+        .into_iter()
+        .map(|job| {
+            let pool = pool.clone();
+            let candidate = candidate.clone();
+            let job = job.clone();
+            tokio::spawn(async move {
+                let explanation = generate_and_cache(&pool, &candidate, &job).await?;
+                Some((job.job_id, explanation))
+            })
+        })
+        .collect();
+
+    let deadline = Instant::now() + ai_wait();
+    let mut pending = 0;
+    for handle in handles {
+        match timeout_at(deadline, handle).await {
+            Ok(Ok(Some((job_id, explanation)))) => {
+                explanations.insert(job_id, explanation);
+            }
+            Ok(Ok(None)) => {}
+            Ok(Err(e)) => warn!("Match explanation task failed: {}", e),
+            Err(_) => pending += 1,
+        }
+    }
+    if pending > 0 {
+        info!(
+            "{} AI match explanations still generating; using heuristics for now",
+            pending
+        );
+    }
+
+    Ok(explanations)
+}
+
+/// Returns the AI explanation of one job, generating it if it isn't cached.
+///
+/// Unlike [`explain_matches`], this waits for the model (up to its 30-second
+/// timeout) and ignores the per-request budget. Returns `None` when no AI
+/// explanation could be produced, and whether the explanation was cached.
+///
+/// # Errors
+///
+/// Returns an error if loading the cached explanation fails.
+pub async fn explain_match(
+    pool: &PgPool,
+    candidate: &CandidateProfile,
+    job: &JobMatch,
+) -> AppResult<Option<(String, bool)>> {
+    if let Some(explanation) = load_cached(pool, candidate, &[job])
+        .await?
+        .remove(&job.job_id)
+    {
+        return Ok(Some((explanation, true)));
+    }
+    if !ai_available() {
+        return Ok(None);
+    }
+
+    Ok(generate_and_cache(pool, candidate, job)
+        .await
+        .map(|explanation| (explanation, false)))
+}
+
+/// Loads cached explanations for the candidate's current profile version
+/// and the strategy each job was scored with.
+async fn load_cached(
+    pool: &PgPool,
+    candidate: &CandidateProfile,
+    jobs: &[&JobMatch],
+) -> AppResult<HashMap<i32, String>> {
+    let job_ids: Vec<i32> = jobs.iter().map(|job| job.job_id).collect();
+    let strategy_hashes: Vec<&str> = jobs.iter().map(|job| job.strategy_hash.as_str()).collect();
+    let cached = sqlx::query!(
+        r#"
+        SELECT e.job_id, e.explanation
+        FROM match_explanations e
+        JOIN unnest($3::int[], $4::text[]) AS scored(job_id, strategy_hash)
+          ON scored.job_id = e.job_id AND scored.strategy_hash = e.strategy_hash
+        WHERE e.user_id = $1 AND e.profile_version = $2
+        "#,
+        candidate.user_id,
+        candidate.version,
+        &job_ids,
+        &strategy_hashes as &[&str]
+    )
+    .fetch_all(pool)
+    .await?
+    .into_iter()
+    .map(|row| (row.job_id, row.explanation))
+    .collect();
+
+    Ok(cached)
+}
+
+/// Asks the AI model to explain a match and caches the result.
+async fn generate_and_cache(
+    pool: &PgPool,
+    candidate: &CandidateProfile,
+    job: &JobMatch,
+) -> Option<String> {
+    let candidate_experience = candidate.experience().map(|exp| exp.to_string());
+    let job_experience = job.experience.to_string();
+    let explanation = {
+        // The semaphore is never closed, so acquiring only waits
+        let _permit = AI_PERMITS.acquire().await.ok()?;
+        generate_ai_explanation_hf(
+            &candidate.skills,
+            &job.required_skills,
+            candidate_experience.as_deref(),
+            &job_experience,
+            candidate.preferred_track_name.as_deref(),
+            &job.job_title,
+            &job.job_description,
+            job.match_score,
+        )
+        .await
+    };
+
+    let explanation = match explanation {
+        Ok(explanation) if !explanation.is_empty() => explanation,
+        Ok(_) => {
+            warn!("Empty AI match explanation for job {}", job.job_id);
+            return None;
+        }
+        Err(e) => {
+            warn!("Failed to generate AI match explanation for job {}: {}", job.job_id, e);
+            return None;
+        }
+    };
+
+    let stored = sqlx::query!(
+        r#"
+        INSERT INTO match_explanations (user_id, job_id, strategy_hash, profile_version, explanation)
+        VALUES ($1, $2, $3, $4, $5)
+        ON CONFLICT (user_id, job_id, strategy_hash) DO UPDATE
+        SET profile_version = EXCLUDED.profile_version,
+            explanation = EXCLUDED.explanation,
+            created_at = CURRENT_TIMESTAMP
+        "#,
+        candidate.user_id,
+        job.job_id,
+        job.strategy_hash,
+        candidate.version,
+        explanation
+    )
+    .execute(pool)
+    .await;
+    if let Err(e) = stored {
+        warn!("Failed to cache match explanation for job {}: {}", job.job_id, e);
+    }
+
+    Some(explanation)
+}
+
+/// Whether an AI model is configured.
+fn ai_available() -> bool {
+    env::var("HUGGINGFACE_API_KEY").is_ok_and(|key| !key.is_empty())
+}
+
+/// AI explanations generated per recommendations request
+/// (`MATCH_EXPLANATION_AI_BUDGET`, 0 disables them).
+fn ai_budget() -> usize {
+    env::var("MATCH_EXPLANATION_AI_BUDGET")
+        .ok()
+        .and_then(|budget| budget.parse().ok())
+        .unwrap_or(DEFAULT_AI_BUDGET)
+}
+
+/// How long a recommendations request waits for AI explanations
+/// (`MATCH_EXPLANATION_WAIT_SECS`).
+fn ai_wait() -> Duration {
+    let secs = env::var("MATCH_EXPLANATION_WAIT_SECS")
+        .ok()
+        .and_then(|secs| secs.parse().ok())
+        .unwrap_or(DEFAULT_AI_WAIT_SECS);
+    Duration::from_secs(secs)
+}
@@ -0,0 +1,595 @@
+//! Match feedback and offline evaluation of match strategies.
+//!
+//! Each recommendations request scored with the user's own strategy stores
+//! the latest showing of every job, with the score components of its
+//! [`EnhancedMatchAnalysis`], in `match_impressions`. Feedback on recommended
+//! jobs goes to `match_feedback` along with the components the job was last
+//! shown with: views and "not interested" (with a reason) come from the
+//! client, saves, applications and application status changes are recorded
+//! by their handlers.
+//!
+//! For evaluation, each shown job is graded by the strongest feedback on it
+//! (see [`gain`]), and each user's jobs are ranked by the score they were
+//! shown with. [`evaluate`] reports precision@k and NDCG@k of those rankings;
+//! [`tune_weights`] re-ranks the same jobs under a grid of weights and keeps
+//! the weights with the best NDCG@k.
+
+use crate::ai_matching::{EnhancedMatchAnalysis, MatchWeights};
+use crate::errors::AppResult;
+use crate::models::{MatchFeedbackEvent, NotInterestedReason};
+use serde::Serialize;
+use sqlx::PgPool;
+use std::collections::HashMap;
+use tracing::{debug, warn};
+use uuid::Uuid;
+
+/// Gain of a job the user was not interested in.
+const NOT_INTERESTED_GAIN: u8 = 0;
+
+/// Lowest gain counted as relevant by precision@k: the user saved the job.
+const RELEVANT_GAIN: u8 = 2;
+
+/// Steps per unit weight in the tuning grid (0.05 apart).
+const TUNING_STEPS: u32 = 20;
+
+/// A job shown to a user and the feedback on it.
+#[derive(Debug, Clone)]
+pub struct JudgedImpression {
+    /// Score the job was shown with
+    pub match_score: f64,
+    /// Skill overlap component
+    pub skill_overlap: f64,
+    /// Experience alignment component
+    pub experience_alignment: f64,
+    /// Track alignment component
+    pub track_alignment: f64,
+    /// Semantic similarity component, when computed
+    pub semantic_similarity: Option<f64>,
+    /// Graded relevance (see [`gain`])
+    pub gain: u8,
+}
+
+/// Impressions and feedback of one match strategy.
+#[derive(Debug, Default)]
+pub struct StrategyFeedback {
+    /// Name of the strategy
+    pub strategy: String,
+    /// Jobs shown to each user
+    pub rankings: Vec<Vec<JudgedImpression>>,
+    /// Feedback events by kind
+    pub events: HashMap<MatchFeedbackEvent, i64>,
+    /// "Not interested" reasons
+    pub reasons: HashMap<NotInterestedReason, i64>,
+}
+
+impl StrategyFeedback {
+    /// Number of jobs shown
+    pub fn impressions(&self) -> usize {
+        self.rankings.iter().map(Vec::len).sum()
+    }
+}
+
+/// A job shown in recommendations, with its score components.
+#[derive(Debug, Clone, Copy)]
+pub struct Impression {
+    /// The job shown
+    pub job_id: i32,
+    /// Match score it was shown with
+    pub match_score: f64,
+    /// Skill overlap component
+    pub skill_overlap: f64,
+    /// Experience alignment component
+    pub experience_alignment: f64,
+    /// Track alignment component
+    pub track_alignment: f64,
+    /// Semantic similarity component, when computed
+    pub semantic_similarity: Option<f64>,
+}
+
+impl Impression {
+    /// The components of a job's match analysis
+    pub fn new(job_id: i32, analysis: &EnhancedMatchAnalysis) -> Self {
+        Self {
+            job_id,
+            match_score: analysis.match_score,
+            skill_overlap: analysis.skill_overlap,
+            experience_alignment: analysis.experience_alignment,
+            track_alignment: analysis.track_alignment,
+            semantic_similarity: analysis.semantic_similarity,
+        }
+    }
+}
+
+/// Mean ranking quality over users with positive feedback.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize)]
+pub struct RankingMetrics {
+    /// Users with at least one job with positive feedback
+    pub users: usize,
+    /// Share of the top k jobs that were saved, applied to or better
+    pub precision_at_k: f64,
+    /// Normalized discounted cumulative gain of the top k jobs
+    pub ndcg_at_k: f64,
+}
+
+/// Stores the jobs shown to a user in one recommendations request.
+///
+/// # Errors
+///
+/// Returns an error if the database operation fails.
+pub async fn record_impressions(
+    pool: &PgPool,
+    user_id: Uuid,
+    strategy: &str,
+    shown: &[Impression],
+) -> AppResult<()> {
+    if shown.is_empty() {
+        return Ok(());
+    }
+
+    let job_ids: Vec<i32> = shown.iter().map(|i| i.job_id).collect();
+    let scores: Vec<f64> = shown.iter().map(|i| i.match_score).collect();
+    let skill_overlaps: Vec<f64> = shown.iter().map(|i| i.skill_overlap).collect();
+    let experience: Vec<f64> = shown.iter().map(|i| i.experience_alignment).collect();
+    let tracks: Vec<f64> = shown.iter().map(|i| i.track_alignment).collect();
+    let semantic: Vec<Option<f64>> = shown.iter().map(|i| i.semantic_similarity).collect();
+
+    sqlx::query!(
+        r#"
+        INSERT INTO match_impressions (
+            user_id, job_id, match_strategy, match_score, skill_overlap,
+            experience_alignment, track_alignment, semantic_similarity
+        )
+        SELECT $1, u.job_id, $2, u.match_score, u.skill_overlap,
+               u.experience_alignment, u.track_alignment, u.semantic_similarity
+        FROM UNNEST($3::int[], $4::float8[], $5::float8[], $6::float8[], $7::float8[], $8::float8[])
+            AS u(job_id, match_score, skill_overlap, experience_alignment, track_alignment, semantic_similarity)
+        ON CONFLICT (user_id, job_id) DO UPDATE SET
+            match_strategy = EXCLUDED.match_strategy,
+            match_score = EXCLUDED.match_score,
+            skill_overlap = EXCLUDED.skill_overlap,
+            experience_alignment = EXCLUDED.experience_alignment,
+            track_alignment = EXCLUDED.track_alignment,
+            semantic_similarity = EXCLUDED.semantic_similarity,
+            shown_at = CURRENT_TIMESTAMP
+        "#,
+        user_id,
+        strategy,
+        &job_ids,
+        &scores,
+        &skill_overlaps,
+        &experience,
+        &tracks,
+        &semantic as &[Option<f64>]
+    )
+    .execute(pool)
+    .await?;
+
+    debug!("Recorded {} impressions for user: {}", shown.len(), user_id);
+    Ok(())
+}
+
+/// Records feedback on a job, with the score components it was last shown
+/// to the user with (none if it never was).
+///
+/// # Errors
+///
+/// Returns an error if the database operation fails.
+pub async fn record_feedback(
+    pool: &PgPool,
+    user_id: Uuid,
+    job_id: i32,
+    event: MatchFeedbackEvent,
+    reason: Option<NotInterestedReason>,
+    application_status: Option<&str>,
+) -> AppResult<()> {
+    sqlx::query!(
+        r#"
+        INSERT INTO match_feedback (
+            user_id, job_id, event, reason, application_status, match_strategy,
+            match_score, skill_overlap, experience_alignment, track_alignment, semantic_similarity
+        )
+        SELECT $1, $2, $3, $4, $5, i.match_strategy,
+               i.match_score, i.skill_overlap, i.experience_alignment, i.track_alignment,
+               i.semantic_similarity
+        FROM (SELECT 1) AS one
+        LEFT JOIN match_impressions i ON i.user_id = $1 AND i.job_id = $2
+        "#,
+        user_id,
+        job_id,
+        event as MatchFeedbackEvent,
+        reason as Option<NotInterestedReason>,
+        application_status
+    )
+    .execute(pool)
+    .await?;
+
+    debug!("Recorded {:?} feedback: user_id={}, job_id={}", event, user_id, job_id);
+    Ok(())
+}
+
+/// Records feedback implied by another action, such as saving a job.
+///
+/// Failures are logged rather than returned, so they never fail the action.
+pub async fn record_outcome(
+    pool: &PgPool,
+    user_id: Uuid,
+    job_id: i32,
+    event: MatchFeedbackEvent,
+    application_status: Option<&str>,
+) {
+    if let Err(e) = record_feedback(pool, user_id, job_id, event, None, application_status).await {
+        warn!(
+            "Failed to record {:?} feedback: user_id={}, job_id={}: {}",
+            event, user_id, job_id, e
+        );
+    }
+}
+
+/// Graded relevance of a job from the feedback on it, in order.
+///
+/// A view scores 1, a save 2 and an application 3; application statuses
+/// mentioning an interview score 4 and offers or hires 5. The strongest
+/// feedback counts, except that "not interested" scores 0 whatever else
+/// happened.
+pub fn gain<'a>(events: impl IntoIterator<Item = (MatchFeedbackEvent, Option<&'a str>)>) -> u8 {
+    let mut gain = 0;
+    for (event, status) in events {
+        let event_gain = match event {
+            MatchFeedbackEvent::NotInterested => return NOT_INTERESTED_GAIN,
+            MatchFeedbackEvent::View => 1,
+            MatchFeedbackEvent::Save => 2,
+            MatchFeedbackEvent::Apply => 3,
+            MatchFeedbackEvent::Status => status.map_or(3, status_gain),
+        };
+        gain = gain.max(event_gain);
+    }
+    gain
+}
+
+/// Gain of an application that reached a status.
+fn status_gain(status: &str) -> u8 {
+    let status = status.to_lowercase();
+    if ["offer", "hired", "accepted"].iter().any(|s| status.contains(s)) {
+        5
+    } else if status.contains("interview") {
+        4
+    } else {
+        3
+    }
+}
+
+/// Loads impressions and feedback of the last `days` days, by strategy.
+///
+/// Feedback on jobs that were never recommended to the user is left out.
+///
+/// # Errors
+///
+/// Returns an error if the database operation fails.
+pub async fn load_feedback(pool: &PgPool, days: i32) -> AppResult<Vec<StrategyFeedback>> {
+    let impressions = sqlx::query!(
+        r#"
+        SELECT user_id, job_id, match_strategy, match_score, skill_overlap,
+               experience_alignment, track_alignment, semantic_similarity
+        FROM match_impressions
+        WHERE shown_at >= NOW() - make_interval(days => $1)
+        ORDER BY match_strategy, user_id, job_id
+        "#,
+        days
+    )
+    .fetch_all(pool)
+    .await?;
+    let feedback = sqlx::query!(
+        r#"
+        SELECT user_id, job_id, match_strategy as "match_strategy!",
+               event as "event: MatchFeedbackEvent",
+               reason as "reason: NotInterestedReason", application_status
+        FROM match_feedback
+        WHERE created_at >= NOW() - make_interval(days => $1)
+          AND match_strategy IS NOT NULL
+        ORDER BY created_at, id
+        "#,
+        days
+    )
+    .fetch_all(pool)
+    .await?;
+
+    let mut events_by_job: HashMap<(Uuid, i32), Vec<_>> = HashMap::new();
+    let mut strategies: Vec<StrategyFeedback> = Vec::new();
+    for row in &feedback {
+        events_by_job
+            .entry((row.user_id, row.job_id))
+            .or_default()
+            .push((row.event, row.application_status.as_deref()));
+        let index = strategy_index(&mut strategies, &row.match_strategy);
+        let strategy = &mut strategies[index];
+        *strategy.events.entry(row.event).or_default() += 1;
+        if let Some(reason) = row.reason {
+            *strategy.reasons.entry(reason).or_default() += 1;
+        }
+    }
+
+    // Rows are ordered by strategy and user, so each user's jobs are adjacent
+    let mut current: Option<(usize, Uuid)> = None;
+    for row in impressions {
+        let index = strategy_index(&mut strategies, &row.match_strategy);
+        let strategy = &mut strategies[index];
+        if current != Some((index, row.user_id)) {
+            strategy.rankings.push(Vec::new());
+            current = Some((index, row.user_id));
+        }
+        let events = events_by_job
+            .get(&(row.user_id, row.job_id))
+            .map(Vec::as_slice)
+            .unwrap_or_default();
+        if let Some(ranking) = strategy.rankings.last_mut() {
+            ranking.push(JudgedImpression {
+                match_score: row.match_score,
+                skill_overlap: row.skill_overlap,
+                experience_alignment: row.experience_alignment,
+                track_alignment: row.track_alignment,
+                semantic_similarity: row.semantic_similarity,
+                gain: gain(events.iter().copied()),
+            });
+        }
+    }
+
+    Ok(strategies)
+}
+
+/// Index of a strategy's feedback, added when missing.
+fn strategy_index(strategies: &mut Vec<StrategyFeedback>, name: &str) -> usize {
+    strategies
+        .iter()
+        .position(|s| s.strategy == name)
+        .unwrap_or_else(|| {
+            strategies.push(StrategyFeedback {
+                strategy: name.to_string(),
+                ..Default::default()
+            });
+            strategies.len() - 1
+        })
+}
+
+/// Precision@k and NDCG@k of users' jobs, ranked by the score they were
+/// shown with or, given `weights`, by the score those weights give them.
+///
+/// Users without positive feedback are left out, as no ranking of their
+/// jobs is better than another.
+pub fn evaluate(
+    rankings: &[Vec<JudgedImpression>],
+    k: usize,
+    weights: Option<MatchWeights>,
+) -> RankingMetrics {
+    let mut metrics = RankingMetrics::default();
+    let mut gains = Vec::new();
+    let mut scored = Vec::new();
+
+    for ranking in rankings {
+        if k == 0 || ranking.iter().all(|impression| impression.gain == 0) {
+            continue;
+        }
+
+        scored.clear();
+        scored.extend(ranking.iter().map(|impression| {
+            let score = weights.map_or(impression.match_score, |weights| {
+                weights.score(
+                    impression.skill_overlap,
+                    impression.experience_alignment,
+                    impression.track_alignment,
+                    impression.semantic_similarity,
+                )
+            });
+            (score, impression.gain)
+        }));
+        // Ties keep the order jobs were loaded in, for every set of weights
+        scored.sort_by(|a, b| b.0.total_cmp(&a.0));
+
+        let relevant = scored
+            .iter()
+            .take(k)
+            .filter(|(_, gain)| *gain >= RELEVANT_GAIN)
+            .count();
+        gains.clear();
+        gains.extend(scored.iter().map(|(_, gain)| *gain));
+        let dcg = discounted_gain(&gains, k);
+        gains.sort_unstable_by(|a, b| b.cmp(a));
+        let ideal = discounted_gain(&gains, k);
+
+        metrics.users += 1;
+        metrics.precision_at_k += relevant as f64 / k as f64;
+        metrics.ndcg_at_k += dcg / ideal;
+    }
+
+    if metrics.users > 0 {
+        metrics.precision_at_k /= metrics.users as f64;
+        metrics.ndcg_at_k /= metrics.users as f64;
+    }
+    metrics
+}
+
+/// Discounted cumulative gain of the first `k` gains.
+fn discounted_gain(gains: &[u8], k: usize) -> f64 {
+    gains
+        .iter()
+        .take(k)
+        .enumerate()
+        .map(|(i, gain)| (2f64.powi(i32::from(*gain)) - 1.0) / (i as f64 + 2.0).log2())
+        .sum()
+}
+
+/// The weights, 0.05 apart and summing to 1, that rank users' jobs with the
+/// best NDCG@k, and their metrics.
+///
+/// `baseline` (the strategy's current weights) is kept unless other weights
+/// do strictly better. Semantic similarity is only weighted when some jobs
+/// have one.
+pub fn tune_weights(
+    rankings: &[Vec<JudgedImpression>],
+    k: usize,
+    baseline: Option<MatchWeights>,
+) -> (MatchWeights, RankingMetrics) {
+    let has_semantic = rankings
+        .iter()
+        .flatten()
+        .any(|impression| impression.semantic_similarity.is_some());
+
+    let mut best = baseline.map(|weights| (weights, evaluate(rankings, k, Some(weights))));
+    for skills in 0..=TUNING_STEPS {
+        for experience in 0..=TUNING_STEPS - skills {
+            for track in 0..=TUNING_STEPS - skills - experience {
+                let semantic = TUNING_STEPS - skills - experience - track;
+                if semantic > 0 && !has_semantic {
+                    continue;
+                }
+                let step = |n: u32| f64::from(n) / f64::from(TUNING_STEPS);
+                let weights = MatchWeights {
+                    skills: step(skills),
+                    experience: step(experience),
+                    track: step(track),
+                    semantic: step(semantic),
+                };
+                let metrics = evaluate(rankings, k, Some(weights));
+                if best.is_none_or(|(_, best)| metrics.ndcg_at_k > best.ndcg_at_k + 1e-9) {
+                    best = Some((weights, metrics));
+                }
+            }
+        }
+    }
+
+    // The grid always has at least one point
+    best.unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn shown(match_score: f64, gain: u8) -> JudgedImpression {
+        JudgedImpression {
+            match_score,
+            skill_overlap: 0.0,
+            experience_alignment: 0.0,
+            track_alignment: 0.0,
+            semantic_similarity: None,
+            gain,
+        }
+    }
+
+    fn assert_close(actual: f64, expected: f64) {
+        assert!((actual - expected).abs() < 1e-9, "{actual} != {expected}");
+    }
+
+    #[test]
+    fn gain_takes_the_strongest_feedback() {
+        use MatchFeedbackEvent::*;
+
+        assert_eq!(gain([]), 0);
+        assert_eq!(gain([(View, None), (Save, None)]), 2);
+        assert_eq!(gain([(Apply, None), (View, None)]), 3);
+        assert_eq!(gain([(Status, None)]), 3);
+        assert_eq!(gain([(Apply, None), (Status, Some("Interview scheduled"))]), 4);
+        assert_eq!(gain([(Status, Some("Offer received")), (Status, Some("interview"))]), 5);
+    }
+
+    #[test]
+    fn not_interested_overrides_other_feedback() {
+        use MatchFeedbackEvent::*;
+
+        assert_eq!(gain([(NotInterested, None), (Save, None)]), 0);
+        assert_eq!(gain([(Apply, None), (NotInterested, None)]), 0);
+    }
+
+    #[test]
+    fn discounted_gain_discounts_by_position() {
+        // (2^3 - 1) / log2(2) + (2^1 - 1) / log2(3) + 0
+        assert_close(discounted_gain(&[3, 1, 0], 3), 7.0 + 1.0 / 3f64.log2());
+        assert_close(discounted_gain(&[3, 1, 0], 1), 7.0);
+        assert_close(discounted_gain(&[0, 1, 3], 3), 1.0 / 3f64.log2() + 7.0 / 2.0);
+        assert_close(discounted_gain(&[3, 1], 0), 0.0);
+    }
+
+    #[test]
+    fn perfect_ranking_scores_higher_than_reversed() {
+        let perfect = vec![vec![shown(90.0, 3), shown(50.0, 1), shown(10.0, 0)]];
+        let reversed = vec![vec![shown(10.0, 3), shown(50.0, 1), shown(90.0, 0)]];
+
+        let best = evaluate(&perfect, 2, None);
+        assert_eq!(best.users, 1);
+        assert_close(best.ndcg_at_k, 1.0);
+        assert_close(best.precision_at_k, 0.5);
+
+        // Shown as gains 0, 1, 3 against the ideal 3, 1, 0
+        let worst = evaluate(&reversed, 3, None);
+        let ideal = 7.0 + 1.0 / 3f64.log2();
+        assert_close(worst.ndcg_at_k, (1.0 / 3f64.log2() + 7.0 / 2.0) / ideal);
+        assert!(worst.ndcg_at_k < best.ndcg_at_k);
+        assert_close(evaluate(&reversed, 2, None).precision_at_k, 0.0);
+    }
+
+    #[test]
+    fn evaluate_averages_over_users() {
+        let rankings = vec![
+            vec![shown(90.0, 2), shown(10.0, 0)],
+            vec![shown(90.0, 0), shown(10.0, 2)],
+        ];
+
+        let metrics = evaluate(&rankings, 1, None);
+
+        assert_eq!(metrics.users, 2);
+        assert_close(metrics.precision_at_k, 0.5);
+        assert_close(metrics.ndcg_at_k, 0.5);
+    }
+
+    #[test]
+    fn evaluate_skips_k_zero_and_users_without_positive_feedback() {
+        let rankings = vec![vec![shown(90.0, 3), shown(10.0, 1)]];
+        assert_eq!(evaluate(&rankings, 0, None), RankingMetrics::default());
+
+        let unjudged = vec![vec![shown(90.0, 0), shown(10.0, 0)]];
+        assert_eq!(evaluate(&unjudged, 5, None), RankingMetrics::default());
+        assert_eq!(evaluate(&[], 5, None), RankingMetrics::default());
+    }
+
+    #[test]
+    fn tune_weights_keeps_the_baseline_on_ties() {
+        // A single relevant job ranks first under any weights
+        let rankings = vec![vec![shown(80.0, 2)]];
+        let baseline = MatchWeights {
+            skills: 0.42,
+            experience: 0.33,
+            track: 0.25,
+            semantic: 0.0,
+        };
+
+        let (weights, metrics) = tune_weights(&rankings, 5, Some(baseline));
+
+        assert_eq!(weights, baseline);
+        assert_close(metrics.ndcg_at_k, 1.0);
+    }
+
+    #[test]
+    fn tune_weights_finds_weights_ranking_relevant_jobs_first() {
+        let skilled = JudgedImpression {
+            skill_overlap: 100.0,
+            ..shown(20.0, 3)
+        };
+        let experienced = JudgedImpression {
+            experience_alignment: 100.0,
+            ..shown(80.0, 0)
+        };
+        let rankings = vec![vec![experienced, skilled]];
+        let baseline = MatchWeights {
+            skills: 0.0,
+            experience: 1.0,
+            track: 0.0,
+            semantic: 0.0,
+        };
+        assert!(evaluate(&rankings, 2, Some(baseline)).ndcg_at_k < 1.0);
+
+        let (weights, metrics) = tune_weights(&rankings, 2, Some(baseline));
+
+        assert!(weights.skills > weights.experience);
+        assert_eq!(weights.semantic, 0.0);
+        assert_close(metrics.ndcg_at_k, 1.0);
+    }
+}
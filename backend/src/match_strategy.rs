@@ -0,0 +1,486 @@
+//! Match scoring strategies and A/B experiments.
+//!
+//! A [`MatchStrategy`] turns a candidate and a job into a match score. The
+//! weighted heuristic of [`calculate_enhanced_match`] is the only kind so
+//! far; deployments configure one or more named heuristics with their own
+//! weights in `MATCH_STRATEGIES_FILE` (see `match_strategies.example.json`).
+//!
+//! When the file defines an experiment, each user is assigned one of its
+//! arms by hashing their ID, so the split is stable without coordination.
+//! The assignment is stored in `match_assignments` when the user is first
+//! scored and on each application, so strategies can be compared by
+//! application outcomes. Requests can override the strategy or its weights
+//! for experimentation; overridden scores are not part of the experiment.
+
+use crate::ai_matching::{EnhancedMatchAnalysis, MatchInput, MatchWeights, calculate_enhanced_match};
+use crate::career_tracks::TrackTaxonomy;
+use crate::errors::{AppError, AppResult};
+use crate::hashing::fnv1a;
+use crate::skills::SkillTaxonomy;
+use serde::{Deserialize, Serialize};
+use sqlx::PgPool;
+use std::env;
+use std::sync::Arc;
+use tracing::{debug, info};
+use uuid::Uuid;
+
+/// Name of the built-in strategy used when no strategies file is given.
+pub const DEFAULT_STRATEGY: &str = "heuristic";
+
+/// Maximum length of strategy and experiment names.
+const MAX_NAME_LENGTH: usize = 100;
+
+/// Scores how well a candidate matches a job.
+pub trait MatchStrategy: Send + Sync {
+    /// Name the strategy is configured and reported under
+    fn name(&self) -> &str;
+
+    /// Kind of strategy, as written in the strategies file
+    fn kind(&self) -> &'static str;
+
+    /// How skills, experience and track count towards the score
+    fn weights(&self) -> MatchWeights;
+
+    /// Settings specific to the kind of strategy, for reports
+    fn settings(&self) -> serde_json::Value;
+
+    /// The same strategy with other weights
+    fn with_weights(&self, weights: MatchWeights) -> Arc<dyn MatchStrategy>;
+
+    /// Hash of the name, kind, weights and settings, which together decide
+    /// the scores; results quoting a score are cached under it
+    fn hash(&self) -> String {
+        let config = serde_json::json!({
+            "name": self.name(),
+            "kind": self.kind(),
+            "weights": self.weights(),
+            "settings": self.settings(),
+        });
+        format!("{:016x}", fnv1a(config.to_string().as_bytes()))
+    }
+
+    /// Scores a candidate against a job
+    fn score(
+        &self,
+        input: &MatchInput,
+        skills: &SkillTaxonomy,
+        tracks: &TrackTaxonomy,
+    ) -> EnhancedMatchAnalysis;
+}
+
+/// The weighted heuristic of [`calculate_enhanced_match`].
+#[derive(Debug, Clone)]
+pub struct HeuristicStrategy {
+    name: String,
+    weights: MatchWeights,
+    extra_skill_bonus: bool,
+}
+
+impl HeuristicStrategy {
+    /// Creates a heuristic strategy; `weights` should be normalized.
+    pub fn new(name: impl Into<String>, weights: MatchWeights, extra_skill_bonus: bool) -> Self {
+        Self {
+            name: name.into(),
+            weights,
+            extra_skill_bonus,
+        }
+    }
+}
+
+impl MatchStrategy for HeuristicStrategy {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn kind(&self) -> &'static str {
+        "heuristic"
+    }
+
+    fn weights(&self) -> MatchWeights {
+        self.weights
+    }
+
+    fn settings(&self) -> serde_json::Value {
+        serde_json::json!({ "extra_skill_bonus": self.extra_skill_bonus })
+    }
+
+    fn with_weights(&self, weights: MatchWeights) -> Arc<dyn MatchStrategy> {
+        Arc::new(Self {
+            weights,
+            ..self.clone()
+        })
+    }
+
+    fn score(
+        &self,
+        input: &MatchInput,
+        skills: &SkillTaxonomy,
+        tracks: &TrackTaxonomy,
+    ) -> EnhancedMatchAnalysis {
+        calculate_enhanced_match(input, skills, tracks, self.weights, self.extra_skill_bonus)
+    }
+}
+
+/// One strategy of an experiment and its share of users.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ExperimentArm {
+    /// Name of the strategy
+    pub strategy: String,
+    /// Percentage of users assigned to it
+    pub percent: u32,
+}
+
+/// An A/B experiment splitting users between strategies.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Experiment {
+    /// Experiment name, recorded with assignments and applications
+    pub name: String,
+    /// Arms in assignment order; percentages sum to 100
+    pub arms: Vec<ExperimentArm>,
+}
+
+impl Experiment {
+    /// The arm a user falls into, by hashing the experiment name and user ID.
+    fn arm_for(&self, user_id: Uuid) -> &ExperimentArm {
+        let bucket = (bucket_hash(&self.name, user_id) % 100) as u32;
+        let mut upper = 0;
+        for arm in &self.arms {
+            upper += arm.percent;
+            if bucket < upper {
+                return arm;
+            }
+        }
+        // Unreachable for validated experiments, whose arms cover 0-99
+        &self.arms[self.arms.len() - 1]
+    }
+}
+
+/// Hash of an experiment name and user ID.
+fn bucket_hash(experiment: &str, user_id: Uuid) -> u64 {
+    fnv1a(experiment.as_bytes().iter().chain(user_id.as_bytes()))
+}
+
+/// Strategies file, as written in `MATCH_STRATEGIES_FILE`.
+#[derive(Debug, Deserialize)]
+struct StrategiesFile {
+    /// Strategy for users outside the experiment (default: the first one)
+    default: Option<String>,
+    strategies: Vec<StrategyConfig>,
+    experiment: Option<Experiment>,
+}
+
+/// A configured strategy.
+#[derive(Debug, Deserialize)]
+struct StrategyConfig {
+    name: String,
+    #[serde(flatten)]
+    kind: StrategyKind,
+}
+
+/// Kind-specific strategy settings.
+#[derive(Debug, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+enum StrategyKind {
+    Heuristic {
+        #[serde(default)]
+        weights: MatchWeights,
+        #[serde(default)]
+        extra_skill_bonus: bool,
+    },
+}
+
+/// The strategy a request is scored with.
+pub struct SelectedStrategy {
+    /// The strategy, with any weight override applied
+    pub strategy: Arc<dyn MatchStrategy>,
+    /// Whether the request chose the strategy or weights itself
+    pub overridden: bool,
+}
+
+/// The match strategies configured for this deployment.
+pub struct MatchStrategies {
+    strategies: Vec<Arc<dyn MatchStrategy>>,
+    default: usize,
+    experiment: Option<Experiment>,
+}
+
+impl MatchStrategies {
+    /// Creates the registry from explicit strategies.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if there are no strategies, names are invalid or
+    /// repeated, `default` is unknown or the experiment is invalid.
+    pub fn new(
+        strategies: Vec<Arc<dyn MatchStrategy>>,
+        default: Option<&str>,
+        experiment: Option<Experiment>,
+    ) -> AppResult<Self> {
+        let invalid = |message: String| Err(AppError::ConfigurationError(message));
+
+        if strategies.is_empty() {
+            return invalid("At least one match strategy must be configured".to_string());
+        }
+        for (i, strategy) in strategies.iter().enumerate() {
+            let name = strategy.name();
+            if name.is_empty() || name.len() > MAX_NAME_LENGTH {
+                return invalid(format!(
+                    "Match strategy name '{}' must be 1-{} characters",
+                    name, MAX_NAME_LENGTH
+                ));
+            }
+            if strategies[..i].iter().any(|other| other.name() == name) {
+                return invalid(format!("Duplicate match strategy '{}'", name));
+            }
+        }
+
+        let position = |name: &str| strategies.iter().position(|s| s.name() == name);
+        let default = match default {
+            Some(name) => match position(name) {
+                Some(index) => index,
+                None => return invalid(format!("Unknown default match strategy '{}'", name)),
+            },
+            None => 0,
+        };
+
+        if let Some(experiment) = &experiment {
+            if experiment.name.is_empty() || experiment.name.len() > MAX_NAME_LENGTH {
+                return invalid(format!(
+                    "Experiment name '{}' must be 1-{} characters",
+                    experiment.name, MAX_NAME_LENGTH
+                ));
+            }
+            for (i, arm) in experiment.arms.iter().enumerate() {
+                if position(&arm.strategy).is_none() {
+                    return invalid(format!(
+                        "Experiment arm '{}' is not a configured match strategy",
+                        arm.strategy
+                    ));
+                }
+                if experiment.arms[..i].iter().any(|other| other.strategy == arm.strategy) {
+                    return invalid(format!("Duplicate experiment arm '{}'", arm.strategy));
+                }
+            }
+            let total: u32 = experiment.arms.iter().map(|arm| arm.percent).sum();
+            if total != 100 {
+                return invalid(format!(
+                    "Experiment arm percentages must sum to 100, got {}",
+                    total
+                ));
+            }
+        }
+
+        Ok(Self {
+            strategies,
+            default,
+            experiment,
+        })
+    }
+
+    /// Creates the registry from environment variables.
+    ///
+    /// - `MATCH_STRATEGIES_FILE` - JSON file of strategies and an optional
+    ///   experiment (see `match_strategies.example.json`)
+    /// - `MATCH_WEIGHTS` - Weights of the built-in `heuristic` strategy used
+    ///   without a strategies file, e.g. `skills:0.6,experience:0.2,track:0.2`
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the strategies file or weights are invalid.
+    pub fn from_env() -> AppResult<Self> {
+        let registry = match env::var("MATCH_STRATEGIES_FILE") {
+            Ok(path) => Self::load_file(&path)?,
+            Err(_) => {
+                let weights = match env::var("MATCH_WEIGHTS") {
+                    Ok(text) => MatchWeights::parse(&text).map_err(|e| {
+                        AppError::ConfigurationError(format!("Invalid MATCH_WEIGHTS: {}", e))
+                    })?,
+                    Err(_) => MatchWeights::default(),
+                };
+                let heuristic = HeuristicStrategy::new(DEFAULT_STRATEGY, weights, false);
+                Self::new(vec![Arc::new(heuristic)], None, None)?
+            }
+        };
+
+        info!(
+            "Match strategies: {} (default: {})",
+            registry.names().join(", "),
+            registry.default_strategy().name()
+        );
+        if let Some(experiment) = registry.experiment() {
+            info!(
+                "Match experiment {}: {}",
+                experiment.name,
+                experiment
+                    .arms
+                    .iter()
+                    .map(|arm| format!("{} {}%", arm.strategy, arm.percent))
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            );
+        }
+
+        Ok(registry)
+    }
+
+    /// Loads strategies from a JSON file.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file cannot be read or is invalid.
+    pub fn load_file(path: &str) -> AppResult<Self> {
+        let contents = std::fs::read_to_string(path).map_err(|e| {
+            AppError::ConfigurationError(format!(
+                "Failed to read match strategies file {}: {}",
+                path, e
+            ))
+        })?;
+        let file: StrategiesFile = serde_json::from_str(&contents).map_err(|e| {
+            AppError::ConfigurationError(format!("Invalid match strategies file {}: {}", path, e))
+        })?;
+
+        let mut strategies: Vec<Arc<dyn MatchStrategy>> = Vec::new();
+        for config in file.strategies {
+            match config.kind {
+                StrategyKind::Heuristic {
+                    weights,
+                    extra_skill_bonus,
+                } => {
+                    let weights = weights.normalized().map_err(|e| {
+                        AppError::ConfigurationError(format!(
+                            "Invalid weights for match strategy '{}': {}",
+                            config.name, e
+                        ))
+                    })?;
+                    strategies.push(Arc::new(HeuristicStrategy::new(
+                        config.name,
+                        weights,
+                        extra_skill_bonus,
+                    )));
+                }
+            }
+        }
+
+        Self::new(strategies, file.default.as_deref(), file.experiment)
+    }
+
+    /// All strategies, in configuration order.
+    pub fn strategies(&self) -> &[Arc<dyn MatchStrategy>] {
+        &self.strategies
+    }
+
+    /// Names of all strategies.
+    pub fn names(&self) -> Vec<&str> {
+        self.strategies.iter().map(|s| s.name()).collect()
+    }
+
+    /// The strategy for users outside the experiment.
+    pub fn default_strategy(&self) -> &Arc<dyn MatchStrategy> {
+        &self.strategies[self.default]
+    }
+
+    /// The running experiment, if any.
+    pub fn experiment(&self) -> Option<&Experiment> {
+        self.experiment.as_ref()
+    }
+
+    /// Looks up a strategy by name.
+    pub fn get(&self, name: &str) -> Option<&Arc<dyn MatchStrategy>> {
+        self.strategies.iter().find(|s| s.name() == name)
+    }
+
+    /// The strategy a user is scored with.
+    ///
+    /// Without an experiment this is the default strategy. Otherwise it is
+    /// the user's stored arm, or a newly assigned one when they have none
+    /// or their arm has been removed from the experiment.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the database operation fails.
+    pub async fn for_user(&self, pool: &PgPool, user_id: Uuid) -> AppResult<Arc<dyn MatchStrategy>> {
+        let Some(experiment) = &self.experiment else {
+            return Ok(self.default_strategy().clone());
+        };
+
+        let stored = sqlx::query_scalar!(
+            "SELECT strategy FROM match_assignments WHERE user_id = $1 AND experiment = $2",
+            user_id,
+            experiment.name
+        )
+        .fetch_optional(pool)
+        .await?;
+        let in_experiment = |name: &str| experiment.arms.iter().any(|arm| arm.strategy == name);
+        if let Some(name) = stored.filter(|name| in_experiment(name))
+            && let Some(strategy) = self.get(&name)
+        {
+            return Ok(strategy.clone());
+        }
+
+        let arm = experiment.arm_for(user_id);
+        sqlx::query!(
+            r#"
+            INSERT INTO match_assignments (user_id, experiment, strategy)
+            VALUES ($1, $2, $3)
+            ON CONFLICT (user_id, experiment) DO UPDATE
+            SET strategy = EXCLUDED.strategy, assigned_at = CURRENT_TIMESTAMP
+            "#,
+            user_id,
+            experiment.name,
+            arm.strategy
+        )
+        .execute(pool)
+        .await?;
+        debug!(
+            "Assigned user {} to match strategy {} in experiment {}",
+            user_id, arm.strategy, experiment.name
+        );
+
+        // Arms are validated to name configured strategies
+        self.get(&arm.strategy).cloned().ok_or(AppError::InternalServerError)
+    }
+
+    /// The strategy a request is scored with, honouring overrides.
+    ///
+    /// `strategy` names a configured strategy and `weights` replaces its
+    /// weights (see [`MatchWeights::parse`]). Without either, this is the
+    /// user's strategy from [`MatchStrategies::for_user`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the strategy is unknown, the weights are invalid
+    /// or the database operation fails.
+    pub async fn select(
+        &self,
+        pool: &PgPool,
+        user_id: Uuid,
+        strategy: Option<&str>,
+        weights: Option<&str>,
+    ) -> AppResult<SelectedStrategy> {
+        let base = match strategy {
+            Some(name) => self.get(name).cloned().ok_or_else(|| {
+                AppError::BadRequest(format!(
+                    "Unknown match strategy '{}', expected one of: {}",
+                    name,
+                    self.names().join(", ")
+                ))
+            })?,
+            None if weights.is_some() => self.default_strategy().clone(),
+            None => {
+                return Ok(SelectedStrategy {
+                    strategy: self.for_user(pool, user_id).await?,
+                    overridden: false,
+                });
+            }
+        };
+        let strategy = match weights {
+            Some(text) => base.with_weights(MatchWeights::parse(text)?),
+            None => base,
+        };
+
+        Ok(SelectedStrategy {
+            strategy,
+            overridden: true,
+        })
+    }
+}
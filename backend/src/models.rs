@@ -5,8 +5,10 @@
 
 use serde::{Deserialize, Deserializer, Serialize};
 use sqlx::FromRow;
+use sqlx::types::Json as SqlJson;
+use std::collections::HashMap;
 use uuid::Uuid;
-use chrono::{DateTime, Utc};
+use chrono::{DateTime, NaiveDate, Utc};
 
 /// Custom deserializer for case-insensitive enum matching
 fn deserialize_case_insensitive<'de, D, T>(deserializer: D) -> Result<T, D::Error>
@@ -22,7 +24,7 @@ where
 }
 
 /// Experience level of a user or required for a job.
-#[derive(Debug, Serialize, sqlx::Type)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, sqlx::Type)]
 #[sqlx(type_name = "experience_level")]
 #[sqlx(rename_all = "lowercase")]
 #[serde(rename_all = "lowercase")]
@@ -33,6 +35,46 @@ pub enum ExperienceLevel {
     Junior,
     /// 3-5 years of experience
     Mid,
+    /// 5-8 years of experience
+    Senior,
+    /// 8+ years of experience, leading teams
+    Lead,
+}
+
+impl ExperienceLevel {
+    /// Returns the database/API name of the level.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ExperienceLevel::Fresher => "fresher",
+            ExperienceLevel::Junior => "junior",
+            ExperienceLevel::Mid => "mid",
+            ExperienceLevel::Senior => "senior",
+            ExperienceLevel::Lead => "lead",
+        }
+    }
+
+    /// Returns the whole years of experience the level covers, as
+    /// `(min, max)`; `max` is `None` for open-ended levels.
+    pub fn years(&self) -> (i32, Option<i32>) {
+        match self {
+            ExperienceLevel::Fresher => (0, Some(0)),
+            ExperienceLevel::Junior => (1, Some(2)),
+            ExperienceLevel::Mid => (3, Some(4)),
+            ExperienceLevel::Senior => (5, Some(7)),
+            ExperienceLevel::Lead => (8, None),
+        }
+    }
+
+    /// Returns the level covering the given years of experience.
+    pub fn from_years(years: i32) -> Self {
+        match years {
+            i32::MIN..=0 => ExperienceLevel::Fresher,
+            1..=2 => ExperienceLevel::Junior,
+            3..=4 => ExperienceLevel::Mid,
+            5..=7 => ExperienceLevel::Senior,
+            _ => ExperienceLevel::Lead,
+        }
+    }
 }
 
 impl std::str::FromStr for ExperienceLevel {
@@ -43,6 +85,8 @@ impl std::str::FromStr for ExperienceLevel {
             "fresher" => Ok(ExperienceLevel::Fresher),
             "junior" => Ok(ExperienceLevel::Junior),
             "mid" => Ok(ExperienceLevel::Mid),
+            "senior" => Ok(ExperienceLevel::Senior),
+            "lead" => Ok(ExperienceLevel::Lead),
             _ => Err(format!("Unknown experience level: {}", s)),
         }
     }
@@ -57,37 +101,67 @@ impl<'de> Deserialize<'de> for ExperienceLevel {
     }
 }
 
-/// Career track or specialization path.
-#[derive(Debug, Serialize, sqlx::Type)]
-#[sqlx(type_name = "career_track")]
-#[sqlx(rename_all = "snake_case")]
-#[serde(rename_all = "snake_case")]
-pub enum CareerTrack {
-    /// Web development and engineering
-    WebDevelopment,
-    /// Data science and analytics
-    Data,
-    /// UI/UX and graphic design
-    Design,
-    /// Digital and content marketing
-    Marketing,
-}
-
-impl std::str::FromStr for CareerTrack {
+/// How well a user knows a skill.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum SkillProficiency {
+    /// Learning the basics
+    Beginner,
+    /// Can work independently
+    Intermediate,
+    /// Can handle complex work and guide others
+    Advanced,
+    /// Deep knowledge, recognized authority
+    Expert,
+}
+
+impl SkillProficiency {
+    /// Returns the API name of the proficiency.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            SkillProficiency::Beginner => "beginner",
+            SkillProficiency::Intermediate => "intermediate",
+            SkillProficiency::Advanced => "advanced",
+            SkillProficiency::Expert => "expert",
+        }
+    }
+
+    /// Returns the rank of the proficiency, from 1 (beginner) to 4 (expert).
+    pub fn rank(&self) -> u8 {
+        match self {
+            SkillProficiency::Beginner => 1,
+            SkillProficiency::Intermediate => 2,
+            SkillProficiency::Advanced => 3,
+            SkillProficiency::Expert => 4,
+        }
+    }
+
+    /// Returns the proficiency expected of someone at an experience level.
+    pub fn expected_for(level: ExperienceLevel) -> Self {
+        match level {
+            ExperienceLevel::Fresher => SkillProficiency::Beginner,
+            ExperienceLevel::Junior | ExperienceLevel::Mid => SkillProficiency::Intermediate,
+            ExperienceLevel::Senior => SkillProficiency::Advanced,
+            ExperienceLevel::Lead => SkillProficiency::Expert,
+        }
+    }
+}
+
+impl std::str::FromStr for SkillProficiency {
     type Err = String;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        match s.to_lowercase().replace('-', "_").replace(' ', "_").as_str() {
-            "web_development" | "webdevelopment" => Ok(CareerTrack::WebDevelopment),
-            "data" => Ok(CareerTrack::Data),
-            "design" => Ok(CareerTrack::Design),
-            "marketing" => Ok(CareerTrack::Marketing),
-            _ => Err(format!("Unknown career track: {}", s)),
+        match s.to_lowercase().as_str() {
+            "beginner" => Ok(SkillProficiency::Beginner),
+            "intermediate" => Ok(SkillProficiency::Intermediate),
+            "advanced" => Ok(SkillProficiency::Advanced),
+            "expert" => Ok(SkillProficiency::Expert),
+            _ => Err(format!("Unknown skill proficiency: {}", s)),
         }
     }
 }
 
-impl<'de> Deserialize<'de> for CareerTrack {
+impl<'de> Deserialize<'de> for SkillProficiency {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
     where
         D: Deserializer<'de>,
@@ -97,7 +171,7 @@ impl<'de> Deserialize<'de> for CareerTrack {
 }
 
 /// Type of job or employment arrangement.
-#[derive(Debug, Serialize, sqlx::Type)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, sqlx::Type)]
 #[sqlx(type_name = "job_type")]
 #[sqlx(rename_all = "snake_case")]
 #[serde(rename_all = "snake_case")]
@@ -116,7 +190,7 @@ impl std::str::FromStr for JobType {
     type Err = String;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        match s.to_lowercase().replace('-', "_").replace(' ', "_").as_str() {
+        match s.to_lowercase().replace(['-', ' '], "_").as_str() {
             "internship" => Ok(JobType::Internship),
             "part_time" | "parttime" => Ok(JobType::PartTime),
             "full_time" | "fulltime" => Ok(JobType::FullTime),
@@ -135,8 +209,44 @@ impl<'de> Deserialize<'de> for JobType {
     }
 }
 
+/// Category an external job posting is listed under.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, sqlx::Type)]
+#[sqlx(type_name = "external_job_category")]
+#[sqlx(rename_all = "lowercase")]
+#[serde(rename_all = "lowercase")]
+pub enum ExternalJobCategory {
+    /// UN agencies and NGOs
+    Ngo,
+    /// Government job portals
+    Govt,
+    /// Local job boards
+    Local,
+}
+
+impl std::str::FromStr for ExternalJobCategory {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "ngo" => Ok(ExternalJobCategory::Ngo),
+            "govt" | "government" => Ok(ExternalJobCategory::Govt),
+            "local" => Ok(ExternalJobCategory::Local),
+            _ => Err(format!("Unknown external job category: {}", s)),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for ExternalJobCategory {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserialize_case_insensitive(deserializer)
+    }
+}
+
 /// Cost indicator for learning resources.
-#[derive(Debug, Serialize, sqlx::Type)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, sqlx::Type)]
 #[sqlx(type_name = "cost_indicator")]
 #[sqlx(rename_all = "lowercase")]
 #[serde(rename_all = "lowercase")]
@@ -168,6 +278,277 @@ impl<'de> Deserialize<'de> for CostIndicator {
     }
 }
 
+/// Role of a user account.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, sqlx::Type)]
+#[sqlx(type_name = "user_role")]
+#[sqlx(rename_all = "snake_case")]
+#[serde(rename_all = "snake_case")]
+pub enum UserRole {
+    /// Regular user looking for jobs and learning resources
+    JobSeeker,
+    /// Member of a company who can post and manage its jobs
+    Employer,
+    /// Platform administrator
+    Admin,
+}
+
+impl std::str::FromStr for UserRole {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().replace(['-', ' '], "_").as_str() {
+            "job_seeker" | "jobseeker" => Ok(UserRole::JobSeeker),
+            "employer" => Ok(UserRole::Employer),
+            "admin" => Ok(UserRole::Admin),
+            _ => Err(format!("Unknown user role: {}", s)),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for UserRole {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserialize_case_insensitive(deserializer)
+    }
+}
+
+/// Verification status of a company profile.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, sqlx::Type)]
+#[sqlx(type_name = "verification_status")]
+#[sqlx(rename_all = "lowercase")]
+#[serde(rename_all = "lowercase")]
+pub enum VerificationStatus {
+    /// Awaiting review by an administrator
+    Pending,
+    /// Confirmed as a genuine organisation
+    Verified,
+    /// Verification was refused
+    Rejected,
+}
+
+impl std::str::FromStr for VerificationStatus {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "pending" => Ok(VerificationStatus::Pending),
+            "verified" => Ok(VerificationStatus::Verified),
+            "rejected" => Ok(VerificationStatus::Rejected),
+            _ => Err(format!("Unknown verification status: {}", s)),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for VerificationStatus {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserialize_case_insensitive(deserializer)
+    }
+}
+
+/// Lifecycle status of a job posting.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, sqlx::Type)]
+#[sqlx(type_name = "job_status")]
+#[sqlx(rename_all = "lowercase")]
+#[serde(rename_all = "lowercase")]
+pub enum JobStatus {
+    /// Being prepared by the employer; not visible to job seekers
+    Draft,
+    /// Listed and accepting applications
+    Open,
+    /// Closed by the employer
+    Closed,
+    /// Past its expiry date or application deadline
+    Expired,
+}
+
+impl std::str::FromStr for JobStatus {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "draft" => Ok(JobStatus::Draft),
+            "open" => Ok(JobStatus::Open),
+            "closed" => Ok(JobStatus::Closed),
+            "expired" => Ok(JobStatus::Expired),
+            _ => Err(format!("Unknown job status: {}", s)),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for JobStatus {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserialize_case_insensitive(deserializer)
+    }
+}
+
+/// Currency of a salary.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, sqlx::Type)]
+#[sqlx(type_name = "salary_currency")]
+#[sqlx(rename_all = "UPPERCASE")]
+#[serde(rename_all = "UPPERCASE")]
+pub enum SalaryCurrency {
+    /// Bangladeshi taka
+    Bdt,
+    /// US dollar
+    Usd,
+    /// Euro
+    Eur,
+    /// Pound sterling
+    Gbp,
+    /// Indian rupee
+    Inr,
+}
+
+impl std::str::FromStr for SalaryCurrency {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "bdt" | "tk" | "taka" => Ok(SalaryCurrency::Bdt),
+            "usd" => Ok(SalaryCurrency::Usd),
+            "eur" => Ok(SalaryCurrency::Eur),
+            "gbp" => Ok(SalaryCurrency::Gbp),
+            "inr" => Ok(SalaryCurrency::Inr),
+            _ => Err(format!("Unknown salary currency: {}", s)),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for SalaryCurrency {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserialize_case_insensitive(deserializer)
+    }
+}
+
+/// Pay period a salary amount refers to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, sqlx::Type)]
+#[sqlx(type_name = "salary_period")]
+#[sqlx(rename_all = "lowercase")]
+#[serde(rename_all = "lowercase")]
+pub enum SalaryPeriod {
+    /// Per hour
+    Hour,
+    /// Per working day
+    Day,
+    /// Per week
+    Week,
+    /// Per month
+    Month,
+    /// Per year
+    Year,
+}
+
+impl std::str::FromStr for SalaryPeriod {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "hour" | "hourly" => Ok(SalaryPeriod::Hour),
+            "day" | "daily" => Ok(SalaryPeriod::Day),
+            "week" | "weekly" => Ok(SalaryPeriod::Week),
+            "month" | "monthly" => Ok(SalaryPeriod::Month),
+            "year" | "yearly" | "annual" => Ok(SalaryPeriod::Year),
+            _ => Err(format!("Unknown salary period: {}", s)),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for SalaryPeriod {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserialize_case_insensitive(deserializer)
+    }
+}
+
+/// Where the work is done.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, sqlx::Type)]
+#[sqlx(type_name = "work_arrangement", rename_all = "snake_case")]
+#[serde(rename_all = "snake_case")]
+pub enum WorkArrangement {
+    /// At the employer's premises
+    OnSite,
+    /// Partly on-site, partly remote
+    Hybrid,
+    /// Fully remote
+    Remote,
+}
+
+impl std::str::FromStr for WorkArrangement {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().replace(['-', ' '], "_").as_str() {
+            "on_site" | "onsite" | "office" => Ok(WorkArrangement::OnSite),
+            "hybrid" => Ok(WorkArrangement::Hybrid),
+            "remote" => Ok(WorkArrangement::Remote),
+            _ => Err(format!("Unknown work arrangement: {}", s)),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for WorkArrangement {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserialize_case_insensitive(deserializer)
+    }
+}
+
+/// Structured location resolved from free-text location against the
+/// Bangladesh gazetteer (see [`crate::locations`]).
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Location {
+    /// Country, when known
+    pub country: Option<String>,
+    /// Administrative division, e.g. "Chattogram"
+    pub division: Option<String>,
+    /// District, e.g. "Cox's Bazar"
+    pub district: Option<String>,
+    /// City or area within the district, e.g. "Ukhia"
+    pub city: Option<String>,
+    /// Latitude of the most specific place found
+    pub latitude: Option<f64>,
+    /// Longitude of the most specific place found
+    pub longitude: Option<f64>,
+    /// On-site, hybrid or remote
+    pub work_arrangement: WorkArrangement,
+}
+
+/// Structured salary of an external posting, parsed from the source's text.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Salary {
+    /// Lower bound, in `currency` per `period`
+    pub min: Option<i32>,
+    /// Upper bound, in `currency` per `period`
+    pub max: Option<i32>,
+    /// Currency of the amounts
+    pub currency: SalaryCurrency,
+    /// Pay period of the amounts
+    pub period: SalaryPeriod,
+    /// True when the salary is negotiable or unspecified ("Competitive")
+    pub negotiable: bool,
+    /// Lower bound normalized to monthly BDT
+    #[serde(default)]
+    pub min_monthly_bdt: Option<i32>,
+    /// Upper bound normalized to monthly BDT
+    #[serde(default)]
+    pub max_monthly_bdt: Option<i32>,
+}
+
 /// User account with profile and career information.
 #[derive(Debug, FromRow, Serialize, Deserialize)]
 pub struct User {
@@ -182,13 +563,18 @@ pub struct User {
     /// Current experience level (nullable until profile completion)
     #[sqlx(rename = "experience_level")]
     pub experience_level: Option<ExperienceLevel>,
-    /// Preferred career track (nullable until profile completion)
+    /// Years of professional experience, when given (more precise than the level)
+    pub experience_years: Option<i32>,
+    /// Slug of the preferred career track (nullable until profile completion)
     #[sqlx(rename = "preferred_track")]
-    pub preferred_track: Option<CareerTrack>,
+    pub preferred_track: Option<String>,
     /// Whether the user has completed their profile onboarding
     pub profile_completed: bool,
     /// List of skills the user possesses
     pub skills: Vec<String>,
+    /// Proficiency per skill, by canonical skill name; skills without one
+    /// are assumed to match the user's experience level
+    pub skill_proficiency: SqlJson<HashMap<String, SkillProficiency>>,
     /// List of projects the user has completed
     pub projects: Vec<String>,
     /// Target job roles the user is interested in
@@ -200,6 +586,14 @@ pub struct User {
     /// Hashed password (excluded from serialization)
     #[serde(skip_serializing)]
     pub password_hash: String,
+    /// Account role (job seeker, employer or admin)
+    pub role: UserRole,
+    /// Company the user belongs to (employers only)
+    pub company_id: Option<i32>,
+    /// Divisions, districts or cities the user wants to work in, or "Remote"
+    pub preferred_locations: Vec<String>,
+    /// Whether employers can find the user as a candidate
+    pub discoverable: bool,
 }
 
 /// Job listing with requirements and details.
@@ -214,34 +608,203 @@ pub struct Job {
     pub company: String,
     /// Job location
     pub location: String,
+    /// On-site, hybrid or remote
+    pub work_arrangement: WorkArrangement,
+    /// Country resolved from `location`
+    pub country: Option<String>,
+    /// Division resolved from `location`
+    pub division: Option<String>,
+    /// District resolved from `location`
+    pub district: Option<String>,
+    /// City or area resolved from `location`
+    pub city: Option<String>,
+    /// Latitude of the resolved place
+    pub latitude: Option<f64>,
+    /// Longitude of the resolved place
+    pub longitude: Option<f64>,
     /// Detailed job description
     #[sqlx(rename = "job_description")]
     pub job_description: String,
-    /// Skills required for the job
+    /// Must-have skills for the job
     #[sqlx(rename = "required_skills")]
     pub required_skills: Vec<String>,
+    /// Nice-to-have skills for the job
+    pub preferred_skills: Vec<String>,
+    /// Weight (1-5) per skill, by canonical skill name; unlisted required
+    /// skills weigh 3 and preferred skills 1
+    pub skill_weights: SqlJson<HashMap<String, i32>>,
     /// Required experience level
     #[sqlx(rename = "experience_level")]
     pub experience_level: ExperienceLevel,
+    /// Minimum years of experience asked for (defaults to the level's range)
+    pub experience_years_min: Option<i32>,
+    /// Maximum years of experience asked for (`None` with a minimum means "or more")
+    pub experience_years_max: Option<i32>,
     /// Type of employment
     #[sqlx(rename = "job_type")]
     pub job_type: JobType,
-    /// Minimum salary offered in the range (optional, in local currency)
+    /// Minimum salary offered in the range (optional, in `salary_currency` per `salary_period`)
     #[sqlx(rename = "salary_min")]
     pub salary_min: Option<i32>,
-    /// Maximum salary offered in the range (optional, in local currency)
+    /// Maximum salary offered in the range (optional, in `salary_currency` per `salary_period`)
     #[sqlx(rename = "salary_max")]
     pub salary_max: Option<i32>,
+    /// Currency of `salary_min` and `salary_max`
+    pub salary_currency: SalaryCurrency,
+    /// Pay period of `salary_min` and `salary_max`
+    pub salary_period: SalaryPeriod,
+    /// Whether the salary is negotiable
+    pub salary_negotiable: bool,
+    /// `salary_min` normalized to monthly BDT
+    pub salary_min_monthly_bdt: Option<i32>,
+    /// `salary_max` normalized to monthly BDT
+    pub salary_max_monthly_bdt: Option<i32>,
     /// List of job responsibilities
     pub responsibilities: Vec<String>,
     /// List of job requirements
     pub requirements: Vec<String>,
     /// List of benefits offered
     pub benefits: Vec<String>,
+    /// Company that owns the posting (None for legacy listings)
+    pub company_id: Option<i32>,
+    /// Lifecycle status
+    pub status: JobStatus,
+    /// Last moment applications are accepted
+    pub application_deadline: Option<DateTime<Utc>>,
+    /// When the listing is taken down
+    pub expires_at: Option<DateTime<Utc>>,
+    /// When the job was posted
+    pub created_at: Option<DateTime<Utc>>,
 }
 
-/// Learning resource for skill development.
+/// Company profile that employer users belong to.
 #[derive(Debug, FromRow, Serialize, Deserialize)]
+pub struct Company {
+    /// Unique company identifier
+    pub id: i32,
+    /// Company name
+    pub name: String,
+    /// URL of the company logo
+    pub logo_url: Option<String>,
+    /// Company website
+    pub website: Option<String>,
+    /// Industry or sector (e.g., "FinTech", "NGO")
+    pub industry: Option<String>,
+    /// Headcount bracket (e.g., "11-50")
+    pub company_size: Option<String>,
+    /// Office locations
+    pub locations: Vec<String>,
+    /// Short description of the organisation
+    pub description: Option<String>,
+    /// Whether the company has been verified by an administrator
+    pub verification_status: VerificationStatus,
+    /// When the company profile was created
+    pub created_at: Option<DateTime<Utc>>,
+}
+
+/// Status of an invitation to join a company.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, sqlx::Type)]
+#[sqlx(type_name = "company_invitation_status", rename_all = "snake_case")]
+#[serde(rename_all = "snake_case")]
+pub enum CompanyInvitationStatus {
+    /// Awaiting the invited user's answer
+    Pending,
+    /// The user joined the company as an employer
+    Accepted,
+    /// The user turned the invitation down
+    Declined,
+}
+
+impl std::str::FromStr for CompanyInvitationStatus {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "pending" => Ok(CompanyInvitationStatus::Pending),
+            "accepted" | "accept" => Ok(CompanyInvitationStatus::Accepted),
+            "declined" | "decline" => Ok(CompanyInvitationStatus::Declined),
+            _ => Err(format!("Unknown company invitation status: {}", s)),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for CompanyInvitationStatus {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserialize_case_insensitive(deserializer)
+    }
+}
+
+/// Invitation for a user to join a company as an employer.
+#[derive(Debug, Clone, FromRow, Serialize, Deserialize)]
+pub struct CompanyInvitation {
+    /// Unique invitation identifier
+    pub id: i32,
+    /// Company the user is invited to
+    pub company_id: i32,
+    /// Invited user
+    pub user_id: Uuid,
+    /// Member of the company who sent the invitation
+    pub invited_by: Option<Uuid>,
+    /// Whether the user accepted
+    pub status: CompanyInvitationStatus,
+    /// When the invitation was sent
+    pub created_at: Option<DateTime<Utc>>,
+    /// When the user answered
+    pub responded_at: Option<DateTime<Utc>>,
+}
+
+/// Career track or sub-track (e.g. Web Development > DevOps & Cloud).
+///
+/// Tracks are stored in the `career_tracks` table and edited by admins; see
+/// [`crate::career_tracks`] for how they are used in matching.
+#[derive(Debug, Clone, FromRow, Serialize, Deserialize)]
+pub struct CareerTrack {
+    /// Unique identifier used in profiles and filters (e.g. "web_development")
+    pub slug: String,
+    /// Name shown to users
+    pub display_name: String,
+    /// Label on generated CVs, if different from the display name
+    pub cv_label: Option<String>,
+    /// Parent track of a sub-track
+    pub parent_slug: Option<String>,
+    /// Lowercase job title keywords that identify the track
+    pub keywords: Vec<String>,
+    /// Skills typical of the track
+    pub related_skills: Vec<String>,
+    /// Whether the track can be chosen for profiles and inferred for jobs
+    pub active: bool,
+    /// When the track was created
+    pub created_at: Option<DateTime<Utc>>,
+    /// When the track was last edited
+    pub updated_at: Option<DateTime<Utc>>,
+}
+
+/// Skill in the skill taxonomy (e.g. "React", also spelled "React.js").
+///
+/// See [`crate::skills`] for how skills are normalized and compared.
+#[derive(Debug, Clone, FromRow, Serialize, Deserialize)]
+pub struct Skill {
+    /// Canonical identifier (e.g. "react_native")
+    pub slug: String,
+    /// Canonical spelling stored on profiles, jobs and resources
+    pub name: String,
+    /// Skill category (e.g. "frontend", "data", "soft_skill")
+    pub category: String,
+    /// Broader skill implied by this one (React implies JavaScript)
+    pub parent_slug: Option<String>,
+    /// Other spellings of the skill
+    pub aliases: Vec<String>,
+    /// When the skill was added
+    pub created_at: Option<DateTime<Utc>>,
+    /// When the skill was last edited
+    pub updated_at: Option<DateTime<Utc>>,
+}
+
+/// Learning resource for skill development.
+#[derive(Debug, Clone, FromRow, Serialize, Deserialize)]
 pub struct LearningResource {
     /// Unique resource identifier
     pub id: i32,
@@ -259,6 +822,167 @@ pub struct LearningResource {
     pub cost: CostIndicator,
 }
 
+/// Job posting ingested from an external source (ReliefWeb, job boards, ...).
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ExternalJob {
+    /// Source-prefixed posting ID, e.g. `reliefweb_123`
+    pub id: String,
+    /// Job title
+    pub title: String,
+    /// Hiring organisation
+    pub company: String,
+    /// Job location
+    pub location: String,
+    /// Location resolved from `location`, for stored postings
+    #[serde(default)]
+    pub location_details: Option<Location>,
+    /// Job description
+    pub description: String,
+    /// Link to the original posting
+    pub url: String,
+    /// Posting date as reported by the source
+    pub posted_date: String,
+    /// Publisher shown to users, e.g. "ReliefWeb" or "BPSC"
+    pub source: String,
+    /// Job type as reported by the source
+    pub job_type: Option<String>,
+    /// Experience level as reported by the source
+    pub experience_level: Option<String>,
+    /// Skills or career categories
+    pub skills: Vec<String>,
+    /// Salary as reported by the source
+    pub salary: Option<String>,
+    /// Salary parsed from `salary`, when it could be parsed
+    #[serde(default)]
+    pub salary_details: Option<Salary>,
+    /// True when the source is currently failing, so the posting may be outdated
+    #[serde(default)]
+    pub stale: bool,
+    /// When the posting was first ingested
+    #[serde(default)]
+    pub first_seen_at: Option<DateTime<Utc>>,
+    /// When the posting was last seen at its source
+    #[serde(default)]
+    pub last_seen_at: Option<DateTime<Utc>>,
+    /// Every source listing this vacancy, including this posting
+    #[serde(default)]
+    pub sources: Vec<ExternalJobLink>,
+}
+
+/// A source listing of a deduplicated external posting.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ExternalJobLink {
+    /// Source-prefixed posting ID
+    pub id: String,
+    /// Publisher shown to users
+    pub source: String,
+    /// Link to the posting at this source
+    pub url: String,
+}
+
+/// A recorded deduplication decision between two external postings.
+#[derive(Debug, Serialize)]
+pub struct ExternalJobMerge {
+    /// Decision ID
+    pub id: i32,
+    /// Posting kept as canonical
+    pub canonical_id: String,
+    /// Source of the canonical posting
+    pub canonical_source: String,
+    /// Posting compared against the canonical one
+    pub duplicate_id: String,
+    /// Source of the compared posting
+    pub duplicate_source: String,
+    /// Shared fingerprint (normalized title, company and location)
+    pub fingerprint: String,
+    /// Description similarity, or `None` when a description was too short to compare
+    pub similarity: Option<f32>,
+    /// True when the postings were merged
+    pub merged: bool,
+    /// When the decision was made
+    pub decided_at: DateTime<Utc>,
+}
+
+/// Ingestion health of an external job source.
+#[derive(Debug, FromRow, Serialize)]
+pub struct ExternalSourceStatus {
+    /// Source key, e.g. `reliefweb`
+    pub source: String,
+    /// When the source was last fetched
+    pub last_attempt_at: DateTime<Utc>,
+    /// When the source was last fetched successfully
+    pub last_success_at: Option<DateTime<Utc>>,
+    /// Error from the last failed fetch
+    pub last_error: Option<String>,
+    /// Number of failed fetches since the last success
+    pub consecutive_failures: i32,
+    /// Postings returned by the last successful fetch
+    pub jobs_fetched: i32,
+    /// Whether postings from this source are currently flagged stale
+    pub stale: bool,
+}
+
+/// Demand for a skill on one snapshot date.
+#[derive(Debug, Serialize)]
+pub struct SkillDemandPoint {
+    /// Snapshot date
+    pub date: NaiveDate,
+    /// Open jobs requiring the skill
+    pub job_count: i64,
+    /// Share of open jobs requiring the skill (0-100)
+    pub share: f64,
+}
+
+/// Demand for a skill over time.
+#[derive(Debug, Serialize)]
+pub struct SkillDemandSeries {
+    /// Skill name
+    pub skill: String,
+    /// One point per snapshot date, oldest first
+    pub points: Vec<SkillDemandPoint>,
+}
+
+/// Skill demand time series between two snapshot dates.
+#[derive(Debug, Serialize)]
+pub struct SkillDemandTrends {
+    /// First snapshot date in the window, or `None` when there are no snapshots
+    pub from: Option<NaiveDate>,
+    /// Latest snapshot date
+    pub to: Option<NaiveDate>,
+    /// One series per skill
+    pub series: Vec<SkillDemandSeries>,
+}
+
+/// Change in demand for a skill between two snapshot dates.
+#[derive(Debug, Clone, Serialize)]
+pub struct SkillDemandChange {
+    /// Skill name
+    pub skill: String,
+    /// Open jobs requiring the skill at the earlier date
+    pub previous_count: i64,
+    /// Open jobs requiring the skill at the later date
+    pub current_count: i64,
+    /// Share of open jobs at the earlier date (0-100)
+    pub previous_share: f64,
+    /// Share of open jobs at the later date (0-100)
+    pub current_share: f64,
+    /// Change in share, in percentage points
+    pub share_change: f64,
+}
+
+/// Skills whose share of open jobs rose or fell the most between two snapshot dates.
+#[derive(Debug, Serialize)]
+pub struct SkillDemandMovers {
+    /// Earlier snapshot date, or `None` when there is not enough history
+    pub from: Option<NaiveDate>,
+    /// Later snapshot date
+    pub to: Option<NaiveDate>,
+    /// Largest increases first
+    pub risers: Vec<SkillDemandChange>,
+    /// Largest decreases first
+    pub fallers: Vec<SkillDemandChange>,
+}
+
 /// Job application tracking information.
 #[derive(Debug, FromRow, Serialize, Deserialize)]
 pub struct ApplicationTracking {
@@ -276,6 +1000,176 @@ pub struct ApplicationTracking {
     pub notes: Option<String>,
 }
 
+/// Status of an employer's request to contact a candidate.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, sqlx::Type)]
+#[sqlx(type_name = "contact_request_status", rename_all = "snake_case")]
+#[serde(rename_all = "snake_case")]
+pub enum ContactRequestStatus {
+    /// Awaiting the candidate's answer
+    Pending,
+    /// The candidate shares their name and email with the employer
+    Accepted,
+    /// The candidate stays anonymous
+    Declined,
+}
+
+impl std::str::FromStr for ContactRequestStatus {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "pending" => Ok(ContactRequestStatus::Pending),
+            "accepted" | "accept" => Ok(ContactRequestStatus::Accepted),
+            "declined" | "decline" => Ok(ContactRequestStatus::Declined),
+            _ => Err(format!("Unknown contact request status: {}", s)),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for ContactRequestStatus {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserialize_case_insensitive(deserializer)
+    }
+}
+
+/// An employer's request to contact a discoverable candidate about a job.
+#[derive(Debug, Clone, FromRow, Serialize, Deserialize)]
+pub struct ContactRequest {
+    /// Unique request identifier
+    pub id: i32,
+    /// Job the candidate is contacted about
+    pub job_id: i32,
+    /// Candidate being contacted
+    pub candidate_id: Uuid,
+    /// Employer who sent the request
+    pub requested_by: Option<Uuid>,
+    /// Message to the candidate
+    pub message: Option<String>,
+    /// Whether the candidate accepted
+    pub status: ContactRequestStatus,
+    /// When the request was sent
+    pub created_at: Option<DateTime<Utc>>,
+    /// When the candidate answered
+    pub responded_at: Option<DateTime<Utc>>,
+}
+
+/// Feedback a user gave on a recommended job, explicitly or by acting on it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, sqlx::Type)]
+#[sqlx(type_name = "match_feedback_event", rename_all = "snake_case")]
+#[serde(rename_all = "snake_case")]
+pub enum MatchFeedbackEvent {
+    /// Opened the job's details
+    View,
+    /// Saved the job
+    Save,
+    /// Applied to the job
+    Apply,
+    /// Changed the status of their application
+    Status,
+    /// Dismissed the job
+    NotInterested,
+}
+
+impl std::str::FromStr for MatchFeedbackEvent {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().replace(['-', ' '], "_").as_str() {
+            "view" => Ok(MatchFeedbackEvent::View),
+            "save" => Ok(MatchFeedbackEvent::Save),
+            "apply" => Ok(MatchFeedbackEvent::Apply),
+            "status" => Ok(MatchFeedbackEvent::Status),
+            "not_interested" => Ok(MatchFeedbackEvent::NotInterested),
+            _ => Err(format!("Unknown feedback event: {}", s)),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for MatchFeedbackEvent {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserialize_case_insensitive(deserializer)
+    }
+}
+
+/// Why a user is not interested in a recommended job.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, sqlx::Type)]
+#[sqlx(type_name = "not_interested_reason", rename_all = "snake_case")]
+#[serde(rename_all = "snake_case")]
+pub enum NotInterestedReason {
+    /// Not the kind of work they are looking for
+    Irrelevant,
+    /// Doesn't fit their skills
+    Skills,
+    /// Too junior or too senior
+    Seniority,
+    /// Wrong location or work arrangement
+    Location,
+    /// Pay too low
+    Salary,
+    /// Not interested in the employer
+    Company,
+    /// Any other reason
+    Other,
+}
+
+impl std::str::FromStr for NotInterestedReason {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "irrelevant" => Ok(NotInterestedReason::Irrelevant),
+            "skills" => Ok(NotInterestedReason::Skills),
+            "seniority" => Ok(NotInterestedReason::Seniority),
+            "location" => Ok(NotInterestedReason::Location),
+            "salary" => Ok(NotInterestedReason::Salary),
+            "company" => Ok(NotInterestedReason::Company),
+            "other" => Ok(NotInterestedReason::Other),
+            _ => Err(format!("Unknown reason: {}", s)),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for NotInterestedReason {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserialize_case_insensitive(deserializer)
+    }
+}
+
+/// A job bookmarked by a user for later.
+///
+/// Points at either an internal job (`job_id`) or an external job, in which
+/// case a snapshot of the external listing is kept in `external_job`.
+#[derive(Debug, FromRow, Serialize, Deserialize)]
+pub struct SavedJob {
+    /// Unique bookmark identifier
+    pub id: i32,
+    /// User who saved the job
+    pub user_id: Uuid,
+    /// Saved internal job
+    pub job_id: Option<i32>,
+    /// Source-prefixed ID of a saved external job
+    pub external_job_id: Option<String>,
+    /// Snapshot of the external job listing
+    pub external_job: Option<serde_json::Value>,
+    /// User-defined labels
+    pub tags: Vec<String>,
+    /// Personal notes
+    pub notes: Option<String>,
+    /// Timestamp when the job was saved
+    pub created_at: Option<DateTime<Utc>>,
+    /// Timestamp of the last update
+    pub updated_at: Option<DateTime<Utc>>,
+}
+
 /// User's progress through a learning resource.
 #[derive(Debug, FromRow, Serialize, Deserialize)]
 pub struct UserProgress {
@@ -293,8 +1187,42 @@ pub struct UserProgress {
     pub completed_at: Option<DateTime<Utc>>,
 }
 
+/// A saved job search that can raise job alerts.
+#[derive(Debug, FromRow, Serialize, Deserialize)]
+pub struct SavedSearch {
+    /// Unique saved search identifier
+    pub id: i32,
+    /// User who owns the search
+    pub user_id: Uuid,
+    /// Display name, e.g. "Remote Rust jobs"
+    pub name: String,
+    /// Free-text query (same syntax as job search)
+    pub query: Option<String>,
+    /// Job type filter
+    pub job_type: Option<JobType>,
+    /// Experience level filter
+    pub experience_level: Option<ExperienceLevel>,
+    /// Location substring filter
+    pub location: Option<String>,
+    /// Minimum salary filter, in BDT per month
+    pub salary_min: Option<i32>,
+    /// Maximum salary filter, in BDT per month
+    pub salary_max: Option<i32>,
+    /// Only alert on jobs matching the user's profile at or above this percentage
+    pub min_match_score: Option<f64>,
+    /// Whether alerts are also sent by email
+    pub email_digest: bool,
+    /// Whether the search raises alerts
+    pub is_active: bool,
+    /// Postings created after this time are considered new
+    pub last_checked_at: DateTime<Utc>,
+    /// When the search was saved
+    pub created_at: Option<DateTime<Utc>>,
+    /// When the search was last updated
+    pub updated_at: Option<DateTime<Utc>>,
+}
+
 /// User notification.
-#[allow(dead_code)]
 #[derive(Debug, FromRow, Serialize, Deserialize)]
 pub struct Notification {
     /// Unique notification identifier
@@ -305,13 +1233,17 @@ pub struct Notification {
     pub title: String,
     /// Notification message content
     pub message: String,
-    /// Type of notification (e.g., "job_match", "resource_recommendation")
+    /// Type of notification (e.g., "job_alert", "resource_recommendation")
     #[sqlx(rename = "type")]
     pub notification_type: String,
     /// Whether the notification has been read
     pub is_read: bool,
     /// When the notification was created
     pub created_at: DateTime<Utc>,
+    /// Job the notification refers to, if any
+    pub job_id: Option<i32>,
+    /// Saved search that raised the notification, if any
+    pub saved_search_id: Option<i32>,
 }
 
 /// Skill assessment record.
@@ -362,8 +1294,8 @@ pub struct UserWithOAuth {
     pub education_level: Option<String>,
     /// Current experience level
     pub experience_level: Option<ExperienceLevel>,
-    /// Preferred career track
-    pub preferred_track: Option<CareerTrack>,
+    /// Slug of the preferred career track
+    pub preferred_track: Option<String>,
     /// Whether the user has completed their profile
     pub profile_completed: bool,
     /// List of skills
@@ -0,0 +1,366 @@
+//! Free-text salary parsing.
+//!
+//! External sources and bulk imports report salaries as text such as
+//! "BDT 80,000 - 120,000", "Tk. 35k per month", "USD 50,000 per annum" or
+//! "Competitive (UN Scale)". [`parse_salary`] turns such text into a
+//! structured [`Salary`].
+//!
+//! Normalization to monthly BDT happens in the database
+//! (`salary_to_monthly_bdt`, which keeps the `*_monthly_bdt` columns up to
+//! date using the `salary_exchange_rates` table), so internal and external
+//! postings are compared with the same exchange rates and pay period factors.
+
+use crate::models::{Salary, SalaryCurrency, SalaryPeriod};
+
+/// Phrases that mark a salary as negotiable or unspecified.
+const NEGOTIABLE_PHRASES: [&str; 7] = [
+    "negotiable",
+    "competitive",
+    "attractive",
+    "as per",
+    "un scale",
+    "commensurate",
+    "depending on",
+];
+
+/// Currency symbols, matched anywhere in the text.
+const CURRENCY_SYMBOLS: [(&str, SalaryCurrency); 5] = [
+    ("৳", SalaryCurrency::Bdt),
+    ("$", SalaryCurrency::Usd),
+    ("€", SalaryCurrency::Eur),
+    ("£", SalaryCurrency::Gbp),
+    ("₹", SalaryCurrency::Inr),
+];
+
+/// Currency words, matched as whole words. Checked before symbols so that
+/// "BDT (approx. $500)" is read as taka.
+const CURRENCY_WORDS: [(&str, SalaryCurrency); 8] = [
+    ("bdt", SalaryCurrency::Bdt),
+    ("tk", SalaryCurrency::Bdt),
+    ("taka", SalaryCurrency::Bdt),
+    ("usd", SalaryCurrency::Usd),
+    ("eur", SalaryCurrency::Eur),
+    ("gbp", SalaryCurrency::Gbp),
+    ("inr", SalaryCurrency::Inr),
+    ("rs", SalaryCurrency::Inr),
+];
+
+/// Pay period words, matched as whole words (with dots removed, so "p.m."
+/// reads as "pm"). Checked in order of how common each period is.
+const PERIOD_WORDS: [(&str, SalaryPeriod); 17] = [
+    ("month", SalaryPeriod::Month),
+    ("monthly", SalaryPeriod::Month),
+    ("mo", SalaryPeriod::Month),
+    ("pm", SalaryPeriod::Month),
+    ("year", SalaryPeriod::Year),
+    ("yearly", SalaryPeriod::Year),
+    ("annual", SalaryPeriod::Year),
+    ("annum", SalaryPeriod::Year),
+    ("pa", SalaryPeriod::Year),
+    ("yr", SalaryPeriod::Year),
+    ("hour", SalaryPeriod::Hour),
+    ("hourly", SalaryPeriod::Hour),
+    ("hr", SalaryPeriod::Hour),
+    ("week", SalaryPeriod::Week),
+    ("weekly", SalaryPeriod::Week),
+    ("day", SalaryPeriod::Day),
+    ("daily", SalaryPeriod::Day),
+];
+
+/// Words that mark a single amount as an upper bound.
+const UPPER_BOUND_WORDS: [&str; 3] = ["up to", "upto", "maximum"];
+
+/// Words that mark a single amount as a lower bound.
+const LOWER_BOUND_WORDS: [&str; 4] = ["from", "starting", "minimum", "+"];
+
+/// Words that mark a number as a duration ("2 years contract") rather than
+/// an amount.
+const DURATION_WORDS: [&str; 6] = ["years", "yrs", "months", "weeks", "days", "hours"];
+
+/// An amount found in the text, with the multiplier its suffix applied.
+struct Amount {
+    value: f64,
+    multiplier: f64,
+}
+
+/// Parses a free-text salary.
+///
+/// Currency defaults to BDT and the pay period to monthly when the text does
+/// not say otherwise. Returns `None` when the text contains neither an amount
+/// nor a negotiable marker.
+///
+/// # Examples
+///
+/// ```
+/// use backend::models::{SalaryCurrency, SalaryPeriod};
+/// use backend::salary::parse_salary;
+///
+/// let salary = parse_salary("USD 40k - 55k per annum").unwrap();
+/// assert_eq!((salary.min, salary.max), (Some(40_000), Some(55_000)));
+/// assert_eq!(salary.currency, SalaryCurrency::Usd);
+/// assert_eq!(salary.period, SalaryPeriod::Year);
+///
+/// assert!(parse_salary("Competitive (UN Scale)").unwrap().negotiable);
+/// ```
+pub fn parse_salary(text: &str) -> Option<Salary> {
+    let lower = text.to_lowercase();
+    let words = split_words(&lower.replace('.', ""));
+    let has_word = |word: &str| words.iter().any(|w| w == word);
+
+    let negotiable = NEGOTIABLE_PHRASES.iter().any(|p| lower.contains(p));
+    let currency = CURRENCY_WORDS
+        .iter()
+        .find(|(word, _)| has_word(word))
+        .or_else(|| CURRENCY_SYMBOLS.iter().find(|(symbol, _)| lower.contains(symbol)))
+        .map_or(SalaryCurrency::Bdt, |(_, currency)| *currency);
+    let period = PERIOD_WORDS
+        .iter()
+        .find(|(word, _)| has_word(word))
+        .map_or(SalaryPeriod::Month, |(_, period)| *period);
+
+    let mut amounts = find_amounts(&lower);
+    // "80-120k": a bare lower bound shares the upper bound's suffix
+    if let [first, second, ..] = amounts.as_mut_slice()
+        && first.multiplier == 1.0
+        && second.multiplier > 1.0
+        && first.value <= second.value
+    {
+        first.multiplier = second.multiplier;
+    }
+    let amounts: Vec<i32> = amounts
+        .iter()
+        .map(|a| (a.value * a.multiplier).round().min(i32::MAX as f64) as i32)
+        .collect();
+
+    let (min, max) = match amounts.as_slice() {
+        [] if negotiable => (None, None),
+        [] => return None,
+        [amount] if UPPER_BOUND_WORDS.iter().any(|w| lower.contains(w)) => (None, Some(*amount)),
+        [amount] if LOWER_BOUND_WORDS.iter().any(|w| lower.contains(w)) => (Some(*amount), None),
+        [amount] => (Some(*amount), Some(*amount)),
+        [a, b, ..] => (Some(*a.min(b)), Some(*a.max(b))),
+    };
+
+    Some(Salary {
+        min,
+        max,
+        currency,
+        period,
+        negotiable,
+        min_monthly_bdt: None,
+        max_monthly_bdt: None,
+    })
+}
+
+/// Splits text into words of letters or of digits, so "bdt80,000" yields
+/// "bdt", "80" and "000".
+fn split_words(text: &str) -> Vec<String> {
+    let mut words: Vec<String> = Vec::new();
+    let mut previous: Option<char> = None;
+    for c in text.chars() {
+        if !c.is_alphanumeric() {
+            previous = None;
+            continue;
+        }
+        match previous {
+            Some(p) if p.is_alphabetic() == c.is_alphabetic() => {
+                if let Some(word) = words.last_mut() {
+                    word.push(c);
+                }
+            }
+            _ => words.push(c.to_string()),
+        }
+        previous = Some(c);
+    }
+    words
+}
+
+/// Finds the amounts in lowercased salary text.
+///
+/// Handles thousands separators, decimals and the suffixes `k`, `lakh`/`lac`
+/// and `m`/`million`. Numbers that are part of a code such as "P-3" or "G5"
+/// (UN grades) and durations such as "2 years" are skipped.
+fn find_amounts(text: &str) -> Vec<Amount> {
+    let chars: Vec<char> = text.chars().collect();
+    let mut amounts = Vec::new();
+    let mut i = 0;
+
+    while i < chars.len() {
+        if !chars[i].is_ascii_digit() {
+            i += 1;
+            continue;
+        }
+
+        let start = i;
+        let mut digits = String::new();
+        while i < chars.len()
+            && (chars[i].is_ascii_digit()
+                || chars[i] == ','
+                || (chars[i] == '.' && chars.get(i + 1).is_some_and(char::is_ascii_digit)))
+        {
+            if chars[i] != ',' {
+                digits.push(chars[i]);
+            }
+            i += 1;
+        }
+
+        let mut suffix_start = i;
+        while chars.get(suffix_start) == Some(&' ') {
+            suffix_start += 1;
+        }
+        let suffix: String = chars[suffix_start..]
+            .iter()
+            .take_while(|c| c.is_alphabetic())
+            .collect();
+        let multiplier = match suffix.as_str() {
+            "k" => 1_000.0,
+            "lakh" | "lakhs" | "lac" | "lacs" => 100_000.0,
+            "m" | "mn" | "million" => 1_000_000.0,
+            _ => 1.0,
+        };
+        if multiplier > 1.0 {
+            i = suffix_start + suffix.chars().count();
+        }
+
+        if is_code(&chars, start) || DURATION_WORDS.contains(&suffix.as_str()) {
+            continue;
+        }
+        if let Ok(value) = digits.parse::<f64>() {
+            amounts.push(Amount { value, multiplier });
+        }
+    }
+
+    amounts
+}
+
+/// Returns true if the number starting at `start` is part of a code such as
+/// "G5" or "P-3" rather than an amount. A currency written right before the
+/// number ("BDT80,000", "Tk-500") does not make it a code.
+fn is_code(chars: &[char], start: usize) -> bool {
+    let before = |offset: usize| start.checked_sub(offset).map(|i| chars[i]);
+
+    match before(1) {
+        Some(c) if c.is_alphabetic() => !is_currency_before(chars, start),
+        // "P-3" is a code, but the "-" in "80k-120k" separates a range
+        Some('-') => before(2).is_some_and(char::is_alphabetic)
+            && !before(3).is_some_and(|c| c.is_ascii_digit())
+            && !is_currency_before(chars, start - 1),
+        _ => false,
+    }
+}
+
+/// Returns true if the letters ending right before `end` spell a currency.
+fn is_currency_before(chars: &[char], end: usize) -> bool {
+    let letters = chars[..end]
+        .iter()
+        .rev()
+        .take_while(|c| c.is_alphabetic())
+        .count();
+    let word: String = chars[end - letters..end].iter().collect();
+    CURRENCY_WORDS.iter().any(|(currency, _)| *currency == word)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(text: &str) -> Salary {
+        parse_salary(text).unwrap_or_else(|| panic!("no salary in {:?}", text))
+    }
+
+    #[test]
+    fn currencies() {
+        assert_eq!(parse("BDT 80,000").currency, SalaryCurrency::Bdt);
+        assert_eq!(parse("Tk. 35,000").currency, SalaryCurrency::Bdt);
+        assert_eq!(parse("৳50,000").currency, SalaryCurrency::Bdt);
+        assert_eq!(parse("$4,000").currency, SalaryCurrency::Usd);
+        assert_eq!(parse("EUR 3,000").currency, SalaryCurrency::Eur);
+        assert_eq!(parse("£2,500").currency, SalaryCurrency::Gbp);
+        assert_eq!(parse("Rs. 60,000").currency, SalaryCurrency::Inr);
+        assert_eq!(parse("BDT (approx. $500) 60,000").currency, SalaryCurrency::Bdt);
+        assert_eq!(parse("45,000").currency, SalaryCurrency::Bdt);
+    }
+
+    #[test]
+    fn currency_code_before_digits() {
+        let salary = parse("BDT80,000");
+        assert_eq!(salary.currency, SalaryCurrency::Bdt);
+        assert_eq!((salary.min, salary.max), (Some(80_000), Some(80_000)));
+
+        let salary = parse("USD50,000 per annum");
+        assert_eq!(salary.currency, SalaryCurrency::Usd);
+        assert_eq!(salary.min, Some(50_000));
+
+        assert_eq!(parse("Tk-500 daily").min, Some(500));
+    }
+
+    #[test]
+    fn ranges() {
+        let salary = parse("BDT 80,000 - 120,000");
+        assert_eq!((salary.min, salary.max), (Some(80_000), Some(120_000)));
+
+        let salary = parse("120,000 to 80,000");
+        assert_eq!((salary.min, salary.max), (Some(80_000), Some(120_000)));
+
+        let salary = parse("80k-120k");
+        assert_eq!((salary.min, salary.max), (Some(80_000), Some(120_000)));
+    }
+
+    #[test]
+    fn bare_lower_bound_shares_suffix() {
+        let salary = parse("80-120k");
+        assert_eq!((salary.min, salary.max), (Some(80_000), Some(120_000)));
+
+        // Already a full amount: the suffix is not applied again
+        let salary = parse("50,000 - 80k");
+        assert_eq!((salary.min, salary.max), (Some(50_000), Some(80_000)));
+    }
+
+    #[test]
+    fn suffixes() {
+        assert_eq!(parse("Tk. 35k per month").min, Some(35_000));
+        assert_eq!(parse("1.5 lakh").min, Some(150_000));
+        assert_eq!(parse("2 lacs per annum").min, Some(200_000));
+        assert_eq!(parse("USD 1.2m per year").min, Some(1_200_000));
+    }
+
+    #[test]
+    fn periods() {
+        assert_eq!(parse("30,000").period, SalaryPeriod::Month);
+        assert_eq!(parse("30,000 p.m.").period, SalaryPeriod::Month);
+        assert_eq!(parse("USD 50,000 per annum").period, SalaryPeriod::Year);
+        assert_eq!(parse("$25 hourly").period, SalaryPeriod::Hour);
+        assert_eq!(parse("Tk 2,000 per day").period, SalaryPeriod::Day);
+        assert_eq!(parse("£400 weekly").period, SalaryPeriod::Week);
+    }
+
+    #[test]
+    fn bounds() {
+        let salary = parse("Up to BDT 60,000");
+        assert_eq!((salary.min, salary.max), (None, Some(60_000)));
+
+        let salary = parse("Starting from 40k");
+        assert_eq!((salary.min, salary.max), (Some(40_000), None));
+    }
+
+    #[test]
+    fn negotiable() {
+        let salary = parse("Negotiable");
+        assert!(salary.negotiable);
+        assert_eq!((salary.min, salary.max), (None, None));
+
+        assert!(parse("Competitive (UN Scale)").negotiable);
+        assert!(parse("Tk 30k-40k (negotiable)").negotiable);
+        assert!(!parse("Tk 30k-40k").negotiable);
+    }
+
+    #[test]
+    fn codes_and_durations_are_not_amounts() {
+        let salary = parse("P-3 grade, 2 years contract, USD 6,000 monthly");
+        assert_eq!((salary.min, salary.max), (Some(6_000), Some(6_000)));
+
+        assert!(parse_salary("G5 position").is_none());
+        assert!(parse_salary("").is_none());
+        assert!(parse_salary("See description").is_none());
+    }
+}
@@ -0,0 +1,472 @@
+//! Skill demand trends.
+//!
+//! A periodic task records how many open jobs require each skill in
+//! `skill_demand_snapshots`, broken down by career track (inferred from the
+//! job title, see [`crate::career_tracks`]), experience level and location (division, or `Remote`). The
+//! number of open jobs in each slice goes to `job_demand_snapshots`, so demand
+//! is compared as a share of the market even as the number of postings
+//! changes. Running twice on the same day replaces that day's snapshot.
+//!
+//! The trends API, the career mentor and learning recommendations read the
+//! snapshots through [`skill_trends`] and [`skill_movers`].
+
+use crate::career_tracks::load_taxonomy;
+use crate::errors::{AppError, AppResult};
+use crate::locations::{REMOTE, parse_division};
+use crate::models::{
+    ExperienceLevel, SkillDemandChange, SkillDemandMovers, SkillDemandPoint,
+    SkillDemandSeries, SkillDemandTrends, WorkArrangement,
+};
+use chrono::{Duration, NaiveDate};
+use sqlx::PgPool;
+use std::collections::{HashMap, HashSet};
+use tokio::task::JoinHandle;
+use tokio::time::MissedTickBehavior;
+use tracing::{error, info};
+
+/// Window compared by the mentor and learning recommendations, in days.
+pub const RECENT_TREND_DAYS: i32 = 30;
+
+/// Longest accepted trend window, in days.
+pub const MAX_TREND_DAYS: i32 = 730;
+
+/// Narrows trends to one slice of the market. `None` fields match every slice.
+#[derive(Debug, Default)]
+pub struct TrendFilters {
+    /// Slugs of a career track and its sub-tracks, inferred from job titles
+    pub tracks: Option<Vec<String>>,
+    /// Experience level required by the jobs
+    pub experience_level: Option<ExperienceLevel>,
+    /// Division, or `Remote`
+    pub location: Option<String>,
+}
+
+/// Career track, experience level and location of a snapshot row.
+type Slice = (Option<String>, ExperienceLevel, Option<String>);
+
+/// Spawns the periodic skill demand snapshot task.
+pub fn spawn_skill_snapshots(pool: PgPool, interval: std::time::Duration) -> JoinHandle<()> {
+    tokio::spawn(async move {
+        let mut ticker = tokio::time::interval(interval);
+        ticker.set_missed_tick_behavior(MissedTickBehavior::Delay);
+
+        loop {
+            ticker.tick().await;
+            if let Err(e) = record_snapshot(&pool).await {
+                error!("Skill demand snapshot failed: {}", e);
+            }
+        }
+    })
+}
+
+/// Records today's skill demand snapshot from open jobs, replacing any
+/// snapshot already taken today.
+///
+/// Returns the number of skill rows written.
+pub async fn record_snapshot(pool: &PgPool) -> AppResult<usize> {
+    let jobs = sqlx::query!(
+        r#"
+        SELECT job_title, required_skills,
+               experience_level as "experience_level: ExperienceLevel",
+               work_arrangement as "work_arrangement: WorkArrangement",
+               division
+        FROM jobs
+        WHERE status = 'open'
+        "#
+    )
+    .fetch_all(pool)
+    .await?;
+
+    let taxonomy = load_taxonomy(pool).await?;
+    let mut totals: HashMap<Slice, i32> = HashMap::new();
+    let mut counts: HashMap<(String, Slice), i32> = HashMap::new();
+    let mut spellings: HashMap<String, HashMap<String, i32>> = HashMap::new();
+
+    for job in jobs {
+        let location = match job.work_arrangement {
+            WorkArrangement::Remote => Some(REMOTE.to_string()),
+            _ => job.division,
+        };
+        let track = taxonomy.infer(&job.job_title).map(|track| track.slug.clone());
+        let slice = (track, job.experience_level, location);
+        *totals.entry(slice.clone()).or_default() += 1;
+
+        let mut seen = HashSet::new();
+        for skill in &job.required_skills {
+            let skill = skill.trim();
+            let key = skill.to_lowercase();
+            if skill.is_empty() || !seen.insert(key.clone()) {
+                continue;
+            }
+            *counts.entry((key.clone(), slice.clone())).or_default() += 1;
+            *spellings.entry(key).or_default().entry(skill.to_string()).or_default() += 1;
+        }
+    }
+
+    // The most common spelling names the skill; ties go to the first alphabetically
+    let names: HashMap<String, String> = spellings
+        .into_iter()
+        .filter_map(|(key, spellings)| {
+            let name = spellings
+                .into_iter()
+                .max_by(|(a, a_count), (b, b_count)| a_count.cmp(b_count).then(b.cmp(a)))?
+                .0;
+            Some((key, name))
+        })
+        .collect();
+
+    let rows = counts.len();
+    let (mut skills, mut keys, mut tracks, mut levels, mut locations, mut job_counts) =
+        (Vec::new(), Vec::new(), Vec::new(), Vec::new(), Vec::new(), Vec::new());
+    for ((key, (track, level, location)), count) in counts {
+        skills.push(names[&key].clone());
+        keys.push(key);
+        tracks.push(track);
+        levels.push(level);
+        locations.push(location);
+        job_counts.push(count);
+    }
+    let (mut total_tracks, mut total_levels, mut total_locations, mut total_counts) =
+        (Vec::new(), Vec::new(), Vec::new(), Vec::new());
+    for ((track, level, location), count) in totals {
+        total_tracks.push(track);
+        total_levels.push(level);
+        total_locations.push(location);
+        total_counts.push(count);
+    }
+
+    let mut tx = pool.begin().await?;
+
+    sqlx::query!("DELETE FROM skill_demand_snapshots WHERE snapshot_date = CURRENT_DATE")
+        .execute(&mut *tx)
+        .await?;
+    sqlx::query!("DELETE FROM job_demand_snapshots WHERE snapshot_date = CURRENT_DATE")
+        .execute(&mut *tx)
+        .await?;
+
+    sqlx::query!(
+        r#"
+        INSERT INTO skill_demand_snapshots
+            (snapshot_date, skill, skill_key, track, experience_level, location, job_count)
+        SELECT CURRENT_DATE, u.skill, u.skill_key, u.track, u.experience_level, u.location, u.job_count
+        FROM UNNEST(
+            $1::text[], $2::text[], $3::text[], $4::experience_level[], $5::text[], $6::int[]
+        ) AS u(skill, skill_key, track, experience_level, location, job_count)
+        "#,
+        &skills,
+        &keys,
+        &tracks as &[Option<String>],
+        &levels as _,
+        &locations as &[Option<String>],
+        &job_counts
+    )
+    .execute(&mut *tx)
+    .await?;
+
+    sqlx::query!(
+        r#"
+        INSERT INTO job_demand_snapshots
+            (snapshot_date, track, experience_level, location, job_count)
+        SELECT CURRENT_DATE, u.track, u.experience_level, u.location, u.job_count
+        FROM UNNEST(
+            $1::text[], $2::experience_level[], $3::text[], $4::int[]
+        ) AS u(track, experience_level, location, job_count)
+        "#,
+        &total_tracks as &[Option<String>],
+        &total_levels as _,
+        &total_locations as &[Option<String>],
+        &total_counts
+    )
+    .execute(&mut *tx)
+    .await?;
+
+    tx.commit().await?;
+
+    info!("Recorded skill demand snapshot: {} skill rows", rows);
+    Ok(rows)
+}
+
+/// Parses a trend location filter: `Remote` or a division.
+///
+/// # Errors
+///
+/// Returns `AppError::BadRequest` if the location is neither.
+pub fn parse_trend_location(location: Option<&str>) -> AppResult<Option<String>> {
+    match location.map(str::trim) {
+        Some(location) if location.eq_ignore_ascii_case(REMOTE) => Ok(Some(REMOTE.to_string())),
+        location => parse_division(location),
+    }
+}
+
+/// Validates a trend window in days.
+///
+/// # Errors
+///
+/// Returns `AppError::BadRequest` if `days` is not between 1 and [`MAX_TREND_DAYS`].
+pub fn validate_days(days: i32) -> AppResult<i32> {
+    if !(1..=MAX_TREND_DAYS).contains(&days) {
+        return Err(AppError::BadRequest(format!(
+            "days must be between 1 and {}",
+            MAX_TREND_DAYS
+        )));
+    }
+    Ok(days)
+}
+
+/// Loads demand over the last `days` days for the given skills, or for the
+/// `limit` most demanded skills at the latest snapshot when `skills` is empty.
+pub async fn skill_trends(
+    pool: &PgPool,
+    filters: &TrendFilters,
+    skills: &[String],
+    days: i32,
+    limit: i64,
+) -> AppResult<SkillDemandTrends> {
+    let Some(to) = latest_snapshot_date(pool).await? else {
+        return Ok(SkillDemandTrends { from: None, to: None, series: Vec::new() });
+    };
+    let since = to - Duration::days(days.into());
+
+    let totals = sqlx::query!(
+        r#"
+        SELECT snapshot_date, SUM(job_count)::bigint as "job_count!"
+        FROM job_demand_snapshots
+        WHERE snapshot_date BETWEEN $1 AND $2
+          AND ($3::text[] IS NULL OR track = ANY($3))
+          AND ($4::experience_level IS NULL OR experience_level = $4)
+          AND ($5::text IS NULL OR location = $5)
+        GROUP BY snapshot_date
+        ORDER BY snapshot_date
+        "#,
+        since,
+        to,
+        filters.tracks.as_deref(),
+        filters.experience_level as _,
+        filters.location
+    )
+    .fetch_all(pool)
+    .await?;
+
+    let keys: Vec<String> = if skills.is_empty() {
+        sqlx::query_scalar!(
+            r#"
+            SELECT skill_key as "skill_key!"
+            FROM skill_demand_snapshots
+            WHERE snapshot_date = $1
+              AND ($2::text[] IS NULL OR track = ANY($2))
+              AND ($3::experience_level IS NULL OR experience_level = $3)
+              AND ($4::text IS NULL OR location = $4)
+            GROUP BY skill_key
+            ORDER BY SUM(job_count) DESC, skill_key
+            LIMIT $5
+            "#,
+            to,
+            filters.tracks.as_deref(),
+            filters.experience_level as _,
+            filters.location,
+            limit
+        )
+        .fetch_all(pool)
+        .await?
+    } else {
+        let mut seen = HashSet::new();
+        skills
+            .iter()
+            .map(|s| s.trim().to_lowercase())
+            .filter(|key| !key.is_empty() && seen.insert(key.clone()))
+            .collect()
+    };
+
+    let dates: Vec<NaiveDate> = totals.iter().map(|t| t.snapshot_date).collect();
+    let counts = load_skill_counts(pool, filters, &dates, Some(&keys)).await?;
+
+    let series = keys
+        .iter()
+        .map(|key| {
+            // Latest spelling, or the requested one for skills without demand
+            let skill = counts
+                .get(key)
+                .and_then(|by_date| by_date.iter().max_by_key(|(date, _)| **date))
+                .map(|(_, (name, _))| name.clone())
+                .or_else(|| {
+                    skills
+                        .iter()
+                        .map(|s| s.trim())
+                        .find(|s| s.to_lowercase() == *key)
+                        .map(str::to_string)
+                })
+                .unwrap_or_else(|| key.clone());
+            let points = totals
+                .iter()
+                .map(|total| {
+                    let job_count = counts
+                        .get(key)
+                        .and_then(|by_date| by_date.get(&total.snapshot_date))
+                        .map_or(0, |(_, count)| *count);
+                    SkillDemandPoint {
+                        date: total.snapshot_date,
+                        job_count,
+                        share: share(job_count, total.job_count),
+                    }
+                })
+                .collect();
+            SkillDemandSeries { skill, points }
+        })
+        .collect();
+
+    Ok(SkillDemandTrends {
+        from: dates.first().copied(),
+        to: Some(to),
+        series,
+    })
+}
+
+/// Finds the skills whose share of open jobs rose or fell the most between
+/// the latest snapshot and the snapshot `days` days earlier (or the oldest
+/// snapshot, when there is less history).
+pub async fn skill_movers(
+    pool: &PgPool,
+    filters: &TrendFilters,
+    days: i32,
+    limit: usize,
+) -> AppResult<SkillDemandMovers> {
+    let Some(to) = latest_snapshot_date(pool).await? else {
+        return Ok(SkillDemandMovers { from: None, to: None, risers: Vec::new(), fallers: Vec::new() });
+    };
+
+    let from = sqlx::query_scalar!(
+        r#"
+        SELECT COALESCE(MAX(snapshot_date) FILTER (WHERE snapshot_date <= $1), MIN(snapshot_date))
+        FROM job_demand_snapshots
+        WHERE snapshot_date < $2
+        "#,
+        to - Duration::days(days.into()),
+        to
+    )
+    .fetch_one(pool)
+    .await?;
+    let Some(from) = from else {
+        return Ok(SkillDemandMovers { from: None, to: Some(to), risers: Vec::new(), fallers: Vec::new() });
+    };
+
+    let totals: HashMap<NaiveDate, i64> = sqlx::query!(
+        r#"
+        SELECT snapshot_date, SUM(job_count)::bigint as "job_count!"
+        FROM job_demand_snapshots
+        WHERE snapshot_date = ANY($1)
+          AND ($2::text[] IS NULL OR track = ANY($2))
+          AND ($3::experience_level IS NULL OR experience_level = $3)
+          AND ($4::text IS NULL OR location = $4)
+        GROUP BY snapshot_date
+        "#,
+        &[from, to] as &[NaiveDate],
+        filters.tracks.as_deref(),
+        filters.experience_level as _,
+        filters.location
+    )
+    .fetch_all(pool)
+    .await?
+    .into_iter()
+    .map(|row| (row.snapshot_date, row.job_count))
+    .collect();
+    let (previous_total, current_total) = (
+        totals.get(&from).copied().unwrap_or(0),
+        totals.get(&to).copied().unwrap_or(0),
+    );
+
+    let counts = load_skill_counts(pool, filters, &[from, to], None).await?;
+    let mut changes: Vec<SkillDemandChange> = counts
+        .into_values()
+        .map(|by_date| {
+            let previous = by_date.get(&from);
+            let current = by_date.get(&to);
+            let skill = current.or(previous).map(|(name, _)| name.clone()).unwrap_or_default();
+            let previous_count = previous.map_or(0, |(_, count)| *count);
+            let current_count = current.map_or(0, |(_, count)| *count);
+            let previous_share = share(previous_count, previous_total);
+            let current_share = share(current_count, current_total);
+            SkillDemandChange {
+                skill,
+                previous_count,
+                current_count,
+                previous_share,
+                current_share,
+                share_change: current_share - previous_share,
+            }
+        })
+        .collect();
+
+    changes.sort_by(|a, b| b.share_change.total_cmp(&a.share_change).then(a.skill.cmp(&b.skill)));
+    let risers: Vec<SkillDemandChange> = changes
+        .iter()
+        .filter(|c| c.share_change > 0.0)
+        .take(limit)
+        .cloned()
+        .collect();
+    let fallers: Vec<SkillDemandChange> = changes
+        .iter()
+        .rev()
+        .filter(|c| c.share_change < 0.0)
+        .take(limit)
+        .cloned()
+        .collect();
+
+    Ok(SkillDemandMovers { from: Some(from), to: Some(to), risers, fallers })
+}
+
+/// Date of the latest snapshot, if any.
+async fn latest_snapshot_date(pool: &PgPool) -> AppResult<Option<NaiveDate>> {
+    let date = sqlx::query_scalar!("SELECT MAX(snapshot_date) FROM job_demand_snapshots")
+        .fetch_one(pool)
+        .await?;
+    Ok(date)
+}
+
+/// Loads skill counts on the given dates, keyed by skill key and date, with
+/// the most common spelling of the skill on each date.
+async fn load_skill_counts(
+    pool: &PgPool,
+    filters: &TrendFilters,
+    dates: &[NaiveDate],
+    keys: Option<&[String]>,
+) -> AppResult<HashMap<String, HashMap<NaiveDate, (String, i64)>>> {
+    let rows = sqlx::query!(
+        r#"
+        SELECT snapshot_date, skill_key,
+               (array_agg(skill ORDER BY job_count DESC, skill))[1] as "skill!",
+               SUM(job_count)::bigint as "job_count!"
+        FROM skill_demand_snapshots
+        WHERE snapshot_date = ANY($1)
+          AND ($2::text[] IS NULL OR skill_key = ANY($2))
+          AND ($3::text[] IS NULL OR track = ANY($3))
+          AND ($4::experience_level IS NULL OR experience_level = $4)
+          AND ($5::text IS NULL OR location = $5)
+        GROUP BY snapshot_date, skill_key
+        "#,
+        dates,
+        keys,
+        filters.tracks.as_deref(),
+        filters.experience_level as _,
+        filters.location
+    )
+    .fetch_all(pool)
+    .await?;
+
+    let mut counts: HashMap<String, HashMap<NaiveDate, (String, i64)>> = HashMap::new();
+    for row in rows {
+        counts
+            .entry(row.skill_key)
+            .or_default()
+            .insert(row.snapshot_date, (row.skill, row.job_count));
+    }
+    Ok(counts)
+}
+
+/// Share of `total` jobs, as a percentage.
+fn share(count: i64, total: i64) -> f64 {
+    if total == 0 {
+        0.0
+    } else {
+        count as f64 / total as f64 * 100.0
+    }
+}
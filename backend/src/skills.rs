@@ -0,0 +1,294 @@
+//! Skill taxonomy.
+//!
+//! Skills are stored in the `skills` table with a canonical name, aliases
+//! ("JS", "JavaScript ES6"), a category and an optional parent skill. Skill
+//! names are compared by a key that ignores case, spaces and punctuation, so
+//! "Node.js", "NodeJS" and "node js" are the same skill.
+//!
+//! Skills on profiles, jobs and external postings are rewritten to their
+//! canonical names on write ([`SkillTaxonomy::normalize`]); unknown skills are
+//! kept as entered. Matching compares skills through the taxonomy
+//! ([`SkillTaxonomy::compare`]), where a skill also counts as its parent
+//! skills: a candidate who knows React has the JavaScript a job asks for.
+
+use crate::ai_matching::{MAX_SKILL_WEIGHT, MIN_SKILL_WEIGHT};
+use crate::errors::{AppError, AppResult};
+use crate::models::Skill;
+use sqlx::PgPool;
+use std::collections::{HashMap, HashSet};
+
+/// How far parent skills are followed, guarding against cycles.
+const MAX_PARENT_DEPTH: usize = 5;
+
+/// All skills, as loaded from the database.
+#[derive(Debug, Clone, Default)]
+pub struct SkillTaxonomy {
+    skills: Vec<Skill>,
+    /// Index into `skills` by the key of each name and alias
+    index: HashMap<String, usize>,
+}
+
+/// Skills a job requires, split by whether a candidate has them.
+#[derive(Debug, Clone, Default)]
+pub struct SkillComparison {
+    /// Required skills the candidate has, as canonical names
+    pub matched: Vec<String>,
+    /// Required skills the candidate lacks, as canonical names
+    pub missing: Vec<String>,
+}
+
+/// A job's skills, as saved.
+#[derive(Debug, Clone, Default)]
+pub struct JobSkillLists {
+    /// Must-have skills, as canonical names
+    pub required: Vec<String>,
+    /// Nice-to-have skills that aren't also required, as canonical names
+    pub preferred: Vec<String>,
+    /// Weight (1-5) per listed skill, by canonical name
+    pub weights: HashMap<String, i32>,
+}
+
+/// Loads every skill in the taxonomy.
+pub async fn load_skill_taxonomy(pool: &PgPool) -> AppResult<SkillTaxonomy> {
+    let skills = sqlx::query_as!(
+        Skill,
+        r#"
+        SELECT slug, name, category, parent_slug, aliases, created_at, updated_at
+        FROM skills
+        ORDER BY slug
+        "#
+    )
+    .fetch_all(pool)
+    .await?;
+
+    Ok(SkillTaxonomy::new(skills))
+}
+
+impl SkillTaxonomy {
+    /// Builds the taxonomy, indexing names before aliases so that an alias
+    /// never shadows another skill's name.
+    pub fn new(skills: Vec<Skill>) -> Self {
+        let mut index = HashMap::new();
+        for (i, skill) in skills.iter().enumerate() {
+            index.entry(skill_key(&skill.name)).or_insert(i);
+        }
+        for (i, skill) in skills.iter().enumerate() {
+            for alias in &skill.aliases {
+                index.entry(skill_key(alias)).or_insert(i);
+            }
+        }
+        index.remove("");
+
+        SkillTaxonomy { skills, index }
+    }
+
+    /// Every skill, by slug.
+    pub fn skills(&self) -> &[Skill] {
+        &self.skills
+    }
+
+    /// Returns the skill with the given slug.
+    pub fn get(&self, slug: &str) -> Option<&Skill> {
+        self.skills.iter().find(|skill| skill.slug == slug)
+    }
+
+    /// Finds a skill by its name or one of its aliases.
+    pub fn find(&self, name: &str) -> Option<&Skill> {
+        self.index.get(&skill_key(name)).map(|&i| &self.skills[i])
+    }
+
+    /// Canonical name of a skill; unknown skills are trimmed, with runs of
+    /// whitespace collapsed.
+    pub fn canonical_name(&self, name: &str) -> String {
+        match self.find(name) {
+            Some(skill) => skill.name.clone(),
+            None => name.split_whitespace().collect::<Vec<_>>().join(" "),
+        }
+    }
+
+    /// Rewrites skills to their canonical names, dropping blanks and
+    /// duplicates ("JS" and "JavaScript" are kept once).
+    pub fn normalize(&self, skills: &[String]) -> Vec<String> {
+        let mut seen = HashSet::new();
+        skills
+            .iter()
+            .map(|skill| self.canonical_name(skill))
+            .filter(|skill| !skill.is_empty() && seen.insert(self.key(skill)))
+            .collect()
+    }
+
+    /// Comparison key of a skill: its slug when known, otherwise the key of
+    /// the name as entered.
+    pub fn key(&self, name: &str) -> String {
+        match self.find(name) {
+            Some(skill) => skill.slug.clone(),
+            None => skill_key(name),
+        }
+    }
+
+    /// Rekeys a per-skill map (proficiencies, weights) by canonical skill
+    /// name, keeping only entries for the given skills.
+    pub fn restrict<V: Copy>(&self, values: &HashMap<String, V>, skills: &[String]) -> HashMap<String, V> {
+        let listed: HashMap<String, &String> = skills.iter().map(|skill| (self.key(skill), skill)).collect();
+        values
+            .iter()
+            .filter_map(|(skill, value)| {
+                listed
+                    .get(&self.key(skill))
+                    .map(|name| (self.canonical_name(name), *value))
+            })
+            .collect()
+    }
+
+    /// Checks that every skill of a per-skill map is one of the given skills.
+    ///
+    /// # Errors
+    ///
+    /// Returns a validation error naming the first skill that isn't.
+    pub fn require_listed<V>(&self, values: &HashMap<String, V>, skills: &[String]) -> AppResult<()> {
+        let listed: HashSet<String> = skills.iter().map(|skill| self.key(skill)).collect();
+        match values.keys().find(|skill| !listed.contains(&self.key(skill))) {
+            Some(skill) => Err(AppError::ValidationError(format!(
+                "'{}' is not one of the listed skills",
+                skill
+            ))),
+            None => Ok(()),
+        }
+    }
+
+    /// Normalizes a job's required and preferred skills and skill weights.
+    ///
+    /// Preferred skills that are also required are dropped.
+    ///
+    /// # Errors
+    ///
+    /// Returns a validation error if a weight is outside 1-5 or is for a
+    /// skill the job doesn't list.
+    pub fn job_skills(
+        &self,
+        required: &[String],
+        preferred: &[String],
+        weights: &HashMap<String, i32>,
+    ) -> AppResult<JobSkillLists> {
+        let required = self.normalize(required);
+        let required_keys: HashSet<String> = required.iter().map(|skill| self.key(skill)).collect();
+        let preferred: Vec<String> = self
+            .normalize(preferred)
+            .into_iter()
+            .filter(|skill| !required_keys.contains(&self.key(skill)))
+            .collect();
+
+        if let Some((skill, weight)) = weights
+            .iter()
+            .find(|(_, weight)| !(MIN_SKILL_WEIGHT..=MAX_SKILL_WEIGHT).contains(*weight))
+        {
+            return Err(AppError::ValidationError(format!(
+                "Weight of '{}' must be between {} and {}, got {}",
+                skill, MIN_SKILL_WEIGHT, MAX_SKILL_WEIGHT, weight
+            )));
+        }
+        let listed: Vec<String> = required.iter().chain(&preferred).cloned().collect();
+        self.require_listed(weights, &listed)?;
+        let weights = self.restrict(weights, &listed);
+
+        Ok(JobSkillLists { required, preferred, weights })
+    }
+
+    /// Keys of a candidate's skills and all their parent skills.
+    pub fn keys_with_parents(&self, skills: &[String]) -> HashSet<String> {
+        let mut keys = HashSet::new();
+        for name in skills {
+            let Some(mut skill) = self.find(name) else {
+                keys.insert(skill_key(name));
+                continue;
+            };
+            keys.insert(skill.slug.clone());
+            for _ in 0..MAX_PARENT_DEPTH {
+                match skill.parent_slug.as_deref().and_then(|slug| self.get(slug)) {
+                    Some(parent) if keys.insert(parent.slug.clone()) => skill = parent,
+                    _ => break,
+                }
+            }
+        }
+        keys
+    }
+
+    /// Splits a job's required skills into those a candidate has, directly,
+    /// by alias or through a more specific skill, and those they lack.
+    pub fn compare(&self, candidate_skills: &[String], required_skills: &[String]) -> SkillComparison {
+        let candidate_keys = self.keys_with_parents(candidate_skills);
+        let mut comparison = SkillComparison::default();
+        for skill in self.normalize(required_skills) {
+            if candidate_keys.contains(&self.key(&skill)) {
+                comparison.matched.push(skill);
+            } else {
+                comparison.missing.push(skill);
+            }
+        }
+        comparison
+    }
+
+    /// How a candidate has `skill` when not under its canonical name: the
+    /// alias they listed ("JS" for JavaScript), or a more specific skill
+    /// that covers it (React for JavaScript), with `true` for the latter.
+    pub fn matched_via(&self, candidate_skills: &[String], skill: &str) -> Option<(String, bool)> {
+        let key = self.key(skill);
+        let canonical = self.canonical_name(skill);
+        if let Some(entry) = candidate_skills.iter().find(|entry| self.key(entry) == key) {
+            let entry = entry.trim();
+            return (entry != canonical).then(|| (entry.to_string(), false));
+        }
+        candidate_skills
+            .iter()
+            .find(|entry| self.keys_with_parents(std::slice::from_ref(*entry)).contains(&key))
+            .map(|entry| (self.canonical_name(entry), true))
+    }
+
+    /// Skills whose name or an alias contains `query`, for autocomplete.
+    ///
+    /// Names starting with the query come first, then aliases starting with
+    /// it, then other matches; shorter names first within each group.
+    pub fn suggest(&self, query: &str, category: Option<&str>, limit: usize) -> Vec<&Skill> {
+        let query = skill_key(query);
+        if query.is_empty() {
+            return Vec::new();
+        }
+
+        let mut matches: Vec<(u8, &Skill)> = self
+            .skills
+            .iter()
+            .filter(|skill| category.is_none_or(|category| skill.category == category))
+            .filter_map(|skill| {
+                let name = skill_key(&skill.name);
+                let aliases: Vec<String> = skill.aliases.iter().map(|alias| skill_key(alias)).collect();
+                let rank = if name.starts_with(&query) {
+                    0
+                } else if aliases.iter().any(|alias| alias.starts_with(&query)) {
+                    1
+                } else if name.contains(&query) || aliases.iter().any(|alias| alias.contains(&query)) {
+                    2
+                } else {
+                    return None;
+                };
+                Some((rank, skill))
+            })
+            .collect();
+
+        matches.sort_by(|(rank_a, a), (rank_b, b)| {
+            rank_a
+                .cmp(rank_b)
+                .then(a.name.len().cmp(&b.name.len()))
+                .then(a.name.cmp(&b.name))
+        });
+        matches.into_iter().take(limit).map(|(_, skill)| skill).collect()
+    }
+}
+
+/// Comparison key of a skill name: lowercase letters and digits, plus the
+/// `+`, `#` and `&` that tell C, C++ and C# (or M&E) apart.
+pub fn skill_key(name: &str) -> String {
+    name.chars()
+        .filter(|c| c.is_alphanumeric() || matches!(c, '+' | '#' | '&'))
+        .flat_map(char::to_lowercase)
+        .collect()
+}
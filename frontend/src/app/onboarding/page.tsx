@@ -24,19 +24,24 @@ export default function OnboardingPage() {
     
     try {
       // Map frontend values to API values
-      const experienceLevelMap: Record<string, 'fresher' | 'junior' | 'mid'> = {
+      const experienceLevelMap: Record<string, 'fresher' | 'junior' | 'mid' | 'senior' | 'lead'> = {
         'fresher': 'fresher',
         'junior': 'junior',
         'mid': 'mid',
-        'senior': 'mid', // Map senior to mid as API only accepts fresher, junior, mid
+        'senior': 'senior',
+        'lead': 'lead',
       }
 
-      const trackMap: Record<string, 'web_development' | 'data' | 'design' | 'marketing'> = {
+      const trackMap: Record<string, string> = {
         'software-dev': 'web_development',
         'data-science': 'data',
         'design': 'design',
         'marketing': 'marketing',
-        'business': 'web_development', // Map business to web_development
+        'business': 'product',
+        'devops': 'devops',
+        'mobile': 'mobile',
+        'finance': 'finance',
+        'ngo_development': 'ngo_development',
       }
 
       await profileApi.completeProfile({
@@ -6,6 +6,7 @@ import { motion } from "framer-motion"
 import Navbar from "@/components/Navbar"
 import { Button } from "@/components/ui/button"
 import { profileApi } from "@/lib/api"
+import { EXPERIENCE_LEVELS, experienceLabel } from "@/lib/experience"
 import { toast } from "sonner"
 import { useRouter } from "next/navigation"
 import { CVUpload } from "@/components/CVUpload"
@@ -59,6 +60,8 @@ export default function ProfilePage() {
           'fresher': 'fresher',
           'junior': 'junior',
           'mid': 'mid',
+          'senior': 'senior',
+          'lead': 'lead',
         }
 
         const trackMap: Record<string, string> = {
@@ -66,6 +69,11 @@ export default function ProfilePage() {
           'data': 'data-science',
           'design': 'design',
           'marketing': 'marketing',
+          'product': 'business',
+          'devops': 'devops',
+          'mobile': 'mobile',
+          'finance': 'finance',
+          'ngo_development': 'ngo_development',
         }
 
         setFormData({
@@ -135,19 +143,24 @@ export default function ProfilePage() {
 
     try {
       // Map frontend values to API values
-      const experienceLevelMap: Record<string, 'fresher' | 'junior' | 'mid'> = {
+      const experienceLevelMap: Record<string, 'fresher' | 'junior' | 'mid' | 'senior' | 'lead'> = {
         'fresher': 'fresher',
         'junior': 'junior',
         'mid': 'mid',
-        'senior': 'mid',
+        'senior': 'senior',
+        'lead': 'lead',
       }
 
-      const trackMap: Record<string, 'web_development' | 'data' | 'design' | 'marketing'> = {
+      const trackMap: Record<string, string> = {
         'software-dev': 'web_development',
         'data-science': 'data',
         'design': 'design',
         'marketing': 'marketing',
-        'business': 'web_development',
+        'business': 'product',
+        'devops': 'devops',
+        'mobile': 'mobile',
+        'finance': 'finance',
+        'ngo_development': 'ngo_development',
       }
 
       const updates: any = {
@@ -317,10 +330,9 @@ export default function ProfilePage() {
                         <SelectValue placeholder="Select experience level" />
                       </SelectTrigger>
                       <SelectContent className="glass-effect border-white/10">
-                        <SelectItem value="fresher">Fresher (0-1 years)</SelectItem>
-                        <SelectItem value="junior">Junior (1-3 years)</SelectItem>
-                        <SelectItem value="mid">Mid-level (3-5 years)</SelectItem>
-                        <SelectItem value="senior">Senior (5+ years)</SelectItem>
+                        {EXPERIENCE_LEVELS.map((level) => (
+                          <SelectItem key={level.value} value={level.value}>{experienceLabel(level)}</SelectItem>
+                        ))}
                       </SelectContent>
                     </Select>
                   </div>
@@ -339,7 +351,11 @@ export default function ProfilePage() {
                         <SelectItem value="data-science">Data Science</SelectItem>
                         <SelectItem value="design">UI/UX Design</SelectItem>
                         <SelectItem value="marketing">Digital Marketing</SelectItem>
-                        <SelectItem value="business">Business Analysis</SelectItem>
+                        <SelectItem value="business">Product & Business Analysis</SelectItem>
+                        <SelectItem value="devops">DevOps & Cloud</SelectItem>
+                        <SelectItem value="mobile">Mobile Development</SelectItem>
+                        <SelectItem value="finance">Finance & Accounting</SelectItem>
+                        <SelectItem value="ngo_development">NGO & Development Sector</SelectItem>
                       </SelectContent>
                     </Select>
                   </div>
@@ -5,6 +5,7 @@ import { motion, AnimatePresence } from "framer-motion"
 import { GraduationCap, Briefcase, Target, ArrowRight, CheckCircle } from "lucide-react"
 import { Button } from "@/components/ui/button"
 import { Progress } from "@/components/ui/progress"
+import { EXPERIENCE_LEVELS, experienceLabel } from "@/lib/experience"
 
 interface OnboardingFlowProps {
   onComplete: (data: { education: string; experience: string; track: string }) => void
@@ -28,12 +29,7 @@ const steps = [
     title: "What's your experience level?",
     description: "Tell us about your professional experience",
     icon: Briefcase,
-    options: [
-      { value: "fresher", label: "Fresher (0-1 years)" },
-      { value: "junior", label: "Junior (1-3 years)" },
-      { value: "mid", label: "Mid-level (3-5 years)" },
-      { value: "senior", label: "Senior (5+ years)" },
-    ],
+    options: EXPERIENCE_LEVELS.map((level) => ({ value: level.value, label: experienceLabel(level) })),
   },
   {
     id: "track",
@@ -45,7 +41,11 @@ const steps = [
       { value: "data-science", label: "Data Science" },
       { value: "design", label: "UI/UX Design" },
       { value: "marketing", label: "Digital Marketing" },
-      { value: "business", label: "Business Analysis" },
+      { value: "business", label: "Product & Business Analysis" },
+      { value: "devops", label: "DevOps & Cloud" },
+      { value: "mobile", label: "Mobile Development" },
+      { value: "finance", label: "Finance & Accounting" },
+      { value: "ngo_development", label: "NGO & Development Sector" },
     ],
   },
 ]
@@ -75,18 +75,23 @@ export interface LoginResponse {
   };
 }
 
+export type SkillProficiency = 'beginner' | 'intermediate' | 'advanced' | 'expert';
+
 export interface ProfileResponse {
   id: string;
   full_name: string;
   email: string;
   profile_completed: boolean;
   education_level: string | null;
-  experience_level: 'fresher' | 'junior' | 'mid' | null;
-  preferred_track: 'web_development' | 'data' | 'design' | 'marketing' | null;
+  experience_level: 'fresher' | 'junior' | 'mid' | 'senior' | 'lead' | null;
+  experience_years: number | null;
+  preferred_track: string | null; // Career track slug, see careerTracksApi
   skills: string[];
+  skill_proficiency: Record<string, SkillProficiency>;
   projects: string[];
   target_roles: string[];
   raw_cv_text: string | null;
+  discoverable: boolean; // Listed (anonymized) as a candidate for employers' jobs
   oauth_provider: string | null;
   avatar_url: string | null;
   created_at: string;
@@ -101,6 +106,8 @@ export interface JobRecommendation {
     location: string;
     job_description: string;
     required_skills: string[];
+    preferred_skills: string[];
+    skill_weights: Record<string, number>; // 1-5; unlisted: 3 required, 1 preferred
     experience_level: string;
     job_type: string;
     salary_min: number | null;
@@ -112,8 +119,53 @@ export interface JobRecommendation {
   match_score: number;
   matched_skills: string[];
   missing_skills: string[];
+  missing_preferred_skills: string[];
+  match_strategy: string;
+  semantic_similarity: number | null; // 0-100, when the strategy weighs it
+}
+
+export interface SkillBreakdown {
+  skill: string;
+  required: boolean;
+  status: 'matched' | 'partial' | 'missing';
+  matched_via: string | null; // alias or more specific skill the user has
+  weight: number;
+  expected_proficiency: 'beginner' | 'intermediate' | 'advanced' | 'expert';
+  proficiency: 'beginner' | 'intermediate' | 'advanced' | 'expert' | null;
+  credit: number; // 0-1
+  contribution: number; // points of the match score earned
+  max_contribution: number;
+  learning_hours: number;
+  resources: LearningRecommendation['resource'][];
 }
 
+export interface MatchBreakdown {
+  job_id: number;
+  match_score: number;
+  match_strategy: string;
+  weights: { skills: number; experience: number; track: number; semantic: number };
+  skill_overlap: number;
+  experience_alignment: number;
+  track_alignment: number;
+  semantic_similarity: number | null;
+  skills: SkillBreakdown[];
+  learning_hours: number;
+}
+
+export type NotInterestedReason =
+  | 'irrelevant'
+  | 'skills'
+  | 'seniority'
+  | 'location'
+  | 'salary'
+  | 'company'
+  | 'other';
+
+// Saves and applications are recorded by their own endpoints
+export type MatchFeedback =
+  | { event: 'view' }
+  | { event: 'not_interested'; reason: NotInterestedReason };
+
 export interface LearningRecommendation {
   resource: {
     id: number;
@@ -125,6 +177,7 @@ export interface LearningRecommendation {
   };
   relevance_score: number;
   new_skills: string[];
+  semantic_similarity: number | null;
 }
 
 export interface SkillGapAnalysis {
@@ -263,8 +316,8 @@ export const profileApi = {
   // Complete profile (onboarding)
   completeProfile: async (data: {
     education_level: string;
-    experience_level: 'fresher' | 'junior' | 'mid';
-    preferred_track: 'web_development' | 'data' | 'design' | 'marketing';
+    experience_level: 'fresher' | 'junior' | 'mid' | 'senior' | 'lead';
+    preferred_track: string;
     skills: string[];
     projects: string[];
     target_roles: string[];
@@ -293,11 +346,13 @@ export const profileApi = {
   updateProfile: async (updates: {
     full_name?: string;
     education_level?: string;
-    experience_level?: 'fresher' | 'junior' | 'mid';
+    experience_level?: 'fresher' | 'junior' | 'mid' | 'senior' | 'lead';
     skills?: string[];
+    skill_proficiency?: Record<string, SkillProficiency>;
     projects?: string[];
     target_roles?: string[];
     raw_cv_text?: string;
+    discoverable?: boolean;
   }): Promise<{ message: string; updated_fields: string[] }> => {
     const token = getToken();
     const response = await fetch(`${API_BASE_URL}/profile`, {
@@ -346,7 +401,7 @@ export const profileApi = {
 export const jobsApi = {
   // Get job recommendations with caching
   getRecommendations: async (filters?: {
-    experience_level?: 'fresher' | 'junior' | 'mid';
+    experience_level?: 'fresher' | 'junior' | 'mid' | 'senior' | 'lead';
     job_type?: 'internship' | 'part_time' | 'full_time' | 'freelance';
     limit?: number;
   }): Promise<JobRecommendation[]> => {
@@ -386,6 +441,101 @@ export const jobsApi = {
     setCachedData(cacheKey, data);
     return data;
   },
+
+  // Get the per-skill breakdown of a job's match score
+  getMatchBreakdown: async (jobId: number): Promise<MatchBreakdown> => {
+    const token = getToken();
+    const response = await fetch(`${API_BASE_URL}/jobs/${jobId}/match-breakdown`, {
+      headers: getHeaders(token),
+    });
+
+    if (response.status === 401) {
+      localStorage.removeItem('authToken');
+      throw new Error('Session expired. Please login again.');
+    }
+
+    if (!response.ok) {
+      const error = await response.json();
+      throw new Error(error.error || 'Failed to fetch match breakdown');
+    }
+
+    return response.json();
+  },
+
+  // Record a view of, or lack of interest in, a recommended job
+  sendFeedback: async (jobId: number, feedback: MatchFeedback): Promise<void> => {
+    const token = getToken();
+    const response = await fetch(`${API_BASE_URL}/jobs/${jobId}/feedback`, {
+      method: 'POST',
+      headers: getHeaders(token),
+      body: JSON.stringify(feedback),
+    });
+
+    if (response.status === 401) {
+      localStorage.removeItem('authToken');
+      throw new Error('Session expired. Please login again.');
+    }
+
+    if (!response.ok) {
+      const error = await response.json();
+      throw new Error(error.error || 'Failed to send feedback');
+    }
+
+    // Dismissed jobs drop out of recommendations
+    if (feedback.event === 'not_interested') {
+      for (const key of Array.from(apiCache.keys())) {
+        if (key.startsWith('jobs_')) apiCache.delete(key);
+      }
+    }
+  },
+};
+
+// Career Tracks API
+export interface CareerTrack {
+  slug: string;
+  display_name: string;
+  cv_label: string | null;
+  parent_slug: string | null;
+  keywords: string[];
+  related_skills: string[];
+  active: boolean;
+}
+
+export const careerTracksApi = {
+  // List active career tracks (top-level tracks first)
+  list: async (): Promise<CareerTrack[]> => {
+    const response = await fetch(`${API_BASE_URL}/career-tracks`);
+
+    if (!response.ok) {
+      const error = await response.json();
+      throw new Error(error.error || 'Failed to fetch career tracks');
+    }
+
+    return await response.json();
+  },
+};
+
+export interface Skill {
+  slug: string;
+  name: string; // Canonical name, stored on profiles and jobs
+  category: string;
+  parent_slug: string | null;
+  aliases: string[];
+}
+
+export const skillsApi = {
+  // Suggest canonical skills matching a name or alias
+  autocomplete: async (query: string, limit = 10): Promise<Skill[]> => {
+    const params = new URLSearchParams({ q: query, limit: String(limit) });
+    const response = await fetch(`${API_BASE_URL}/skills/autocomplete?${params}`);
+
+    if (!response.ok) {
+      const error = await response.json();
+      throw new Error(error.error || 'Failed to fetch skill suggestions');
+    }
+
+    return await response.json();
+  },
 };
 
 // External Jobs APIs (NGO, Government, Local Boards)
@@ -589,6 +739,156 @@ export const applicationsApi = {
   },
 };
 
+// Candidate Discovery APIs
+export type ContactRequestStatus = 'pending' | 'accepted' | 'declined';
+
+export interface CandidateMatch {
+  candidate_id: string;
+  match_score: number;
+  skill_overlap: number;
+  experience_alignment: number;
+  track_alignment: number;
+  semantic_similarity: number | null;
+  matched_skills: string[];
+  missing_required_skills: string[];
+  missing_preferred_skills: string[];
+  profile: {
+    education_level: string | null;
+    experience_level: 'fresher' | 'junior' | 'mid' | 'senior' | 'lead' | null;
+    experience_years: number | null;
+    preferred_track: string | null;
+    skills: string[];
+    skill_proficiency: Record<string, SkillProficiency>;
+    preferred_locations: string[];
+  };
+  contact_status: ContactRequestStatus | null;
+  // Only once the candidate accepted a contact request
+  contact: {
+    full_name: string;
+    email: string;
+    target_roles: string[];
+    projects: string[];
+  } | null;
+}
+
+export interface JobCandidates {
+  job_id: number;
+  match_strategy: string;
+  total_candidates: number;
+  candidates: CandidateMatch[];
+}
+
+export interface ContactRequest {
+  id: number;
+  job_id: number;
+  candidate_id: string;
+  requested_by: string | null;
+  message: string | null;
+  status: ContactRequestStatus;
+  created_at: string;
+  responded_at: string | null;
+  job_title: string;
+  company: string;
+}
+
+export const candidatesApi = {
+  // Rank discoverable candidates for a company job (employers)
+  getForJob: async (
+    jobId: number,
+    params: { limit?: number; min_score?: number } = {}
+  ): Promise<JobCandidates> => {
+    const token = getToken();
+    const query = new URLSearchParams();
+    if (params.limit !== undefined) query.set('limit', String(params.limit));
+    if (params.min_score !== undefined) query.set('min_score', String(params.min_score));
+    const response = await fetch(`${API_BASE_URL}/employer/jobs/${jobId}/candidates?${query}`, {
+      headers: getHeaders(token),
+    });
+
+    if (response.status === 401) {
+      localStorage.removeItem('authToken');
+      throw new Error('Session expired. Please login again.');
+    }
+
+    if (!response.ok) {
+      const error = await response.json();
+      throw new Error(error.error || 'Failed to fetch candidates');
+    }
+
+    return response.json();
+  },
+
+  // Ask to contact a candidate about a company job (employers)
+  requestContact: async (jobId: number, candidateId: string, message?: string): Promise<any> => {
+    const token = getToken();
+    const response = await fetch(
+      `${API_BASE_URL}/employer/jobs/${jobId}/candidates/${candidateId}/contact`,
+      {
+        method: 'POST',
+        headers: getHeaders(token),
+        body: JSON.stringify({ message }),
+      }
+    );
+
+    if (response.status === 401) {
+      localStorage.removeItem('authToken');
+      throw new Error('Session expired. Please login again.');
+    }
+
+    if (!response.ok) {
+      const error = await response.json();
+      throw new Error(error.error || 'Failed to send contact request');
+    }
+
+    return response.json();
+  },
+
+  // Requests of employers to contact the user
+  getContactRequests: async (): Promise<ContactRequest[]> => {
+    const token = getToken();
+    const response = await fetch(`${API_BASE_URL}/contact-requests`, {
+      headers: getHeaders(token),
+    });
+
+    if (response.status === 401) {
+      localStorage.removeItem('authToken');
+      throw new Error('Session expired. Please login again.');
+    }
+
+    if (!response.ok) {
+      const error = await response.json();
+      throw new Error(error.error || 'Failed to fetch contact requests');
+    }
+
+    return response.json();
+  },
+
+  // Accept (sharing name and email with the company) or decline a request
+  respondToContactRequest: async (
+    requestId: number,
+    status: 'accepted' | 'declined'
+  ): Promise<any> => {
+    const token = getToken();
+    const response = await fetch(`${API_BASE_URL}/contact-requests/${requestId}`, {
+      method: 'PUT',
+      headers: getHeaders(token),
+      body: JSON.stringify({ status }),
+    });
+
+    if (response.status === 401) {
+      localStorage.removeItem('authToken');
+      throw new Error('Session expired. Please login again.');
+    }
+
+    if (!response.ok) {
+      const error = await response.json();
+      throw new Error(error.error || 'Failed to respond to contact request');
+    }
+
+    return response.json();
+  },
+};
+
 // Progress APIs
 export const progressApi = {
   // Start learning resource
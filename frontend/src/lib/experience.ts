@@ -0,0 +1,32 @@
+// Experience levels and the whole years of experience each covers.
+// Mirrors ExperienceLevel::years in backend/src/models.rs, which matching and
+// the experience filters use, so keep the two in sync.
+
+export type ExperienceLevel = 'fresher' | 'junior' | 'mid' | 'senior' | 'lead';
+
+export interface ExperienceLevelRange {
+  value: ExperienceLevel;
+  name: string;
+  minYears: number;
+  // null for open-ended levels
+  maxYears: number | null;
+}
+
+export const EXPERIENCE_LEVELS: ExperienceLevelRange[] = [
+  { value: 'fresher', name: 'Fresher', minYears: 0, maxYears: 0 },
+  { value: 'junior', name: 'Junior', minYears: 1, maxYears: 2 },
+  { value: 'mid', name: 'Mid-level', minYears: 3, maxYears: 4 },
+  { value: 'senior', name: 'Senior', minYears: 5, maxYears: 7 },
+  { value: 'lead', name: 'Lead', minYears: 8, maxYears: null },
+];
+
+// Label such as "Junior (1-2 years)" for a level picker
+export function experienceLabel(level: ExperienceLevelRange): string {
+  if (level.maxYears === null) {
+    return `${level.name} (${level.minYears}+ years)`;
+  }
+  if (level.maxYears === 0) {
+    return `${level.name} (less than 1 year)`;
+  }
+  return `${level.name} (${level.minYears}-${level.maxYears} years)`;
+}